@@ -1,6 +1,12 @@
+mod schema;
+mod validation;
+
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+pub use schema::*;
+pub use validation::*;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum PlutusVersion {
@@ -26,7 +32,7 @@ pub struct Preamble {
     pub license: String,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Reference {
     #[serde(rename = "$ref")]
     pub reference: String,
@@ -119,7 +125,7 @@ pub struct OptionDefinition {
     pub any_of: [serde_json::Value; 2], // Some and None
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ConstructorDefinition {
     pub title: String,
     #[serde(rename = "dataType")]
@@ -157,14 +163,14 @@ pub enum PrimitiveDefinition {
     Bool(BoolDefinition),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Items {
     Single(Reference),
     Multiple(Vec<Reference>),
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum AnyOf {
     Constructors(Vec<ConstructorDefinition>),
@@ -172,7 +178,7 @@ pub enum AnyOf {
     Bool([serde_json::Value; 2]),   // False and True
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct Definition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AnyOf, Blueprint, ConstructorDefinition, Definition, Definitions, Items, Reference};
+use crate::data::{Bool, ByteString, Int, List, Map, PlutusData, PlutusDataJson, Tuple};
+
+/// Emits the CIP-57 definitions of a Rust type, usually through `#[derive(PlutusSchema)]`.
+///
+/// Definition keys follow the Aiken conventions (`Int`, `ByteArray`, `List$Int`,
+/// `Option$ByteArray`, `Pairs$ByteArray_Int`), so the generated fragment can be
+/// compared with a compiler-produced `plutus.json`.
+pub trait PlutusSchema {
+    /// The key of the type under `definitions`
+    fn schema_key() -> String;
+
+    /// Insert the definition of the type and of every type it refers to
+    fn add_definitions(definitions: &mut Definitions);
+
+    fn schema_reference() -> Reference {
+        definition_reference(&Self::schema_key())
+    }
+
+    fn schema_definitions() -> Definitions {
+        let mut definitions = Definitions::new();
+        Self::add_definitions(&mut definitions);
+        definitions
+    }
+}
+
+/// `types/Datum` -> `#/definitions/types~1Datum`
+pub fn definition_reference(key: &str) -> Reference {
+    Reference {
+        reference: format!(
+            "#/definitions/{}",
+            key.replace('~', "~0").replace('/', "~1")
+        ),
+    }
+}
+
+/// A definition generated from Rust that does not match the blueprint
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDrift {
+    pub key: String,
+    pub message: String,
+}
+
+impl Blueprint {
+    /// Compare the definitions generated for `T` against this blueprint.
+    ///
+    /// Descriptions are ignored, everything else (titles, constructor indexes,
+    /// field references, data types) has to match. An empty vector means no drift.
+    pub fn schema_drift<T: PlutusSchema>(&self) -> Vec<SchemaDrift> {
+        let mut generated: Vec<(String, Definition)> =
+            T::schema_definitions().into_iter().collect();
+        generated.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut drifts = vec![];
+        for (key, definition) in generated {
+            let message = match self.definitions.get(&key) {
+                None => "missing from the blueprint".to_string(),
+                Some(existing) => {
+                    let expected = without_description(existing);
+                    let actual = without_description(&definition);
+                    if expected == actual {
+                        continue;
+                    }
+                    format!(
+                        "expected {}, generated {}",
+                        serde_json::to_string(&expected).unwrap_or_default(),
+                        serde_json::to_string(&actual).unwrap_or_default()
+                    )
+                }
+            };
+            drifts.push(SchemaDrift { key, message });
+        }
+        drifts
+    }
+}
+
+fn without_description(definition: &Definition) -> Definition {
+    Definition {
+        description: None,
+        ..definition.clone()
+    }
+}
+
+fn constructor(title: &str, index: u32, fields: Vec<Reference>) -> ConstructorDefinition {
+    ConstructorDefinition {
+        title: title.to_string(),
+        data_type: "constructor".to_string(),
+        index,
+        fields,
+    }
+}
+
+fn insert_definition(definitions: &mut Definitions, key: &str, definition: Definition) {
+    definitions.entry(key.to_string()).or_insert(definition);
+}
+
+impl PlutusSchema for Int {
+    fn schema_key() -> String {
+        "Int".to_string()
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        let definition = Definition {
+            data_type: Some("integer".to_string()),
+            ..Default::default()
+        };
+        insert_definition(definitions, "Int", definition);
+    }
+}
+
+impl PlutusSchema for ByteString {
+    fn schema_key() -> String {
+        "ByteArray".to_string()
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        let definition = Definition {
+            title: Some("ByteArray".to_string()),
+            data_type: Some("bytes".to_string()),
+            ..Default::default()
+        };
+        insert_definition(definitions, "ByteArray", definition);
+    }
+}
+
+impl PlutusSchema for Bool {
+    fn schema_key() -> String {
+        "Bool".to_string()
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        let definition = Definition {
+            title: Some("Bool".to_string()),
+            any_of: Some(AnyOf::Constructors(vec![
+                constructor("False", 0, vec![]),
+                constructor("True", 1, vec![]),
+            ])),
+            ..Default::default()
+        };
+        insert_definition(definitions, "Bool", definition);
+    }
+}
+
+impl PlutusSchema for PlutusData {
+    fn schema_key() -> String {
+        "Data".to_string()
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        let definition = Definition {
+            title: Some("Data".to_string()),
+            description: Some("Any Plutus data.".to_string()),
+            ..Default::default()
+        };
+        insert_definition(definitions, "Data", definition);
+    }
+}
+
+impl<T: PlutusSchema> PlutusSchema for Box<T> {
+    fn schema_key() -> String {
+        T::schema_key()
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        T::add_definitions(definitions)
+    }
+}
+
+impl<T> PlutusSchema for List<T>
+where
+    T: PlutusSchema + PlutusDataJson,
+{
+    fn schema_key() -> String {
+        format!("List${}", T::schema_key())
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        T::add_definitions(definitions);
+        let definition = Definition {
+            data_type: Some("list".to_string()),
+            items: Some(Items::Single(T::schema_reference())),
+            ..Default::default()
+        };
+        insert_definition(definitions, &Self::schema_key(), definition);
+    }
+}
+
+impl<K, V> PlutusSchema for Map<K, V>
+where
+    K: PlutusSchema + PlutusDataJson,
+    V: PlutusSchema + PlutusDataJson,
+{
+    fn schema_key() -> String {
+        format!("Pairs${}_{}", K::schema_key(), V::schema_key())
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        K::add_definitions(definitions);
+        V::add_definitions(definitions);
+        let definition = Definition {
+            title: Some(format!("Pairs<{}, {}>", K::schema_key(), V::schema_key())),
+            data_type: Some("map".to_string()),
+            keys: Some(K::schema_reference()),
+            values: Some(V::schema_reference()),
+            ..Default::default()
+        };
+        insert_definition(definitions, &Self::schema_key(), definition);
+    }
+}
+
+impl<T: PlutusSchema> PlutusSchema for Option<T> {
+    fn schema_key() -> String {
+        format!("Option${}", T::schema_key())
+    }
+
+    fn add_definitions(definitions: &mut Definitions) {
+        T::add_definitions(definitions);
+        let definition = Definition {
+            title: Some("Option".to_string()),
+            any_of: Some(AnyOf::Constructors(vec![
+                constructor("Some", 0, vec![T::schema_reference()]),
+                constructor("None", 1, vec![]),
+            ])),
+            ..Default::default()
+        };
+        insert_definition(definitions, &Self::schema_key(), definition);
+    }
+}
+
+macro_rules! impl_plutus_schema_tuple {
+    ( $( $name:ident )+ ) => {
+        impl<$($name,)+> PlutusSchema for Tuple<($($name,)+)>
+        where
+            $($name: PlutusSchema + PlutusDataJson,)+
+        {
+            fn schema_key() -> String {
+                let keys: Vec<String> = vec![$($name::schema_key(),)+];
+                format!("Tuple${}", keys.join("_"))
+            }
+
+            fn add_definitions(definitions: &mut Definitions) {
+                $($name::add_definitions(definitions);)+
+                let definition = Definition {
+                    title: Some("Tuple".to_string()),
+                    data_type: Some("list".to_string()),
+                    items: Some(Items::Multiple(vec![$($name::schema_reference(),)+])),
+                    ..Default::default()
+                };
+                insert_definition(definitions, &Self::schema_key(), definition);
+            }
+        }
+    };
+}
+
+impl_plutus_schema_tuple!(T1);
+impl_plutus_schema_tuple!(T1 T2);
+impl_plutus_schema_tuple!(T1 T2 T3);
+impl_plutus_schema_tuple!(T1 T2 T3 T4);
+impl_plutus_schema_tuple!(T1 T2 T3 T4 T5);
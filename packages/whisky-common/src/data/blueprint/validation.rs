@@ -0,0 +1,341 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{AnyOf, Blueprint, Definition, Items};
+use crate::WError;
+
+const DEFINITIONS_PREFIX: &str = "#/definitions/";
+
+/// A place where the PlutusData does not conform to the blueprint schema.
+///
+/// `path` points into the detailed schema JSON, e.g. `$.fields[1].list[0]`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SchemaMismatch {
+    pub path: String,
+    pub message: String,
+}
+
+struct Variant {
+    index: u64,
+    fields: Vec<String>,
+}
+
+impl Blueprint {
+    /// Look up a definition either by its `$ref` (`#/definitions/types~1Datum`)
+    /// or by its raw key (`types/Datum`)
+    pub fn resolve_reference(&self, reference: &str) -> Result<&Definition, WError> {
+        let key = match reference.strip_prefix(DEFINITIONS_PREFIX) {
+            Some(pointer) => pointer.replace("~1", "/").replace("~0", "~"),
+            None => reference.to_string(),
+        };
+        self.definitions.get(&key).ok_or_else(|| {
+            WError::new(
+                "resolve_reference",
+                &format!("definition not found: {}", reference),
+            )
+        })
+    }
+
+    /// Validate PlutusData in detailed schema JSON against a blueprint definition.
+    ///
+    /// Returns every mismatch found, an empty vector meaning the data conforms.
+    /// Errors are only returned when the blueprint itself cannot be followed.
+    pub fn validate_plutus_data(
+        &self,
+        reference: &str,
+        data: &Value,
+    ) -> Result<Vec<SchemaMismatch>, WError> {
+        let mut mismatches = vec![];
+        self.validate_reference(reference, data, "$", &mut mismatches)
+            .map_err(WError::add_err_trace("validate_plutus_data"))?;
+        Ok(mismatches)
+    }
+
+    fn validate_reference(
+        &self,
+        reference: &str,
+        data: &Value,
+        path: &str,
+        mismatches: &mut Vec<SchemaMismatch>,
+    ) -> Result<(), WError> {
+        let definition = self.resolve_reference(reference)?;
+        self.validate_definition(definition, data, path, mismatches)
+    }
+
+    fn validate_definition(
+        &self,
+        definition: &Definition,
+        data: &Value,
+        path: &str,
+        mismatches: &mut Vec<SchemaMismatch>,
+    ) -> Result<(), WError> {
+        if let Some(variants) = constructor_variants(definition)? {
+            return self.validate_constructor(&variants, data, path, mismatches);
+        }
+
+        let mut mismatch = |message: String| {
+            mismatches.push(SchemaMismatch {
+                path: path.to_string(),
+                message,
+            })
+        };
+        match definition.data_type.as_deref() {
+            // No dataType nor anyOf is the opaque `Data`, which accepts any PlutusData
+            None => {
+                if describe(data) == "invalid PlutusData" {
+                    mismatch(format!("expected PlutusData, got {}", data));
+                }
+                Ok(())
+            }
+            Some("integer") => {
+                match data.get("int") {
+                    Some(int) if is_integer(int) => {}
+                    Some(int) => mismatch(format!("expected integer, got {}", int)),
+                    None => mismatch(format!("expected integer, got {}", describe(data))),
+                }
+                Ok(())
+            }
+            Some("bytes") => {
+                match data.get("bytes") {
+                    Some(Value::String(bytes)) if is_hex(bytes) => {}
+                    Some(bytes) => mismatch(format!("expected hex bytes, got {}", bytes)),
+                    None => mismatch(format!("expected bytes, got {}", describe(data))),
+                }
+                Ok(())
+            }
+            Some("list") => {
+                let items = match data.get("list") {
+                    Some(Value::Array(items)) => items,
+                    _ => {
+                        mismatch(format!("expected list, got {}", describe(data)));
+                        return Ok(());
+                    }
+                };
+                match &definition.items {
+                    Some(Items::Single(item)) => {
+                        for (i, value) in items.iter().enumerate() {
+                            let item_path = format!("{}.list[{}]", path, i);
+                            self.validate_reference(
+                                &item.reference,
+                                value,
+                                &item_path,
+                                mismatches,
+                            )?;
+                        }
+                    }
+                    Some(Items::Multiple(tuple)) => {
+                        if items.len() != tuple.len() {
+                            mismatch(format!(
+                                "expected tuple of {} items, got {}",
+                                tuple.len(),
+                                items.len()
+                            ));
+                            return Ok(());
+                        }
+                        for (i, (item, value)) in tuple.iter().zip(items).enumerate() {
+                            let item_path = format!("{}.list[{}]", path, i);
+                            self.validate_reference(
+                                &item.reference,
+                                value,
+                                &item_path,
+                                mismatches,
+                            )?;
+                        }
+                    }
+                    // A list without items schema holds arbitrary Data
+                    None => {}
+                }
+                Ok(())
+            }
+            Some("map") => {
+                let entries = match data.get("map") {
+                    Some(Value::Array(entries)) => entries,
+                    _ => {
+                        mismatch(format!("expected map, got {}", describe(data)));
+                        return Ok(());
+                    }
+                };
+                for (i, entry) in entries.iter().enumerate() {
+                    let entry_path = format!("{}.map[{}]", path, i);
+                    let (key, value) = match (entry.get("k"), entry.get("v")) {
+                        (Some(key), Some(value)) => (key, value),
+                        _ => {
+                            mismatches.push(SchemaMismatch {
+                                path: entry_path,
+                                message: "expected map entry with `k` and `v`".to_string(),
+                            });
+                            continue;
+                        }
+                    };
+                    if let Some(keys) = &definition.keys {
+                        let key_path = format!("{}.k", entry_path);
+                        self.validate_reference(&keys.reference, key, &key_path, mismatches)?;
+                    }
+                    if let Some(values) = &definition.values {
+                        let value_path = format!("{}.v", entry_path);
+                        self.validate_reference(&values.reference, value, &value_path, mismatches)?;
+                    }
+                }
+                Ok(())
+            }
+            Some(other) => Err(WError::new(
+                "validate_definition",
+                &format!("unsupported dataType: {}", other),
+            )),
+        }
+    }
+
+    fn validate_constructor(
+        &self,
+        variants: &[Variant],
+        data: &Value,
+        path: &str,
+        mismatches: &mut Vec<SchemaMismatch>,
+    ) -> Result<(), WError> {
+        let (index, fields) = match (
+            data.get("constructor").and_then(Value::as_u64),
+            data.get("fields").and_then(Value::as_array),
+        ) {
+            (Some(index), Some(fields)) => (index, fields),
+            _ => {
+                mismatches.push(SchemaMismatch {
+                    path: path.to_string(),
+                    message: format!("expected constructor, got {}", describe(data)),
+                });
+                return Ok(());
+            }
+        };
+
+        let variant = match variants.iter().find(|variant| variant.index == index) {
+            Some(variant) => variant,
+            None => {
+                let expected: Vec<String> = variants.iter().map(|v| v.index.to_string()).collect();
+                mismatches.push(SchemaMismatch {
+                    path: format!("{}.constructor", path),
+                    message: format!(
+                        "expected constructor index one of [{}], got {}",
+                        expected.join(", "),
+                        index
+                    ),
+                });
+                return Ok(());
+            }
+        };
+
+        for (i, reference) in variant.fields.iter().enumerate() {
+            let field_path = format!("{}.fields[{}]", path, i);
+            match fields.get(i) {
+                Some(field) => {
+                    self.validate_reference(reference, field, &field_path, mismatches)?
+                }
+                None => mismatches.push(SchemaMismatch {
+                    path: field_path,
+                    message: format!(
+                        "missing field of constructor {}, expected {} fields",
+                        index,
+                        variant.fields.len()
+                    ),
+                }),
+            }
+        }
+        for i in variant.fields.len()..fields.len() {
+            mismatches.push(SchemaMismatch {
+                path: format!("{}.fields[{}]", path, i),
+                message: format!(
+                    "unexpected field of constructor {}, expected {} fields",
+                    index,
+                    variant.fields.len()
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Normalize the constructor shapes of a definition, whether given as `anyOf`
+/// (custom types, `Option`, `Bool`) or as a single `constructor` dataType
+fn constructor_variants(definition: &Definition) -> Result<Option<Vec<Variant>>, WError> {
+    match &definition.any_of {
+        Some(AnyOf::Constructors(constructors)) => Ok(Some(
+            constructors
+                .iter()
+                .map(|constructor| Variant {
+                    index: constructor.index as u64,
+                    fields: constructor
+                        .fields
+                        .iter()
+                        .map(|field| field.reference.clone())
+                        .collect(),
+                })
+                .collect(),
+        )),
+        Some(AnyOf::Option(constructors)) | Some(AnyOf::Bool(constructors)) => constructors
+            .iter()
+            .map(raw_variant)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some),
+        None if definition.data_type.as_deref() == Some("constructor") => Ok(Some(vec![Variant {
+            index: definition.index.unwrap_or_default() as u64,
+            fields: definition
+                .fields
+                .iter()
+                .flatten()
+                .map(|field| field.reference.clone())
+                .collect(),
+        }])),
+        None => Ok(None),
+    }
+}
+
+fn raw_variant(constructor: &Value) -> Result<Variant, WError> {
+    let index = constructor
+        .get("index")
+        .and_then(Value::as_u64)
+        .ok_or_else(WError::from_opt("raw_variant", "constructor without index"))?;
+    let fields = match constructor.get("fields") {
+        Some(Value::Array(fields)) => fields
+            .iter()
+            .map(|field| {
+                field
+                    .get("$ref")
+                    .and_then(Value::as_str)
+                    .map(String::from)
+                    .ok_or_else(WError::from_opt("raw_variant", "field without $ref"))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => vec![],
+    };
+    Ok(Variant { index, fields })
+}
+
+fn describe(data: &Value) -> &'static str {
+    let keys = match data.as_object() {
+        Some(object) => object,
+        None => return "invalid PlutusData",
+    };
+    if keys.contains_key("int") {
+        "integer"
+    } else if keys.contains_key("bytes") {
+        "bytes"
+    } else if keys.contains_key("list") {
+        "list"
+    } else if keys.contains_key("map") {
+        "map"
+    } else if keys.contains_key("constructor") {
+        "constructor"
+    } else {
+        "invalid PlutusData"
+    }
+}
+
+// Integers beyond 64 bits are parsed by serde_json as floats, or decoded from CBOR as decimal strings
+fn is_integer(value: &Value) -> bool {
+    value.is_i64()
+        || value.is_u64()
+        || value.as_f64().is_some_and(|f| f.fract() == 0.0)
+        || value.as_str().is_some_and(|s| s.parse::<i128>().is_ok())
+}
+
+fn is_hex(value: &str) -> bool {
+    value.len().is_multiple_of(2) && value.chars().all(|c| c.is_ascii_hexdigit())
+}
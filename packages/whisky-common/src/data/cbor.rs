@@ -0,0 +1,397 @@
+use serde_json::{json, Map as JsonMap, Value};
+
+use crate::WError;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+const INDEFINITE: u8 = 31;
+const BREAK: u8 = 0xff;
+
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+const TAG_GENERAL_CONSTR: u64 = 102;
+const TAG_COMPACT_CONSTR: u64 = 121; // constructors 0 - 6
+const TAG_EXTENDED_CONSTR: u64 = 1280; // constructors 7 - 127
+
+/// Bytes longer than this are encoded as an indefinite byte string of 64-byte chunks
+const BYTES_CHUNK_SIZE: usize = 64;
+
+/// Encode PlutusData in detailed schema JSON to CBOR.
+///
+/// Follows the encoding of the Plutus reference implementation (and CSL):
+/// non-empty lists and constructor fields are indefinite-length, maps are definite,
+/// bytes over 64 bytes are chunked, constructors use the compact tags 121-127 and 1280+,
+/// falling back to tag 102 for indexes above 127, and integers beyond 64 bits are bignums.
+pub fn encode_plutus_data(data: &Value) -> Result<Vec<u8>, WError> {
+    let mut buffer = vec![];
+    write_data(&mut buffer, data).map_err(WError::add_err_trace("encode_plutus_data"))?;
+    Ok(buffer)
+}
+
+/// Decode PlutusData CBOR into detailed schema JSON.
+///
+/// Definite and indefinite lengths, chunked bytes and all constructor tag forms are accepted.
+/// Integers outside the 64-bit range are given as decimal strings, as accepted by `Int::from_json`.
+pub fn decode_plutus_data(cbor: &[u8]) -> Result<Value, WError> {
+    let mut decoder = Decoder { cbor, position: 0 };
+    let data = decoder
+        .read_data()
+        .map_err(WError::add_err_trace("decode_plutus_data"))?;
+    if decoder.position != cbor.len() {
+        return Err(WError::new(
+            "decode_plutus_data",
+            &format!(
+                "trailing bytes after PlutusData at position {}",
+                decoder.position
+            ),
+        ));
+    }
+    Ok(data)
+}
+
+fn write_head(buffer: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    if value < 24 {
+        buffer.push(major | value as u8);
+    } else if value <= u8::MAX as u64 {
+        buffer.push(major | 24);
+        buffer.push(value as u8);
+    } else if value <= u16::MAX as u64 {
+        buffer.push(major | 25);
+        buffer.extend_from_slice(&(value as u16).to_be_bytes());
+    } else if value <= u32::MAX as u64 {
+        buffer.push(major | 26);
+        buffer.extend_from_slice(&(value as u32).to_be_bytes());
+    } else {
+        buffer.push(major | 27);
+        buffer.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn write_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    if bytes.len() <= BYTES_CHUNK_SIZE {
+        write_head(buffer, MAJOR_BYTES, bytes.len() as u64);
+        buffer.extend_from_slice(bytes);
+    } else {
+        buffer.push(MAJOR_BYTES << 5 | INDEFINITE);
+        for chunk in bytes.chunks(BYTES_CHUNK_SIZE) {
+            write_head(buffer, MAJOR_BYTES, chunk.len() as u64);
+            buffer.extend_from_slice(chunk);
+        }
+        buffer.push(BREAK);
+    }
+}
+
+fn write_list(buffer: &mut Vec<u8>, items: &[Value]) -> Result<(), WError> {
+    if items.is_empty() {
+        write_head(buffer, MAJOR_ARRAY, 0);
+        return Ok(());
+    }
+    buffer.push(MAJOR_ARRAY << 5 | INDEFINITE);
+    for item in items {
+        write_data(buffer, item)?;
+    }
+    buffer.push(BREAK);
+    Ok(())
+}
+
+fn write_integer(buffer: &mut Vec<u8>, int: i128) {
+    let (major, tag, magnitude) = if int >= 0 {
+        (MAJOR_UNSIGNED, TAG_POSITIVE_BIGNUM, int as u128)
+    } else {
+        // CBOR negative integers encode -1 - n
+        (MAJOR_NEGATIVE, TAG_NEGATIVE_BIGNUM, (-1 - int) as u128)
+    };
+    match u64::try_from(magnitude) {
+        Ok(value) => write_head(buffer, major, value),
+        Err(_) => {
+            write_head(buffer, MAJOR_TAG, tag);
+            let bytes = magnitude.to_be_bytes();
+            let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+            write_bytes(buffer, &bytes[start..]);
+        }
+    }
+}
+
+fn write_data(buffer: &mut Vec<u8>, data: &Value) -> Result<(), WError> {
+    if let Some(int) = data.get("int") {
+        write_integer(buffer, json_integer(int)?);
+    } else if let Some(bytes) = data.get("bytes") {
+        let bytes = bytes
+            .as_str()
+            .ok_or_else(WError::from_opt("write_data", "bytes must be a hex string"))
+            .and_then(|hex_str| hex::decode(hex_str).map_err(WError::from_err("write_data")))?;
+        write_bytes(buffer, &bytes);
+    } else if let Some(list) = data.get("list") {
+        let items = list
+            .as_array()
+            .ok_or_else(WError::from_opt("write_data", "list must be an array"))?;
+        write_list(buffer, items)?;
+    } else if let Some(map) = data.get("map") {
+        let entries = map
+            .as_array()
+            .ok_or_else(WError::from_opt("write_data", "map must be an array"))?;
+        write_head(buffer, MAJOR_MAP, entries.len() as u64);
+        for entry in entries {
+            match (entry.get("k"), entry.get("v")) {
+                (Some(key), Some(value)) => {
+                    write_data(buffer, key)?;
+                    write_data(buffer, value)?;
+                }
+                _ => return Err(WError::new("write_data", "map entry must have `k` and `v`")),
+            }
+        }
+    } else if let Some(constructor) = data.get("constructor") {
+        let index = constructor
+            .as_u64()
+            .ok_or_else(WError::from_opt("write_data", "invalid constructor index"))?;
+        let fields = data
+            .get("fields")
+            .and_then(Value::as_array)
+            .ok_or_else(WError::from_opt(
+                "write_data",
+                "constructor fields must be an array",
+            ))?;
+        match index {
+            0..=6 => write_head(buffer, MAJOR_TAG, TAG_COMPACT_CONSTR + index),
+            7..=127 => write_head(buffer, MAJOR_TAG, TAG_EXTENDED_CONSTR + index - 7),
+            _ => {
+                write_head(buffer, MAJOR_TAG, TAG_GENERAL_CONSTR);
+                write_head(buffer, MAJOR_ARRAY, 2);
+                write_head(buffer, MAJOR_UNSIGNED, index);
+            }
+        }
+        write_list(buffer, fields)?;
+    } else {
+        return Err(WError::new(
+            "write_data",
+            &format!("unrecognized PlutusData JSON: {}", data),
+        ));
+    }
+    Ok(())
+}
+
+fn json_integer(int: &Value) -> Result<i128, WError> {
+    int.as_i64()
+        .map(|v| v as i128)
+        .or_else(|| int.as_u64().map(|v| v as i128))
+        .or_else(|| int.as_str().and_then(|s| s.parse::<i128>().ok()))
+        .ok_or_else(|| WError::new("json_integer", &format!("invalid integer: {}", int)))
+}
+
+struct Decoder<'a> {
+    cbor: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn read_u8(&mut self) -> Result<u8, WError> {
+        let byte = *self
+            .cbor
+            .get(self.position)
+            .ok_or_else(|| WError::new("Decoder::read_u8", "unexpected end of CBOR input"))?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn peek_break(&self) -> bool {
+        self.cbor.get(self.position) == Some(&BREAK)
+    }
+
+    fn read_slice(&mut self, length: u64) -> Result<&'a [u8], WError> {
+        let end = usize::try_from(length)
+            .ok()
+            .and_then(|length| self.position.checked_add(length))
+            .filter(|end| *end <= self.cbor.len())
+            .ok_or_else(|| WError::new("Decoder::read_slice", "unexpected end of CBOR input"))?;
+        let slice = &self.cbor[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    /// Reads an item head, the length being `None` for indefinite-length items
+    fn read_head(&mut self) -> Result<(u8, Option<u64>), WError> {
+        let initial = self.read_u8()?;
+        let major = initial >> 5;
+        let additional = initial & 0x1f;
+        let value = match additional {
+            0..=23 => Some(additional as u64),
+            24 => Some(self.read_u8()? as u64),
+            25 => Some(u16::from_be_bytes(self.read_slice(2)?.try_into().unwrap()) as u64),
+            26 => Some(u32::from_be_bytes(self.read_slice(4)?.try_into().unwrap()) as u64),
+            27 => Some(u64::from_be_bytes(self.read_slice(8)?.try_into().unwrap())),
+            INDEFINITE if matches!(major, MAJOR_BYTES | MAJOR_ARRAY | MAJOR_MAP) => None,
+            _ => {
+                return Err(WError::new(
+                    "Decoder::read_head",
+                    &format!("invalid CBOR head 0x{:02x}", initial),
+                ))
+            }
+        };
+        Ok((major, value))
+    }
+
+    fn read_definite(&mut self, expected_major: u8) -> Result<u64, WError> {
+        match self.read_head()? {
+            (major, Some(value)) if major == expected_major => Ok(value),
+            (major, _) => Err(WError::new(
+                "Decoder::read_definite",
+                &format!("expected CBOR major type {}, got {}", expected_major, major),
+            )),
+        }
+    }
+
+    /// Reads the content of a byte string whose head has been consumed
+    fn read_bytes(&mut self, length: Option<u64>) -> Result<Vec<u8>, WError> {
+        match length {
+            Some(length) => Ok(self.read_slice(length)?.to_vec()),
+            None => {
+                let mut bytes = vec![];
+                while !self.peek_break() {
+                    let chunk_length = self.read_definite(MAJOR_BYTES)?;
+                    bytes.extend_from_slice(self.read_slice(chunk_length)?);
+                }
+                self.position += 1;
+                Ok(bytes)
+            }
+        }
+    }
+
+    fn read_list(&mut self) -> Result<Vec<Value>, WError> {
+        match self.read_head()? {
+            (MAJOR_ARRAY, length) => self.read_items(length),
+            (major, _) => Err(WError::new(
+                "Decoder::read_list",
+                &format!("expected CBOR array, got major type {}", major),
+            )),
+        }
+    }
+
+    fn read_items(&mut self, length: Option<u64>) -> Result<Vec<Value>, WError> {
+        let mut items = vec![];
+        match length {
+            Some(length) => {
+                for _ in 0..length {
+                    items.push(self.read_data()?);
+                }
+            }
+            None => {
+                while !self.peek_break() {
+                    items.push(self.read_data()?);
+                }
+                self.position += 1;
+            }
+        }
+        Ok(items)
+    }
+
+    fn read_bignum(&mut self, negative: bool) -> Result<Value, WError> {
+        let length = match self.read_head()? {
+            (MAJOR_BYTES, length) => length,
+            (major, _) => {
+                return Err(WError::new(
+                    "Decoder::read_bignum",
+                    &format!("expected bignum bytes, got major type {}", major),
+                ))
+            }
+        };
+        let bytes = self.read_bytes(length)?;
+        let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+        let magnitude = match bytes[start..].len() {
+            0..=16 => bytes[start..]
+                .iter()
+                .fold(0u128, |acc, b| (acc << 8) | *b as u128),
+            _ => u128::MAX,
+        };
+        let magnitude = i128::try_from(magnitude).map_err(|_| {
+            WError::new(
+                "Decoder::read_bignum",
+                "integer exceeds the supported 128-bit range",
+            )
+        })?;
+        let int = if negative { -1 - magnitude } else { magnitude };
+        Ok(integer_json(int))
+    }
+
+    fn read_data(&mut self) -> Result<Value, WError> {
+        let (major, value) = self.read_head()?;
+        match (major, value) {
+            (MAJOR_UNSIGNED, Some(value)) => Ok(json!({ "int": value })),
+            (MAJOR_NEGATIVE, Some(value)) => Ok(integer_json(-1 - value as i128)),
+            (MAJOR_BYTES, length) => {
+                let bytes = self.read_bytes(length)?;
+                Ok(json!({ "bytes": hex::encode(bytes) }))
+            }
+            (MAJOR_ARRAY, length) => Ok(json!({ "list": self.read_items(length)? })),
+            (MAJOR_MAP, length) => {
+                let mut entries = vec![];
+                let mut read_entry = |decoder: &mut Self| -> Result<(), WError> {
+                    let key = decoder.read_data()?;
+                    let value = decoder.read_data()?;
+                    let mut entry = JsonMap::new();
+                    entry.insert("k".to_string(), key);
+                    entry.insert("v".to_string(), value);
+                    entries.push(Value::Object(entry));
+                    Ok(())
+                };
+                match length {
+                    Some(length) => {
+                        for _ in 0..length {
+                            read_entry(self)?;
+                        }
+                    }
+                    None => {
+                        while !self.peek_break() {
+                            read_entry(self)?;
+                        }
+                        self.position += 1;
+                    }
+                }
+                Ok(json!({ "map": entries }))
+            }
+            (MAJOR_TAG, Some(TAG_POSITIVE_BIGNUM)) => self.read_bignum(false),
+            (MAJOR_TAG, Some(TAG_NEGATIVE_BIGNUM)) => self.read_bignum(true),
+            (MAJOR_TAG, Some(tag @ 121..=127)) => Ok(json!({
+                "constructor": tag - TAG_COMPACT_CONSTR,
+                "fields": self.read_list()?,
+            })),
+            (MAJOR_TAG, Some(tag @ 1280..=1400)) => Ok(json!({
+                "constructor": tag - TAG_EXTENDED_CONSTR + 7,
+                "fields": self.read_list()?,
+            })),
+            (MAJOR_TAG, Some(TAG_GENERAL_CONSTR)) => {
+                let length = self.read_definite(MAJOR_ARRAY)?;
+                if length != 2 {
+                    return Err(WError::new(
+                        "Decoder::read_data",
+                        "tag 102 constructor must be a pair of index and fields",
+                    ));
+                }
+                let index = self.read_definite(MAJOR_UNSIGNED)?;
+                Ok(json!({ "constructor": index, "fields": self.read_list()? }))
+            }
+            _ => Err(WError::new(
+                "Decoder::read_data",
+                &format!(
+                    "unsupported CBOR item at position {}",
+                    self.position.saturating_sub(1)
+                ),
+            )),
+        }
+    }
+}
+
+fn integer_json(int: i128) -> Value {
+    if let Ok(int) = i64::try_from(int) {
+        json!({ "int": int })
+    } else if let Ok(int) = u64::try_from(int) {
+        json!({ "int": int })
+    } else {
+        json!({ "int": int.to_string() })
+    }
+}
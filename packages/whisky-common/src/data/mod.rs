@@ -1,16 +1,22 @@
 mod aliases;
 pub mod blueprint;  // Public so blueprint types can be accessed via data::blueprint::
+mod cbor;
 mod credentials;
+mod pretty;
 mod primitives;
 mod value;
+mod value_delta;
 use std::fmt::Debug;
 
 pub use aliases::*;
+pub use cbor::*;
 // Note: Blueprint types are NOT wildcard exported to avoid conflicts
 // Access them via whisky::data::blueprint::TypeName
 pub use credentials::*;
+pub use pretty::*;
 pub use primitives::*;
 pub use value::*;
+pub use value_delta::*;
 
 use crate::WError;
 
@@ -30,6 +36,33 @@ pub trait PlutusDataJson: Clone + Debug + Sized {
         Self::from_json(&value)
     }
 
+    /// Serialize to PlutusData CBOR bytes, see [`encode_plutus_data`] for the encoding choices
+    fn to_cbor_bytes(&self) -> Result<Vec<u8>, WError> {
+        encode_plutus_data(&self.to_json())
+    }
+
+    /// Serialize to PlutusData CBOR hex
+    fn to_cbor(&self) -> Result<String, WError> {
+        self.to_cbor_bytes().map(hex::encode)
+    }
+
+    /// Human-readable rendering, see [`pretty_plutus_data`]
+    fn to_pretty(&self) -> String {
+        pretty_plutus_data(&self.to_json())
+    }
+
+    /// Parse from PlutusData CBOR bytes, e.g. an inline datum
+    fn from_cbor_bytes(cbor: &[u8]) -> Result<Self, WError> {
+        let value = decode_plutus_data(cbor)?;
+        Self::from_json(&value)
+    }
+
+    /// Parse from PlutusData CBOR hex
+    fn from_cbor(cbor_hex: &str) -> Result<Self, WError> {
+        let cbor = hex::decode(cbor_hex).map_err(WError::from_err("PlutusDataJson::from_cbor"))?;
+        Self::from_cbor_bytes(&cbor)
+    }
+
     /// Parse from constructor fields array (the inverse of to_constr_field).
     /// Default implementation handles single-element arrays by extracting the first element.
     fn from_constr_field(fields: &serde_json::Value) -> Result<Self, WError> {
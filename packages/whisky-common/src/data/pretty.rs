@@ -0,0 +1,346 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+use super::decode_plutus_data;
+use crate::WError;
+
+const MAX_LINE_WIDTH: usize = 80;
+const INDENT: &str = "  ";
+
+/// Render PlutusData in detailed schema JSON for humans.
+///
+/// Constructors are printed as `Constr 0 [..]`, byte strings as `"text"` when they are
+/// printable UTF-8 and `h'..'` otherwise, and addresses and asset classes are recognized
+/// by shape. Values that fit on a line are kept on one line.
+pub fn pretty_plutus_data(data: &Value) -> String {
+    render(data, 0)
+}
+
+/// Render PlutusData CBOR hex for humans, see [`pretty_plutus_data`]
+pub fn pretty_plutus_data_cbor(cbor_hex: &str) -> Result<String, WError> {
+    let cbor = hex::decode(cbor_hex).map_err(WError::from_err("pretty_plutus_data_cbor"))?;
+    let data =
+        decode_plutus_data(&cbor).map_err(WError::add_err_trace("pretty_plutus_data_cbor"))?;
+    Ok(pretty_plutus_data(&data))
+}
+
+/// One place where two PlutusData differ.
+///
+/// `path` points into the detailed schema JSON, e.g. `$.fields[1].list[0]`, and both
+/// sides are rendered on a single line, `<missing>` standing for an absent item.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DataDiff {
+    pub path: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for DataDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.path, self.expected, self.actual
+        )
+    }
+}
+
+/// Structural diff of two PlutusData in detailed schema JSON.
+///
+/// Constructors with the same index, lists and maps are compared item by item so the
+/// differences point at the innermost field; an empty vector means the data are equal.
+pub fn diff_plutus_data(expected: &Value, actual: &Value) -> Vec<DataDiff> {
+    let mut diffs = vec![];
+    diff_at(Some(expected), Some(actual), "$", false, &mut diffs);
+    diffs
+}
+
+/// Partial structural diff, see [`diff_plutus_data`].
+///
+/// A JSON `null` anywhere in `expected` matches whatever PlutusData sits at that place in
+/// `actual`, so only the fields of interest need spelling out.
+pub fn partial_diff_plutus_data(expected: &Value, actual: &Value) -> Vec<DataDiff> {
+    let mut diffs = vec![];
+    diff_at(Some(expected), Some(actual), "$", true, &mut diffs);
+    diffs
+}
+
+fn diff_at(
+    expected: Option<&Value>,
+    actual: Option<&Value>,
+    path: &str,
+    partial: bool,
+    diffs: &mut Vec<DataDiff>,
+) {
+    if partial && expected.is_some_and(Value::is_null) && actual.is_some() {
+        return;
+    }
+    let (expected, actual) = match (expected, actual) {
+        (Some(expected), Some(actual)) => (expected, actual),
+        (expected, actual) => {
+            diffs.push(DataDiff {
+                path: path.to_string(),
+                expected: expected.map_or("<missing>".to_string(), render_inline),
+                actual: actual.map_or("<missing>".to_string(), render_inline),
+            });
+            return;
+        }
+    };
+    if expected == actual {
+        return;
+    }
+
+    if let (Some(expected_index), Some(actual_index)) =
+        (expected.get("constructor"), actual.get("constructor"))
+    {
+        if expected_index == actual_index {
+            return diff_items(
+                items(expected, "fields"),
+                items(actual, "fields"),
+                &format!("{}.fields", path),
+                partial,
+                diffs,
+            );
+        }
+    } else if expected.get("list").is_some() && actual.get("list").is_some() {
+        return diff_items(
+            items(expected, "list"),
+            items(actual, "list"),
+            &format!("{}.list", path),
+            partial,
+            diffs,
+        );
+    } else if expected.get("map").is_some() && actual.get("map").is_some() {
+        let expected_entries = items(expected, "map");
+        let actual_entries = items(actual, "map");
+        for i in 0..expected_entries.len().max(actual_entries.len()) {
+            let entry_path = format!("{}.map[{}]", path, i);
+            match (expected_entries.get(i), actual_entries.get(i)) {
+                (Some(expected_entry), Some(actual_entry)) => {
+                    for side in ["k", "v"] {
+                        diff_at(
+                            expected_entry.get(side),
+                            actual_entry.get(side),
+                            &format!("{}.{}", entry_path, side),
+                            partial,
+                            diffs,
+                        );
+                    }
+                }
+                (expected_entry, actual_entry) => diffs.push(DataDiff {
+                    path: entry_path,
+                    expected: expected_entry.map_or("<missing>".to_string(), render_entry),
+                    actual: actual_entry.map_or("<missing>".to_string(), render_entry),
+                }),
+            }
+        }
+        return;
+    }
+
+    diffs.push(DataDiff {
+        path: path.to_string(),
+        expected: render_inline(expected),
+        actual: render_inline(actual),
+    });
+}
+
+fn diff_items(
+    expected: &[Value],
+    actual: &[Value],
+    path: &str,
+    partial: bool,
+    diffs: &mut Vec<DataDiff>,
+) {
+    for i in 0..expected.len().max(actual.len()) {
+        diff_at(
+            expected.get(i),
+            actual.get(i),
+            &format!("{}[{}]", path, i),
+            partial,
+            diffs,
+        );
+    }
+}
+
+fn items<'a>(data: &'a Value, key: &str) -> &'a [Value] {
+    data.get(key)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+fn render(data: &Value, depth: usize) -> String {
+    // Recognized shapes stay on one line whatever their width
+    if let Some(known) = render_address(data).or_else(|| render_asset_class(data)) {
+        return known;
+    }
+    let inline = render_inline(data);
+    if inline.len() + depth * INDENT.len() <= MAX_LINE_WIDTH {
+        return inline;
+    }
+
+    let indent = INDENT.repeat(depth + 1);
+    let close_indent = INDENT.repeat(depth);
+    let block = |open: &str, lines: Vec<String>, close: &str| {
+        format!(
+            "{}\n{}\n{}{}",
+            open,
+            lines
+                .iter()
+                .map(|line| format!("{}{},", indent, line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            close_indent,
+            close
+        )
+    };
+
+    if let Some(index) = data.get("constructor") {
+        let lines = items(data, "fields")
+            .iter()
+            .map(|field| render(field, depth + 1))
+            .collect();
+        block(&format!("Constr {} [", index), lines, "]")
+    } else if data.get("list").is_some() {
+        let lines = items(data, "list")
+            .iter()
+            .map(|item| render(item, depth + 1))
+            .collect();
+        block("[", lines, "]")
+    } else if data.get("map").is_some() {
+        let lines = items(data, "map")
+            .iter()
+            .map(|entry| {
+                let key = entry.get("k").map_or(String::new(), render_inline);
+                let value = entry
+                    .get("v")
+                    .map_or(String::new(), |v| render(v, depth + 1));
+                format!("{}: {}", key, value)
+            })
+            .collect();
+        block("{", lines, "}")
+    } else {
+        inline
+    }
+}
+
+fn render_inline(data: &Value) -> String {
+    if let Some(int) = data.get("int") {
+        // Big integers decoded from CBOR are kept as decimal strings
+        return match int.as_str() {
+            Some(int) => int.to_string(),
+            None => int.to_string(),
+        };
+    }
+    if let Some(bytes) = data.get("bytes").and_then(Value::as_str) {
+        return render_bytes(bytes);
+    }
+    if let Some(list) = data.get("list").and_then(Value::as_array) {
+        let rendered: Vec<String> = list.iter().map(render_inline).collect();
+        return format!("[{}]", rendered.join(", "));
+    }
+    if let Some(map) = data.get("map").and_then(Value::as_array) {
+        let rendered: Vec<String> = map.iter().map(render_entry).collect();
+        return format!("{{{}}}", rendered.join(", "));
+    }
+    if let Some(index) = data.get("constructor") {
+        if let Some(address) = render_address(data) {
+            return address;
+        }
+        if let Some(asset_class) = render_asset_class(data) {
+            return asset_class;
+        }
+        let rendered: Vec<String> = items(data, "fields").iter().map(render_inline).collect();
+        return format!("Constr {} [{}]", index, rendered.join(", "));
+    }
+    data.to_string()
+}
+
+fn render_entry(entry: &Value) -> String {
+    let key = entry.get("k").map_or(String::new(), render_inline);
+    let value = entry.get("v").map_or(String::new(), render_inline);
+    format!("{}: {}", key, value)
+}
+
+fn render_bytes(bytes_hex: &str) -> String {
+    if let Ok(bytes) = hex::decode(bytes_hex) {
+        if let Ok(text) = String::from_utf8(bytes) {
+            if !text.is_empty() && text.chars().all(|c| !c.is_control()) {
+                return format!("{:?}", text);
+            }
+        }
+    }
+    format!("h'{}'", bytes_hex)
+}
+
+fn constructor_parts(data: &Value) -> Option<(u64, &[Value])> {
+    let index = data.get("constructor")?.as_u64()?;
+    let fields = data.get("fields")?.as_array()?;
+    Some((index, fields.as_slice()))
+}
+
+fn bytes_of_len(data: &Value, hex_lens: &[usize]) -> Option<String> {
+    let bytes = data.get("bytes")?.as_str()?;
+    hex_lens.contains(&bytes.len()).then(|| bytes.to_string())
+}
+
+/// `Constr 0 [h'..']` -> `key h'..'`, `Constr 1 [h'..']` -> `script h'..'`
+fn render_credential(data: &Value) -> Option<String> {
+    match constructor_parts(data)? {
+        (0, [hash]) => Some(format!("key h'{}'", bytes_of_len(hash, &[56])?)),
+        (1, [hash]) => Some(format!("script h'{}'", bytes_of_len(hash, &[56])?)),
+        _ => None,
+    }
+}
+
+fn render_stake_credential(data: &Value) -> Option<String> {
+    match constructor_parts(data)? {
+        (1, []) => Some("none".to_string()),
+        (0, [referenced]) => match constructor_parts(referenced)? {
+            (0, [credential]) => render_credential(credential),
+            (1, [slot, tx_index, cert_index]) => Some(format!(
+                "pointer({}, {}, {})",
+                render_inline(slot),
+                render_inline(tx_index),
+                render_inline(cert_index)
+            )),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `Address(payment: key h'..', stake: script h'..')`
+fn render_address(data: &Value) -> Option<String> {
+    match constructor_parts(data)? {
+        (0, [payment, stake]) => Some(format!(
+            "Address(payment: {}, stake: {})",
+            render_credential(payment)?,
+            render_stake_credential(stake)?
+        )),
+        _ => None,
+    }
+}
+
+/// `AssetClass(h'<policy id>', "name")`, or `AssetClass(lovelace)`
+fn render_asset_class(data: &Value) -> Option<String> {
+    match constructor_parts(data)? {
+        (0, [policy_id, asset_name]) => {
+            let policy_id = bytes_of_len(policy_id, &[0, 56])?;
+            let asset_name = asset_name.get("bytes")?.as_str()?;
+            if asset_name.len() > 64 {
+                return None;
+            }
+            if policy_id.is_empty() && asset_name.is_empty() {
+                return Some("AssetClass(lovelace)".to_string());
+            }
+            Some(format!(
+                "AssetClass(h'{}', {})",
+                policy_id,
+                render_bytes(asset_name)
+            ))
+        }
+        _ => None,
+    }
+}
@@ -43,6 +43,18 @@ impl PlutusDataJson for Bool {
     }
 }
 
+impl From<bool> for Bool {
+    fn from(b: bool) -> Self {
+        Bool::new(b)
+    }
+}
+
+impl From<Bool> for bool {
+    fn from(b: Bool) -> Self {
+        b == Bool::True
+    }
+}
+
 pub fn bool(b: bool) -> Value {
     if b {
         constr1(json!([]))
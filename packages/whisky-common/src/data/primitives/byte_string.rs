@@ -32,6 +32,24 @@ impl PlutusDataJson for ByteString {
     }
 }
 
+impl From<&str> for ByteString {
+    fn from(bytes: &str) -> Self {
+        ByteString::new(bytes)
+    }
+}
+
+impl From<String> for ByteString {
+    fn from(bytes: String) -> Self {
+        ByteString { bytes }
+    }
+}
+
+impl From<ByteString> for String {
+    fn from(byte_string: ByteString) -> Self {
+        byte_string.bytes
+    }
+}
+
 pub fn byte_string(bytes: &str) -> Value {
     json!({ "bytes": bytes })
 }
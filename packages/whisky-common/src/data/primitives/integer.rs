@@ -37,6 +37,46 @@ impl PlutusDataJson for Int {
     }
 }
 
+impl From<i128> for Int {
+    fn from(int: i128) -> Self {
+        Int { int }
+    }
+}
+
+impl From<Int> for i128 {
+    fn from(int: Int) -> Self {
+        int.int
+    }
+}
+
+// Conversions for `#[plutus(as = "Int")]` fields of native integer types
+macro_rules! impl_int_conversions {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for Int {
+                fn from(int: $ty) -> Self {
+                    Int { int: int as i128 }
+                }
+            }
+
+            impl TryFrom<Int> for $ty {
+                type Error = WError;
+
+                fn try_from(int: Int) -> Result<Self, Self::Error> {
+                    <$ty>::try_from(int.int).map_err(|_| {
+                        WError::new(
+                            concat!("Int::try_into::<", stringify!($ty), ">"),
+                            &format!("{} is out of range", int.int),
+                        )
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_int_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
 pub fn integer(int: i128) -> Value {
     json!({ "int": int })
 }
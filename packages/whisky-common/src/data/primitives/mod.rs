@@ -4,6 +4,7 @@ mod constructors;
 mod integer;
 mod list;
 mod map;
+mod option;
 mod tuple;
 pub use boolean::*;
 pub use byte_string::*;
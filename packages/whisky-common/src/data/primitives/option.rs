@@ -0,0 +1,47 @@
+use serde_json::{json, Value};
+
+use crate::{
+    data::{constr0, constr1, PlutusDataJson},
+    WError,
+};
+
+/// `Option<T>` follows Aiken's `Option`: `Some(x)` is constructor 0 with a single field,
+/// `None` is constructor 1 without fields
+impl<T> PlutusDataJson for Option<T>
+where
+    T: PlutusDataJson,
+{
+    fn to_json(&self) -> Value {
+        match self {
+            Some(value) => constr0(json!([value.to_json()])),
+            None => constr1(json!([])),
+        }
+    }
+
+    fn from_json(value: &Value) -> Result<Self, WError> {
+        let constructor = value
+            .get("constructor")
+            .ok_or_else(|| WError::new("Option::from_json", "missing 'constructor' field"))?
+            .as_u64()
+            .ok_or_else(|| WError::new("Option::from_json", "invalid 'constructor' value"))?;
+        let fields = value
+            .get("fields")
+            .and_then(Value::as_array)
+            .ok_or_else(|| WError::new("Option::from_json", "invalid 'fields' value"))?;
+
+        match (constructor, fields.as_slice()) {
+            (0, [field]) => T::from_json(field)
+                .map(Some)
+                .map_err(WError::add_err_trace("Option::from_json")),
+            (1, []) => Ok(None),
+            _ => Err(WError::new(
+                "Option::from_json",
+                &format!(
+                    "expected Some with 1 field or None without fields, got constructor {} with {} fields",
+                    constructor,
+                    fields.len()
+                ),
+            )),
+        }
+    }
+}
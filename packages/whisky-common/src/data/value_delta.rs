@@ -0,0 +1,291 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::{
+    data::Value,
+    models::{Asset, Certificate, CertificateType, Protocol, TxBuilderBody, TxIn, Withdrawal},
+    WError,
+};
+
+/// A signed multi-asset quantity, e.g. a mint/burn or the value change of an address.
+///
+/// Units follow [`Value`] (`lovelace` or policy id + asset name in hex). Zero entries are
+/// dropped, and every arithmetic operation is checked so an overflow surfaces as an error.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValueDelta(pub BTreeMap<String, i128>);
+
+impl ValueDelta {
+    pub fn new() -> Self {
+        ValueDelta(BTreeMap::new())
+    }
+
+    pub fn from_value(value: &Value) -> Self {
+        let mut delta = ValueDelta::new();
+        for (unit, quantity) in &value.0 {
+            // u64 always fits in i128, no overflow possible on distinct units
+            let current = delta.0.entry(Value::sanitize_unit(unit)).or_insert(0);
+            *current += *quantity as i128;
+        }
+        delta.0.retain(|_, quantity| *quantity != 0);
+        delta
+    }
+
+    pub fn from_assets(assets: &[Asset]) -> Result<Self, WError> {
+        let mut delta = ValueDelta::new();
+        delta.add_assets(assets)?;
+        Ok(delta)
+    }
+
+    pub fn add_asset(&mut self, unit: &str, quantity: i128) -> Result<&mut Self, WError> {
+        let unit = Value::sanitize_unit(unit);
+        let current = self.get(&unit);
+        let updated = current.checked_add(quantity).ok_or_else(|| {
+            WError::new(
+                "ValueDelta::add_asset",
+                &format!("overflow adding {} to {} of {}", quantity, current, unit),
+            )
+        })?;
+        if updated == 0 {
+            self.0.remove(&unit);
+        } else {
+            self.0.insert(unit, updated);
+        }
+        Ok(self)
+    }
+
+    pub fn add_assets(&mut self, assets: &[Asset]) -> Result<&mut Self, WError> {
+        for asset in assets {
+            let quantity =
+                parse_quantity(asset).map_err(WError::add_err_trace("ValueDelta::add_assets"))?;
+            self.add_asset(&asset.unit(), quantity)?;
+        }
+        Ok(self)
+    }
+
+    pub fn sub_assets(&mut self, assets: &[Asset]) -> Result<&mut Self, WError> {
+        for asset in assets {
+            let quantity =
+                parse_quantity(asset).map_err(WError::add_err_trace("ValueDelta::sub_assets"))?;
+            let negated = quantity.checked_neg().ok_or_else(|| {
+                WError::new("ValueDelta::sub_assets", "overflow negating quantity")
+            })?;
+            self.add_asset(&asset.unit(), negated)?;
+        }
+        Ok(self)
+    }
+
+    pub fn checked_add(&self, other: &ValueDelta) -> Result<ValueDelta, WError> {
+        let mut result = self.clone();
+        for (unit, quantity) in &other.0 {
+            result
+                .add_asset(unit, *quantity)
+                .map_err(WError::add_err_trace("ValueDelta::checked_add"))?;
+        }
+        Ok(result)
+    }
+
+    pub fn checked_sub(&self, other: &ValueDelta) -> Result<ValueDelta, WError> {
+        let negated = other
+            .checked_neg()
+            .map_err(WError::add_err_trace("ValueDelta::checked_sub"))?;
+        self.checked_add(&negated)
+            .map_err(WError::add_err_trace("ValueDelta::checked_sub"))
+    }
+
+    pub fn checked_neg(&self) -> Result<ValueDelta, WError> {
+        let mut result = ValueDelta::new();
+        for (unit, quantity) in &self.0 {
+            let negated = quantity.checked_neg().ok_or_else(|| {
+                WError::new(
+                    "ValueDelta::checked_neg",
+                    &format!("overflow negating {} of {}", quantity, unit),
+                )
+            })?;
+            result.0.insert(unit.clone(), negated);
+        }
+        Ok(result)
+    }
+
+    // Accessor
+    pub fn get(&self, unit: &str) -> i128 {
+        self.0
+            .get(&Value::sanitize_unit(unit))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The entries gained (strictly positive quantities)
+    pub fn positive(&self) -> ValueDelta {
+        ValueDelta(
+            self.0
+                .iter()
+                .filter(|(_, quantity)| **quantity > 0)
+                .map(|(unit, quantity)| (unit.clone(), *quantity))
+                .collect(),
+        )
+    }
+
+    /// The entries lost (strictly negative quantities)
+    pub fn negative(&self) -> ValueDelta {
+        ValueDelta(
+            self.0
+                .iter()
+                .filter(|(_, quantity)| **quantity < 0)
+                .map(|(unit, quantity)| (unit.clone(), *quantity))
+                .collect(),
+        )
+    }
+
+    /// Quantities grouped by policy id, lovelace is listed under the empty policy `""`
+    pub fn by_policy(&self) -> BTreeMap<String, BTreeMap<String, i128>> {
+        let mut policies: BTreeMap<String, BTreeMap<String, i128>> = BTreeMap::new();
+        for (unit, quantity) in &self.0 {
+            let (policy, name) = Asset::unit_to_tuple(unit);
+            policies.entry(policy).or_default().insert(name, *quantity);
+        }
+        policies
+    }
+
+    /// Convert back to an unsigned [`Value`], failing on negative or out of range quantities
+    pub fn to_value(&self) -> Result<Value, WError> {
+        let mut value = Value::new();
+        for (unit, quantity) in &self.0 {
+            let quantity = u64::try_from(*quantity).map_err(|_| {
+                WError::new(
+                    "ValueDelta::to_value",
+                    &format!("quantity {} of {} does not fit in u64", quantity, unit),
+                )
+            })?;
+            value.add_asset(unit, quantity);
+        }
+        Ok(value)
+    }
+
+    pub fn to_asset_vec(&self) -> Vec<Asset> {
+        self.0
+            .iter()
+            .map(|(unit, quantity)| Asset::new(unit.clone(), quantity.to_string()))
+            .collect()
+    }
+
+    /// The value balance of a transaction body:
+    /// inputs − outputs + mint + withdrawals − deposits + deposit refunds − donation.
+    ///
+    /// For a balanced body the result is exactly the fee in lovelace. Deposits include those of
+    /// proposals. Input amounts must be resolved, and deposits the certificates do not carry
+    /// (pool registration, stake deregistration) are taken from `protocol`.
+    pub fn from_tx_builder_body(
+        body: &TxBuilderBody,
+        protocol: &Protocol,
+    ) -> Result<ValueDelta, WError> {
+        let mut delta = ValueDelta::new();
+
+        for input in &body.inputs {
+            let tx_in = match input {
+                TxIn::PubKeyTxIn(tx_in) => &tx_in.tx_in,
+                TxIn::SimpleScriptTxIn(tx_in) => &tx_in.tx_in,
+                TxIn::ScriptTxIn(tx_in) => &tx_in.tx_in,
+            };
+            let amount = tx_in.amount.as_ref().ok_or_else(|| {
+                WError::new(
+                    "ValueDelta::from_tx_builder_body",
+                    &format!(
+                        "missing amount of input {}#{}",
+                        tx_in.tx_hash, tx_in.tx_index
+                    ),
+                )
+            })?;
+            delta
+                .add_assets(amount)
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        for output in &body.outputs {
+            delta
+                .sub_assets(&output.amount)
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        for mint in &body.mints {
+            let mint = mint.get_mint_parameter();
+            delta
+                .add_asset(
+                    &format!("{}{}", mint.policy_id, mint.asset_name),
+                    mint.amount,
+                )
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        for withdrawal in &body.withdrawals {
+            let coin = match withdrawal {
+                Withdrawal::PubKeyWithdrawal(withdrawal) => withdrawal.coin,
+                Withdrawal::PlutusScriptWithdrawal(withdrawal) => withdrawal.coin,
+                Withdrawal::SimpleScriptWithdrawal(withdrawal) => withdrawal.coin,
+            };
+            delta
+                .add_asset("lovelace", coin as i128)
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        for certificate in &body.certificates {
+            let cert = match certificate {
+                Certificate::BasicCertificate(cert) => cert,
+                Certificate::ScriptCertificate(cert) => &cert.cert,
+                Certificate::SimpleScriptCertificate(cert) => &cert.cert,
+            };
+            delta
+                .add_asset("lovelace", certificate_deposit_change(cert, protocol))
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        for proposal in &body.proposals {
+            delta
+                .add_asset("lovelace", -(proposal.proposal_type().deposit as i128))
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        if let Some(donation) = body.donation {
+            delta
+                .add_asset("lovelace", -(donation as i128))
+                .map_err(WError::add_err_trace("ValueDelta::from_tx_builder_body"))?;
+        }
+
+        Ok(delta)
+    }
+}
+
+/// Lovelace released (positive) or locked (negative) by a certificate
+pub fn certificate_deposit_change(cert: &CertificateType, protocol: &Protocol) -> i128 {
+    match cert {
+        CertificateType::RegisterStake(cert) => -(cert.coin as i128),
+        CertificateType::StakeRegistrationAndDelegation(cert) => -(cert.coin as i128),
+        CertificateType::VoteRegistrationAndDelegation(cert) => -(cert.coin as i128),
+        CertificateType::StakeVoteRegistrationAndDelegation(cert) => -(cert.coin as i128),
+        CertificateType::DRepRegistration(cert) => -(cert.coin as i128),
+        CertificateType::RegisterPool(_) => -(protocol.pool_deposit as i128),
+        CertificateType::DeregisterStake(_) => protocol.key_deposit as i128,
+        CertificateType::DRepDeregistration(cert) => cert.coin as i128,
+        _ => 0,
+    }
+}
+
+fn parse_quantity(asset: &Asset) -> Result<i128, WError> {
+    asset.quantity().parse::<i128>().map_err(|_| {
+        WError::new(
+            "parse_quantity",
+            &format!(
+                "invalid quantity {:?} for unit {}",
+                asset.quantity(),
+                asset.unit()
+            ),
+        )
+    })
+}
@@ -2,6 +2,7 @@ mod evaluator;
 mod fetcher;
 mod provider;
 mod serializer;
+mod signer;
 mod submitter;
 mod tx_builder;
 mod tx_parser;
@@ -9,6 +10,7 @@ mod tx_parser;
 // pub use serializer::*;
 pub use evaluator::*;
 pub use fetcher::*;
+pub use signer::*;
 pub use submitter::*;
 pub use tx_builder::*;
 pub use tx_parser::*;
@@ -0,0 +1,11 @@
+use crate::*;
+use async_trait::async_trait;
+
+/// A signer holding keys outside of the transaction builder, e.g. an HSM, a KMS or a signing daemon.
+///
+/// The signer only ever sees the transaction body hash, so the keys never leave the signer.
+#[async_trait]
+pub trait Signer: Send + Sync {
+    /// Signs the transaction body hash, returning one vkey witness per key used
+    async fn sign_tx_hash(&self, tx_hash: &str) -> Result<Vec<VkeyWitness>, WError>;
+}
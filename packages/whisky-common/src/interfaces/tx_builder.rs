@@ -1,4 +1,6 @@
-use crate::{errors::*, Protocol, PubKeyTxIn, TxBuilderBody};
+use crate::{
+    errors::*, Network, Phase1Error, Protocol, PubKeyTxIn, TxBuilderBody, UTxO, VkeyWitness,
+};
 use std::fmt::Debug;
 
 pub trait TxBuildable: Debug + Send + Sync {
@@ -9,9 +11,20 @@ pub trait TxBuildable: Debug + Send + Sync {
     fn serialize_tx_body(&mut self) -> Result<String, WError>;
     fn unbalanced_serialize_tx_body(&mut self) -> Result<String, WError>;
     fn complete_signing(&mut self) -> Result<String, WError>;
+    fn tx_hash(&mut self) -> Result<String, WError>;
+    fn add_vkey_witnesses(&mut self, vkey_witnesses: Vec<VkeyWitness>) -> Result<String, WError>;
     fn set_tx_hex(&mut self, tx_hex: String);
     fn tx_hex(&mut self) -> String;
     fn tx_evaluation_multiplier_percentage(&self) -> u64;
 
     fn add_tx_in(&mut self, input: PubKeyTxIn) -> Result<(), WError>;
+
+    fn validate_phase_one(
+        &self,
+        tx_hex: &str,
+        utxos: &[UTxO],
+        protocol: &Protocol,
+        network: &Network,
+        current_slot: u64,
+    ) -> Result<Vec<Phase1Error>, WError>;
 }
@@ -9,6 +9,8 @@ pub mod data;
 pub mod errors;
 pub mod interfaces;
 pub mod models;
+pub mod tx_diff;
+pub mod tx_explainer;
 pub mod tx_tester;
 pub mod utils;
 pub use algo::*;
@@ -16,6 +18,8 @@ pub use constants::*;
 pub use errors::*;
 pub use interfaces::*;
 pub use models::*;
+pub use tx_diff::*;
+pub use tx_explainer::*;
 pub use tx_tester::*;
 pub use utils::*;
 
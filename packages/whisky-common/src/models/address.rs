@@ -0,0 +1,609 @@
+use bech32::{Bech32, Hrp};
+
+use super::{Credential, DeserializedAddress};
+use crate::{data, WError};
+
+/// A stake pointer, locating the certificate which registered the stake credential
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pointer {
+    pub slot: u64,
+    pub tx_index: u64,
+    pub cert_index: u64,
+}
+
+/// A Byron (bootstrap) address, kept as its raw CBOR bytes in hex
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByronAddress {
+    pub bytes: String,
+}
+
+/// A Cardano address of any era, independent of the serialization backend.
+///
+/// - `Base` - payment and stake credentials
+/// - `Enterprise` - payment credential only
+/// - `Pointer` - payment credential, stake credential referenced by a pointer
+/// - `Reward` - stake credential only, i.e. a stake address
+/// - `Byron` - legacy bootstrap address, base58 encoded
+///
+/// `network_id` is the header network nibble, 1 for mainnet and 0 for the testnets.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Address {
+    Base {
+        network_id: u8,
+        payment: Credential,
+        stake: Credential,
+    },
+    Enterprise {
+        network_id: u8,
+        payment: Credential,
+    },
+    Pointer {
+        network_id: u8,
+        payment: Credential,
+        pointer: Pointer,
+    },
+    Reward {
+        network_id: u8,
+        stake: Credential,
+    },
+    Byron(ByronAddress),
+}
+
+impl Address {
+    pub fn base(network_id: u8, payment: Credential, stake: Credential) -> Self {
+        Address::Base {
+            network_id,
+            payment,
+            stake,
+        }
+    }
+
+    pub fn enterprise(network_id: u8, payment: Credential) -> Self {
+        Address::Enterprise {
+            network_id,
+            payment,
+        }
+    }
+
+    pub fn reward(network_id: u8, stake: Credential) -> Self {
+        Address::Reward { network_id, stake }
+    }
+
+    /// Parse an address in bech32, hex or, for Byron addresses, base58
+    pub fn parse(address: &str) -> Result<Self, WError> {
+        if let Ok(parsed) = Self::from_bech32(address) {
+            return Ok(parsed);
+        }
+        if let Ok(parsed) = Self::from_hex(address) {
+            return Ok(parsed);
+        }
+        ByronAddress::from_base58(address)
+            .map(Address::Byron)
+            .map_err(|_| {
+                WError::new(
+                    "Address - parse",
+                    &format!("not a bech32, hex or base58 address: {}", address),
+                )
+            })
+    }
+
+    pub fn from_bech32(bech32_str: &str) -> Result<Self, WError> {
+        let (_, bytes) = bech32::decode(bech32_str)
+            .map_err(|e| WError::new("Address - from_bech32", &e.to_string()))?;
+        Self::from_bytes(&bytes).map_err(WError::add_err_trace("Address - from_bech32"))
+    }
+
+    /// Encode in bech32, or in base58 for Byron addresses
+    pub fn to_bech32(&self) -> Result<String, WError> {
+        let prefix = match self {
+            Address::Byron(byron) => return byron.to_base58(),
+            Address::Reward { .. } if self.is_mainnet() => "stake",
+            Address::Reward { .. } => "stake_test",
+            _ if self.is_mainnet() => "addr",
+            _ => "addr_test",
+        };
+        let hrp = Hrp::parse(prefix).map_err(WError::from_err("Address - to_bech32"))?;
+        bech32::encode::<Bech32>(hrp, &self.to_bytes()?)
+            .map_err(WError::from_err("Address - to_bech32"))
+    }
+
+    pub fn from_hex(hex_str: &str) -> Result<Self, WError> {
+        let bytes = hex::decode(hex_str).map_err(WError::from_err("Address - from_hex"))?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_hex(&self) -> Result<String, WError> {
+        Ok(hex::encode(self.to_bytes()?))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WError> {
+        let header = *bytes
+            .first()
+            .ok_or_else(WError::from_opt("Address - from_bytes", "empty address"))?;
+        let network_id = header & 0x0f;
+        let payload = &bytes[1..];
+        let hash_at = |offset: usize, is_script: bool| {
+            credential_from_bytes(payload.get(offset..offset + 28), is_script)
+        };
+        let expect_len = |len: usize| {
+            if payload.len() == len {
+                Ok(())
+            } else {
+                Err(WError::new(
+                    "Address - from_bytes",
+                    &format!("invalid length {} for header {:#04x}", bytes.len(), header),
+                ))
+            }
+        };
+        match header >> 4 {
+            kind @ 0..=3 => {
+                expect_len(56)?;
+                Ok(Address::base(
+                    network_id,
+                    hash_at(0, kind & 0b01 != 0)?,
+                    hash_at(28, kind & 0b10 != 0)?,
+                ))
+            }
+            kind @ 4..=5 => {
+                let mut rest = payload.get(28..).ok_or_else(WError::from_opt(
+                    "Address - from_bytes",
+                    "pointer address too short",
+                ))?;
+                let pointer = Pointer {
+                    slot: read_variable_nat(&mut rest)?,
+                    tx_index: read_variable_nat(&mut rest)?,
+                    cert_index: read_variable_nat(&mut rest)?,
+                };
+                if !rest.is_empty() {
+                    return Err(WError::new(
+                        "Address - from_bytes",
+                        "trailing bytes after pointer",
+                    ));
+                }
+                Ok(Address::Pointer {
+                    network_id,
+                    payment: hash_at(0, kind == 5)?,
+                    pointer,
+                })
+            }
+            kind @ 6..=7 => {
+                expect_len(28)?;
+                Ok(Address::enterprise(network_id, hash_at(0, kind == 7)?))
+            }
+            8 => ByronAddress::from_bytes(bytes).map(Address::Byron),
+            kind @ 14..=15 => {
+                expect_len(28)?;
+                Ok(Address::reward(network_id, hash_at(0, kind == 15)?))
+            }
+            _ => Err(WError::new(
+                "Address - from_bytes",
+                &format!("unsupported header {:#04x}", header),
+            )),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WError> {
+        let (kind, network_id, credentials) = match self {
+            Address::Base {
+                network_id,
+                payment,
+                stake,
+            } => (
+                is_script(payment) as u8 | ((is_script(stake) as u8) << 1),
+                *network_id,
+                vec![payment, stake],
+            ),
+            Address::Enterprise {
+                network_id,
+                payment,
+            } => (6 + is_script(payment) as u8, *network_id, vec![payment]),
+            Address::Pointer {
+                network_id,
+                payment,
+                ..
+            } => (4 + is_script(payment) as u8, *network_id, vec![payment]),
+            Address::Reward { network_id, stake } => {
+                (14 + is_script(stake) as u8, *network_id, vec![stake])
+            }
+            Address::Byron(byron) => {
+                return hex::decode(&byron.bytes).map_err(WError::from_err("Address - to_bytes"))
+            }
+        };
+        let mut bytes = vec![(kind << 4) | (network_id & 0x0f)];
+        for credential in credentials {
+            bytes.extend(credential_to_bytes(credential)?);
+        }
+        if let Address::Pointer { pointer, .. } = self {
+            for nat in [pointer.slot, pointer.tx_index, pointer.cert_index] {
+                write_variable_nat(&mut bytes, nat);
+            }
+        }
+        Ok(bytes)
+    }
+
+    pub fn network_id(&self) -> u8 {
+        match self {
+            Address::Base { network_id, .. }
+            | Address::Enterprise { network_id, .. }
+            | Address::Pointer { network_id, .. }
+            | Address::Reward { network_id, .. } => *network_id,
+            Address::Byron(byron) => byron.network_id(),
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        self.network_id() == 1
+    }
+
+    pub fn payment_credential(&self) -> Option<&Credential> {
+        match self {
+            Address::Base { payment, .. }
+            | Address::Enterprise { payment, .. }
+            | Address::Pointer { payment, .. } => Some(payment),
+            Address::Reward { .. } | Address::Byron(_) => None,
+        }
+    }
+
+    pub fn stake_credential(&self) -> Option<&Credential> {
+        match self {
+            Address::Base { stake, .. } | Address::Reward { stake, .. } => Some(stake),
+            _ => None,
+        }
+    }
+
+    /// The reward address of the stake credential, for base and reward addresses
+    pub fn stake_address(&self) -> Option<Address> {
+        self.stake_credential()
+            .map(|stake| Address::reward(self.network_id(), stake.clone()))
+    }
+
+    /// The Plutus `Address` data, failing for reward, pointer and Byron addresses
+    pub fn to_plutus_data(&self) -> Result<data::Address, WError> {
+        let payment = self.payment_credential().ok_or_else(|| {
+            WError::new(
+                "Address - to_plutus_data",
+                "reward and Byron addresses have no Plutus representation",
+            )
+        })?;
+        if let Address::Pointer { .. } = self {
+            return Err(WError::new(
+                "Address - to_plutus_data",
+                "pointer addresses are not supported",
+            ));
+        }
+        let stake = self.stake_credential();
+        Ok(data::Address::new(
+            credential_hash(payment),
+            stake.map(credential_hash),
+            is_script(payment),
+            stake.is_some_and(is_script),
+        ))
+    }
+
+    pub fn from_plutus_data(address: &data::Address, network_id: u8) -> Self {
+        let payment = credential(&address.payment_key_hash, address.is_script_payment_key);
+        match &address.stake_credential {
+            Some(stake) => Address::base(
+                network_id,
+                payment,
+                credential(stake, address.is_script_stake_key),
+            ),
+            None => Address::enterprise(network_id, payment),
+        }
+    }
+
+    /// The credential hashes of a base, enterprise or reward address
+    pub fn to_deserialized_address(&self) -> Result<DeserializedAddress, WError> {
+        if let Address::Pointer { .. } | Address::Byron(_) = self {
+            return Err(WError::new(
+                "Address - to_deserialized_address",
+                "pointer and Byron addresses are not supported",
+            ));
+        }
+        let (pub_key_hash, script_hash) = split_credential(self.payment_credential());
+        let (stake_key_hash, stake_key_script_hash) = split_credential(self.stake_credential());
+        Ok(DeserializedAddress::new(
+            pub_key_hash,
+            script_hash,
+            stake_key_hash,
+            stake_key_script_hash,
+        ))
+    }
+
+    /// A base or enterprise address from exactly one payment hash and at most one stake hash
+    pub fn from_deserialized_address(
+        address_obj: &DeserializedAddress,
+        network_id: u8,
+    ) -> Result<Self, WError> {
+        let payment = match (
+            address_obj.pub_key_hash.as_str(),
+            address_obj.script_hash.as_str(),
+        ) {
+            (pub_key_hash, "") if !pub_key_hash.is_empty() => credential(pub_key_hash, false),
+            ("", script_hash) if !script_hash.is_empty() => credential(script_hash, true),
+            _ => {
+                return Err(WError::new(
+                    "Address - from_deserialized_address",
+                    &format!(
+                        "Must provide exactly one of pub_key_hash or script_hash, pub_key_hash: {}, script_hash: {}",
+                        address_obj.pub_key_hash, address_obj.script_hash
+                    ),
+                ))
+            }
+        };
+        match (
+            address_obj.stake_key_hash.as_str(),
+            address_obj.stake_key_script_hash.as_str(),
+        ) {
+            ("", "") => Ok(Address::enterprise(network_id, payment)),
+            (stake_key_hash, "") => Ok(Address::base(
+                network_id,
+                payment,
+                credential(stake_key_hash, false),
+            )),
+            ("", stake_key_script_hash) => Ok(Address::base(
+                network_id,
+                payment,
+                credential(stake_key_script_hash, true),
+            )),
+            _ => Err(WError::new(
+                "Address - from_deserialized_address",
+                &format!(
+                    "Must provide at most one of stake_key_hash or stake_key_script_hash, stake_key_hash: {}, stake_key_script_hash: {}",
+                    address_obj.stake_key_hash, address_obj.stake_key_script_hash
+                ),
+            )),
+        }
+    }
+}
+
+impl ByronAddress {
+    pub fn from_base58(base58_str: &str) -> Result<Self, WError> {
+        let bytes = base58_decode(base58_str)
+            .map_err(WError::add_err_trace("ByronAddress - from_base58"))?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_base58(&self) -> Result<String, WError> {
+        let bytes =
+            hex::decode(&self.bytes).map_err(WError::from_err("ByronAddress - to_base58"))?;
+        Ok(base58_encode(&bytes))
+    }
+
+    /// Check the CBOR envelope `[#6.24(bytes), crc32]` and the checksum of the payload
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WError> {
+        let (payload, crc) =
+            byron_envelope(bytes).map_err(WError::add_err_trace("ByronAddress - from_bytes"))?;
+        if crc32(payload) != crc {
+            return Err(WError::new("ByronAddress - from_bytes", "invalid checksum"));
+        }
+        Ok(ByronAddress {
+            bytes: hex::encode(bytes),
+        })
+    }
+
+    /// 1 for mainnet, 0 when the attributes carry a testnet protocol magic
+    pub fn network_id(&self) -> u8 {
+        let protocol_magic = hex::decode(&self.bytes)
+            .ok()
+            .and_then(|bytes| byron_protocol_magic(&bytes));
+        match protocol_magic {
+            Some(_) => 0,
+            None => 1,
+        }
+    }
+}
+
+fn is_script(credential: &Credential) -> bool {
+    matches!(credential, Credential::ScriptHash(_))
+}
+
+fn credential(hash: &str, is_script: bool) -> Credential {
+    if is_script {
+        Credential::ScriptHash(hash.to_string())
+    } else {
+        Credential::KeyHash(hash.to_string())
+    }
+}
+
+fn credential_hash(credential: &Credential) -> &str {
+    match credential {
+        Credential::KeyHash(hash) | Credential::ScriptHash(hash) => hash,
+    }
+}
+
+fn split_credential(credential: Option<&Credential>) -> (&str, &str) {
+    match credential {
+        Some(Credential::KeyHash(hash)) => (hash, ""),
+        Some(Credential::ScriptHash(hash)) => ("", hash),
+        None => ("", ""),
+    }
+}
+
+fn credential_from_bytes(bytes: Option<&[u8]>, is_script: bool) -> Result<Credential, WError> {
+    let bytes = bytes.ok_or_else(WError::from_opt(
+        "Address - from_bytes",
+        "missing credential hash",
+    ))?;
+    Ok(credential(&hex::encode(bytes), is_script))
+}
+
+fn credential_to_bytes(credential: &Credential) -> Result<Vec<u8>, WError> {
+    let hash = credential_hash(credential);
+    let bytes = hex::decode(hash).map_err(WError::from_err("Address - invalid credential hash"))?;
+    if bytes.len() != 28 {
+        return Err(WError::new(
+            "Address - invalid credential hash",
+            &format!("expected 28 bytes, got {}", bytes.len()),
+        ));
+    }
+    Ok(bytes)
+}
+
+// Pointers use variable-length naturals: 7 bits per byte, big-endian, high bit set on all
+// but the last byte
+fn read_variable_nat(bytes: &mut &[u8]) -> Result<u64, WError> {
+    let mut nat: u64 = 0;
+    loop {
+        let (byte, rest) = bytes.split_first().ok_or_else(WError::from_opt(
+            "Address - from_bytes",
+            "truncated pointer",
+        ))?;
+        *bytes = rest;
+        nat = nat
+            .checked_mul(128)
+            .ok_or_else(WError::from_opt("Address - from_bytes", "pointer overflow"))?
+            | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Ok(nat);
+        }
+    }
+}
+
+fn write_variable_nat(bytes: &mut Vec<u8>, nat: u64) {
+    let mut groups = vec![(nat & 0x7f) as u8];
+    let mut rest = nat >> 7;
+    while rest > 0 {
+        groups.push((rest & 0x7f) as u8 | 0x80);
+        rest >>= 7;
+    }
+    bytes.extend(groups.iter().rev());
+}
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn base58_encode(bytes: &[u8]) -> String {
+    let mut digits: Vec<u8> = vec![];
+    for byte in bytes {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut encoded = "1".repeat(zeros);
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|digit| BASE58_ALPHABET[*digit as usize] as char),
+    );
+    encoded
+}
+
+fn base58_decode(base58_str: &str) -> Result<Vec<u8>, WError> {
+    let mut bytes: Vec<u8> = vec![];
+    for c in base58_str.chars() {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|a| *a as char == c)
+            .ok_or_else(|| WError::new("base58_decode", &format!("invalid character {}", c)))?
+            as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = base58_str.chars().take_while(|c| *c == '1').count();
+    let mut decoded = vec![0; zeros];
+    decoded.extend(bytes.into_iter().rev());
+    Ok(decoded)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Read a CBOR head, returning the major type and its argument
+fn read_cbor_head(bytes: &mut &[u8]) -> Option<(u8, u64)> {
+    let (initial, rest) = bytes.split_first()?;
+    let size = match initial & 0x1f {
+        info @ 0..=23 => {
+            *bytes = rest;
+            return Some((initial >> 5, info as u64));
+        }
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return None,
+    };
+    let argument = rest.get(..size)?;
+    *bytes = &rest[size..];
+    let value = argument
+        .iter()
+        .fold(0u64, |value, byte| (value << 8) | *byte as u64);
+    Some((initial >> 5, value))
+}
+
+fn read_cbor_bytes<'a>(bytes: &mut &'a [u8]) -> Option<&'a [u8]> {
+    match read_cbor_head(bytes)? {
+        (2, len) => {
+            let content = bytes.get(..len as usize)?;
+            *bytes = &bytes[len as usize..];
+            Some(content)
+        }
+        _ => None,
+    }
+}
+
+/// Split `[#6.24(bytes .cbor payload), crc32]` into the payload and the checksum
+fn byron_envelope(bytes: &[u8]) -> Result<(&[u8], u32), WError> {
+    let invalid = || WError::new("byron_envelope", "invalid Byron address CBOR");
+    let mut cursor = bytes;
+    if read_cbor_head(&mut cursor) != Some((4, 2)) || read_cbor_head(&mut cursor) != Some((6, 24)) {
+        return Err(invalid());
+    }
+    let payload = read_cbor_bytes(&mut cursor).ok_or_else(invalid)?;
+    match read_cbor_head(&mut cursor) {
+        Some((0, crc)) if cursor.is_empty() => Ok((payload, crc as u32)),
+        _ => Err(invalid()),
+    }
+}
+
+/// The protocol magic attribute (key 2) of the payload `[root, attributes, type]`
+fn byron_protocol_magic(bytes: &[u8]) -> Option<u64> {
+    let (mut payload, _) = byron_envelope(bytes).ok()?;
+    if read_cbor_head(&mut payload)? != (4, 3) {
+        return None;
+    }
+    read_cbor_bytes(&mut payload)?;
+    let (major, entries) = read_cbor_head(&mut payload)?;
+    if major != 5 {
+        return None;
+    }
+    for _ in 0..entries {
+        let (_, key) = read_cbor_head(&mut payload)?;
+        // Attribute values are CBOR wrapped in bytes
+        let mut value = read_cbor_bytes(&mut payload)?;
+        if key == 2 {
+            return match read_cbor_head(&mut value)? {
+                (0, magic) => Some(magic),
+                _ => None,
+            };
+        }
+    }
+    None
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// A bootstrap witness, i.e. the signature of a Byron address key over the transaction body hash
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapWitness {
+    /// The 32 bytes public key in hex
+    pub vkey: String,
+    /// The 64 bytes signature in hex
+    pub signature: String,
+    /// The 32 bytes chain code of the extended public key in hex
+    pub chain_code: String,
+    /// The CBOR encoded attributes of the Byron address in hex
+    pub attributes: String,
+}
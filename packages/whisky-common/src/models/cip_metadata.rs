@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::{File, Metadata, TransactionMetadatum, METADATUM_MAX_BYTES};
+use crate::WError;
+
+/// Metadata label of CIP-25 NFT metadata
+pub const CIP25_LABEL: u64 = 721;
+
+/// Metadata label of CIP-20 transaction messages
+pub const CIP20_LABEL: u64 = 674;
+
+/// CIP-25 v1 keys policies and asset names with text, v2 with raw bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cip25Version {
+    V1,
+    V2,
+}
+
+/// The metadata of one CIP-25 asset
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cip25Asset {
+    pub name: String,
+    pub image: String,
+    pub media_type: Option<String>,
+    pub description: Option<String>,
+    pub files: Vec<File>,
+    /// Any other property, e.g. `attributes`
+    pub extra: Vec<(String, TransactionMetadatum)>,
+}
+
+impl Cip25Asset {
+    pub fn new(name: &str, image: &str) -> Self {
+        Cip25Asset {
+            name: name.to_string(),
+            image: image.to_string(),
+            media_type: None,
+            description: None,
+            files: vec![],
+            extra: vec![],
+        }
+    }
+
+    pub fn media_type(&mut self, media_type: &str) -> &mut Self {
+        self.media_type = Some(media_type.to_string());
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn file(&mut self, name: &str, media_type: &str, src: &str) -> &mut Self {
+        self.files.push(File {
+            name: name.to_string(),
+            media_type: media_type.to_string(),
+            src: src.to_string(),
+        });
+        self
+    }
+
+    pub fn field(&mut self, key: &str, value: TransactionMetadatum) -> &mut Self {
+        self.extra.push((key.to_string(), value));
+        self
+    }
+
+    pub fn to_metadatum(&self) -> TransactionMetadatum {
+        let mut entries = vec![
+            ("name", TransactionMetadatum::text(&self.name)),
+            ("image", TransactionMetadatum::text(&self.image)),
+        ];
+        if let Some(media_type) = &self.media_type {
+            entries.push(("mediaType", TransactionMetadatum::text(media_type)));
+        }
+        if let Some(description) = &self.description {
+            entries.push(("description", TransactionMetadatum::text(description)));
+        }
+        if !self.files.is_empty() {
+            let files = self
+                .files
+                .iter()
+                .map(|file| {
+                    TransactionMetadatum::text_map(vec![
+                        ("name", TransactionMetadatum::text(&file.name)),
+                        ("mediaType", TransactionMetadatum::text(&file.media_type)),
+                        ("src", TransactionMetadatum::text(&file.src)),
+                    ])
+                })
+                .collect();
+            entries.push(("files", TransactionMetadatum::List(files)));
+        }
+        for (key, value) in &self.extra {
+            entries.push((key, value.clone().chunked()));
+        }
+        TransactionMetadatum::text_map(entries)
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Self, WError> {
+        let entries = match metadatum {
+            TransactionMetadatum::Map(entries) => entries,
+            _ => {
+                return Err(WError::new(
+                    "Cip25Asset::from_metadatum",
+                    "asset metadata is not a map",
+                ))
+            }
+        };
+        let text_of = |key: &str| metadatum.get(key).and_then(TransactionMetadatum::as_text);
+        let mut asset = Cip25Asset::new(
+            &text_of("name").ok_or_else(WError::from_opt(
+                "Cip25Asset::from_metadatum",
+                "missing name",
+            ))?,
+            &text_of("image").ok_or_else(WError::from_opt(
+                "Cip25Asset::from_metadatum",
+                "missing image",
+            ))?,
+        );
+        asset.media_type = text_of("mediaType");
+        asset.description = text_of("description");
+        if let Some(TransactionMetadatum::List(files)) = metadatum.get("files") {
+            for file in files {
+                let file_text = |key: &str| file.get(key).and_then(TransactionMetadatum::as_text);
+                match (file_text("name"), file_text("mediaType"), file_text("src")) {
+                    (Some(name), Some(media_type), Some(src)) => {
+                        asset.file(&name, &media_type, &src);
+                    }
+                    _ => {
+                        return Err(WError::new(
+                            "Cip25Asset::from_metadatum",
+                            "file without name, mediaType or src",
+                        ))
+                    }
+                }
+            }
+        }
+        for (key, value) in entries {
+            if let TransactionMetadatum::Text(key) = key {
+                if !["name", "image", "mediaType", "description", "files"].contains(&key.as_str()) {
+                    asset.extra.push((key.clone(), value.clone()));
+                }
+            }
+        }
+        Ok(asset)
+    }
+}
+
+/// CIP-25 NFT metadata, attached under label 721
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cip25Metadata {
+    pub version: Cip25Version,
+    /// Assets by policy id and asset name, both in hex
+    pub assets: BTreeMap<String, BTreeMap<String, Cip25Asset>>,
+}
+
+impl Cip25Metadata {
+    pub fn new(version: Cip25Version) -> Self {
+        Cip25Metadata {
+            version,
+            assets: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_asset(&mut self, policy_id: &str, asset_name: &str, asset: Cip25Asset) -> &mut Self {
+        self.assets
+            .entry(policy_id.to_string())
+            .or_default()
+            .insert(asset_name.to_string(), asset);
+        self
+    }
+
+    /// The metadatum under label 721.
+    ///
+    /// Fails for v1 when an asset name is not UTF-8, as v1 keys assets by text.
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, WError> {
+        let mut policies = vec![];
+        for (policy_id, assets) in &self.assets {
+            let mut entries = vec![];
+            for (asset_name, asset) in assets {
+                let key = match self.version {
+                    Cip25Version::V1 => TransactionMetadatum::Text(
+                        hex_to_utf8(asset_name)
+                            .map_err(WError::add_err_trace("Cip25Metadata::to_metadatum"))?,
+                    ),
+                    Cip25Version::V2 => TransactionMetadatum::Bytes(asset_name.clone()),
+                };
+                entries.push((key, asset.to_metadatum()));
+            }
+            let key = match self.version {
+                Cip25Version::V1 => TransactionMetadatum::Text(policy_id.clone()),
+                Cip25Version::V2 => TransactionMetadatum::Bytes(policy_id.clone()),
+            };
+            policies.push((key, TransactionMetadatum::Map(entries)));
+        }
+        if self.version == Cip25Version::V2 {
+            policies.push((
+                TransactionMetadatum::text("version"),
+                TransactionMetadatum::Int(2),
+            ));
+        }
+        Ok(TransactionMetadatum::Map(policies))
+    }
+
+    pub fn to_metadata(&self) -> Result<Metadata, WError> {
+        let metadatum = self
+            .to_metadatum()
+            .map_err(WError::add_err_trace("Cip25Metadata::to_metadata"))?;
+        Ok(Metadata::new(CIP25_LABEL, metadatum))
+    }
+
+    pub fn from_metadatum(metadatum: &TransactionMetadatum) -> Result<Self, WError> {
+        let policies = match metadatum {
+            TransactionMetadatum::Map(entries) => entries,
+            _ => {
+                return Err(WError::new(
+                    "Cip25Metadata::from_metadatum",
+                    "metadata is not a map",
+                ))
+            }
+        };
+        let version = match metadatum.get("version") {
+            Some(TransactionMetadatum::Int(2)) => Cip25Version::V2,
+            _ => Cip25Version::V1,
+        };
+        let mut cip25 = Cip25Metadata::new(version);
+        for (policy_key, assets) in policies {
+            let policy_id = match policy_key {
+                TransactionMetadatum::Text(text) if text == "version" => continue,
+                TransactionMetadatum::Text(policy_id) | TransactionMetadatum::Bytes(policy_id) => {
+                    policy_id.clone()
+                }
+                _ => {
+                    return Err(WError::new(
+                        "Cip25Metadata::from_metadatum",
+                        "invalid policy id key",
+                    ))
+                }
+            };
+            let assets = match assets {
+                TransactionMetadatum::Map(assets) => assets,
+                _ => {
+                    return Err(WError::new(
+                        "Cip25Metadata::from_metadatum",
+                        &format!("assets of policy {} are not a map", policy_id),
+                    ))
+                }
+            };
+            for (asset_key, asset) in assets {
+                let asset_name = match asset_key {
+                    TransactionMetadatum::Text(name) => hex::encode(name),
+                    TransactionMetadatum::Bytes(name) => name.clone(),
+                    _ => {
+                        return Err(WError::new(
+                            "Cip25Metadata::from_metadatum",
+                            "invalid asset name key",
+                        ))
+                    }
+                };
+                let asset = Cip25Asset::from_metadatum(asset)
+                    .map_err(WError::add_err_trace("Cip25Metadata::from_metadatum"))?;
+                cip25.add_asset(&policy_id, &asset_name, asset);
+            }
+        }
+        Ok(cip25)
+    }
+}
+
+/// A CIP-20 message, `{"msg": [..lines]}`, lines longer than 64 bytes being split
+pub fn cip20_message(lines: &[&str]) -> TransactionMetadatum {
+    let lines = lines
+        .iter()
+        .flat_map(|line| match TransactionMetadatum::text(line) {
+            TransactionMetadatum::List(chunks) => chunks,
+            text => vec![text],
+        })
+        .collect();
+    TransactionMetadatum::text_map(vec![("msg", TransactionMetadatum::List(lines))])
+}
+
+/// The lines of a CIP-20 message, `None` when the metadatum is not one
+pub fn parse_cip20_message(metadatum: &TransactionMetadatum) -> Option<Vec<String>> {
+    match metadatum.get("msg")? {
+        TransactionMetadatum::List(lines) => lines
+            .iter()
+            .map(|line| match line {
+                TransactionMetadatum::Text(text) if text.len() <= METADATUM_MAX_BYTES => {
+                    Some(text.clone())
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn hex_to_utf8(hex_str: &str) -> Result<String, WError> {
+    let bytes = hex::decode(hex_str).map_err(WError::from_err("hex_to_utf8"))?;
+    String::from_utf8(bytes).map_err(|_| {
+        WError::new(
+            "hex_to_utf8",
+            &format!("asset name {} is not UTF-8", hex_str),
+        )
+    })
+}
@@ -0,0 +1,220 @@
+use bech32::{Bech32, Hrp};
+
+use super::{Credential, DRep, Voter};
+use crate::WError;
+
+/// The role of a governance credential, which decides its bech32 prefix and CIP-129 header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovernanceIdKind {
+    CommitteeHot,
+    CommitteeCold,
+    DRep,
+}
+
+impl GovernanceIdKind {
+    fn prefix(&self) -> &'static str {
+        match self {
+            GovernanceIdKind::CommitteeHot => "cc_hot",
+            GovernanceIdKind::CommitteeCold => "cc_cold",
+            GovernanceIdKind::DRep => "drep",
+        }
+    }
+
+    // The upper 4 bits of the CIP-129 header byte
+    fn header_bits(&self) -> u8 {
+        match self {
+            GovernanceIdKind::CommitteeHot => 0b0000,
+            GovernanceIdKind::CommitteeCold => 0b0001,
+            GovernanceIdKind::DRep => 0b0010,
+        }
+    }
+}
+
+/// The identifier of a DRep or a constitutional committee member.
+///
+/// - CIP-129 - `drep`, `cc_cold` or `cc_hot` prefix, with a header byte telling the key type
+///   and whether the credential is a key hash or a script hash
+/// - CIP-105 - the credential hash alone, with the `_vkh` / `_script` suffix on the prefix
+///
+/// Decoding accepts both forms, while encoding defaults to CIP-129.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceId {
+    pub kind: GovernanceIdKind,
+    pub credential: Credential,
+}
+
+impl GovernanceId {
+    pub fn new(kind: GovernanceIdKind, credential: Credential) -> Self {
+        Self { kind, credential }
+    }
+
+    pub fn drep(credential: Credential) -> Self {
+        Self::new(GovernanceIdKind::DRep, credential)
+    }
+
+    pub fn committee_cold(credential: Credential) -> Self {
+        Self::new(GovernanceIdKind::CommitteeCold, credential)
+    }
+
+    pub fn committee_hot(credential: Credential) -> Self {
+        Self::new(GovernanceIdKind::CommitteeHot, credential)
+    }
+
+    /// Decodes a CIP-129 identifier, or a CIP-105 identifier with one of the prefixes
+    /// `drep`, `drep_vkh`, `drep_script`, `cc_cold`, `cc_cold_vkh`, `cc_cold_script`,
+    /// `cc_hot`, `cc_hot_vkh` and `cc_hot_script`.
+    pub fn from_bech32(bech32_str: &str) -> Result<Self, WError> {
+        let (hrp, data) = bech32::decode(bech32_str)
+            .map_err(|e| WError::new("GovernanceId - from_bech32", &e.to_string()))?;
+        let hrp = hrp.to_string();
+        if data.len() == 29 {
+            let id = Self::from_bytes(&data)?;
+            if id.kind.prefix() != hrp {
+                return Err(WError::new(
+                    "GovernanceId - from_bech32",
+                    &format!("prefix {} does not match the header byte", hrp),
+                ));
+            }
+            return Ok(id);
+        }
+        if data.len() != 28 {
+            return Err(WError::new(
+                "GovernanceId - from_bech32",
+                &format!("invalid payload length: {} bytes", data.len()),
+            ));
+        }
+        let hash = hex::encode(&data);
+        let (kind, is_script) = match hrp.as_str() {
+            "drep" | "drep_vkh" => (GovernanceIdKind::DRep, false),
+            "drep_script" => (GovernanceIdKind::DRep, true),
+            "cc_cold" | "cc_cold_vkh" => (GovernanceIdKind::CommitteeCold, false),
+            "cc_cold_script" => (GovernanceIdKind::CommitteeCold, true),
+            "cc_hot" | "cc_hot_vkh" => (GovernanceIdKind::CommitteeHot, false),
+            "cc_hot_script" => (GovernanceIdKind::CommitteeHot, true),
+            other => {
+                return Err(WError::new(
+                    "GovernanceId - from_bech32",
+                    &format!("unsupported prefix: {}", other),
+                ))
+            }
+        };
+        let credential = if is_script {
+            Credential::ScriptHash(hash)
+        } else {
+            Credential::KeyHash(hash)
+        };
+        Ok(Self::new(kind, credential))
+    }
+
+    /// Encodes the identifier in CIP-129 bech32, i.e. with the header byte before the credential hash
+    pub fn to_bech32(&self) -> Result<String, WError> {
+        encode_bech32(self.kind.prefix(), &self.to_bytes()?)
+    }
+
+    /// Encodes the identifier in the legacy CIP-105 bech32, e.g. `drep_vkh` or `cc_hot_script`
+    pub fn to_bech32_cip105(&self) -> Result<String, WError> {
+        let (suffix, hash) = match &self.credential {
+            Credential::KeyHash(hash) => ("vkh", hash),
+            Credential::ScriptHash(hash) => ("script", hash),
+        };
+        let prefix = format!("{}_{}", self.kind.prefix(), suffix);
+        encode_bech32(&prefix, &decode_hash(hash)?)
+    }
+
+    /// Decodes the CIP-129 identifier bytes in hex, i.e. the header byte followed by the credential hash
+    pub fn from_hex(hex_str: &str) -> Result<Self, WError> {
+        let bytes = hex::decode(hex_str)
+            .map_err(WError::from_err("GovernanceId - from_hex - invalid hex"))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Encodes the identifier in CIP-129 bytes in hex
+    pub fn to_hex(&self) -> Result<String, WError> {
+        Ok(hex::encode(self.to_bytes()?))
+    }
+
+    /// Gets the DRep to delegate votes to, in certificates
+    pub fn to_drep(&self) -> Result<DRep, WError> {
+        match self.kind {
+            GovernanceIdKind::DRep => Ok(DRep::DRepId(self.to_bech32()?)),
+            _ => Err(WError::new(
+                "GovernanceId - to_drep",
+                "only DRep identifiers can be used as DRep",
+            )),
+        }
+    }
+
+    /// Gets the voter of a DRep or a committee hot credential, in votes
+    pub fn to_voter(&self) -> Result<Voter, WError> {
+        match self.kind {
+            GovernanceIdKind::DRep => Ok(Voter::DRepId(self.to_bech32()?)),
+            GovernanceIdKind::CommitteeHot => Ok(Voter::ConstitutionalCommitteeHotCred(
+                self.credential.clone(),
+            )),
+            GovernanceIdKind::CommitteeCold => Err(WError::new(
+                "GovernanceId - to_voter",
+                "committee cold credentials cannot vote, use the authorized hot credential",
+            )),
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, WError> {
+        if bytes.len() != 29 {
+            return Err(WError::new(
+                "GovernanceId - from_bytes",
+                &format!("expected 29 bytes, got {}", bytes.len()),
+            ));
+        }
+        let header = bytes[0];
+        let kind = match header >> 4 {
+            0b0000 => GovernanceIdKind::CommitteeHot,
+            0b0001 => GovernanceIdKind::CommitteeCold,
+            0b0010 => GovernanceIdKind::DRep,
+            _ => {
+                return Err(WError::new(
+                    "GovernanceId - from_bytes",
+                    &format!("invalid key type in header byte: {:02x}", header),
+                ))
+            }
+        };
+        let hash = hex::encode(&bytes[1..]);
+        let credential = match header & 0b0000_1111 {
+            0b0010 => Credential::KeyHash(hash),
+            0b0011 => Credential::ScriptHash(hash),
+            _ => {
+                return Err(WError::new(
+                    "GovernanceId - from_bytes",
+                    &format!("invalid credential type in header byte: {:02x}", header),
+                ))
+            }
+        };
+        Ok(Self::new(kind, credential))
+    }
+
+    fn to_bytes(&self) -> Result<Vec<u8>, WError> {
+        let (credential_bits, hash) = match &self.credential {
+            Credential::KeyHash(hash) => (0b0010, hash),
+            Credential::ScriptHash(hash) => (0b0011, hash),
+        };
+        let mut bytes = vec![(self.kind.header_bits() << 4) | credential_bits];
+        bytes.extend(decode_hash(hash)?);
+        Ok(bytes)
+    }
+}
+
+fn decode_hash(hash: &str) -> Result<Vec<u8>, WError> {
+    let bytes =
+        hex::decode(hash).map_err(WError::from_err("GovernanceId - invalid credential hash"))?;
+    if bytes.len() != 28 {
+        return Err(WError::new(
+            "GovernanceId - invalid credential hash",
+            &format!("expected 28 bytes, got {}", bytes.len()),
+        ));
+    }
+    Ok(bytes)
+}
+
+fn encode_bech32(prefix: &str, bytes: &[u8]) -> Result<String, WError> {
+    let hrp = Hrp::parse(prefix).map_err(WError::from_err("GovernanceId - invalid prefix"))?;
+    bech32::encode::<Bech32>(hrp, bytes).map_err(WError::from_err("GovernanceId - to_bech32"))
+}
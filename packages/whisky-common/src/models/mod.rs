@@ -1,22 +1,38 @@
 mod account_info;
 mod action;
+mod address;
 mod asset;
 mod asset_metadata;
 mod block_info;
+mod bootstrap_witness;
+mod cip_metadata;
 mod data;
 mod deserialized_address;
+mod governance_id;
 mod governance_proposal_info;
+mod native_script;
+mod phase_one;
 mod protocol;
 mod transaction_info;
 mod tx_builder_types;
+mod vkey_witness;
+mod witness_report;
 pub use account_info::*;
 pub use action::*;
+pub use address::*;
 pub use asset::*;
 pub use asset_metadata::*;
 pub use block_info::*;
+pub use bootstrap_witness::*;
+pub use cip_metadata::*;
 pub use data::*;
 pub use deserialized_address::*;
+pub use governance_id::*;
 pub use governance_proposal_info::*;
+pub use native_script::*;
+pub use phase_one::*;
 pub use protocol::*;
 pub use transaction_info::*;
 pub use tx_builder_types::*;
+pub use vkey_witness::*;
+pub use witness_report::*;
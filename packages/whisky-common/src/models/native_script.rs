@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::ValidityRange;
+
+/// Native script in the cardano-cli JSON format, e.g.
+///
+/// ```json
+/// {
+///     "type": "atLeast",
+///     "required": 2,
+///     "scripts": [
+///         { "type": "sig", "keyHash": "..." },
+///         { "type": "sig", "keyHash": "..." },
+///         { "type": "sig", "keyHash": "..." }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NativeScript {
+    Sig {
+        #[serde(rename = "keyHash")]
+        key_hash: String,
+    },
+    All {
+        scripts: Vec<NativeScript>,
+    },
+    Any {
+        scripts: Vec<NativeScript>,
+    },
+    AtLeast {
+        required: u32,
+        scripts: Vec<NativeScript>,
+    },
+    /// Valid only when the transaction's `invalid_hereafter` is at or before the slot
+    Before {
+        slot: u64,
+    },
+    /// Valid only when the transaction's `invalid_before` is at or after the slot
+    After {
+        slot: u64,
+    },
+}
+
+impl NativeScript {
+    pub fn from_json(json: &str) -> Result<Self, crate::WError> {
+        serde_json::from_str(json).map_err(crate::WError::from_err("NativeScript - from_json"))
+    }
+
+    pub fn to_json(&self) -> Result<String, crate::WError> {
+        serde_json::to_string(self).map_err(crate::WError::from_err("NativeScript - to_json"))
+    }
+
+    /// All key hashes appearing in the script, in the order of appearance without duplicates
+    pub fn key_hashes(&self) -> Vec<String> {
+        let mut key_hashes: Vec<String> = vec![];
+        self.collect_key_hashes(&mut key_hashes);
+        key_hashes
+    }
+
+    fn collect_key_hashes(&self, key_hashes: &mut Vec<String>) {
+        match self {
+            NativeScript::Sig { key_hash } => {
+                if !key_hashes.contains(key_hash) {
+                    key_hashes.push(key_hash.clone());
+                }
+            }
+            NativeScript::All { scripts }
+            | NativeScript::Any { scripts }
+            | NativeScript::AtLeast { scripts, .. } => {
+                for script in scripts {
+                    script.collect_key_hashes(key_hashes);
+                }
+            }
+            NativeScript::Before { .. } | NativeScript::After { .. } => {}
+        }
+    }
+
+    /// Evaluates the script with the ledger rules, given the key hashes that signed the
+    /// transaction and the transaction validity range.
+    pub fn is_satisfied(&self, signers: &HashSet<String>, validity_range: &ValidityRange) -> bool {
+        match self {
+            NativeScript::Sig { key_hash } => signers.contains(key_hash),
+            NativeScript::All { scripts } => scripts
+                .iter()
+                .all(|script| script.is_satisfied(signers, validity_range)),
+            NativeScript::Any { scripts } => scripts
+                .iter()
+                .any(|script| script.is_satisfied(signers, validity_range)),
+            NativeScript::AtLeast { required, scripts } => {
+                scripts
+                    .iter()
+                    .filter(|script| script.is_satisfied(signers, validity_range))
+                    .count()
+                    >= *required as usize
+            }
+            NativeScript::Before { slot } => validity_range
+                .invalid_hereafter
+                .is_some_and(|invalid_hereafter| invalid_hereafter <= *slot),
+            NativeScript::After { slot } => validity_range
+                .invalid_before
+                .is_some_and(|invalid_before| invalid_before >= *slot),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Asset, Protocol, UtxoInput, WitnessReport};
+use crate::data::Value;
+
+/// A phase-1 ledger rule broken by a transaction
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Phase1Error {
+    /// Spent, collateral or reference inputs whose UTxO was not provided. No other rule is
+    /// checked when any input is unresolved.
+    BadInputs(Vec<UtxoInput>),
+    ValueNotConserved {
+        consumed: Vec<Asset>,
+        produced: Vec<Asset>,
+    },
+    FeeTooSmall {
+        min_fee: u64,
+        fee: u64,
+    },
+    OutputTooSmall {
+        output_index: usize,
+        min_lovelace: u64,
+        lovelace: u64,
+    },
+    TxTooLarge {
+        size: u64,
+        max_size: u64,
+    },
+    NoCollateralInputs,
+    TooManyCollateralInputs {
+        count: u64,
+        max: u64,
+    },
+    InsufficientCollateral {
+        required: u64,
+        provided: u64,
+    },
+    /// The total collateral declared in the body differs from the collateral provided
+    IncorrectTotalCollateral {
+        declared: u64,
+        provided: u64,
+    },
+    OutsideValidityInterval {
+        current_slot: u64,
+        invalid_before: Option<u64>,
+        invalid_hereafter: Option<u64>,
+    },
+    /// Key hashes that must sign the transaction but have no valid signature
+    MissingVkeyWitnesses(Vec<String>),
+    /// Public keys whose signature does not verify against the transaction body hash
+    InvalidSignatures(Vec<String>),
+    MissingScripts(Vec<String>),
+    ExtraneousScripts(Vec<String>),
+    MissingDatums(Vec<String>),
+    ExtraneousDatums(Vec<String>),
+    ScriptDataHashMismatch {
+        body: Option<String>,
+        computed: Option<String>,
+    },
+}
+
+/// The quantities of a fully resolved transaction that phase-1 validation depends on, as
+/// collected by a serializer backend
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Phase1Facts {
+    /// The size of the serialized transaction in bytes
+    pub tx_size: u64,
+    pub fee: u64,
+    /// The minimum fee including script execution and reference script costs
+    pub min_fee: u64,
+    /// Spent inputs, withdrawals, deposit refunds and minted assets
+    pub consumed: Value,
+    /// Outputs, fee, deposits, treasury donation and burnt assets
+    pub produced: Value,
+    /// The lovelace and minimum lovelace of each output
+    pub outputs: Vec<(u64, u64)>,
+    pub has_redeemers: bool,
+    pub collateral_inputs: u64,
+    /// Lovelace of the collateral inputs net of the collateral return
+    pub collateral: u64,
+    pub total_collateral: Option<u64>,
+    pub invalid_before: Option<u64>,
+    pub invalid_hereafter: Option<u64>,
+    pub witness_report: WitnessReport,
+}
+
+impl Phase1Facts {
+    /// Check the facts against the ledger rules under the given protocol parameters, returning
+    /// every broken rule
+    pub fn validate(&self, protocol: &Protocol, current_slot: u64) -> Vec<Phase1Error> {
+        let mut errors: Vec<Phase1Error> = vec![];
+
+        if !self.witness_report.unresolved_inputs.is_empty() {
+            errors.push(Phase1Error::BadInputs(
+                self.witness_report.unresolved_inputs.clone(),
+            ));
+        } else if non_zero(&self.consumed) != non_zero(&self.produced) {
+            errors.push(Phase1Error::ValueNotConserved {
+                consumed: self.consumed.to_asset_vec(),
+                produced: self.produced.to_asset_vec(),
+            });
+        }
+
+        if self.fee < self.min_fee {
+            errors.push(Phase1Error::FeeTooSmall {
+                min_fee: self.min_fee,
+                fee: self.fee,
+            });
+        }
+
+        for (output_index, (lovelace, min_lovelace)) in self.outputs.iter().enumerate() {
+            if lovelace < min_lovelace {
+                errors.push(Phase1Error::OutputTooSmall {
+                    output_index,
+                    min_lovelace: *min_lovelace,
+                    lovelace: *lovelace,
+                });
+            }
+        }
+
+        if self.tx_size > protocol.max_tx_size as u64 {
+            errors.push(Phase1Error::TxTooLarge {
+                size: self.tx_size,
+                max_size: protocol.max_tx_size as u64,
+            });
+        }
+
+        if self.has_redeemers {
+            errors.extend(self.validate_collateral(protocol));
+        }
+
+        let before = self.invalid_before.is_some_and(|slot| current_slot < slot);
+        let hereafter = self
+            .invalid_hereafter
+            .is_some_and(|slot| current_slot >= slot);
+        if before || hereafter {
+            errors.push(Phase1Error::OutsideValidityInterval {
+                current_slot,
+                invalid_before: self.invalid_before,
+                invalid_hereafter: self.invalid_hereafter,
+            });
+        }
+
+        errors.extend(self.validate_witnesses());
+        errors
+    }
+
+    fn validate_collateral(&self, protocol: &Protocol) -> Vec<Phase1Error> {
+        if self.collateral_inputs == 0 {
+            return vec![Phase1Error::NoCollateralInputs];
+        }
+        let mut errors: Vec<Phase1Error> = vec![];
+        let max = protocol.max_collateral_inputs.max(0) as u64;
+        if self.collateral_inputs > max {
+            errors.push(Phase1Error::TooManyCollateralInputs {
+                count: self.collateral_inputs,
+                max,
+            });
+        }
+        let required = (self.fee as f64 * protocol.collateral_percent / 100.0).ceil() as u64;
+        if self.collateral < required {
+            errors.push(Phase1Error::InsufficientCollateral {
+                required,
+                provided: self.collateral,
+            });
+        }
+        if let Some(declared) = self.total_collateral {
+            if declared != self.collateral {
+                errors.push(Phase1Error::IncorrectTotalCollateral {
+                    declared,
+                    provided: self.collateral,
+                });
+            }
+        }
+        errors
+    }
+
+    fn validate_witnesses(&self) -> Vec<Phase1Error> {
+        let report = &self.witness_report;
+        let mut errors: Vec<Phase1Error> = vec![];
+        let missing_vkeys = report.missing_vkeys();
+        if !missing_vkeys.is_empty() {
+            errors.push(Phase1Error::MissingVkeyWitnesses(missing_vkeys));
+        }
+        if !report.invalid_signatures.is_empty() {
+            errors.push(Phase1Error::InvalidSignatures(
+                report
+                    .invalid_signatures
+                    .iter()
+                    .map(|witness| witness.vkey.clone())
+                    .collect(),
+            ));
+        }
+        if !report.missing_scripts.is_empty() {
+            errors.push(Phase1Error::MissingScripts(report.missing_scripts.clone()));
+        }
+        if !report.extra_scripts.is_empty() {
+            errors.push(Phase1Error::ExtraneousScripts(report.extra_scripts.clone()));
+        }
+        if !report.missing_datums.is_empty() {
+            errors.push(Phase1Error::MissingDatums(report.missing_datums.clone()));
+        }
+        if !report.extra_datums.is_empty() {
+            errors.push(Phase1Error::ExtraneousDatums(report.extra_datums.clone()));
+        }
+        if !report.script_data_hash.matches() {
+            errors.push(Phase1Error::ScriptDataHashMismatch {
+                body: report.script_data_hash.body.clone(),
+                computed: report.script_data_hash.computed.clone(),
+            });
+        }
+        errors
+    }
+}
+
+fn non_zero(value: &Value) -> Vec<(&String, &u64)> {
+    value
+        .0
+        .iter()
+        .filter(|(_, quantity)| **quantity > 0)
+        .collect()
+}
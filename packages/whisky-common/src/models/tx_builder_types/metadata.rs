@@ -1,8 +1,312 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::WError;
+
+/// Maximum length in bytes of a text or bytes metadatum
+pub const METADATUM_MAX_BYTES: usize = 64;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Metadata {
     pub tag: String,
+    /// JSON without conversions, strings stand for both text and bytes
     pub metadata: String,
+    /// The typed form, preferred over `metadata` when building
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadatum: Option<TransactionMetadatum>,
+}
+
+/// A transaction metadatum, bytes being hex encoded. Ints range over ±(2^64 - 1).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionMetadatum {
+    Int(i128),
+    Bytes(String),
+    Text(String),
+    List(Vec<TransactionMetadatum>),
+    Map(Vec<(TransactionMetadatum, TransactionMetadatum)>),
+}
+
+impl TransactionMetadatum {
+    pub fn int(int: i128) -> Result<Self, WError> {
+        check_int(int, "TransactionMetadatum::int").map(TransactionMetadatum::Int)
+    }
+
+    /// Text, split into a list of chunks when longer than 64 bytes
+    pub fn text(text: &str) -> Self {
+        let chunks = chunk_text(text);
+        if chunks.len() == 1 {
+            TransactionMetadatum::Text(chunks[0].clone())
+        } else {
+            TransactionMetadatum::List(chunks.into_iter().map(TransactionMetadatum::Text).collect())
+        }
+    }
+
+    /// Hex bytes, split into a list of chunks when longer than 64 bytes
+    pub fn bytes(bytes_hex: &str) -> Self {
+        let hex_chunk = METADATUM_MAX_BYTES * 2;
+        if bytes_hex.len() <= hex_chunk {
+            return TransactionMetadatum::Bytes(bytes_hex.to_string());
+        }
+        let chunks = bytes_hex
+            .as_bytes()
+            .chunks(hex_chunk)
+            .map(|chunk| TransactionMetadatum::Bytes(String::from_utf8_lossy(chunk).to_string()))
+            .collect();
+        TransactionMetadatum::List(chunks)
+    }
+
+    pub fn list(items: Vec<TransactionMetadatum>) -> Self {
+        TransactionMetadatum::List(items)
+    }
+
+    pub fn map(entries: Vec<(TransactionMetadatum, TransactionMetadatum)>) -> Self {
+        TransactionMetadatum::Map(entries)
+    }
+
+    /// A map keyed by text, the most common shape of metadata
+    pub fn text_map(entries: Vec<(&str, TransactionMetadatum)>) -> Self {
+        TransactionMetadatum::Map(
+            entries
+                .into_iter()
+                .map(|(key, value)| (TransactionMetadatum::text(key), value))
+                .collect(),
+        )
+    }
+
+    /// Split every text and bytes longer than 64 bytes into a list of chunks
+    pub fn chunked(self) -> Self {
+        match self {
+            TransactionMetadatum::Text(text) => TransactionMetadatum::text(&text),
+            TransactionMetadatum::Bytes(bytes) => TransactionMetadatum::bytes(&bytes),
+            TransactionMetadatum::List(items) => {
+                TransactionMetadatum::List(items.into_iter().map(Self::chunked).collect())
+            }
+            TransactionMetadatum::Map(entries) => TransactionMetadatum::Map(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.chunked(), value.chunked()))
+                    .collect(),
+            ),
+            int => int,
+        }
+    }
+
+    /// The text of a `Text`, or of a list of `Text` chunks joined back together
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            TransactionMetadatum::Text(text) => Some(text.clone()),
+            TransactionMetadatum::List(items) => items
+                .iter()
+                .map(|item| match item {
+                    TransactionMetadatum::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<&str>>>()
+                .map(|chunks| chunks.concat()),
+            _ => None,
+        }
+    }
+
+    /// The value under a text key of a map
+    pub fn get(&self, key: &str) -> Option<&TransactionMetadatum> {
+        match self {
+            TransactionMetadatum::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, TransactionMetadatum::Text(text) if text == key))
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// JSON in the detailed metadata schema, e.g. `{"string": "hello"}`, `{"bytes": "00"}`
+    pub fn to_detailed_json(&self) -> Value {
+        match self {
+            TransactionMetadatum::Int(int) => json!({ "int": int_json(*int) }),
+            TransactionMetadatum::Bytes(bytes) => json!({ "bytes": bytes }),
+            TransactionMetadatum::Text(text) => json!({ "string": text }),
+            TransactionMetadatum::List(items) => json!({
+                "list": items.iter().map(Self::to_detailed_json).collect::<Vec<_>>()
+            }),
+            TransactionMetadatum::Map(entries) => json!({
+                "map": entries
+                    .iter()
+                    .map(|(k, v)| json!({ "k": k.to_detailed_json(), "v": v.to_detailed_json() }))
+                    .collect::<Vec<_>>()
+            }),
+        }
+    }
+
+    pub fn from_detailed_json(value: &Value) -> Result<Self, WError> {
+        let object = value.as_object().ok_or_else(|| {
+            WError::new(
+                "TransactionMetadatum::from_detailed_json",
+                "expected object",
+            )
+        })?;
+        if let Some(int) = object.get("int") {
+            let int = match int {
+                Value::String(int) => int.parse::<i128>().ok(),
+                Value::Number(int) => int.to_string().parse::<i128>().ok(),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                WError::new(
+                    "TransactionMetadatum::from_detailed_json",
+                    &format!("invalid int {}", int),
+                )
+            })?;
+            return check_int(int, "TransactionMetadatum::from_detailed_json")
+                .map(TransactionMetadatum::Int);
+        }
+        if let Some(bytes) = object.get("bytes").and_then(Value::as_str) {
+            return Ok(TransactionMetadatum::Bytes(bytes.to_string()));
+        }
+        if let Some(text) = object.get("string").and_then(Value::as_str) {
+            return Ok(TransactionMetadatum::Text(text.to_string()));
+        }
+        if let Some(items) = object.get("list").and_then(Value::as_array) {
+            return items
+                .iter()
+                .map(Self::from_detailed_json)
+                .collect::<Result<Vec<_>, _>>()
+                .map(TransactionMetadatum::List);
+        }
+        if let Some(entries) = object.get("map").and_then(Value::as_array) {
+            return entries
+                .iter()
+                .map(|entry| {
+                    let key = entry.get("k").ok_or_else(WError::from_opt(
+                        "TransactionMetadatum::from_detailed_json",
+                        "missing map key",
+                    ))?;
+                    let value = entry.get("v").ok_or_else(WError::from_opt(
+                        "TransactionMetadatum::from_detailed_json",
+                        "missing map value",
+                    ))?;
+                    Ok((
+                        Self::from_detailed_json(key)?,
+                        Self::from_detailed_json(value)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, WError>>()
+                .map(TransactionMetadatum::Map);
+        }
+        Err(WError::new(
+            "TransactionMetadatum::from_detailed_json",
+            &format!("unrecognized metadatum {}", value),
+        ))
+    }
+
+    /// JSON without conversions, as accepted by `TxBuilder::metadata_value`
+    pub fn to_json(&self) -> Value {
+        match self {
+            TransactionMetadatum::Int(int) => int_json(*int),
+            TransactionMetadatum::Bytes(bytes) => json!(bytes),
+            TransactionMetadatum::Text(text) => json!(text),
+            TransactionMetadatum::List(items) => {
+                Value::Array(items.iter().map(Self::to_json).collect())
+            }
+            TransactionMetadatum::Map(entries) => Value::Object(
+                entries
+                    .iter()
+                    .map(|(k, v)| {
+                        let key = match k.to_json() {
+                            Value::String(key) => key,
+                            other => other.to_string(),
+                        };
+                        (key, v.to_json())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Read JSON without conversions: strings become (chunked) text, numbers ints,
+    /// arrays lists and objects maps keyed by text
+    pub fn from_json(value: &Value) -> Result<Self, WError> {
+        match value {
+            Value::Number(number) => number
+                .to_string()
+                .parse::<i128>()
+                .map_err(WError::from_err("TransactionMetadatum::from_json"))
+                .and_then(|int| check_int(int, "TransactionMetadatum::from_json"))
+                .map(TransactionMetadatum::Int),
+            Value::String(text) => Ok(TransactionMetadatum::text(text)),
+            Value::Array(items) => items
+                .iter()
+                .map(Self::from_json)
+                .collect::<Result<Vec<_>, _>>()
+                .map(TransactionMetadatum::List),
+            Value::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| Ok((TransactionMetadatum::text(key), Self::from_json(value)?)))
+                .collect::<Result<Vec<_>, WError>>()
+                .map(TransactionMetadatum::Map),
+            _ => Err(WError::new(
+                "TransactionMetadatum::from_json",
+                &format!("unsupported metadata value {}", value),
+            )),
+        }
+    }
+}
+
+impl Metadata {
+    /// Metadata from its typed form, chunking long text and bytes
+    pub fn new(tag: u64, metadatum: TransactionMetadatum) -> Self {
+        let metadatum = metadatum.chunked();
+        Metadata {
+            tag: tag.to_string(),
+            metadata: metadatum.to_json().to_string(),
+            metadatum: Some(metadatum),
+        }
+    }
+
+    /// The typed form, or `metadata` read as JSON without conversions when it is absent
+    pub fn to_metadatum(&self) -> Result<TransactionMetadatum, WError> {
+        if let Some(metadatum) = &self.metadatum {
+            return Ok(metadatum.clone());
+        }
+        let value: Value = serde_json::from_str(&self.metadata)
+            .map_err(WError::from_err("Metadata::to_metadatum"))?;
+        TransactionMetadatum::from_json(&value)
+            .map_err(WError::add_err_trace("Metadata::to_metadatum"))
+    }
+}
+
+/// Reject ints a metadatum cannot hold, i.e. beyond ±(2^64 - 1)
+fn check_int(int: i128, origin: &str) -> Result<i128, WError> {
+    if int.unsigned_abs() > u64::MAX as u128 {
+        return Err(WError::new(
+            origin,
+            &format!("int {} out of metadatum range", int),
+        ));
+    }
+    Ok(int)
+}
+
+/// A JSON number when the int fits in an i64 or u64, its decimal string otherwise
+fn int_json(int: i128) -> Value {
+    if let Ok(int) = i64::try_from(int) {
+        json!(int)
+    } else if let Ok(int) = u64::try_from(int) {
+        json!(int)
+    } else {
+        json!(int.to_string())
+    }
+}
+
+/// Split text into chunks of at most 64 bytes, never inside a UTF-8 character
+fn chunk_text(text: &str) -> Vec<String> {
+    let mut chunks = vec![];
+    let mut current = String::new();
+    for c in text.chars() {
+        if current.len() + c.len_utf8() > METADATUM_MAX_BYTES {
+            chunks.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+    }
+    chunks.push(current);
+    chunks
 }
@@ -5,6 +5,7 @@ mod metadata;
 mod mint;
 mod network;
 mod output;
+mod proposal;
 mod redeemer;
 mod script;
 mod tx_builder_body;
@@ -21,6 +22,7 @@ pub use metadata::*;
 pub use mint::*;
 pub use network::*;
 pub use output::*;
+pub use proposal::*;
 pub use redeemer::*;
 pub use script::*;
 pub use tx_builder_body::*;
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Anchor, Redeemer, ScriptSource};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Proposal {
+    BasicProposal(ProposalType),
+    ScriptProposal(ScriptProposal),
+}
+
+/// A proposal whose governance action is guarded by the constitution script,
+/// i.e. a parameter change or treasury withdrawal carrying a policy hash
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptProposal {
+    pub proposal: ProposalType,
+    pub redeemer: Option<Redeemer>,
+    pub script_source: Option<ScriptSource>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalType {
+    pub deposit: u64,
+    /// The reward account receiving the deposit refund, in bech32
+    pub reward_account: String,
+    /// The governance action in CBOR hex, kept as is so it survives a parse and rebuild unchanged
+    pub gov_action: String,
+    pub anchor: Anchor,
+}
+
+impl Proposal {
+    pub fn proposal_type(&self) -> &ProposalType {
+        match self {
+            Proposal::BasicProposal(proposal) => proposal,
+            Proposal::ScriptProposal(script_proposal) => &script_proposal.proposal,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{BootstrapWitness, VkeyWitness};
+
 use super::{
-    Certificate, Datum, Metadata, MintItem, Network, Output, PubKeyTxIn, RefTxIn, TxIn,
+    Certificate, Datum, Metadata, MintItem, Network, Output, Proposal, PubKeyTxIn, RefTxIn, TxIn,
     ValidityRange, Vote, Withdrawal,
 };
 
@@ -21,7 +23,19 @@ pub struct TxBuilderBody {
     pub validity_range: ValidityRange,
     pub certificates: Vec<Certificate>,
     pub votes: Vec<Vote>,
+    #[serde(default)]
+    pub proposals: Vec<Proposal>,
+    #[serde(default)]
+    pub treasury_value: Option<u64>,
+    #[serde(default)]
+    pub donation: Option<u64>,
     pub signing_key: Vec<String>,
+    /// Witnesses of a parsed transaction, added to the built transaction as they are. They
+    /// only verify against an unchanged body.
+    #[serde(default)]
+    pub vkey_witnesses: Vec<VkeyWitness>,
+    #[serde(default)]
+    pub bootstrap_witnesses: Vec<BootstrapWitness>,
     pub fee: Option<String>,
     pub network: Option<Network>,
     pub total_collateral: Option<String>,
@@ -42,12 +56,17 @@ impl TxBuilderBody {
             change_datum: None,
             certificates: vec![],
             votes: vec![],
+            proposals: vec![],
+            treasury_value: None,
+            donation: None,
             metadata: vec![],
             validity_range: ValidityRange {
                 invalid_before: None,
                 invalid_hereafter: None,
             },
             signing_key: vec![],
+            vkey_witnesses: vec![],
+            bootstrap_witnesses: vec![],
             fee: None,
             network: None,
             total_collateral: None,
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// A vkey witness, i.e. an ed25519 signature over the transaction body hash
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VkeyWitness {
+    /// The 32 bytes public key in hex
+    pub vkey: String,
+    /// The 64 bytes signature in hex
+    pub signature: String,
+}
@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+
+use super::UtxoInput;
+
+/// The part of a transaction that requires a vkey witness
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VkeySource {
+    Input(UtxoInput),
+    Collateral(UtxoInput),
+    /// The index of the certificate in the transaction body
+    Certificate(usize),
+    /// The reward address in bech32
+    Withdrawal(String),
+    /// The index of the voter in the voting procedures
+    Vote(usize),
+    RequiredSigner,
+    /// The hash of the native script listing the key. Native scripts may not need every
+    /// listed key, so these are reported but never counted as missing.
+    NativeScript(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredVkey {
+    pub key_hash: String,
+    pub sources: Vec<VkeySource>,
+    /// Whether a valid signature from this key is present in the witness set
+    pub signed: bool,
+}
+
+impl RequiredVkey {
+    /// Whether the ledger requires this key regardless of native script semantics
+    pub fn is_mandatory(&self) -> bool {
+        self.sources
+            .iter()
+            .any(|source| !matches!(source, VkeySource::NativeScript(_)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VkeyWitnessCheck {
+    /// The public key in hex
+    pub vkey: String,
+    /// The blake2b-224 hash of the public key in hex
+    pub key_hash: String,
+    /// Whether the signature verifies against the transaction body hash
+    pub valid: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptDataHashCheck {
+    /// The script data hash declared in the transaction body
+    pub body: Option<String>,
+    /// The script data hash computed from the witness set under the network's cost models
+    pub computed: Option<String>,
+}
+
+impl ScriptDataHashCheck {
+    pub fn matches(&self) -> bool {
+        self.body == self.computed
+    }
+}
+
+/// The raw witness requirements and provisions of a transaction, as collected by a
+/// serializer backend. Turn it into a [`WitnessReport`] to compare both sides.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitnessInventory {
+    pub required_vkeys: Vec<(String, VkeySource)>,
+    pub vkey_witnesses: Vec<VkeyWitnessCheck>,
+    pub required_scripts: Vec<String>,
+    pub witness_scripts: Vec<String>,
+    pub reference_scripts: Vec<String>,
+    /// Datum hashes of spent script outputs that have no inline datum
+    pub required_datums: Vec<String>,
+    /// Datum hashes that may be witnessed without being required, e.g. the ones of outputs
+    pub allowed_datums: Vec<String>,
+    pub witness_datums: Vec<String>,
+    /// Inputs whose UTxO was not provided, so their witness requirements are unknown
+    pub unresolved_inputs: Vec<UtxoInput>,
+    pub script_data_hash: ScriptDataHashCheck,
+}
+
+/// A detailed audit of a transaction witness set against what its body requires
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WitnessReport {
+    pub required_vkeys: Vec<RequiredVkey>,
+    /// Key hashes of valid vkey witnesses that no part of the transaction requires
+    pub extraneous_vkeys: Vec<String>,
+    pub invalid_signatures: Vec<VkeyWitnessCheck>,
+    pub missing_scripts: Vec<String>,
+    pub extra_scripts: Vec<String>,
+    pub missing_datums: Vec<String>,
+    pub extra_datums: Vec<String>,
+    pub unresolved_inputs: Vec<UtxoInput>,
+    pub script_data_hash: ScriptDataHashCheck,
+}
+
+impl WitnessReport {
+    pub fn new(inventory: WitnessInventory) -> Self {
+        let is_signed = |key_hash: &str| {
+            inventory
+                .vkey_witnesses
+                .iter()
+                .any(|witness| witness.valid && witness.key_hash == key_hash)
+        };
+
+        let mut required_vkeys: Vec<RequiredVkey> = vec![];
+        for (key_hash, source) in inventory.required_vkeys {
+            match required_vkeys
+                .iter_mut()
+                .find(|required| required.key_hash == key_hash)
+            {
+                Some(required) => {
+                    if !required.sources.contains(&source) {
+                        required.sources.push(source);
+                    }
+                }
+                None => required_vkeys.push(RequiredVkey {
+                    signed: is_signed(&key_hash),
+                    key_hash,
+                    sources: vec![source],
+                }),
+            }
+        }
+
+        let mut extraneous_vkeys: Vec<String> = vec![];
+        for witness in inventory.vkey_witnesses.iter().filter(|w| w.valid) {
+            if !required_vkeys
+                .iter()
+                .any(|required| required.key_hash == witness.key_hash)
+                && !extraneous_vkeys.contains(&witness.key_hash)
+            {
+                extraneous_vkeys.push(witness.key_hash.clone());
+            }
+        }
+
+        let invalid_signatures = inventory
+            .vkey_witnesses
+            .iter()
+            .filter(|witness| !witness.valid)
+            .cloned()
+            .collect();
+
+        let missing_scripts = difference(
+            &inventory.required_scripts,
+            &[
+                inventory.witness_scripts.as_slice(),
+                inventory.reference_scripts.as_slice(),
+            ],
+        );
+        let extra_scripts = difference(
+            &inventory.witness_scripts,
+            &[inventory.required_scripts.as_slice()],
+        );
+        let missing_datums = difference(
+            &inventory.required_datums,
+            &[inventory.witness_datums.as_slice()],
+        );
+        let extra_datums = difference(
+            &inventory.witness_datums,
+            &[
+                inventory.required_datums.as_slice(),
+                inventory.allowed_datums.as_slice(),
+            ],
+        );
+
+        WitnessReport {
+            required_vkeys,
+            extraneous_vkeys,
+            invalid_signatures,
+            missing_scripts,
+            extra_scripts,
+            missing_datums,
+            extra_datums,
+            unresolved_inputs: inventory.unresolved_inputs,
+            script_data_hash: inventory.script_data_hash,
+        }
+    }
+
+    /// Key hashes that must sign the transaction but have no valid signature
+    pub fn missing_vkeys(&self) -> Vec<String> {
+        self.required_vkeys
+            .iter()
+            .filter(|required| required.is_mandatory() && !required.signed)
+            .map(|required| required.key_hash.clone())
+            .collect()
+    }
+
+    /// Whether the witness set satisfies every requirement found. Extraneous vkey witnesses
+    /// are allowed by the ledger, unresolved inputs are not since they cannot be checked.
+    pub fn is_valid(&self) -> bool {
+        self.missing_vkeys().is_empty()
+            && self.invalid_signatures.is_empty()
+            && self.missing_scripts.is_empty()
+            && self.extra_scripts.is_empty()
+            && self.missing_datums.is_empty()
+            && self.extra_datums.is_empty()
+            && self.unresolved_inputs.is_empty()
+            && self.script_data_hash.matches()
+    }
+}
+
+/// The deduplicated items of `items` that appear in none of `others`, in first-seen order
+fn difference(items: &[String], others: &[&[String]]) -> Vec<String> {
+    let mut result: Vec<String> = vec![];
+    for item in items {
+        if !others.iter().any(|other| other.contains(item)) && !result.contains(item) {
+            result.push(item.clone());
+        }
+    }
+    result
+}
@@ -0,0 +1,434 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::ValueDelta,
+    tx_explainer::{describe_voter, tx_in_parameter},
+    Certificate, Datum, DatumSource, MintItem, Output, OutputScriptSource, Redeemer, ScriptPurpose,
+    TxBuilderBody, TxIn, Vote, WError, Withdrawal,
+};
+
+/// The semantic difference between two versions of a transaction, e.g. ours and the one a
+/// wallet or partner sent back.
+///
+/// Inputs, reference inputs, collaterals and signers are compared as sets, so reordering
+/// them is not a change. Outputs are paired by content first and then by address, so an
+/// output whose value was rebalanced shows up as a change rather than a removal and an
+/// addition.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxDiff {
+    pub inputs: SetDiff,
+    pub reference_inputs: SetDiff,
+    pub collaterals: SetDiff,
+    pub outputs_added: Vec<IndexedOutput>,
+    pub outputs_removed: Vec<IndexedOutput>,
+    pub output_changes: Vec<OutputChange>,
+    /// The mint of the second tx minus the mint of the first
+    pub mint: ValueDelta,
+    pub redeemers: Vec<RedeemerChange>,
+    /// Datum changes of the script inputs spent by both txs
+    pub datums: Vec<DatumChange>,
+    /// Fees set in the tx bodies, see [`TxDiff::with_fees`] for parsed txs
+    pub fee: Option<AmountChange>,
+    /// Total memory budget of all redeemers
+    pub ex_units_mem: Option<AmountChange>,
+    /// Total CPU budget of all redeemers
+    pub ex_units_steps: Option<AmountChange>,
+    pub required_signers: SetDiff,
+    /// Key hashes of the vkey witnesses, see [`TxDiff::with_vkey_witnesses`]
+    pub vkey_witnesses: SetDiff,
+}
+
+/// Items only in the second tx (`added`) and only in the first (`removed`)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// An output and its index in the tx it belongs to
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedOutput {
+    pub index: usize,
+    pub output: Output,
+}
+
+/// An output to the same address in both txs whose content differs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputChange {
+    pub address: String,
+    pub before_index: usize,
+    pub after_index: usize,
+    /// The value of the second output minus the first
+    pub value: ValueDelta,
+    pub datum: Option<Change<Option<Datum>>>,
+    pub reference_script: Option<Change<Option<OutputScriptSource>>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// A redeemer added, removed or changed in data or budget, keyed by what it is run for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemerChange {
+    pub purpose: ScriptPurpose,
+    pub before: Option<Redeemer>,
+    pub after: Option<Redeemer>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatumChange {
+    /// The input as `tx_hash#tx_index`
+    pub input: String,
+    pub before: Option<DatumSource>,
+    pub after: Option<DatumSource>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountChange {
+    pub before: u64,
+    pub after: u64,
+    pub delta: i128,
+}
+
+impl TxDiff {
+    /// Diff two tx bodies, e.g. from `TxParser::get_builder_body`. A missing fee counts as 0.
+    pub fn new(before: &TxBuilderBody, after: &TxBuilderBody) -> Result<Self, WError> {
+        let (outputs_added, outputs_removed, output_changes) =
+            diff_outputs(&before.outputs, &after.outputs)
+                .map_err(WError::add_err_trace("TxDiff::new"))?;
+        let mint = mint_value(after)
+            .and_then(|after_mint| after_mint.checked_sub(&mint_value(before)?))
+            .map_err(WError::add_err_trace("TxDiff::new"))?;
+        let before_redeemers = redeemers(before);
+        let after_redeemers = redeemers(after);
+        let (before_mem, before_steps) = total_ex_units(&before_redeemers);
+        let (after_mem, after_steps) = total_ex_units(&after_redeemers);
+
+        Ok(TxDiff {
+            inputs: SetDiff::new(
+                &before.inputs.iter().map(input_id).collect::<Vec<_>>(),
+                &after.inputs.iter().map(input_id).collect::<Vec<_>>(),
+            ),
+            reference_inputs: SetDiff::new(
+                &before
+                    .reference_inputs
+                    .iter()
+                    .map(|input| format!("{}#{}", input.tx_hash, input.tx_index))
+                    .collect::<Vec<_>>(),
+                &after
+                    .reference_inputs
+                    .iter()
+                    .map(|input| format!("{}#{}", input.tx_hash, input.tx_index))
+                    .collect::<Vec<_>>(),
+            ),
+            collaterals: SetDiff::new(
+                &before
+                    .collaterals
+                    .iter()
+                    .map(|input| format!("{}#{}", input.tx_in.tx_hash, input.tx_in.tx_index))
+                    .collect::<Vec<_>>(),
+                &after
+                    .collaterals
+                    .iter()
+                    .map(|input| format!("{}#{}", input.tx_in.tx_hash, input.tx_in.tx_index))
+                    .collect::<Vec<_>>(),
+            ),
+            outputs_added,
+            outputs_removed,
+            output_changes,
+            mint,
+            redeemers: diff_redeemers(before_redeemers, after_redeemers),
+            datums: diff_datums(before, after),
+            fee: AmountChange::new(fee(before)?, fee(after)?),
+            ex_units_mem: AmountChange::new(before_mem, after_mem),
+            ex_units_steps: AmountChange::new(before_steps, after_steps),
+            required_signers: SetDiff::new(&before.required_signatures, &after.required_signatures),
+            vkey_witnesses: SetDiff::default(),
+        })
+    }
+
+    /// Compare the key hashes of the vkey witnesses of both txs, which the tx bodies do not carry
+    pub fn with_vkey_witnesses(&mut self, before: &[String], after: &[String]) -> &mut Self {
+        self.vkey_witnesses = SetDiff::new(before, after);
+        self
+    }
+
+    /// Compare the fees of two parsed txs, which their builder bodies leave for the TxBuilder
+    /// to recalculate
+    pub fn with_fees(&mut self, before: u64, after: u64) -> &mut Self {
+        self.fee = AmountChange::new(before, after);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == TxDiff::default()
+    }
+
+    pub fn to_json(&self) -> Result<String, WError> {
+        serde_json::to_string_pretty(self).map_err(WError::from_err("TxDiff::to_json"))
+    }
+}
+
+impl SetDiff {
+    pub fn new(before: &[String], after: &[String]) -> Self {
+        SetDiff {
+            added: after
+                .iter()
+                .filter(|item| !before.contains(item))
+                .cloned()
+                .collect(),
+            removed: before
+                .iter()
+                .filter(|item| !after.contains(item))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl AmountChange {
+    /// `None` when the amounts are equal
+    pub fn new(before: u64, after: u64) -> Option<Self> {
+        if before == after {
+            return None;
+        }
+        Some(AmountChange {
+            before,
+            after,
+            delta: after as i128 - before as i128,
+        })
+    }
+}
+
+fn input_id(input: &TxIn) -> String {
+    let tx_in = tx_in_parameter(input);
+    format!("{}#{}", tx_in.tx_hash, tx_in.tx_index)
+}
+
+fn fee(body: &TxBuilderBody) -> Result<u64, WError> {
+    body.fee
+        .as_ref()
+        .map(|fee| fee.parse::<u64>())
+        .transpose()
+        .map(Option::unwrap_or_default)
+        .map_err(WError::from_err("TxDiff::new - fee"))
+}
+
+fn mint_value(body: &TxBuilderBody) -> Result<ValueDelta, WError> {
+    let mut mint = ValueDelta::new();
+    for item in &body.mints {
+        let item = item.get_mint_parameter();
+        mint.add_asset(
+            &format!("{}{}", item.policy_id, item.asset_name),
+            item.amount,
+        )?;
+    }
+    Ok(mint)
+}
+
+type OutputsDiff = (Vec<IndexedOutput>, Vec<IndexedOutput>, Vec<OutputChange>);
+
+fn diff_outputs(before: &[Output], after: &[Output]) -> Result<OutputsDiff, WError> {
+    let mut unmatched_before: Vec<usize> = (0..before.len()).collect();
+    let mut unmatched_after: Vec<usize> = (0..after.len()).collect();
+
+    // Identical outputs are unchanged wherever they moved
+    unmatched_before.retain(|&i| {
+        match unmatched_after.iter().position(|&j| after[j] == before[i]) {
+            Some(position) => {
+                unmatched_after.remove(position);
+                false
+            }
+            None => true,
+        }
+    });
+
+    let mut changes = vec![];
+    let mut removed = vec![];
+    for i in unmatched_before {
+        let position = unmatched_after
+            .iter()
+            .position(|&j| after[j].address == before[i].address);
+        let j = match position {
+            Some(position) => unmatched_after.remove(position),
+            None => {
+                removed.push(IndexedOutput {
+                    index: i,
+                    output: before[i].clone(),
+                });
+                continue;
+            }
+        };
+        let (old, new) = (&before[i], &after[j]);
+        changes.push(OutputChange {
+            address: old.address.clone(),
+            before_index: i,
+            after_index: j,
+            value: ValueDelta::from_assets(&new.amount)?
+                .checked_sub(&ValueDelta::from_assets(&old.amount)?)?,
+            datum: Change::new(&old.datum, &new.datum),
+            reference_script: Change::new(&old.reference_script, &new.reference_script),
+        });
+    }
+
+    let added = unmatched_after
+        .into_iter()
+        .map(|j| IndexedOutput {
+            index: j,
+            output: after[j].clone(),
+        })
+        .collect();
+    Ok((added, removed, changes))
+}
+
+impl<T: Clone + PartialEq> Change<T> {
+    /// `None` when the values are equal
+    fn new(before: &T, after: &T) -> Option<Self> {
+        if before == after {
+            return None;
+        }
+        Some(Change {
+            before: before.clone(),
+            after: after.clone(),
+        })
+    }
+}
+
+/// The redeemers of a tx by purpose, minting policies once however many assets they mint
+fn redeemers(body: &TxBuilderBody) -> Vec<(ScriptPurpose, Redeemer)> {
+    let mut redeemers = vec![];
+
+    for input in &body.inputs {
+        if let TxIn::ScriptTxIn(script_tx_in) = input {
+            if let Some(redeemer) = &script_tx_in.script_tx_in.redeemer {
+                let purpose = ScriptPurpose::Spend {
+                    tx_hash: script_tx_in.tx_in.tx_hash.clone(),
+                    tx_index: script_tx_in.tx_in.tx_index,
+                };
+                redeemers.push((purpose, redeemer.clone()));
+            }
+        }
+    }
+
+    for mint in &body.mints {
+        if let MintItem::ScriptMint(mint) = mint {
+            let purpose = ScriptPurpose::Mint {
+                policy_id: mint.mint.policy_id.clone(),
+            };
+            if let Some(redeemer) = &mint.redeemer {
+                if !redeemers.iter().any(|(p, _)| *p == purpose) {
+                    redeemers.push((purpose, redeemer.clone()));
+                }
+            }
+        }
+    }
+
+    for withdrawal in &body.withdrawals {
+        if let Withdrawal::PlutusScriptWithdrawal(withdrawal) = withdrawal {
+            if let Some(redeemer) = &withdrawal.redeemer {
+                let purpose = ScriptPurpose::Withdraw {
+                    address: withdrawal.address.clone(),
+                };
+                redeemers.push((purpose, redeemer.clone()));
+            }
+        }
+    }
+
+    for (index, certificate) in body.certificates.iter().enumerate() {
+        if let Certificate::ScriptCertificate(certificate) = certificate {
+            if let Some(redeemer) = &certificate.redeemer {
+                redeemers.push((ScriptPurpose::Certify { index }, redeemer.clone()));
+            }
+        }
+    }
+
+    for vote in &body.votes {
+        if let Vote::ScriptVote(vote) = vote {
+            if let Some(redeemer) = &vote.redeemer {
+                let purpose = ScriptPurpose::Vote {
+                    voter: describe_voter(&vote.vote.voter),
+                };
+                redeemers.push((purpose, redeemer.clone()));
+            }
+        }
+    }
+
+    redeemers
+}
+
+fn total_ex_units(redeemers: &[(ScriptPurpose, Redeemer)]) -> (u64, u64) {
+    redeemers
+        .iter()
+        .fold((0, 0), |(mem, steps), (_, redeemer)| {
+            (mem + redeemer.ex_units.mem, steps + redeemer.ex_units.steps)
+        })
+}
+
+fn diff_redeemers(
+    before: Vec<(ScriptPurpose, Redeemer)>,
+    after: Vec<(ScriptPurpose, Redeemer)>,
+) -> Vec<RedeemerChange> {
+    let mut changes = vec![];
+    for (purpose, redeemer) in &before {
+        let after_redeemer = after
+            .iter()
+            .find(|(p, _)| p == purpose)
+            .map(|(_, redeemer)| redeemer);
+        if after_redeemer != Some(redeemer) {
+            changes.push(RedeemerChange {
+                purpose: purpose.clone(),
+                before: Some(redeemer.clone()),
+                after: after_redeemer.cloned(),
+            });
+        }
+    }
+    for (purpose, redeemer) in after {
+        if !before.iter().any(|(p, _)| *p == purpose) {
+            changes.push(RedeemerChange {
+                purpose,
+                before: None,
+                after: Some(redeemer),
+            });
+        }
+    }
+    changes
+}
+
+fn diff_datums(before: &TxBuilderBody, after: &TxBuilderBody) -> Vec<DatumChange> {
+    let datum_source = |input: &TxIn| match input {
+        TxIn::ScriptTxIn(script_tx_in) => script_tx_in.script_tx_in.datum_source.clone(),
+        _ => None,
+    };
+    before
+        .inputs
+        .iter()
+        .filter_map(|old| {
+            let id = input_id(old);
+            let new = after.inputs.iter().find(|new| input_id(new) == id)?;
+            let (old_datum, new_datum) = (datum_source(old), datum_source(new));
+            if old_datum == new_datum {
+                return None;
+            }
+            Some(DatumChange {
+                input: id,
+                before: old_datum,
+                after: new_datum,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+use crate::Asset;
+
+use super::{ScriptExecution, ScriptPurpose, SlotTime, TxExplanation};
+
+/// Render the explanation as Markdown, sections without content are left out
+pub fn render(explanation: &TxExplanation) -> String {
+    let mut sections = vec!["# Transaction summary".to_string()];
+
+    if !explanation.transfers.is_empty() {
+        let mut lines = vec![
+            "## Transfers".to_string(),
+            String::new(),
+            "| Address | Sends | Receives |".to_string(),
+            "| --- | --- | --- |".to_string(),
+        ];
+        for transfer in &explanation.transfers {
+            lines.push(format!(
+                "| `{}` | {} | {} |",
+                transfer.address,
+                describe_assets(&transfer.sent),
+                describe_assets(&transfer.received)
+            ));
+        }
+        sections.push(lines.join("\n"));
+    }
+
+    if !explanation.mints.is_empty() {
+        let items = explanation.mints.iter().map(|mint| {
+            let name = match &mint.asset_name_text {
+                Some(text) => format!(" ({})", text),
+                None => String::new(),
+            };
+            let action = if mint.quantity < 0 { "Burn" } else { "Mint" };
+            format!(
+                "{} {} `{}{}`{}",
+                action,
+                mint.quantity.unsigned_abs(),
+                mint.policy_id,
+                mint.asset_name,
+                name
+            )
+        });
+        sections.push(list_section("Minted and burned", items));
+    }
+
+    if !explanation.scripts.is_empty() {
+        let items = explanation.scripts.iter().map(describe_script);
+        sections.push(list_section("Scripts executed", items));
+    }
+
+    if !explanation.certificates.is_empty() {
+        sections.push(list_section(
+            "Certificates",
+            explanation.certificates.iter().cloned(),
+        ));
+    }
+
+    if !explanation.votes.is_empty() {
+        sections.push(list_section("Votes", explanation.votes.iter().cloned()));
+    }
+
+    if !explanation.proposals.is_empty() {
+        sections.push(list_section(
+            "Proposals",
+            explanation.proposals.iter().cloned(),
+        ));
+    }
+
+    if !explanation.withdrawals.is_empty() {
+        let items = explanation.withdrawals.iter().map(|withdrawal| {
+            format!(
+                "Withdraw {} lovelace from `{}`",
+                withdrawal.coin, withdrawal.address
+            )
+        });
+        sections.push(list_section("Withdrawals", items));
+    }
+
+    if explanation.deposits > 0 || explanation.refunds > 0 {
+        sections.push(list_section(
+            "Deposits and refunds",
+            [
+                format!("Deposits: {} lovelace", explanation.deposits),
+                format!("Refunds: {} lovelace", explanation.refunds),
+            ]
+            .into_iter(),
+        ));
+    }
+
+    if let Some(donation) = explanation.donation {
+        sections.push(format!("## Treasury donation\n\n{} lovelace", donation));
+    }
+
+    if let Some(fee) = explanation.fee {
+        sections.push(format!("## Fee\n\n{} lovelace", fee));
+    }
+
+    let validity = &explanation.validity;
+    if validity.invalid_before.is_some() || validity.invalid_hereafter.is_some() {
+        let mut items = vec![];
+        if let Some(time) = &validity.invalid_before {
+            items.push(format!("Valid from {}", describe_slot_time(time)));
+        }
+        if let Some(time) = &validity.invalid_hereafter {
+            items.push(format!("Valid until {}", describe_slot_time(time)));
+        }
+        sections.push(list_section("Validity", items.into_iter()));
+    }
+
+    if !explanation.required_signers.is_empty() {
+        let items = explanation
+            .required_signers
+            .iter()
+            .map(|signer| format!("`{}`", signer));
+        sections.push(list_section("Required signers", items));
+    }
+
+    format!("{}\n", sections.join("\n\n"))
+}
+
+fn list_section(title: &str, items: impl Iterator<Item = String>) -> String {
+    let items: Vec<String> = items.map(|item| format!("- {}", item)).collect();
+    format!("## {}\n\n{}", title, items.join("\n"))
+}
+
+fn describe_assets(assets: &[Asset]) -> String {
+    if assets.is_empty() {
+        return "-".to_string();
+    }
+    assets
+        .iter()
+        .map(|asset| format!("{} {}", asset.quantity(), asset.unit()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn describe_script(script: &ScriptExecution) -> String {
+    let purpose = match &script.purpose {
+        ScriptPurpose::Spend { tx_hash, tx_index } => format!("Spend `{}#{}`", tx_hash, tx_index),
+        ScriptPurpose::Mint { policy_id } => format!("Mint under policy `{}`", policy_id),
+        ScriptPurpose::Withdraw { address } => format!("Withdraw from `{}`", address),
+        ScriptPurpose::Certify { index } => format!("Certificate #{}", index),
+        ScriptPurpose::Vote { voter } => format!("Vote by {}", voter),
+    };
+    let mut details = vec![script.language.clone()];
+    if let Some(script_hash) = &script.script_hash {
+        details.push(format!("script `{}`", script_hash));
+    }
+    if let Some(reference_input) = &script.reference_input {
+        details.push(format!("reference `{}`", reference_input));
+    }
+    if let Some(ex_units) = &script.ex_units {
+        details.push(format!("{} mem / {} steps", ex_units.mem, ex_units.steps));
+    }
+    let redeemer = match &script.redeemer {
+        // Multi-line redeemers would break the list item
+        Some(redeemer) => format!(
+            ", redeemer `{}`",
+            redeemer.split_whitespace().collect::<Vec<_>>().join(" ")
+        ),
+        None => String::new(),
+    };
+    format!("{} ({}){}", purpose, details.join(", "), redeemer)
+}
+
+fn describe_slot_time(time: &SlotTime) -> String {
+    format!("{} (slot {})", time.utc, time.slot)
+}
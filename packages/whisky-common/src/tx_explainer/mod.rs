@@ -0,0 +1,629 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data::{certificate_deposit_change, pretty_plutus_data_cbor, ValueDelta},
+    utils::{slot_to_begin_unix_time, unix_time_to_utc, SlotConfig},
+    Address, Asset, Budget, Certificate, CertificateType, Credential, DRep, LanguageVersion,
+    MintItem, Proposal, Protocol, Redeemer, RefTxIn, ScriptSource, SimpleScriptSource,
+    SimpleScriptTxInParameter, TxBuilderBody, TxIn, TxInParameter, UTxO, Vote, VoteKind, VoteType,
+    Voter, WError, Withdrawal,
+};
+
+mod markdown;
+
+/// A plain-terms summary of a transaction for humans, e.g. support staff.
+///
+/// Built from a parsed [`TxBuilderBody`] with [`TxExplanation::new`], and rendered with
+/// [`TxExplanation::to_json`] or [`TxExplanation::to_markdown`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxExplanation {
+    /// Net value change per address, addresses whose value does not change are left out
+    pub transfers: Vec<AddressTransfer>,
+    pub mints: Vec<MintedAsset>,
+    pub scripts: Vec<ScriptExecution>,
+    pub certificates: Vec<String>,
+    pub votes: Vec<String>,
+    pub proposals: Vec<String>,
+    pub withdrawals: Vec<WithdrawalSummary>,
+    /// Lovelace locked as deposits by certificates and proposals
+    pub deposits: u64,
+    /// Lovelace of deposits returned by certificates
+    pub refunds: u64,
+    /// Lovelace donated to the treasury
+    pub donation: Option<u64>,
+    /// Fee set in the tx body, see [`TxExplanation::with_fee`] for parsed txs
+    pub fee: Option<u64>,
+    pub validity: ValidityWindow,
+    pub required_signers: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressTransfer {
+    pub address: String,
+    pub sent: Vec<Asset>,
+    pub received: Vec<Asset>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintedAsset {
+    pub policy_id: String,
+    pub asset_name: String,
+    /// The asset name as text when it is printable UTF-8
+    pub asset_name_text: Option<String>,
+    /// Positive when minted, negative when burned
+    pub quantity: i128,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalSummary {
+    pub address: String,
+    pub coin: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ScriptPurpose {
+    Spend { tx_hash: String, tx_index: u32 },
+    Mint { policy_id: String },
+    Withdraw { address: String },
+    Certify { index: usize },
+    Vote { voter: String },
+}
+
+/// A script run by the transaction and what it is run for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptExecution {
+    pub purpose: ScriptPurpose,
+    pub script_hash: Option<String>,
+    /// `PlutusV1`, `PlutusV2`, `PlutusV3` or `Native`
+    pub language: String,
+    /// The UTxO holding the script when it is provided by reference
+    pub reference_input: Option<String>,
+    /// The redeemer rendered with [`crate::data::pretty_plutus_data`]
+    pub redeemer: Option<String>,
+    pub ex_units: Option<Budget>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidityWindow {
+    pub invalid_before: Option<SlotTime>,
+    pub invalid_hereafter: Option<SlotTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotTime {
+    pub slot: u64,
+    /// POSIX time in milliseconds at the beginning of the slot
+    pub unix_time: u64,
+    pub utc: String,
+}
+
+impl TxExplanation {
+    /// Explain a tx body, e.g. from `TxParser::get_builder_body`.
+    ///
+    /// Inputs without address or amount are looked up in `resolved_utxos`. Deposits the
+    /// certificates do not carry are taken from `protocol`, and slots are converted to UTC
+    /// with `slot_config`.
+    pub fn new(
+        body: &TxBuilderBody,
+        resolved_utxos: &[UTxO],
+        protocol: &Protocol,
+        slot_config: &SlotConfig,
+    ) -> Result<Self, WError> {
+        let mut deposits = 0;
+        let mut refunds = 0;
+        for cert in body.certificates.iter().map(certificate_type) {
+            let change = certificate_deposit_change(cert, protocol);
+            if change < 0 {
+                deposits += change.unsigned_abs() as u64;
+            } else {
+                refunds += change as u64;
+            }
+        }
+        for proposal in &body.proposals {
+            deposits += proposal.proposal_type().deposit;
+        }
+        let fee = body
+            .fee
+            .as_ref()
+            .map(|fee| fee.parse::<u64>())
+            .transpose()
+            .map_err(WError::from_err("TxExplanation::new - fee"))?;
+
+        Ok(TxExplanation {
+            transfers: transfers(body, resolved_utxos)
+                .map_err(WError::add_err_trace("TxExplanation::new"))?,
+            mints: body.mints.iter().map(minted_asset).collect(),
+            scripts: script_executions(body, resolved_utxos),
+            certificates: body
+                .certificates
+                .iter()
+                .map(|cert| describe_certificate(certificate_type(cert)))
+                .collect(),
+            votes: body.votes.iter().map(describe_vote).collect(),
+            proposals: body.proposals.iter().map(describe_proposal).collect(),
+            withdrawals: body
+                .withdrawals
+                .iter()
+                .map(|withdrawal| {
+                    let (address, coin) = match withdrawal {
+                        Withdrawal::PubKeyWithdrawal(w) => (&w.address, w.coin),
+                        Withdrawal::PlutusScriptWithdrawal(w) => (&w.address, w.coin),
+                        Withdrawal::SimpleScriptWithdrawal(w) => (&w.address, w.coin),
+                    };
+                    WithdrawalSummary {
+                        address: address.clone(),
+                        coin,
+                    }
+                })
+                .collect(),
+            deposits,
+            refunds,
+            donation: body.donation,
+            fee,
+            validity: ValidityWindow {
+                invalid_before: body
+                    .validity_range
+                    .invalid_before
+                    .map(|slot| slot_time(slot, slot_config)),
+                invalid_hereafter: body
+                    .validity_range
+                    .invalid_hereafter
+                    .map(|slot| slot_time(slot, slot_config)),
+            },
+            required_signers: body.required_signatures.clone(),
+        })
+    }
+
+    /// Set the fee of a parsed tx, which its builder body leaves for the TxBuilder to recalculate
+    pub fn with_fee(&mut self, fee: u64) -> &mut Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    pub fn to_json(&self) -> Result<String, WError> {
+        serde_json::to_string_pretty(self).map_err(WError::from_err("TxExplanation::to_json"))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        markdown::render(self)
+    }
+}
+
+fn certificate_type(certificate: &Certificate) -> &CertificateType {
+    match certificate {
+        Certificate::BasicCertificate(cert) => cert,
+        Certificate::ScriptCertificate(cert) => &cert.cert,
+        Certificate::SimpleScriptCertificate(cert) => &cert.cert,
+    }
+}
+
+pub(crate) fn tx_in_parameter(input: &TxIn) -> &TxInParameter {
+    match input {
+        TxIn::PubKeyTxIn(tx_in) => &tx_in.tx_in,
+        TxIn::SimpleScriptTxIn(tx_in) => &tx_in.tx_in,
+        TxIn::ScriptTxIn(tx_in) => &tx_in.tx_in,
+    }
+}
+
+/// The address and amount of an input, from the input itself or the resolved UTxOs
+fn resolve_input(tx_in: &TxInParameter, resolved_utxos: &[UTxO]) -> Option<(String, Vec<Asset>)> {
+    if let (Some(address), Some(amount)) = (&tx_in.address, &tx_in.amount) {
+        return Some((address.clone(), amount.clone()));
+    }
+    resolved_utxos
+        .iter()
+        .find(|utxo| {
+            utxo.input.tx_hash == tx_in.tx_hash && utxo.input.output_index == tx_in.tx_index
+        })
+        .map(|utxo| (utxo.output.address.clone(), utxo.output.amount.clone()))
+}
+
+fn transfers(
+    body: &TxBuilderBody,
+    resolved_utxos: &[UTxO],
+) -> Result<Vec<AddressTransfer>, WError> {
+    // Addresses are kept in order of first appearance, inputs first
+    let mut changes: Vec<(String, ValueDelta)> = vec![];
+    for input in &body.inputs {
+        let tx_in = tx_in_parameter(input);
+        let (address, amount) = resolve_input(tx_in, resolved_utxos).ok_or_else(|| {
+            WError::new(
+                "transfers",
+                &format!("input {}#{} is unresolved", tx_in.tx_hash, tx_in.tx_index),
+            )
+        })?;
+        change_of(&mut changes, &address).sub_assets(&amount)?;
+    }
+    for output in &body.outputs {
+        change_of(&mut changes, &output.address).add_assets(&output.amount)?;
+    }
+
+    changes
+        .into_iter()
+        .filter(|(_, delta)| !delta.is_empty())
+        .map(|(address, delta)| {
+            Ok(AddressTransfer {
+                address,
+                sent: delta.negative().checked_neg()?.to_asset_vec(),
+                received: delta.positive().to_asset_vec(),
+            })
+        })
+        .collect()
+}
+
+fn change_of<'a>(changes: &'a mut Vec<(String, ValueDelta)>, address: &str) -> &'a mut ValueDelta {
+    let i = match changes.iter().position(|(a, _)| a == address) {
+        Some(i) => i,
+        None => {
+            changes.push((address.to_string(), ValueDelta::new()));
+            changes.len() - 1
+        }
+    };
+    &mut changes[i].1
+}
+
+fn minted_asset(mint: &MintItem) -> MintedAsset {
+    let mint = mint.get_mint_parameter();
+    let asset_name_text = hex::decode(&mint.asset_name)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .filter(|text| !text.is_empty() && !text.chars().any(char::is_control));
+    MintedAsset {
+        policy_id: mint.policy_id,
+        asset_name: mint.asset_name,
+        asset_name_text,
+        quantity: mint.amount,
+    }
+}
+
+fn script_executions(body: &TxBuilderBody, resolved_utxos: &[UTxO]) -> Vec<ScriptExecution> {
+    let mut scripts = vec![];
+
+    for input in &body.inputs {
+        let tx_in = tx_in_parameter(input);
+        let purpose = ScriptPurpose::Spend {
+            tx_hash: tx_in.tx_hash.clone(),
+            tx_index: tx_in.tx_index,
+        };
+        let address_script_hash = resolve_input(tx_in, resolved_utxos)
+            .and_then(|(address, _)| Address::parse(&address).ok())
+            .and_then(|address| script_hash_of(address.payment_credential()));
+        match input {
+            TxIn::PubKeyTxIn(_) => {}
+            TxIn::ScriptTxIn(script_tx_in) => scripts.push(plutus_execution(
+                purpose,
+                address_script_hash,
+                script_tx_in.script_tx_in.script_source.as_ref(),
+                script_tx_in.script_tx_in.redeemer.as_ref(),
+            )),
+            TxIn::SimpleScriptTxIn(simple_script_tx_in) => {
+                let source = match &simple_script_tx_in.simple_script_tx_in {
+                    SimpleScriptTxInParameter::ProvidedSimpleScriptSource(source) => {
+                        SimpleScriptSource::ProvidedSimpleScriptSource(source.clone())
+                    }
+                    SimpleScriptTxInParameter::InlineSimpleScriptSource(source) => {
+                        SimpleScriptSource::InlineSimpleScriptSource(source.clone())
+                    }
+                };
+                scripts.push(native_execution(
+                    purpose,
+                    address_script_hash,
+                    Some(&source),
+                ))
+            }
+        }
+    }
+
+    // A policy runs once however many of its assets are minted
+    let mut policies: Vec<String> = vec![];
+    for mint in &body.mints {
+        let policy_id = mint.get_mint_parameter().policy_id;
+        if policies.contains(&policy_id) {
+            continue;
+        }
+        policies.push(policy_id.clone());
+        let purpose = ScriptPurpose::Mint {
+            policy_id: policy_id.clone(),
+        };
+        scripts.push(match mint {
+            MintItem::ScriptMint(mint) => plutus_execution(
+                purpose,
+                Some(policy_id),
+                mint.script_source.as_ref(),
+                mint.redeemer.as_ref(),
+            ),
+            MintItem::SimpleScriptMint(mint) => {
+                native_execution(purpose, Some(policy_id), mint.script_source.as_ref())
+            }
+        });
+    }
+
+    for withdrawal in &body.withdrawals {
+        match withdrawal {
+            Withdrawal::PubKeyWithdrawal(_) => {}
+            Withdrawal::PlutusScriptWithdrawal(w) => scripts.push(plutus_execution(
+                ScriptPurpose::Withdraw {
+                    address: w.address.clone(),
+                },
+                stake_script_hash(&w.address),
+                w.script_source.as_ref(),
+                w.redeemer.as_ref(),
+            )),
+            Withdrawal::SimpleScriptWithdrawal(w) => scripts.push(native_execution(
+                ScriptPurpose::Withdraw {
+                    address: w.address.clone(),
+                },
+                stake_script_hash(&w.address),
+                w.script_source.as_ref(),
+            )),
+        }
+    }
+
+    for (index, certificate) in body.certificates.iter().enumerate() {
+        let script_hash =
+            certificate_stake_address(certificate_type(certificate)).and_then(stake_script_hash);
+        match certificate {
+            Certificate::BasicCertificate(_) => {}
+            Certificate::ScriptCertificate(cert) => scripts.push(plutus_execution(
+                ScriptPurpose::Certify { index },
+                script_hash,
+                cert.script_source.as_ref(),
+                cert.redeemer.as_ref(),
+            )),
+            Certificate::SimpleScriptCertificate(cert) => scripts.push(native_execution(
+                ScriptPurpose::Certify { index },
+                script_hash,
+                cert.simple_script_source.as_ref(),
+            )),
+        }
+    }
+
+    for vote in &body.votes {
+        match vote {
+            Vote::BasicVote(_) => {}
+            Vote::ScriptVote(vote) => scripts.push(plutus_execution(
+                ScriptPurpose::Vote {
+                    voter: describe_voter(&vote.vote.voter),
+                },
+                voter_script_hash(&vote.vote.voter),
+                vote.script_source.as_ref(),
+                vote.redeemer.as_ref(),
+            )),
+            Vote::SimpleScriptVote(vote) => scripts.push(native_execution(
+                ScriptPurpose::Vote {
+                    voter: describe_voter(&vote.vote.voter),
+                },
+                voter_script_hash(&vote.vote.voter),
+                vote.simple_script_source.as_ref(),
+            )),
+        }
+    }
+
+    scripts
+}
+
+fn plutus_execution(
+    purpose: ScriptPurpose,
+    script_hash: Option<String>,
+    source: Option<&ScriptSource>,
+    redeemer: Option<&Redeemer>,
+) -> ScriptExecution {
+    let (language, inline_source) = match source {
+        Some(ScriptSource::ProvidedScriptSource(source)) => (Some(&source.language_version), None),
+        Some(ScriptSource::InlineScriptSource(source)) => {
+            (Some(&source.language_version), Some(source))
+        }
+        None => (None, None),
+    };
+    ScriptExecution {
+        purpose,
+        script_hash: inline_source
+            .map(|source| source.script_hash.clone())
+            .or(script_hash),
+        language: match language {
+            Some(LanguageVersion::V1) => "PlutusV1",
+            Some(LanguageVersion::V2) => "PlutusV2",
+            Some(LanguageVersion::V3) => "PlutusV3",
+            None => "Plutus",
+        }
+        .to_string(),
+        reference_input: inline_source.map(|source| describe_ref_tx_in(&source.ref_tx_in)),
+        redeemer: redeemer.map(|redeemer| {
+            pretty_plutus_data_cbor(&redeemer.data).unwrap_or_else(|_| redeemer.data.clone())
+        }),
+        ex_units: redeemer.map(|redeemer| redeemer.ex_units.clone()),
+    }
+}
+
+fn native_execution(
+    purpose: ScriptPurpose,
+    script_hash: Option<String>,
+    source: Option<&SimpleScriptSource>,
+) -> ScriptExecution {
+    let (script_hash, reference_input) = match source {
+        Some(SimpleScriptSource::InlineSimpleScriptSource(source)) => (
+            Some(source.simple_script_hash.clone()),
+            Some(describe_ref_tx_in(&source.ref_tx_in)),
+        ),
+        _ => (script_hash, None),
+    };
+    ScriptExecution {
+        purpose,
+        script_hash,
+        language: "Native".to_string(),
+        reference_input,
+        redeemer: None,
+        ex_units: None,
+    }
+}
+
+fn script_hash_of(credential: Option<&Credential>) -> Option<String> {
+    match credential {
+        Some(Credential::ScriptHash(hash)) => Some(hash.clone()),
+        _ => None,
+    }
+}
+
+fn stake_script_hash(address: &str) -> Option<String> {
+    Address::parse(address)
+        .ok()
+        .and_then(|address| script_hash_of(address.stake_credential()))
+}
+
+fn voter_script_hash(voter: &Voter) -> Option<String> {
+    match voter {
+        Voter::ConstitutionalCommitteeHotCred(credential) => script_hash_of(Some(credential)),
+        _ => None,
+    }
+}
+
+fn certificate_stake_address(cert: &CertificateType) -> Option<&str> {
+    let address = match cert {
+        CertificateType::RegisterStake(cert) => &cert.stake_key_address,
+        CertificateType::DeregisterStake(cert) => &cert.stake_key_address,
+        CertificateType::DelegateStake(cert) => &cert.stake_key_address,
+        CertificateType::VoteDelegation(cert) => &cert.stake_key_address,
+        CertificateType::StakeAndVoteDelegation(cert) => &cert.stake_key_address,
+        CertificateType::StakeRegistrationAndDelegation(cert) => &cert.stake_key_address,
+        CertificateType::VoteRegistrationAndDelegation(cert) => &cert.stake_key_address,
+        CertificateType::StakeVoteRegistrationAndDelegation(cert) => &cert.stake_key_address,
+        _ => return None,
+    };
+    Some(address)
+}
+
+fn slot_time(slot: u64, slot_config: &SlotConfig) -> SlotTime {
+    let unix_time = slot_to_begin_unix_time(slot, slot_config);
+    SlotTime {
+        slot,
+        unix_time,
+        utc: unix_time_to_utc(unix_time),
+    }
+}
+
+fn describe_ref_tx_in(ref_tx_in: &RefTxIn) -> String {
+    format!("{}#{}", ref_tx_in.tx_hash, ref_tx_in.tx_index)
+}
+
+fn describe_drep(drep: &DRep) -> String {
+    match drep {
+        DRep::DRepId(drep_id) => format!("DRep {}", drep_id),
+        DRep::AlwaysAbstain => "always abstain".to_string(),
+        DRep::AlwaysNoConfidence => "always no confidence".to_string(),
+    }
+}
+
+pub(crate) fn describe_voter(voter: &Voter) -> String {
+    match voter {
+        Voter::ConstitutionalCommitteeHotCred(Credential::KeyHash(hash))
+        | Voter::ConstitutionalCommitteeHotCred(Credential::ScriptHash(hash)) => {
+            format!("Committee member {}", hash)
+        }
+        Voter::DRepId(drep_id) => format!("DRep {}", drep_id),
+        Voter::StakingPoolKeyHash(pool) => format!("Pool {}", pool),
+    }
+}
+
+fn describe_vote(vote: &Vote) -> String {
+    let vote: &VoteType = match vote {
+        Vote::BasicVote(vote) => vote,
+        Vote::ScriptVote(vote) => &vote.vote,
+        Vote::SimpleScriptVote(vote) => &vote.vote,
+    };
+    let kind = match vote.voting_procedure.vote_kind {
+        VoteKind::Yes => "Yes",
+        VoteKind::No => "No",
+        VoteKind::Abstain => "Abstain",
+    };
+    format!(
+        "{} votes {} on governance action {}",
+        describe_voter(&vote.voter),
+        kind,
+        describe_ref_tx_in(&vote.gov_action_id)
+    )
+}
+
+fn describe_proposal(proposal: &Proposal) -> String {
+    let proposal = proposal.proposal_type();
+    format!(
+        "Propose a governance action with a deposit of {} lovelace, refunded to {}",
+        proposal.deposit, proposal.reward_account
+    )
+}
+
+fn describe_certificate(cert: &CertificateType) -> String {
+    match cert {
+        CertificateType::RegisterStake(cert) => format!(
+            "Register stake address {} with a deposit of {} lovelace",
+            cert.stake_key_address, cert.coin
+        ),
+        CertificateType::DeregisterStake(cert) => format!(
+            "Deregister stake address {}, refunding its deposit",
+            cert.stake_key_address
+        ),
+        CertificateType::DelegateStake(cert) => format!(
+            "Delegate stake of {} to pool {}",
+            cert.stake_key_address, cert.pool_id
+        ),
+        CertificateType::RegisterPool(cert) => format!(
+            "Register pool operated by {} with pledge {} lovelace and cost {} lovelace",
+            cert.pool_params.operator, cert.pool_params.pledge, cert.pool_params.cost
+        ),
+        CertificateType::RetirePool(cert) => {
+            format!("Retire pool {} at epoch {}", cert.pool_id, cert.epoch)
+        }
+        CertificateType::VoteDelegation(cert) => format!(
+            "Delegate votes of {} to {}",
+            cert.stake_key_address,
+            describe_drep(&cert.drep)
+        ),
+        CertificateType::StakeAndVoteDelegation(cert) => format!(
+            "Delegate stake of {} to pool {} and votes to {}",
+            cert.stake_key_address,
+            cert.pool_key_hash,
+            describe_drep(&cert.drep)
+        ),
+        CertificateType::StakeRegistrationAndDelegation(cert) => format!(
+            "Register stake address {} with a deposit of {} lovelace and delegate to pool {}",
+            cert.stake_key_address, cert.coin, cert.pool_key_hash
+        ),
+        CertificateType::VoteRegistrationAndDelegation(cert) => format!(
+            "Register stake address {} with a deposit of {} lovelace and delegate votes to {}",
+            cert.stake_key_address,
+            cert.coin,
+            describe_drep(&cert.drep)
+        ),
+        CertificateType::StakeVoteRegistrationAndDelegation(cert) => format!(
+            "Register stake address {} with a deposit of {} lovelace, delegate to pool {} and votes to {}",
+            cert.stake_key_address,
+            cert.coin,
+            cert.pool_key_hash,
+            describe_drep(&cert.drep)
+        ),
+        CertificateType::CommitteeHotAuth(cert) => format!(
+            "Authorize committee hot key {} for cold key {}",
+            cert.committee_hot_key_address, cert.committee_cold_key_address
+        ),
+        CertificateType::CommitteeColdResign(cert) => format!(
+            "Resign committee cold key {}",
+            cert.committee_cold_key_address
+        ),
+        CertificateType::DRepRegistration(cert) => format!(
+            "Register DRep {} with a deposit of {} lovelace",
+            cert.drep_id, cert.coin
+        ),
+        CertificateType::DRepDeregistration(cert) => format!(
+            "Deregister DRep {}, refunding {} lovelace",
+            cert.drep_id, cert.coin
+        ),
+        CertificateType::DRepUpdate(cert) => format!("Update DRep {}", cert.drep_id),
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    data::ValueDelta, Address, Asset, Credential, Protocol, TxBuilderBody, TxIn, TxInParameter,
+    UTxO, WError,
+};
+
+use super::{certificates::same_address, TxTester};
+
+impl TxTester {
+    /// ## Testing methods for balance
+    ///
+    /// Checks if the value held at the address changes by exactly `expected`, i.e. the outputs
+    /// paying to it minus the inputs spent from it. Input amounts come from the inputs
+    /// themselves or from the UTxOs given to [`TxTester::with_utxos`].
+    pub fn address_balance_change(&mut self, address: &str, expected: &ValueDelta) -> &mut Self {
+        let change = self.balance_change(|output_address| same_address(output_address, address));
+        self.check_balance_change(
+            "address_balance_change",
+            &format!("address {}", address),
+            change,
+            expected,
+        )
+    }
+
+    /// ## Testing methods for balance
+    ///
+    /// Checks if the value held at all addresses with the payment credential changes by
+    /// exactly `expected`, see [`TxTester::address_balance_change`].
+    pub fn payment_credential_balance_change(
+        &mut self,
+        credential: &Credential,
+        expected: &ValueDelta,
+    ) -> &mut Self {
+        let change = self.balance_change(|output_address| {
+            Address::parse(output_address)
+                .is_ok_and(|address| address.payment_credential() == Some(credential))
+        });
+        self.check_balance_change(
+            "payment_credential_balance_change",
+            &format!("payment credential {:?}", credential),
+            change,
+            expected,
+        )
+    }
+
+    /// ## Testing methods for balance
+    ///
+    /// Checks if the value held at all addresses with the stake credential changes by
+    /// exactly `expected`, see [`TxTester::address_balance_change`].
+    pub fn stake_credential_balance_change(
+        &mut self,
+        credential: &Credential,
+        expected: &ValueDelta,
+    ) -> &mut Self {
+        let change = self.balance_change(|output_address| {
+            Address::parse(output_address)
+                .is_ok_and(|address| address.stake_credential() == Some(credential))
+        });
+        self.check_balance_change(
+            "stake_credential_balance_change",
+            &format!("stake credential {:?}", credential),
+            change,
+            expected,
+        )
+    }
+
+    /// ## Testing methods for balance
+    ///
+    /// Checks if value is conserved: inputs + withdrawals + mint + deposit refunds equal
+    /// outputs + fee + deposits. Deposits the certificates do not carry are taken from `protocol`.
+    pub fn value_conserved(&mut self, protocol: &Protocol) -> &mut Self {
+        let fee = match self.tx_body.fee.clone() {
+            Some(fee) => match fee.parse::<i128>() {
+                Ok(fee) => fee,
+                Err(_) => {
+                    self.add_trace("value_conserved", &format!("tx fee {} is invalid", fee));
+                    return self;
+                }
+            },
+            None => {
+                self.add_trace("value_conserved", "tx fee is not set");
+                return self;
+            }
+        };
+        let delta = match ValueDelta::from_tx_builder_body(&self.resolved_body(), protocol) {
+            Ok(delta) => delta,
+            Err(err) => {
+                self.add_trace(
+                    "value_conserved",
+                    &format!("cannot compute tx balance: {:?}", err),
+                );
+                return self;
+            }
+        };
+        let mut imbalance = delta;
+        if let Err(err) = imbalance.add_asset("lovelace", -fee) {
+            self.add_trace("value_conserved", &format!("{:?}", err));
+            return self;
+        }
+        if !imbalance.is_empty() {
+            self.add_trace(
+                "value_conserved",
+                &format!(
+                    "tx is not balanced, consumed minus produced is {:?} after fee {}",
+                    imbalance.0, fee
+                ),
+            );
+        } else {
+            self.add_pass("value_conserved");
+        }
+        self
+    }
+
+    fn check_balance_change(
+        &mut self,
+        func_name: &str,
+        holder: &str,
+        change: Result<ValueDelta, WError>,
+        expected: &ValueDelta,
+    ) -> &mut Self {
+        match change {
+            Ok(change) if change == *expected => self.add_pass(func_name),
+            Ok(change) => {
+                let difference = change
+                    .checked_sub(expected)
+                    .map(|difference| format!("{:?}", difference.0))
+                    .unwrap_or_else(|err| format!("{:?}", err));
+                self.add_mismatch(
+                    func_name,
+                    &format!(
+                        "{} changes by {:?}, expected {:?}, difference {}",
+                        holder, change.0, expected.0, difference
+                    ),
+                    &format!("{:?}", expected.0),
+                    &format!("{:?}", change.0),
+                );
+            }
+            Err(err) => {
+                self.add_trace(
+                    func_name,
+                    &format!("cannot compute value change of {}: {:?}", holder, err),
+                );
+            }
+        }
+        self
+    }
+
+    /// The outputs minus the inputs at the addresses accepted by `holds`
+    fn balance_change(&self, holds: impl Fn(&str) -> bool) -> Result<ValueDelta, WError> {
+        let mut change = ValueDelta::new();
+        for input in &self.tx_body.inputs {
+            let tx_in = tx_in_parameter(input);
+            let (address, amount) = self.resolve_input(tx_in).ok_or_else(|| {
+                WError::new(
+                    "balance_change",
+                    &format!(
+                        "input {}#{} is unresolved, provide the spent UTxO with with_utxos",
+                        tx_in.tx_hash, tx_in.tx_index
+                    ),
+                )
+            })?;
+            if holds(&address) {
+                change.sub_assets(&amount)?;
+            }
+        }
+        for output in &self.tx_body.outputs {
+            if holds(&output.address) {
+                change.add_assets(&output.amount)?;
+            }
+        }
+        Ok(change)
+    }
+
+    /// The address and amount of an input, from the input itself or the resolved UTxOs
+    fn resolve_input(&self, tx_in: &TxInParameter) -> Option<(String, Vec<Asset>)> {
+        if let (Some(address), Some(amount)) = (&tx_in.address, &tx_in.amount) {
+            return Some((address.clone(), amount.clone()));
+        }
+        self.resolved_utxo(&tx_in.tx_hash, tx_in.tx_index)
+            .map(|utxo| (utxo.output.address.clone(), utxo.output.amount.clone()))
+    }
+
+    /// The tx body with input amounts filled in from the resolved UTxOs where missing
+    fn resolved_body(&self) -> TxBuilderBody {
+        let mut body = self.tx_body.clone();
+        for input in body.inputs.iter_mut() {
+            let tx_in = match input {
+                TxIn::PubKeyTxIn(tx_in) => &mut tx_in.tx_in,
+                TxIn::SimpleScriptTxIn(tx_in) => &mut tx_in.tx_in,
+                TxIn::ScriptTxIn(tx_in) => &mut tx_in.tx_in,
+            };
+            if tx_in.amount.is_none() {
+                tx_in.amount = self
+                    .resolved_utxo(&tx_in.tx_hash, tx_in.tx_index)
+                    .map(|utxo| utxo.output.amount.clone());
+            }
+        }
+        body
+    }
+
+    /// The UTxO given to [`TxTester::with_utxos`] at `tx_hash#tx_index`
+    pub(super) fn resolved_utxo(&self, tx_hash: &str, tx_index: u32) -> Option<&UTxO> {
+        self.resolved_utxos
+            .iter()
+            .find(|utxo| utxo.input.tx_hash == tx_hash && utxo.input.output_index == tx_index)
+    }
+}
+
+pub(super) fn tx_in_parameter(input: &TxIn) -> &TxInParameter {
+    match input {
+        TxIn::PubKeyTxIn(tx_in) => &tx_in.tx_in,
+        TxIn::SimpleScriptTxIn(tx_in) => &tx_in.tx_in,
+        TxIn::ScriptTxIn(tx_in) => &tx_in.tx_in,
+    }
+}
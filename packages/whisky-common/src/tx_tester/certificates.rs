@@ -0,0 +1,182 @@
+use crate::{Address, Certificate, CertificateType, DRep, GovernanceId};
+
+use super::TxTester;
+
+impl TxTester {
+    /// ## Testing methods for certificates
+    ///
+    /// Checks if the stake address delegates to the pool, by any certificate delegating stake.
+    pub fn stake_delegated(&mut self, stake_address: &str, pool_id: &str) -> &mut Self {
+        let is_delegated = self.certificate_types().iter().any(|cert| match cert {
+            CertificateType::DelegateStake(cert) => {
+                same_address(&cert.stake_key_address, stake_address) && cert.pool_id == pool_id
+            }
+            CertificateType::StakeAndVoteDelegation(cert) => {
+                same_address(&cert.stake_key_address, stake_address)
+                    && cert.pool_key_hash == pool_id
+            }
+            CertificateType::StakeRegistrationAndDelegation(cert) => {
+                same_address(&cert.stake_key_address, stake_address)
+                    && cert.pool_key_hash == pool_id
+            }
+            CertificateType::StakeVoteRegistrationAndDelegation(cert) => {
+                same_address(&cert.stake_key_address, stake_address)
+                    && cert.pool_key_hash == pool_id
+            }
+            _ => false,
+        });
+        if !is_delegated {
+            self.add_trace(
+                "stake_delegated",
+                &format!(
+                    "tx does not delegate stake of {} to pool {}",
+                    stake_address, pool_id
+                ),
+            );
+        } else {
+            self.add_pass("stake_delegated");
+        }
+        self
+    }
+
+    /// ## Testing methods for certificates
+    ///
+    /// Checks if the stake address delegates its votes to the DRep, by any certificate delegating votes.
+    pub fn vote_delegated(&mut self, stake_address: &str, drep: &DRep) -> &mut Self {
+        let is_delegated = self.certificate_types().iter().any(|cert| {
+            let (cert_stake_address, cert_drep) = match cert {
+                CertificateType::VoteDelegation(cert) => (&cert.stake_key_address, &cert.drep),
+                CertificateType::StakeAndVoteDelegation(cert) => {
+                    (&cert.stake_key_address, &cert.drep)
+                }
+                CertificateType::VoteRegistrationAndDelegation(cert) => {
+                    (&cert.stake_key_address, &cert.drep)
+                }
+                CertificateType::StakeVoteRegistrationAndDelegation(cert) => {
+                    (&cert.stake_key_address, &cert.drep)
+                }
+                _ => return false,
+            };
+            same_address(cert_stake_address, stake_address) && same_drep(cert_drep, drep)
+        });
+        if !is_delegated {
+            self.add_trace(
+                "vote_delegated",
+                &format!(
+                    "tx does not delegate votes of {} to {:?}",
+                    stake_address, drep
+                ),
+            );
+        } else {
+            self.add_pass("vote_delegated");
+        }
+        self
+    }
+
+    /// ## Testing methods for certificates
+    ///
+    /// Checks if the stake address is registered, alone or together with a delegation.
+    pub fn stake_registered(&mut self, stake_address: &str) -> &mut Self {
+        let is_registered = self.certificate_types().iter().any(|cert| {
+            let cert_stake_address = match cert {
+                CertificateType::RegisterStake(cert) => &cert.stake_key_address,
+                CertificateType::StakeRegistrationAndDelegation(cert) => &cert.stake_key_address,
+                CertificateType::VoteRegistrationAndDelegation(cert) => &cert.stake_key_address,
+                CertificateType::StakeVoteRegistrationAndDelegation(cert) => {
+                    &cert.stake_key_address
+                }
+                _ => return false,
+            };
+            same_address(cert_stake_address, stake_address)
+        });
+        if !is_registered {
+            self.add_trace(
+                "stake_registered",
+                &format!("tx does not register stake address {}", stake_address),
+            );
+        } else {
+            self.add_pass("stake_registered");
+        }
+        self
+    }
+
+    /// ## Testing methods for certificates
+    ///
+    /// Checks if the stake address is deregistered.
+    pub fn stake_deregistered(&mut self, stake_address: &str) -> &mut Self {
+        let is_deregistered = self.certificate_types().iter().any(|cert| match cert {
+            CertificateType::DeregisterStake(cert) => {
+                same_address(&cert.stake_key_address, stake_address)
+            }
+            _ => false,
+        });
+        if !is_deregistered {
+            self.add_trace(
+                "stake_deregistered",
+                &format!("tx does not deregister stake address {}", stake_address),
+            );
+        } else {
+            self.add_pass("stake_deregistered");
+        }
+        self
+    }
+
+    /// ## Testing methods for certificates
+    ///
+    /// Checks if the transaction has exactly the expected number of certificates.
+    pub fn certificates_count(&mut self, expected_count: usize) -> &mut Self {
+        let count = self.tx_body.certificates.len();
+        if count != expected_count {
+            self.add_mismatch(
+                "certificates_count",
+                &format!("tx has {} certificates, expected {}", count, expected_count),
+                &expected_count.to_string(),
+                &count.to_string(),
+            );
+        } else {
+            self.add_pass("certificates_count");
+        }
+        self
+    }
+
+    fn certificate_types(&self) -> Vec<&CertificateType> {
+        self.tx_body
+            .certificates
+            .iter()
+            .map(|certificate| match certificate {
+                Certificate::BasicCertificate(cert) => cert,
+                Certificate::ScriptCertificate(cert) => &cert.cert,
+                Certificate::SimpleScriptCertificate(cert) => &cert.cert,
+            })
+            .collect()
+    }
+}
+
+/// Compare addresses by content, so bech32 and hex forms of the same address match
+pub(super) fn same_address(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    match (Address::parse(a), Address::parse(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn same_drep(a: &DRep, b: &DRep) -> bool {
+    match (a, b) {
+        (DRep::DRepId(a), DRep::DRepId(b)) => same_drep_id(a, b),
+        _ => a == b,
+    }
+}
+
+/// Compare DRep ids by credential, so CIP-129 and CIP-105 ids of the same DRep match
+pub(super) fn same_drep_id(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    match (GovernanceId::from_bech32(a), GovernanceId::from_bech32(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
@@ -0,0 +1,230 @@
+use std::any::type_name;
+
+use serde_json::Value;
+
+use crate::{
+    data::{decode_plutus_data, partial_diff_plutus_data, pretty_plutus_data, PlutusDataJson},
+    Datum, DatumSource, TxIn, TxInParameter, UTxO,
+};
+
+use super::{balance::tx_in_parameter, TxTester};
+
+impl TxTester {
+    /// ## Datum resolution for testing datums
+    ///
+    /// Register a datum by its hash, so outputs and inputs attaching it by hash can be checked.
+    pub fn with_datum(&mut self, datum_hash: &str, datum_cbor: &str) -> &mut Self {
+        self.datums
+            .insert(datum_hash.to_string(), datum_cbor.to_string());
+        self
+    }
+
+    /// ## Datum resolution for testing datums
+    ///
+    /// Register the UTxOs spent by the transaction, so the datums of inputs can be checked.
+    pub fn with_utxos(&mut self, utxos: &[UTxO]) -> &mut Self {
+        self.resolved_utxos.extend_from_slice(utxos);
+        self
+    }
+
+    /// ## Testing methods for outputs
+    ///
+    /// *Reminder - It must be called after filtering methods for outputs*
+    ///
+    /// Check if any output has a datum, inline or resolved by hash, decoding into `T`
+    /// and satisfying the predicate.
+    pub fn outputs_datum_satisfy<T, F>(&mut self, predicate: F) -> &mut Self
+    where
+        T: PlutusDataJson,
+        F: Fn(&T) -> bool,
+    {
+        let candidates = self.output_datums();
+        self.check_datums(
+            "outputs_datum_satisfy",
+            "outputs",
+            candidates,
+            typed_check::<T, F>(predicate),
+        )
+    }
+
+    /// ## Testing methods for outputs
+    ///
+    /// *Reminder - It must be called after filtering methods for outputs*
+    ///
+    /// Check if any output has a datum, inline or resolved by hash, partially matching
+    /// `expected` in detailed schema JSON, see [`partial_diff_plutus_data`].
+    pub fn outputs_datum_match(&mut self, expected: &Value) -> &mut Self {
+        let candidates = self.output_datums();
+        self.check_datums(
+            "outputs_datum_match",
+            "outputs",
+            candidates,
+            partial_check(expected),
+        )
+    }
+
+    /// ## Testing methods for inputs
+    ///
+    /// *Reminder - It must be called after filtering methods for inputs*
+    ///
+    /// Check if any input has a datum decoding into `T` and satisfying the predicate.
+    /// Datums come from the script input itself or from the UTxOs given to [`TxTester::with_utxos`].
+    pub fn inputs_datum_satisfy<T, F>(&mut self, predicate: F) -> &mut Self
+    where
+        T: PlutusDataJson,
+        F: Fn(&T) -> bool,
+    {
+        let candidates = self.input_datums();
+        self.check_datums(
+            "inputs_datum_satisfy",
+            "inputs",
+            candidates,
+            typed_check::<T, F>(predicate),
+        )
+    }
+
+    /// ## Testing methods for inputs
+    ///
+    /// *Reminder - It must be called after filtering methods for inputs*
+    ///
+    /// Check if any input has a datum partially matching `expected` in detailed schema JSON,
+    /// see [`partial_diff_plutus_data`].
+    pub fn inputs_datum_match(&mut self, expected: &Value) -> &mut Self {
+        let candidates = self.input_datums();
+        self.check_datums(
+            "inputs_datum_match",
+            "inputs",
+            candidates,
+            partial_check(expected),
+        )
+    }
+
+    /// Run `check` on every candidate datum, tracing why each one failed if none passes
+    fn check_datums(
+        &mut self,
+        func_name: &str,
+        kind: &str,
+        candidates: Vec<(String, Result<String, String>)>,
+        check: impl Fn(&Value) -> Result<(), String>,
+    ) -> &mut Self {
+        let mut failures = vec![];
+        for (label, datum_cbor) in candidates {
+            let result = datum_cbor.and_then(|datum_cbor| {
+                let data = decode_datum(&datum_cbor)?;
+                check(&data)
+            });
+            match result {
+                Ok(()) => {
+                    self.add_pass(func_name);
+                    return self;
+                }
+                Err(reason) => failures.push(format!("{}: {}", label, reason)),
+            }
+        }
+        let message = if failures.is_empty() {
+            format!("No {} to check datums of", kind)
+        } else {
+            format!("No {} with a matching datum\n{}", kind, failures.join("\n"))
+        };
+        self.add_trace(func_name, &message);
+        self
+    }
+
+    fn output_datums(&self) -> Vec<(String, Result<String, String>)> {
+        self.outputs_evaluating
+            .iter()
+            .enumerate()
+            .map(|(i, output)| {
+                let label = format!("output {} at {}", i, output.address);
+                let datum_cbor = match &output.datum {
+                    Some(Datum::Inline(datum)) | Some(Datum::Embedded(datum)) => Ok(datum.clone()),
+                    Some(Datum::Hash(datum_hash)) => self.resolve_datum_hash(datum_hash),
+                    None => Err("no datum".to_string()),
+                };
+                (label, datum_cbor)
+            })
+            .collect()
+    }
+
+    fn input_datums(&self) -> Vec<(String, Result<String, String>)> {
+        self.inputs_evaluating
+            .iter()
+            .map(|input| {
+                let tx_in = tx_in_parameter(input);
+                let label = format!("input {}#{}", tx_in.tx_hash, tx_in.tx_index);
+                (label, self.resolve_input_datum(input, tx_in))
+            })
+            .collect()
+    }
+
+    fn resolve_input_datum(&self, input: &TxIn, tx_in: &TxInParameter) -> Result<String, String> {
+        if let TxIn::ScriptTxIn(script_tx_in) = input {
+            if let Some(DatumSource::ProvidedDatumSource(source)) =
+                &script_tx_in.script_tx_in.datum_source
+            {
+                return Ok(source.data.clone());
+            }
+        }
+        match self.resolved_utxo(&tx_in.tx_hash, tx_in.tx_index) {
+            Some(resolved) => match (&resolved.output.plutus_data, &resolved.output.data_hash) {
+                (Some(datum), _) => Ok(datum.clone()),
+                (None, Some(datum_hash)) => self.resolve_datum_hash(datum_hash),
+                (None, None) => Err("no datum".to_string()),
+            },
+            None => Err("datum unknown, provide the spent UTxO with with_utxos".to_string()),
+        }
+    }
+
+    fn resolve_datum_hash(&self, datum_hash: &str) -> Result<String, String> {
+        self.datums.get(datum_hash).cloned().ok_or_else(|| {
+            format!(
+                "datum hash {} unresolved, provide the datum with with_datum",
+                datum_hash
+            )
+        })
+    }
+}
+
+fn decode_datum(datum_cbor: &str) -> Result<Value, String> {
+    let cbor = hex::decode(datum_cbor)
+        .map_err(|err| format!("datum {} is not valid hex: {}", datum_cbor, err))?;
+    decode_plutus_data(&cbor)
+        .map_err(|err| format!("datum {} is not PlutusData: {:?}", datum_cbor, err))
+}
+
+fn typed_check<T, F>(predicate: F) -> impl Fn(&Value) -> Result<(), String>
+where
+    T: PlutusDataJson,
+    F: Fn(&T) -> bool,
+{
+    move |data: &Value| {
+        let typed = T::from_json(data).map_err(|err| {
+            format!(
+                "datum does not decode into {}: {:?}\n  datum: {}",
+                type_name::<T>(),
+                err,
+                pretty_plutus_data(data)
+            )
+        })?;
+        if predicate(&typed) {
+            Ok(())
+        } else {
+            Err(format!(
+                "datum does not satisfy the predicate\n  datum: {}",
+                pretty_plutus_data(data)
+            ))
+        }
+    }
+}
+
+fn partial_check(expected: &Value) -> impl Fn(&Value) -> Result<(), String> + '_ {
+    move |data: &Value| {
+        let diffs = partial_diff_plutus_data(expected, data);
+        if diffs.is_empty() {
+            Ok(())
+        } else {
+            let diffs: Vec<String> = diffs.iter().map(|diff| format!("  {}", diff)).collect();
+            Err(format!("datum differs:\n{}", diffs.join("\n")))
+        }
+    }
+}
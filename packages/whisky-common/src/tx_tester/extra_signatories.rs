@@ -11,7 +11,9 @@ impl TxTester {
                 "key_signed",
                 &format!("tx does not have key {} signed", key_hash),
             )
-        };
+        } else {
+            self.add_pass("key_signed");
+        }
         self
     }
 
@@ -31,6 +33,8 @@ impl TxTester {
                     key_hashes.join(", ")
                 ),
             );
+        } else {
+            self.add_pass("one_of_keys_signed");
         }
         self
     }
@@ -56,6 +60,8 @@ impl TxTester {
                     missing_keys.join(", ")
                 ),
             );
+        } else {
+            self.add_pass("all_keys_signed");
         }
         self
     }
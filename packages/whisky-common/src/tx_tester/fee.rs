@@ -0,0 +1,24 @@
+use super::TxTester;
+
+impl TxTester {
+    /// ## Testing methods for fee
+    ///
+    /// Checks if the transaction fee in lovelace is within `min` and `max`, both inclusive.
+    pub fn fee_within(&mut self, min: u64, max: u64) -> &mut Self {
+        match self.tx_body.fee.as_ref().map(|fee| fee.parse::<u64>()) {
+            Some(Ok(fee)) if (min..=max).contains(&fee) => self.add_pass("fee_within"),
+            Some(Ok(fee)) => self.add_mismatch(
+                "fee_within",
+                &format!("tx fee {} is not within {} and {}", fee, min, max),
+                &format!("{}..={}", min, max),
+                &fee.to_string(),
+            ),
+            Some(Err(_)) => self.add_trace(
+                "fee_within",
+                &format!("tx fee {:?} is not a valid amount", self.tx_body.fee),
+            ),
+            None => self.add_trace("fee_within", "tx fee is not set"),
+        }
+        self
+    }
+}
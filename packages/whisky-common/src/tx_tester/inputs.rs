@@ -112,13 +112,17 @@ impl TxTester {
         });
         let is_value_correct = value.eq(&expected_value);
         if !is_value_correct {
-            self.add_trace(
+            self.add_mismatch(
                 "inputs_value",
                 &format!(
                     "inputs {:?} have value {:?}, expect {:?}",
                     self.inputs_evaluating, value, expected_value
                 ),
+                &format!("{:?}", expected_value),
+                &format!("{:?}", value),
             );
+        } else {
+            self.add_pass("inputs_value");
         }
 
         self
@@ -149,6 +153,8 @@ impl TxTester {
                 "inputs_inline_datum_exist",
                 &format!("No inputs with inline datum matching: {}", datum_cbor),
             );
+        } else {
+            self.add_pass("inputs_inline_datum_exist");
         }
         self
     }
@@ -0,0 +1,59 @@
+use crate::{TransactionMetadatum, WError};
+
+use super::TxTester;
+
+impl TxTester {
+    /// ## Testing methods for metadata
+    ///
+    /// Checks if the transaction has metadata under the label, e.g. 721 for CIP-25.
+    pub fn metadata_exists(&mut self, label: u64) -> &mut Self {
+        if self.metadata_logic(label).is_none() {
+            self.add_trace(
+                "metadata_exists",
+                &format!("tx does not have metadata under label {}", label),
+            );
+        } else {
+            self.add_pass("metadata_exists");
+        }
+        self
+    }
+
+    /// ## Testing methods for metadata
+    ///
+    /// Checks if the metadata under the label equals the expected value, long text and bytes
+    /// being compared in their chunked form.
+    pub fn metadata_value(&mut self, label: u64, expected: &TransactionMetadatum) -> &mut Self {
+        let expected = expected.clone().chunked();
+        match self.metadata_logic(label) {
+            Some(Ok(metadatum)) if metadatum == expected => self.add_pass("metadata_value"),
+            Some(Ok(metadatum)) => self.add_mismatch(
+                "metadata_value",
+                &format!(
+                    "metadata under label {} is {}, expected {}",
+                    label,
+                    metadatum.to_json(),
+                    expected.to_json()
+                ),
+                &expected.to_json().to_string(),
+                &metadatum.to_json().to_string(),
+            ),
+            Some(Err(err)) => self.add_trace(
+                "metadata_value",
+                &format!("metadata under label {} is invalid: {:?}", label, err),
+            ),
+            None => self.add_trace(
+                "metadata_value",
+                &format!("tx does not have metadata under label {}", label),
+            ),
+        }
+        self
+    }
+
+    fn metadata_logic(&self, label: u64) -> Option<Result<TransactionMetadatum, WError>> {
+        self.tx_body
+            .metadata
+            .iter()
+            .find(|metadata| metadata.tag == label.to_string())
+            .map(|metadata| metadata.to_metadatum())
+    }
+}
@@ -15,6 +15,8 @@ impl TxTester {
                     policy_id, asset_name, quantity
                 ),
             );
+        } else {
+            self.add_pass("token_minted");
         }
 
         self
@@ -50,6 +52,9 @@ impl TxTester {
                 ),
             );
         }
+        if is_token_minted && is_only_one_mint {
+            self.add_pass("only_token_minted");
+        }
         self
     }
 
@@ -93,6 +98,9 @@ impl TxTester {
                 ),
             );
         }
+        if is_token_minted && is_only_one_mint {
+            self.add_pass("policy_only_minted_token");
+        }
         self
     }
 
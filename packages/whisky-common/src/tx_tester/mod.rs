@@ -1,10 +1,23 @@
-use crate::{Output, TxBuilderBody, TxIn, WError};
+use std::collections::HashMap;
 
+use crate::{Output, TxBuilderBody, TxIn, UTxO, WError};
+
+mod balance;
+mod certificates;
+mod datums;
 mod extra_signatories;
+mod fee;
 mod inputs;
+mod metadata;
 mod mints;
 mod outputs;
+mod reference_inputs;
+mod report;
 mod validity_range;
+mod votes;
+mod withdrawals;
+
+pub use report::*;
 
 #[derive(Clone)]
 pub struct TxTester {
@@ -12,6 +25,12 @@ pub struct TxTester {
     pub inputs_evaluating: Vec<TxIn>,
     pub outputs_evaluating: Vec<Output>,
     pub traces: Option<WError>,
+    /// Datum CBOR by datum hash, to resolve datums attached by hash
+    pub datums: HashMap<String, String>,
+    /// The UTxOs spent by the transaction, to resolve datums of inputs
+    pub resolved_utxos: Vec<UTxO>,
+    /// Every assertion run so far, see [`TxTester::report`]
+    pub assertions: Vec<AssertionRecord>,
 }
 
 impl TxTester {
@@ -21,10 +40,83 @@ impl TxTester {
             inputs_evaluating: vec![],
             outputs_evaluating: vec![],
             traces: None,
+            datums: HashMap::new(),
+            resolved_utxos: vec![],
+            assertions: vec![],
         }
     }
 
     pub fn add_trace(&mut self, func_name: &str, message: &str) {
+        self.record_assertion(func_name, false, Some(message), None, None);
+        self.push_trace(func_name, message);
+    }
+
+    /// Record a failed assertion together with the values compared
+    pub fn add_mismatch(&mut self, func_name: &str, message: &str, expected: &str, actual: &str) {
+        self.record_assertion(
+            func_name,
+            false,
+            Some(message),
+            Some(expected),
+            Some(actual),
+        );
+        self.push_trace(func_name, message);
+    }
+
+    /// Record a passed assertion
+    pub fn add_pass(&mut self, func_name: &str) {
+        self.record_assertion(func_name, true, None, None, None);
+    }
+
+    pub fn report(&self) -> TesterReport {
+        TesterReport {
+            assertions: self.assertions.clone(),
+        }
+    }
+
+    fn record_assertion(
+        &mut self,
+        func_name: &str,
+        passed: bool,
+        message: Option<&str>,
+        expected: Option<&str>,
+        actual: Option<&str>,
+    ) {
+        let inputs_evaluating = self
+            .inputs_evaluating
+            .iter()
+            .map(|input| {
+                let tx_in = balance::tx_in_parameter(input);
+                format!("{}#{}", tx_in.tx_hash, tx_in.tx_index)
+            })
+            .collect();
+        let outputs_evaluating = self
+            .outputs_evaluating
+            .iter()
+            .map(|output| {
+                match self
+                    .tx_body
+                    .outputs
+                    .iter()
+                    .position(|candidate| candidate == output)
+                {
+                    Some(i) => format!("#{} {}", i, output.address),
+                    None => output.address.clone(),
+                }
+            })
+            .collect();
+        self.assertions.push(AssertionRecord {
+            name: func_name.to_string(),
+            passed,
+            message: message.map(str::to_string),
+            expected: expected.map(str::to_string),
+            actual: actual.map(str::to_string),
+            inputs_evaluating,
+            outputs_evaluating,
+        });
+    }
+
+    fn push_trace(&mut self, func_name: &str, message: &str) {
         if let Some(existing_trace) = &mut self.traces {
             let msg = format!("[Error - {}]: {}", func_name, message);
             existing_trace.add_trace(&msg);
@@ -1,4 +1,7 @@
-use crate::{data::Value, Datum};
+use crate::{
+    data::{decode_plutus_data, diff_plutus_data, pretty_plutus_data, Value},
+    Datum,
+};
 
 use super::TxTester;
 
@@ -109,13 +112,17 @@ impl TxTester {
         });
         let is_value_correct = value.eq(&expected_value);
         if !is_value_correct {
-            self.add_trace(
+            self.add_mismatch(
                 "outputs_value",
                 &format!(
                     "tx outputs {:?} have value {:?}, expected {:?}",
                     self.outputs_evaluating, value, expected_value
                 ),
+                &format!("{:?}", expected_value),
+                &format!("{:?}", value),
             );
+        } else {
+            self.add_pass("outputs_value");
         }
         self
     }
@@ -142,9 +149,52 @@ impl TxTester {
         if outputs_with_inline_datum.is_empty() {
             self.add_trace(
                 "outputs_inline_datum_exist",
-                &format!("No outputs with inline datum matching: {}", datum_cbor),
+                &format!(
+                    "No outputs with inline datum matching: {}{}",
+                    datum_cbor,
+                    self.inline_datum_mismatches(datum_cbor)
+                ),
             );
+        } else {
+            self.add_pass("outputs_inline_datum_exist");
         }
         self
     }
+
+    /// Describe how the inline datums of the evaluating outputs differ from `datum_cbor`
+    fn inline_datum_mismatches(&self, datum_cbor: &str) -> String {
+        let expected = match decode_datum(datum_cbor) {
+            Some(expected) => expected,
+            None => return String::new(),
+        };
+        let mut lines = vec![format!("expected datum: {}", pretty_plutus_data(&expected))];
+        for (i, output) in self.outputs_evaluating.iter().enumerate() {
+            if let Some(Datum::Inline(datum)) = &output.datum {
+                match decode_datum(datum) {
+                    Some(actual) => {
+                        let diffs: Vec<String> = diff_plutus_data(&expected, &actual)
+                            .iter()
+                            .map(|diff| format!("  {}", diff))
+                            .collect();
+                        lines.push(format!(
+                            "output {} at {} differs:\n{}",
+                            i,
+                            output.address,
+                            diffs.join("\n")
+                        ));
+                    }
+                    None => lines.push(format!(
+                        "output {} at {} has undecodable inline datum {}",
+                        i, output.address, datum
+                    )),
+                }
+            }
+        }
+        format!("\n{}", lines.join("\n"))
+    }
+}
+
+fn decode_datum(datum_cbor: &str) -> Option<serde_json::Value> {
+    let cbor = hex::decode(datum_cbor).ok()?;
+    decode_plutus_data(&cbor).ok()
 }
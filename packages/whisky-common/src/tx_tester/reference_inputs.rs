@@ -0,0 +1,41 @@
+use super::TxTester;
+
+impl TxTester {
+    /// ## Testing methods for reference inputs
+    ///
+    /// Checks if the UTxO `tx_hash#tx_index` is referenced by the transaction.
+    pub fn reference_input_exists(&mut self, tx_hash: &str, tx_index: u32) -> &mut Self {
+        let is_referenced = self
+            .tx_body
+            .reference_inputs
+            .iter()
+            .any(|ref_input| ref_input.tx_hash == tx_hash && ref_input.tx_index == tx_index);
+        if !is_referenced {
+            self.add_trace(
+                "reference_input_exists",
+                &format!("tx does not reference input {}#{}", tx_hash, tx_index),
+            );
+        } else {
+            self.add_pass("reference_input_exists");
+        }
+        self
+    }
+
+    /// ## Testing methods for collaterals
+    ///
+    /// Checks if the UTxO `tx_hash#tx_index` is used as collateral by the transaction.
+    pub fn collateral_exists(&mut self, tx_hash: &str, tx_index: u32) -> &mut Self {
+        let is_collateral = self.tx_body.collaterals.iter().any(|collateral| {
+            collateral.tx_in.tx_hash == tx_hash && collateral.tx_in.tx_index == tx_index
+        });
+        if !is_collateral {
+            self.add_trace(
+                "collateral_exists",
+                &format!("tx does not use {}#{} as collateral", tx_hash, tx_index),
+            );
+        } else {
+            self.add_pass("collateral_exists");
+        }
+        self
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+
+use crate::WError;
+
+/// One assertion run by a [`super::TxTester`].
+///
+/// The evaluating inputs and outputs are captured when the assertion runs, inputs as
+/// `tx_hash#tx_index` and outputs as their index in the tx body followed by the address.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertionRecord {
+    pub name: String,
+    pub passed: bool,
+    pub message: Option<String>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+    pub inputs_evaluating: Vec<String>,
+    pub outputs_evaluating: Vec<String>,
+}
+
+/// The assertions run by a [`super::TxTester`], in order, for CI reporting
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TesterReport {
+    pub assertions: Vec<AssertionRecord>,
+}
+
+impl TesterReport {
+    pub fn success(&self) -> bool {
+        self.assertions.iter().all(|assertion| assertion.passed)
+    }
+
+    pub fn passed(&self) -> Vec<&AssertionRecord> {
+        self.assertions
+            .iter()
+            .filter(|assertion| assertion.passed)
+            .collect()
+    }
+
+    pub fn failed(&self) -> Vec<&AssertionRecord> {
+        self.assertions
+            .iter()
+            .filter(|assertion| !assertion.passed)
+            .collect()
+    }
+
+    pub fn to_json(&self) -> Result<String, WError> {
+        serde_json::to_string_pretty(self).map_err(WError::from_err("TesterReport::to_json"))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, WError> {
+        serde_json::from_str(json).map_err(WError::from_err("TesterReport::from_json"))
+    }
+
+    /// Render as a JUnit XML test suite named `suite_name`, one test case per assertion.
+    ///
+    /// Test cases are numbered so repeated assertions stay distinct, and a failure carries
+    /// the message, expected and actual values and the filter context in its body.
+    pub fn to_junit_xml(&self, suite_name: &str) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            escape_xml(suite_name),
+            self.assertions.len(),
+            self.failed().len()
+        ));
+        for (i, assertion) in self.assertions.iter().enumerate() {
+            let open = format!(
+                "  <testcase classname=\"{}\" name=\"{}. {}\"",
+                escape_xml(suite_name),
+                i + 1,
+                escape_xml(&assertion.name)
+            );
+            if assertion.passed {
+                xml.push_str(&format!("{}/>\n", open));
+                continue;
+            }
+            let message = assertion.message.clone().unwrap_or_default();
+            xml.push_str(&format!("{}>\n", open));
+            xml.push_str(&format!(
+                "    <failure message=\"{}\">{}</failure>\n",
+                escape_xml(message.lines().next().unwrap_or_default()),
+                escape_xml(&failure_body(assertion))
+            ));
+            xml.push_str("  </testcase>\n");
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+}
+
+fn failure_body(assertion: &AssertionRecord) -> String {
+    let mut lines = vec![];
+    if let Some(message) = &assertion.message {
+        lines.push(message.clone());
+    }
+    if let Some(expected) = &assertion.expected {
+        lines.push(format!("expected: {}", expected));
+    }
+    if let Some(actual) = &assertion.actual {
+        lines.push(format!("actual: {}", actual));
+    }
+    if !assertion.inputs_evaluating.is_empty() {
+        lines.push(format!(
+            "inputs evaluating: {}",
+            assertion.inputs_evaluating.join(", ")
+        ));
+    }
+    if !assertion.outputs_evaluating.is_empty() {
+        lines.push(format!(
+            "outputs evaluating: {}",
+            assertion.outputs_evaluating.join(", ")
+        ));
+    }
+    lines.join("\n")
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -26,6 +26,8 @@ impl TxTester {
                     invalid_before, required_timestamp
                 ),
             );
+        } else {
+            self.add_pass("valid_after");
         }
 
         self
@@ -57,6 +59,8 @@ impl TxTester {
                     invalid_hereafter, required_timestamp
                 ),
             );
+        } else {
+            self.add_pass("valid_before");
         }
 
         self
@@ -0,0 +1,72 @@
+use crate::{Vote, VoteKind, VoteType, Voter};
+
+use super::{certificates::same_drep_id, TxTester};
+
+impl TxTester {
+    /// ## Testing methods for votes
+    ///
+    /// Checks if the voter casts `vote_kind` on the governance action `gov_action_tx_hash#gov_action_index`.
+    pub fn voted(
+        &mut self,
+        voter: &Voter,
+        gov_action_tx_hash: &str,
+        gov_action_index: u32,
+        vote_kind: VoteKind,
+    ) -> &mut Self {
+        let is_voted = self.votes_logic().iter().any(|vote| {
+            same_voter(&vote.voter, voter)
+                && vote.gov_action_id.tx_hash == gov_action_tx_hash
+                && vote.gov_action_id.tx_index == gov_action_index
+                && vote.voting_procedure.vote_kind == vote_kind
+        });
+        if !is_voted {
+            self.add_trace(
+                "voted",
+                &format!(
+                    "tx does not have {:?} voting {:?} on gov action {}#{}",
+                    voter, vote_kind, gov_action_tx_hash, gov_action_index
+                ),
+            );
+        } else {
+            self.add_pass("voted");
+        }
+        self
+    }
+
+    /// ## Testing methods for votes
+    ///
+    /// Checks if the DRep casts `vote_kind` on the governance action, see [`TxTester::voted`].
+    pub fn drep_voted(
+        &mut self,
+        drep_id: &str,
+        gov_action_tx_hash: &str,
+        gov_action_index: u32,
+        vote_kind: VoteKind,
+    ) -> &mut Self {
+        self.voted(
+            &Voter::DRepId(drep_id.to_string()),
+            gov_action_tx_hash,
+            gov_action_index,
+            vote_kind,
+        )
+    }
+
+    fn votes_logic(&self) -> Vec<&VoteType> {
+        self.tx_body
+            .votes
+            .iter()
+            .map(|vote| match vote {
+                Vote::BasicVote(vote) => vote,
+                Vote::ScriptVote(vote) => &vote.vote,
+                Vote::SimpleScriptVote(vote) => &vote.vote,
+            })
+            .collect()
+    }
+}
+
+fn same_voter(a: &Voter, b: &Voter) -> bool {
+    match (a, b) {
+        (Voter::DRepId(a), Voter::DRepId(b)) => same_drep_id(a, b),
+        _ => a == b,
+    }
+}
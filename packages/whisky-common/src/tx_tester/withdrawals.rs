@@ -0,0 +1,96 @@
+use crate::Withdrawal;
+
+use super::{certificates::same_address, TxTester};
+
+impl TxTester {
+    /// ## Testing methods for withdrawals
+    ///
+    /// Checks if the transaction withdraws exactly `coin` lovelace from the stake address.
+    pub fn withdrawal(&mut self, stake_address: &str, coin: u64) -> &mut Self {
+        let is_withdrawn = self
+            .withdrawal_logic(stake_address)
+            .is_some_and(|(withdrawn, _)| withdrawn == coin);
+        if !is_withdrawn {
+            self.add_trace(
+                "withdrawal",
+                &format!(
+                    "tx does not withdraw {} from {}, found {}",
+                    coin,
+                    stake_address,
+                    self.describe_withdrawal(stake_address)
+                ),
+            );
+        } else {
+            self.add_pass("withdrawal");
+        }
+        self
+    }
+
+    /// ## Testing methods for withdrawals
+    ///
+    /// Checks if the transaction withdraws exactly `coin` lovelace from the script stake address,
+    /// witnessed by a Plutus or native script.
+    pub fn script_withdrawal(&mut self, stake_address: &str, coin: u64) -> &mut Self {
+        let is_withdrawn = self
+            .withdrawal_logic(stake_address)
+            .is_some_and(|(withdrawn, is_script)| withdrawn == coin && is_script);
+        if !is_withdrawn {
+            self.add_trace(
+                "script_withdrawal",
+                &format!(
+                    "tx does not withdraw {} from script stake address {}, found {}",
+                    coin,
+                    stake_address,
+                    self.describe_withdrawal(stake_address)
+                ),
+            );
+        } else {
+            self.add_pass("script_withdrawal");
+        }
+        self
+    }
+
+    /// ## Testing methods for withdrawals
+    ///
+    /// Checks if the transaction has no withdrawals.
+    pub fn no_withdrawals(&mut self) -> &mut Self {
+        if !self.tx_body.withdrawals.is_empty() {
+            self.add_trace(
+                "no_withdrawals",
+                &format!(
+                    "tx has {} withdrawals, expected none",
+                    self.tx_body.withdrawals.len()
+                ),
+            );
+        } else {
+            self.add_pass("no_withdrawals");
+        }
+        self
+    }
+
+    /// The coin withdrawn from the stake address and whether a script witnesses it
+    fn withdrawal_logic(&self, stake_address: &str) -> Option<(u64, bool)> {
+        self.tx_body
+            .withdrawals
+            .iter()
+            .find_map(|withdrawal| match withdrawal {
+                Withdrawal::PubKeyWithdrawal(w) => {
+                    same_address(&w.address, stake_address).then_some((w.coin, false))
+                }
+                Withdrawal::PlutusScriptWithdrawal(w) => {
+                    same_address(&w.address, stake_address).then_some((w.coin, true))
+                }
+                Withdrawal::SimpleScriptWithdrawal(w) => {
+                    same_address(&w.address, stake_address).then_some((w.coin, true))
+                }
+            })
+    }
+
+    fn describe_withdrawal(&self, stake_address: &str) -> String {
+        match self.withdrawal_logic(stake_address) {
+            Some((coin, true)) => format!("script withdrawal of {}", coin),
+            Some((coin, false)) => format!("key withdrawal of {}", coin),
+            None => "no withdrawal".to_string(),
+        }
+    }
+}
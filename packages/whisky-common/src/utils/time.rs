@@ -158,3 +158,47 @@ pub fn resolve_epoch_no(network: &Network, milliseconds: Option<u64>) -> Option<
     let epoch = (time - config.zero_time) / 1000 / config.epoch_length + config.start_epoch;
     Some(epoch)
 }
+
+/// Format a POSIX time as an ISO 8601 UTC timestamp, e.g. `2024-01-01T00:00:00Z`.
+///
+/// # Arguments
+/// * `unix_time` - The POSIX time in milliseconds
+///
+/// # Returns
+/// The timestamp, with milliseconds only when they are not zero
+pub fn unix_time_to_utc(unix_time: u64) -> String {
+    let millis = unix_time % 1000;
+    let seconds = unix_time / 1000;
+    let (days, seconds_of_day) = (seconds / 86400, seconds % 86400);
+
+    // Civil date from days since 1970-01-01, Howard Hinnant's algorithm
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let time = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    );
+    if millis == 0 {
+        format!("{}Z", time)
+    } else {
+        format!("{}.{:03}Z", time, millis)
+    }
+}
@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky_common::data::blueprint::{Blueprint, SchemaMismatch};
+
+    fn blueprint() -> Blueprint {
+        serde_json::from_value(json!({
+            "preamble": {
+                "title": "test/validation",
+                "description": "",
+                "version": "0.0.0",
+                "plutusVersion": "v3",
+                "compiler": { "name": "Aiken", "version": "v1.1.9" },
+                "license": "Apache-2.0"
+            },
+            "validators": [],
+            "definitions": {
+                "ByteArray": { "dataType": "bytes" },
+                "Int": { "dataType": "integer" },
+                "Data": { "title": "Data", "description": "Any Plutus data." },
+                "List$Int": { "dataType": "list", "items": { "$ref": "#/definitions/Int" } },
+                "Pairs$ByteArray_Int": {
+                    "title": "Pairs<ByteArray, Int>",
+                    "dataType": "map",
+                    "keys": { "$ref": "#/definitions/ByteArray" },
+                    "values": { "$ref": "#/definitions/Int" }
+                },
+                "Tuple$ByteArray_Int": {
+                    "title": "Tuple",
+                    "dataType": "list",
+                    "items": [
+                        { "$ref": "#/definitions/ByteArray" },
+                        { "$ref": "#/definitions/Int" }
+                    ]
+                },
+                "Option$Int": {
+                    "title": "Option",
+                    "anyOf": [
+                        {
+                            "title": "Some",
+                            "description": "An optional value.",
+                            "dataType": "constructor",
+                            "index": 0,
+                            "fields": [{ "$ref": "#/definitions/Int" }]
+                        },
+                        {
+                            "title": "None",
+                            "description": "Nothing.",
+                            "dataType": "constructor",
+                            "index": 1,
+                            "fields": []
+                        }
+                    ]
+                },
+                "types/Datum": {
+                    "title": "Datum",
+                    "anyOf": [
+                        {
+                            "title": "Datum",
+                            "dataType": "constructor",
+                            "index": 0,
+                            "fields": [
+                                { "$ref": "#/definitions/ByteArray" },
+                                { "$ref": "#/definitions/Option$Int" },
+                                { "$ref": "#/definitions/List$Int" },
+                                { "$ref": "#/definitions/Pairs$ByteArray_Int" },
+                                { "$ref": "#/definitions/Tuple$ByteArray_Int" },
+                                { "$ref": "#/definitions/Data" }
+                            ]
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn valid_datum() -> serde_json::Value {
+        json!({
+            "constructor": 0,
+            "fields": [
+                { "bytes": "abcd" },
+                { "constructor": 0, "fields": [{ "int": 5 }] },
+                { "list": [{ "int": 1 }, { "int": 2 }] },
+                { "map": [{ "k": { "bytes": "aa" }, "v": { "int": 3 } }] },
+                { "list": [{ "bytes": "bb" }, { "int": 4 }] },
+                { "map": [] }
+            ]
+        })
+    }
+
+    fn mismatch(path: &str, message: &str) -> SchemaMismatch {
+        SchemaMismatch {
+            path: path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_reference() {
+        let blueprint = blueprint();
+        assert!(blueprint
+            .resolve_reference("#/definitions/types~1Datum")
+            .is_ok());
+        assert!(blueprint.resolve_reference("types/Datum").is_ok());
+        assert!(blueprint
+            .resolve_reference("#/definitions/Unknown")
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_valid_data() {
+        let mismatches = blueprint()
+            .validate_plutus_data("#/definitions/types~1Datum", &valid_datum())
+            .unwrap();
+        assert_eq!(mismatches, vec![]);
+    }
+
+    #[test]
+    fn test_validate_bignum_string() {
+        let data = json!({ "constructor": 0, "fields": [{ "int": "18446744073709551616" }] });
+        let mismatches = blueprint()
+            .validate_plutus_data("#/definitions/Option$Int", &data)
+            .unwrap();
+        assert_eq!(mismatches, vec![]);
+    }
+
+    #[test]
+    fn test_validate_wrong_constructor_index() {
+        let data = json!({ "constructor": 1, "fields": [] });
+        let mismatches = blueprint()
+            .validate_plutus_data("#/definitions/types~1Datum", &data)
+            .unwrap();
+        assert_eq!(
+            mismatches,
+            vec![mismatch(
+                "$.constructor",
+                "expected constructor index one of [0], got 1"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_nested_mismatches() {
+        let mut data = valid_datum();
+        data["fields"][0] = json!({ "int": 1 });
+        data["fields"][1] = json!({ "constructor": 0, "fields": [] });
+        data["fields"][2]["list"][1] = json!({ "bytes": "00" });
+        data["fields"][3]["map"][0]["k"] = json!({ "bytes": "xyz" });
+        data["fields"][4] = json!({ "list": [{ "bytes": "bb" }] });
+
+        let mismatches = blueprint()
+            .validate_plutus_data("#/definitions/types~1Datum", &data)
+            .unwrap();
+        assert_eq!(
+            mismatches,
+            vec![
+                mismatch("$.fields[0]", "expected bytes, got integer"),
+                mismatch(
+                    "$.fields[1].fields[0]",
+                    "missing field of constructor 0, expected 1 fields"
+                ),
+                mismatch("$.fields[2].list[1]", "expected integer, got bytes"),
+                mismatch("$.fields[3].map[0].k", "expected hex bytes, got \"xyz\""),
+                mismatch("$.fields[4]", "expected tuple of 2 items, got 1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_unexpected_field() {
+        let mut data = valid_datum();
+        data["fields"][1] = json!({ "constructor": 1, "fields": [{ "int": 1 }] });
+        let mismatches = blueprint()
+            .validate_plutus_data("#/definitions/types~1Datum", &data)
+            .unwrap();
+        assert_eq!(
+            mismatches,
+            vec![mismatch(
+                "$.fields[1].fields[0]",
+                "unexpected field of constructor 1, expected 0 fields"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_validate_unknown_reference() {
+        assert!(blueprint()
+            .validate_plutus_data("#/definitions/Missing", &valid_datum())
+            .is_err());
+    }
+}
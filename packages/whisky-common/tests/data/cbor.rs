@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky_common::data::*;
+
+    fn encode_hex(data: serde_json::Value) -> String {
+        hex::encode(encode_plutus_data(&data).unwrap())
+    }
+
+    #[test]
+    fn test_encode_integers() {
+        assert_eq!(encode_hex(json!({ "int": 42 })), "182a");
+        assert_eq!(encode_hex(json!({ "int": -1 })), "20");
+        assert_eq!(
+            encode_hex(json!({ "int": "18446744073709551616" })),
+            "c249010000000000000000"
+        );
+        assert_eq!(
+            encode_hex(json!({ "int": "-18446744073709551617" })),
+            "c349010000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_encode_bytes_chunking() {
+        let short = "ab".repeat(64);
+        assert_eq!(
+            encode_hex(json!({ "bytes": short })),
+            format!("5840{}", short)
+        );
+
+        let long = "ab".repeat(65);
+        assert_eq!(
+            encode_hex(json!({ "bytes": long })),
+            format!("5f5840{}41abff", "ab".repeat(64))
+        );
+    }
+
+    #[test]
+    fn test_encode_lists_and_maps() {
+        assert_eq!(encode_hex(json!({ "list": [] })), "80");
+        assert_eq!(
+            encode_hex(json!({ "list": [{ "int": 1 }, { "int": 2 }] })),
+            "9f0102ff"
+        );
+        assert_eq!(
+            encode_hex(json!({ "map": [{ "k": { "bytes": "aa" }, "v": { "int": 1 } }] })),
+            "a141aa01"
+        );
+    }
+
+    #[test]
+    fn test_encode_constructor_tags() {
+        assert_eq!(
+            encode_hex(json!({ "constructor": 0, "fields": [] })),
+            "d87980"
+        );
+        assert_eq!(
+            encode_hex(json!({ "constructor": 1, "fields": [{ "int": 1 }] })),
+            "d87a9f01ff"
+        );
+        assert_eq!(
+            encode_hex(json!({ "constructor": 7, "fields": [] })),
+            "d9050080"
+        );
+        assert_eq!(
+            encode_hex(json!({ "constructor": 127, "fields": [] })),
+            "d9057880"
+        );
+        assert_eq!(
+            encode_hex(json!({ "constructor": 200, "fields": [] })),
+            "d8668218c880"
+        );
+    }
+
+    #[test]
+    fn test_decode_alternative_encodings() {
+        // Definite-length fields decode to the same data as the indefinite form
+        let definite = hex::decode("d879820102").unwrap();
+        let indefinite = hex::decode("d8799f0102ff").unwrap();
+        let expected = json!({ "constructor": 0, "fields": [{ "int": 1 }, { "int": 2 }] });
+        assert_eq!(decode_plutus_data(&definite).unwrap(), expected);
+        assert_eq!(decode_plutus_data(&indefinite).unwrap(), expected);
+
+        let tag_102 = hex::decode("d866820780").unwrap();
+        let compact = hex::decode("d9050080").unwrap();
+        assert_eq!(
+            decode_plutus_data(&tag_102).unwrap(),
+            decode_plutus_data(&compact).unwrap()
+        );
+
+        let chunked = hex::decode("5f42aabb41ccff").unwrap();
+        assert_eq!(
+            decode_plutus_data(&chunked).unwrap(),
+            json!({ "bytes": "aabbcc" })
+        );
+
+        let indefinite_map = hex::decode("bf41aa01ff").unwrap();
+        assert_eq!(
+            decode_plutus_data(&indefinite_map).unwrap(),
+            json!({ "map": [{ "k": { "bytes": "aa" }, "v": { "int": 1 } }] })
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid_cbor() {
+        assert!(decode_plutus_data(&hex::decode("9f01").unwrap()).is_err());
+        assert!(decode_plutus_data(&hex::decode("0102").unwrap()).is_err());
+        assert!(decode_plutus_data(&hex::decode("f6").unwrap()).is_err());
+        assert!(decode_plutus_data(&hex::decode("d87b").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_typed_round_trip() {
+        let value: Constr<(ByteString, Int, List<Int>)> = Constr::new(
+            8,
+            (
+                ByteString::new(&"cd".repeat(80)),
+                Int::new(i64::MIN as i128),
+                List::new(&[Int::new(1), Int::new(-2)]),
+            ),
+        );
+        let cbor = value.to_cbor().unwrap();
+        let parsed = Constr::<(ByteString, Int, List<Int>)>::from_cbor(&cbor).unwrap();
+        assert_eq!(parsed.to_json(), value.to_json());
+        assert_eq!(parsed.to_cbor().unwrap(), cbor);
+
+        let big = Int::from_cbor("c249010000000000000000").unwrap();
+        assert_eq!(big.int, u64::MAX as i128 + 1);
+
+        // The untyped PlutusData holds single field constructors only
+        let data =
+            json!({ "constructor": 2, "fields": [{ "list": [{ "int": 1 }, { "bytes": "aa" }] }] });
+        let parsed_data = PlutusData::from_cbor_bytes(&encode_plutus_data(&data).unwrap());
+        assert_eq!(parsed_data.unwrap().to_json(), data);
+    }
+}
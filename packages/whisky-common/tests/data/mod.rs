@@ -1,5 +1,9 @@
 mod aliases;
+mod blueprint;
+mod cbor;
 mod constructors;
 mod credentials;
+mod pretty;
 mod primitives;
 mod value;
+mod value_delta;
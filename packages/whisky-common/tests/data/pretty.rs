@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky_common::data::*;
+
+    const KEY_HASH: &str = "e1197f10e85bc4a3a812e34e22339e1df56b7fb6386a9510d7a304ff";
+    const SCRIPT_HASH: &str = "7c87b6b5a0963af3eadb107da2ac4e1d34747a4df363858b649aa845";
+
+    #[test]
+    fn test_pretty_primitives() {
+        assert_eq!(pretty_plutus_data(&integer(-42)), "-42");
+        assert_eq!(pretty_plutus_data(&byte_string("68656c6c6f")), "\"hello\"");
+        assert_eq!(pretty_plutus_data(&byte_string("00ff")), "h'00ff'");
+        assert_eq!(pretty_plutus_data(&byte_string("")), "h''");
+        assert_eq!(
+            pretty_plutus_data(&constr(1, vec![integer(1), byte_string("ab")])),
+            "Constr 1 [1, h'ab']"
+        );
+        assert_eq!(
+            pretty_plutus_data(
+                &json!({"map": [{"k": {"bytes": "6b6579"}, "v": {"list": [{"int": 1}]}}]})
+            ),
+            "{\"key\": [1]}"
+        );
+    }
+
+    #[test]
+    fn test_pretty_recognized_shapes() {
+        assert_eq!(
+            pretty_plutus_data(&pub_key_address(KEY_HASH, Some(SCRIPT_HASH), true)),
+            format!(
+                "Address(payment: key h'{}', stake: script h'{}')",
+                KEY_HASH, SCRIPT_HASH
+            )
+        );
+        assert_eq!(
+            pretty_plutus_data(&script_address(SCRIPT_HASH, None, false)),
+            format!("Address(payment: script h'{}', stake: none)", SCRIPT_HASH)
+        );
+        assert_eq!(
+            pretty_plutus_data(&asset_class(KEY_HASH, "74657374")),
+            format!("AssetClass(h'{}', \"test\")", KEY_HASH)
+        );
+        assert_eq!(
+            pretty_plutus_data(&asset_class("", "")),
+            "AssetClass(lovelace)"
+        );
+    }
+
+    #[test]
+    fn test_pretty_multiline() {
+        let data = constr0(vec![
+            list(vec![byte_string(KEY_HASH), byte_string(SCRIPT_HASH)]),
+            integer(1),
+        ]);
+        let expected = format!(
+            "Constr 0 [\n  [\n    h'{}',\n    h'{}',\n  ],\n  1,\n]",
+            KEY_HASH, SCRIPT_HASH
+        );
+        assert_eq!(pretty_plutus_data(&data), expected);
+    }
+
+    #[test]
+    fn test_pretty_cbor() {
+        assert_eq!(
+            pretty_plutus_data_cbor("d8799f0102ff").unwrap(),
+            "Constr 0 [1, 2]"
+        );
+        assert!(pretty_plutus_data_cbor("zz").is_err());
+        assert_eq!(Int::new(7).to_pretty(), "7");
+    }
+
+    #[test]
+    fn test_diff_points_at_fields() {
+        let expected = constr0(vec![integer(1), list(vec![integer(1), integer(2)])]);
+        let actual = constr0(vec![integer(2), list(vec![integer(1)])]);
+        let diffs = diff_plutus_data(&expected, &actual);
+        assert_eq!(
+            diffs,
+            vec![
+                DataDiff {
+                    path: "$.fields[0]".to_string(),
+                    expected: "1".to_string(),
+                    actual: "2".to_string(),
+                },
+                DataDiff {
+                    path: "$.fields[1].list[1]".to_string(),
+                    expected: "2".to_string(),
+                    actual: "<missing>".to_string(),
+                },
+            ]
+        );
+        assert_eq!(diffs[0].to_string(), "$.fields[0]: expected 1, got 2");
+        assert!(diff_plutus_data(&expected, &expected).is_empty());
+    }
+
+    #[test]
+    fn test_diff_constructor_and_map() {
+        let diffs = diff_plutus_data(&constr0(vec![integer(1)]), &constr1(vec![integer(1)]));
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "$");
+        assert_eq!(diffs[0].actual, "Constr 1 [1]");
+
+        let expected = json!({"map": [{"k": {"int": 1}, "v": {"bytes": "00"}}]});
+        let actual = json!({"map": [{"k": {"int": 1}, "v": {"bytes": "01"}}, {"k": {"int": 2}, "v": {"int": 3}}]});
+        let diffs = diff_plutus_data(&expected, &actual);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].path, "$.map[0].v");
+        assert_eq!(diffs[1].path, "$.map[1]");
+        assert_eq!(diffs[1].expected, "<missing>");
+        assert_eq!(diffs[1].actual, "2: 3");
+    }
+
+    #[test]
+    fn test_partial_diff_wildcards() {
+        let actual = constr0(vec![integer(1), byte_string("00"), list(vec![integer(2)])]);
+        let expected = constr0(vec![json!(null), byte_string("00"), json!(null)]);
+        assert!(partial_diff_plutus_data(&expected, &actual).is_empty());
+        assert!(!diff_plutus_data(&expected, &actual).is_empty());
+
+        let expected = constr0(vec![integer(2), json!(null), json!(null)]);
+        let diffs = partial_diff_plutus_data(&expected, &actual);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "$.fields[0]");
+
+        let expected = constr0(vec![json!(null), json!(null)]);
+        let diffs = partial_diff_plutus_data(&expected, &actual);
+        assert_eq!(diffs[0].path, "$.fields[2]");
+        assert_eq!(diffs[0].expected, "<missing>");
+    }
+}
@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::{
+        data::{Value, ValueDelta},
+        *,
+    };
+
+    const POLICY: &str = "baefdc6c5b191be372a794cd8d40d839ec0dbdd3c28957267dc81700";
+
+    fn unit(name: &str) -> String {
+        format!("{}{}", POLICY, name)
+    }
+
+    fn pub_key_input(tx_index: u32, amount: Vec<Asset>) -> TxIn {
+        TxIn::PubKeyTxIn(PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: "a".repeat(64),
+                tx_index,
+                amount: Some(amount),
+                address: None,
+            },
+        })
+    }
+
+    #[test]
+    fn test_signed_arithmetic() {
+        let mut delta = ValueDelta::new();
+        delta.add_asset("lovelace", 10).unwrap();
+        delta.add_asset(&unit("aa"), -5).unwrap();
+        assert_eq!(delta.get(""), 10);
+        assert_eq!(delta.get(&unit("aa")), -5);
+
+        let neg = delta.checked_neg().unwrap();
+        assert_eq!(neg.get("lovelace"), -10);
+        assert_eq!(neg.get(&unit("aa")), 5);
+
+        // Zero entries are dropped
+        assert!(delta.checked_add(&neg).unwrap().is_empty());
+        let doubled = delta.checked_sub(&neg).unwrap();
+        assert_eq!(doubled.get("lovelace"), 20);
+        assert_eq!(doubled.get(&unit("aa")), -10);
+
+        assert_eq!(delta.positive().to_asset_vec().len(), 1);
+        assert_eq!(delta.negative().get(&unit("aa")), -5);
+    }
+
+    #[test]
+    fn test_overflow_is_error() {
+        let mut delta = ValueDelta::new();
+        delta.add_asset("lovelace", i128::MAX).unwrap();
+        assert!(delta.add_asset("lovelace", 1).is_err());
+
+        let mut min = ValueDelta::new();
+        min.add_asset("lovelace", i128::MIN).unwrap();
+        assert!(min.checked_neg().is_err());
+        assert!(ValueDelta::new().checked_sub(&min).is_err());
+    }
+
+    #[test]
+    fn test_invalid_quantity_is_error() {
+        let assets = vec![Asset::new_from_str("lovelace", "1.5")];
+        assert!(ValueDelta::from_assets(&assets).is_err());
+    }
+
+    #[test]
+    fn test_value_conversion() {
+        let mut value = Value::new();
+        value
+            .add_asset("lovelace", u64::MAX)
+            .add_asset(&unit("aa"), 3);
+        let delta = ValueDelta::from_value(&value);
+        assert_eq!(delta.get("lovelace"), u64::MAX as i128);
+        assert_eq!(delta.to_value().unwrap(), value);
+
+        let mut negative = ValueDelta::new();
+        negative.add_asset("lovelace", -1).unwrap();
+        assert!(negative.to_value().is_err());
+    }
+
+    #[test]
+    fn test_by_policy() {
+        let delta = ValueDelta::from_assets(&[
+            Asset::new_from_str("lovelace", "1"),
+            Asset::new(unit("aa"), "-2".to_string()),
+            Asset::new(unit("bb"), "3".to_string()),
+        ])
+        .unwrap();
+        let policies = delta.by_policy();
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[""][""], 1);
+        assert_eq!(policies[POLICY]["aa"], -2);
+        assert_eq!(policies[POLICY]["bb"], 3);
+    }
+
+    #[test]
+    fn test_from_tx_builder_body() {
+        let mut body = TxBuilderBody::new();
+        body.inputs = vec![
+            pub_key_input(0, vec![Asset::new_from_str("lovelace", "10000000")]),
+            pub_key_input(1, vec![Asset::new(unit("aa"), "5".to_string())]),
+        ];
+        body.outputs = vec![Output {
+            address: "addr_test".to_string(),
+            amount: vec![
+                Asset::new_from_str("lovelace", "7800000"),
+                Asset::new(unit("aa"), "2".to_string()),
+                Asset::new(unit("bb"), "1".to_string()),
+            ],
+            datum: None,
+            reference_script: None,
+        }];
+        body.mints = vec![
+            MintItem::SimpleScriptMint(SimpleScriptMint {
+                mint: MintParameter {
+                    policy_id: POLICY.to_string(),
+                    asset_name: "aa".to_string(),
+                    amount: -3,
+                },
+                script_source: None,
+            }),
+            MintItem::SimpleScriptMint(SimpleScriptMint {
+                mint: MintParameter {
+                    policy_id: POLICY.to_string(),
+                    asset_name: "bb".to_string(),
+                    amount: 1,
+                },
+                script_source: None,
+            }),
+        ];
+        body.withdrawals = vec![Withdrawal::PubKeyWithdrawal(PubKeyWithdrawal {
+            address: "stake_test".to_string(),
+            coin: 1000000,
+        })];
+        body.certificates = vec![Certificate::BasicCertificate(
+            CertificateType::RegisterStake(RegisterStake {
+                stake_key_address: "stake_test".to_string(),
+                coin: 2000000,
+            }),
+        )];
+
+        let delta = ValueDelta::from_tx_builder_body(&body, &Protocol::default()).unwrap();
+        // Balanced: only the fee is left
+        assert_eq!(
+            delta.to_asset_vec(),
+            vec![Asset::new_from_str("lovelace", "1200000")]
+        );
+
+        body.proposals = vec![Proposal::BasicProposal(ProposalType {
+            deposit: 1000000,
+            reward_account: "stake_test".to_string(),
+            gov_action: "8106".to_string(),
+            anchor: Anchor {
+                anchor_url: "https://example.com".to_string(),
+                anchor_data_hash: "00".repeat(32),
+            },
+        })];
+        body.donation = Some(100000);
+        let delta = ValueDelta::from_tx_builder_body(&body, &Protocol::default()).unwrap();
+        // Proposal deposits and the donation are paid by the transaction
+        assert_eq!(
+            delta.to_asset_vec(),
+            vec![Asset::new_from_str("lovelace", "100000")]
+        );
+
+        body.inputs = vec![TxIn::PubKeyTxIn(PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: "a".repeat(64),
+                tx_index: 0,
+                amount: None,
+                address: None,
+            },
+        })];
+        assert!(ValueDelta::from_tx_builder_body(&body, &Protocol::default()).is_err());
+    }
+}
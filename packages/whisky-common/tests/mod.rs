@@ -1,3 +1,6 @@
 mod data;
 mod models;
+mod tx_diff;
+mod tx_explainer;
+mod tx_tester;
 mod utils;
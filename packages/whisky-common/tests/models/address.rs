@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::{data, models::*};
+
+    // CIP-19 test vectors
+    const KEY_HASH: &str = "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e";
+    const SCRIPT_HASH: &str = "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f";
+    const STAKE_KEY_HASH: &str = "337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251";
+    const BASE: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+    const BASE_SCRIPT_KEY: &str = "addr1z8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gten0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs9yc0hh";
+    const BASE_TESTNET: &str = "addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgs68faae";
+    const POINTER: &str = "addr1gx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer5pnz75xxcrzqf96k";
+    const ENTERPRISE: &str = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+    const ENTERPRISE_SCRIPT: &str = "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx";
+    const REWARD: &str = "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+    const REWARD_TESTNET: &str = "stake_test1uqehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gssrtvn";
+    const BYRON_MAINNET: &str = "Ae2tdPwUPEZFRbyhz3cpfC2CumGzNkFBN2L42rcUc2yjQpEkxDbkPodpMAi";
+    const BYRON_TESTNET: &str = "37btjrVyb4KDXBNC4haBVPCrro8AQPHwvCMp3RFhhSVWwfFmZ6wwzSK6JK1hY6wHNmtrpTf1kdbva8TCneM2YsiXT7mrzT21EacHnPpz5YyUdj64na";
+
+    fn key(hash: &str) -> Credential {
+        Credential::KeyHash(hash.to_string())
+    }
+
+    fn script(hash: &str) -> Credential {
+        Credential::ScriptHash(hash.to_string())
+    }
+
+    #[test]
+    fn test_base_address() {
+        let address = Address::from_bech32(BASE).unwrap();
+        assert_eq!(
+            address,
+            Address::base(1, key(KEY_HASH), key(STAKE_KEY_HASH))
+        );
+        assert!(address.is_mainnet());
+        assert_eq!(address.to_bech32().unwrap(), BASE);
+
+        let address = Address::from_bech32(BASE_SCRIPT_KEY).unwrap();
+        assert_eq!(
+            address,
+            Address::base(1, script(SCRIPT_HASH), key(STAKE_KEY_HASH))
+        );
+        assert_eq!(address.to_bech32().unwrap(), BASE_SCRIPT_KEY);
+    }
+
+    #[test]
+    fn test_testnet_address() {
+        let address = Address::from_bech32(BASE_TESTNET).unwrap();
+        assert_eq!(address.network_id(), 0);
+        assert_eq!(address.to_bech32().unwrap(), BASE_TESTNET);
+        assert_eq!(
+            address.stake_address().unwrap().to_bech32().unwrap(),
+            REWARD_TESTNET
+        );
+    }
+
+    #[test]
+    fn test_enterprise_address() {
+        let address = Address::from_bech32(ENTERPRISE).unwrap();
+        assert_eq!(address, Address::enterprise(1, key(KEY_HASH)));
+        assert_eq!(address.stake_address(), None);
+        assert_eq!(address.to_bech32().unwrap(), ENTERPRISE);
+
+        let address = Address::from_bech32(ENTERPRISE_SCRIPT).unwrap();
+        assert_eq!(address, Address::enterprise(1, script(SCRIPT_HASH)));
+    }
+
+    #[test]
+    fn test_pointer_address() {
+        let address = Address::from_bech32(POINTER).unwrap();
+        assert_eq!(
+            address,
+            Address::Pointer {
+                network_id: 1,
+                payment: key(KEY_HASH),
+                pointer: Pointer {
+                    slot: 2498243,
+                    tx_index: 27,
+                    cert_index: 3,
+                },
+            }
+        );
+        assert_eq!(address.to_bech32().unwrap(), POINTER);
+        assert!(address.to_plutus_data().is_err());
+    }
+
+    #[test]
+    fn test_reward_address() {
+        let address = Address::from_bech32(REWARD).unwrap();
+        assert_eq!(address, Address::reward(1, key(STAKE_KEY_HASH)));
+        assert_eq!(address.to_bech32().unwrap(), REWARD);
+        assert_eq!(
+            Address::from_bech32(BASE).unwrap().stake_address(),
+            Some(address)
+        );
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let address = Address::from_bech32(BASE).unwrap();
+        let hex = address.to_hex().unwrap();
+        assert!(hex.starts_with("01"));
+        assert_eq!(Address::from_hex(&hex).unwrap(), address);
+        assert_eq!(Address::parse(&hex).unwrap(), address);
+    }
+
+    #[test]
+    fn test_byron_address() {
+        let address = Address::parse(BYRON_MAINNET).unwrap();
+        assert!(matches!(address, Address::Byron(_)));
+        assert!(address.is_mainnet());
+        assert_eq!(address.to_bech32().unwrap(), BYRON_MAINNET);
+        assert_eq!(address.payment_credential(), None);
+        assert_eq!(
+            Address::from_hex(&address.to_hex().unwrap()).unwrap(),
+            address
+        );
+
+        let address = Address::parse(BYRON_TESTNET).unwrap();
+        assert_eq!(address.network_id(), 0);
+        assert_eq!(address.to_bech32().unwrap(), BYRON_TESTNET);
+    }
+
+    #[test]
+    fn test_byron_address_with_bad_checksum() {
+        let mut tampered = BYRON_MAINNET.to_string();
+        tampered.replace_range(20..21, "x");
+        assert!(ByronAddress::from_base58(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_plutus_data_round_trip() {
+        let address = Address::from_bech32(BASE_SCRIPT_KEY).unwrap();
+        let plutus_address = address.to_plutus_data().unwrap();
+        assert_eq!(plutus_address.payment_key_hash, SCRIPT_HASH);
+        assert!(plutus_address.is_script_payment_key);
+        assert_eq!(
+            plutus_address.stake_credential,
+            Some(STAKE_KEY_HASH.to_string())
+        );
+        assert!(!plutus_address.is_script_stake_key);
+        assert_eq!(Address::from_plutus_data(&plutus_address, 1), address);
+
+        let enterprise = data::Address::new(KEY_HASH, None, false, false);
+        assert_eq!(
+            Address::from_plutus_data(&enterprise, 1)
+                .to_bech32()
+                .unwrap(),
+            ENTERPRISE
+        );
+    }
+
+    #[test]
+    fn test_deserialized_address() {
+        let address = Address::from_bech32(BASE).unwrap();
+        let deserialized = address.to_deserialized_address().unwrap();
+        assert_eq!(
+            deserialized,
+            DeserializedAddress::new(KEY_HASH, "", STAKE_KEY_HASH, "")
+        );
+        assert_eq!(
+            Address::from_deserialized_address(&deserialized, 1).unwrap(),
+            address
+        );
+
+        let invalid = DeserializedAddress::new(KEY_HASH, SCRIPT_HASH, "", "");
+        assert!(Address::from_deserialized_address(&invalid, 1).is_err());
+    }
+}
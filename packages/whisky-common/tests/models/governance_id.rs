@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::models::*;
+
+    const KEY_HASH: &str = "6b1d7d4c0c6d3b4b2f2b1f3b1e4d5c6a7b8c9d0e1f2a3b4c5d6e7f80";
+    const SCRIPT_HASH: &str = "8d3f9f6e2e8f5d6d4f4d3f5d3f6f7e8c9d0e1f2a3b4c5d6e7f8091a2";
+
+    #[test]
+    fn test_cip129_header_bytes() {
+        let cases = vec![
+            (
+                GovernanceId::drep(Credential::KeyHash(KEY_HASH.to_string())),
+                "22",
+            ),
+            (
+                GovernanceId::drep(Credential::ScriptHash(SCRIPT_HASH.to_string())),
+                "23",
+            ),
+            (
+                GovernanceId::committee_cold(Credential::KeyHash(KEY_HASH.to_string())),
+                "12",
+            ),
+            (
+                GovernanceId::committee_cold(Credential::ScriptHash(SCRIPT_HASH.to_string())),
+                "13",
+            ),
+            (
+                GovernanceId::committee_hot(Credential::KeyHash(KEY_HASH.to_string())),
+                "02",
+            ),
+            (
+                GovernanceId::committee_hot(Credential::ScriptHash(SCRIPT_HASH.to_string())),
+                "03",
+            ),
+        ];
+        for (id, header) in cases {
+            let id_hex = id.to_hex().unwrap();
+            assert_eq!(&id_hex[..2], header);
+            assert_eq!(GovernanceId::from_hex(&id_hex).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_bech32_round_trip() {
+        let cases = vec![
+            (
+                GovernanceId::drep(Credential::KeyHash(KEY_HASH.to_string())),
+                "drep1",
+                "drep_vkh1",
+            ),
+            (
+                GovernanceId::drep(Credential::ScriptHash(SCRIPT_HASH.to_string())),
+                "drep1",
+                "drep_script1",
+            ),
+            (
+                GovernanceId::committee_cold(Credential::KeyHash(KEY_HASH.to_string())),
+                "cc_cold1",
+                "cc_cold_vkh1",
+            ),
+            (
+                GovernanceId::committee_hot(Credential::ScriptHash(SCRIPT_HASH.to_string())),
+                "cc_hot1",
+                "cc_hot_script1",
+            ),
+        ];
+        for (id, cip129_prefix, cip105_prefix) in cases {
+            let cip129 = id.to_bech32().unwrap();
+            assert!(cip129.starts_with(cip129_prefix));
+            assert_eq!(GovernanceId::from_bech32(&cip129).unwrap(), id);
+
+            let cip105 = id.to_bech32_cip105().unwrap();
+            assert!(cip105.starts_with(cip105_prefix));
+            assert_eq!(GovernanceId::from_bech32(&cip105).unwrap(), id);
+        }
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let drep_hex = GovernanceId::drep(Credential::KeyHash(KEY_HASH.to_string()))
+            .to_hex()
+            .unwrap();
+        assert!(GovernanceId::from_hex(&format!("42{}", &drep_hex[2..])).is_err());
+        assert!(GovernanceId::from_hex(&format!("24{}", &drep_hex[2..])).is_err());
+
+        // A DRep header under the committee hot prefix
+        let mismatched = bech32::encode::<bech32::Bech32>(
+            bech32::Hrp::parse("cc_hot").unwrap(),
+            &hex::decode(&drep_hex).unwrap(),
+        )
+        .unwrap();
+        assert!(GovernanceId::from_bech32(&mismatched).is_err());
+    }
+
+    #[test]
+    fn test_to_voter_and_drep() {
+        let drep = GovernanceId::drep(Credential::KeyHash(KEY_HASH.to_string()));
+        assert_eq!(
+            drep.to_voter().unwrap(),
+            Voter::DRepId(drep.to_bech32().unwrap())
+        );
+        assert_eq!(
+            drep.to_drep().unwrap(),
+            DRep::DRepId(drep.to_bech32().unwrap())
+        );
+
+        let committee_hot = GovernanceId::committee_hot(Credential::KeyHash(KEY_HASH.to_string()));
+        assert_eq!(
+            committee_hot.to_voter().unwrap(),
+            Voter::ConstitutionalCommitteeHotCred(Credential::KeyHash(KEY_HASH.to_string()))
+        );
+        assert!(committee_hot.to_drep().is_err());
+
+        let committee_cold =
+            GovernanceId::committee_cold(Credential::KeyHash(KEY_HASH.to_string()));
+        assert!(committee_cold.to_voter().is_err());
+    }
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky_common::models::*;
+
+    const POLICY: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f";
+
+    #[test]
+    fn test_short_text_is_not_chunked() {
+        assert_eq!(
+            TransactionMetadatum::text("hello"),
+            TransactionMetadatum::Text("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_long_text_is_chunked_on_char_boundaries() {
+        let text = "é".repeat(40);
+        let metadatum = TransactionMetadatum::text(&text);
+        match &metadatum {
+            TransactionMetadatum::List(chunks) => {
+                assert_eq!(chunks.len(), 2);
+                assert_eq!(chunks[0], TransactionMetadatum::Text("é".repeat(32)));
+            }
+            _ => panic!("expected a list of chunks"),
+        }
+        assert_eq!(metadatum.as_text(), Some(text));
+    }
+
+    #[test]
+    fn test_long_bytes_are_chunked() {
+        let bytes = "ab".repeat(100);
+        let metadatum = TransactionMetadatum::bytes(&bytes);
+        assert_eq!(
+            metadatum,
+            TransactionMetadatum::List(vec![
+                TransactionMetadatum::Bytes("ab".repeat(64)),
+                TransactionMetadatum::Bytes("ab".repeat(36)),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_chunked_is_recursive() {
+        let long = "a".repeat(65);
+        let metadatum = TransactionMetadatum::Map(vec![(
+            TransactionMetadatum::Text("k".to_string()),
+            TransactionMetadatum::List(vec![TransactionMetadatum::Text(long.clone())]),
+        )])
+        .chunked();
+        assert_eq!(
+            metadatum.get("k"),
+            Some(&TransactionMetadatum::List(vec![
+                TransactionMetadatum::List(vec![
+                    TransactionMetadatum::Text("a".repeat(64)),
+                    TransactionMetadatum::Text("a".to_string()),
+                ])
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_detailed_json_round_trip() {
+        let metadatum = TransactionMetadatum::text_map(vec![
+            ("int", TransactionMetadatum::Int(-42)),
+            ("big", TransactionMetadatum::Int(u64::MAX as i128)),
+            ("bytes", TransactionMetadatum::bytes("deadbeef")),
+            (
+                "list",
+                TransactionMetadatum::list(vec![TransactionMetadatum::text("a")]),
+            ),
+        ]);
+        let detailed = metadatum.to_detailed_json();
+        assert_eq!(detailed["map"][0]["v"], json!({ "int": -42 }));
+        assert_eq!(detailed["map"][1]["v"], json!({ "int": u64::MAX }));
+        assert_eq!(detailed["map"][2]["v"], json!({ "bytes": "deadbeef" }));
+        assert_eq!(
+            TransactionMetadatum::from_detailed_json(&detailed).unwrap(),
+            metadatum
+        );
+    }
+
+    #[test]
+    fn test_int_out_of_range() {
+        let max = u64::MAX as i128;
+        assert!(TransactionMetadatum::int(-max).is_ok());
+        assert!(TransactionMetadatum::int(max + 1).is_err());
+        assert!(TransactionMetadatum::int(-max - 1).is_err());
+        assert!(TransactionMetadatum::from_json(&json!(u64::MAX)).is_ok());
+        assert!(TransactionMetadatum::from_detailed_json(
+            &json!({ "int": "18446744073709551616" })
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_from_json() {
+        let metadatum =
+            TransactionMetadatum::from_json(&json!({ "a": [1, "b"], "c": "d" })).unwrap();
+        assert_eq!(
+            metadatum.get("a"),
+            Some(&TransactionMetadatum::List(vec![
+                TransactionMetadatum::Int(1),
+                TransactionMetadatum::Text("b".to_string()),
+            ]))
+        );
+        assert_eq!(metadatum.to_json(), json!({ "a": [1, "b"], "c": "d" }));
+    }
+
+    #[test]
+    fn test_cip25_v1() {
+        let image = format!("ipfs://{}", "Q".repeat(70));
+        let mut asset = Cip25Asset::new("My NFT", &image);
+        asset
+            .media_type("image/png")
+            .file("full", "image/png", "ipfs://full")
+            .field("artist", TransactionMetadatum::text("anon"));
+        let mut cip25 = Cip25Metadata::new(Cip25Version::V1);
+        cip25.add_asset(POLICY, &hex::encode("MyNFT"), asset.clone());
+
+        let metadata = cip25.to_metadata().unwrap();
+        assert_eq!(metadata.tag, "721");
+        let metadatum = metadata.metadatum.unwrap();
+        let nft = metadatum.get(POLICY).unwrap().get("MyNFT").unwrap();
+        assert_eq!(
+            nft.get("image"),
+            Some(&TransactionMetadatum::List(vec![
+                TransactionMetadatum::Text(image[..64].to_string()),
+                TransactionMetadatum::Text(image[64..].to_string()),
+            ]))
+        );
+        assert!(metadatum.get("version").is_none());
+
+        let decoded = Cip25Metadata::from_metadatum(&metadatum).unwrap();
+        assert_eq!(decoded, cip25);
+    }
+
+    #[test]
+    fn test_cip25_v2() {
+        let mut cip25 = Cip25Metadata::new(Cip25Version::V2);
+        cip25.add_asset(POLICY, "000de140ff", Cip25Asset::new("Token", "ipfs://x"));
+        let metadatum = cip25.to_metadatum().unwrap();
+        match &metadatum {
+            TransactionMetadatum::Map(entries) => {
+                assert_eq!(
+                    entries[0].0,
+                    TransactionMetadatum::Bytes(POLICY.to_string())
+                );
+            }
+            _ => panic!("expected a map"),
+        }
+        assert_eq!(
+            metadatum.get("version"),
+            Some(&TransactionMetadatum::Int(2))
+        );
+        assert_eq!(Cip25Metadata::from_metadatum(&metadatum).unwrap(), cip25);
+    }
+
+    #[test]
+    fn test_cip25_v1_rejects_non_utf8_asset_name() {
+        let mut cip25 = Cip25Metadata::new(Cip25Version::V1);
+        cip25.add_asset(POLICY, "000de140ff", Cip25Asset::new("Token", "ipfs://x"));
+        assert!(cip25.to_metadatum().is_err());
+    }
+
+    #[test]
+    fn test_cip20_message() {
+        let long_line = "x".repeat(70);
+        let metadatum = cip20_message(&["Invoice #42", &long_line]);
+        assert_eq!(
+            parse_cip20_message(&metadatum),
+            Some(vec![
+                "Invoice #42".to_string(),
+                "x".repeat(64),
+                "x".repeat(6),
+            ])
+        );
+        let metadata = Metadata::new(CIP20_LABEL, metadatum.clone());
+        assert_eq!(metadata.tag, "674");
+        assert_eq!(metadata.metadatum, Some(metadatum));
+    }
+}
@@ -1 +1,7 @@
+mod address;
 mod asset;
+mod governance_id;
+mod metadata;
+mod native_script;
+mod phase_one;
+mod witness_report;
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use whisky_common::models::*;
+
+    const KEY_1: &str = "6b1d7d4c0c6d3b4b2f2b1f3b1e4d5c6a7b8c9d0e1f2a3b4c5d6e7f80";
+    const KEY_2: &str = "7c2e8e5d1d7e4c5c3f3c2f4c2f5e6d7b8c9d0e1f2a3b4c5d6e7f8091";
+    const KEY_3: &str = "8d3f9f6e2e8f5d6d4f4d3f5d3f6f7e8c9d0e1f2a3b4c5d6e7f8091a2";
+
+    fn treasury_script() -> NativeScript {
+        NativeScript::All {
+            scripts: vec![
+                NativeScript::AtLeast {
+                    required: 2,
+                    scripts: vec![
+                        NativeScript::Sig {
+                            key_hash: KEY_1.to_string(),
+                        },
+                        NativeScript::Sig {
+                            key_hash: KEY_2.to_string(),
+                        },
+                        NativeScript::Sig {
+                            key_hash: KEY_3.to_string(),
+                        },
+                    ],
+                },
+                NativeScript::After { slot: 1000 },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_native_script_json() {
+        let json = format!(
+            r#"{{"type":"all","scripts":[{{"type":"atLeast","required":2,"scripts":[{{"type":"sig","keyHash":"{}"}},{{"type":"sig","keyHash":"{}"}},{{"type":"sig","keyHash":"{}"}}]}},{{"type":"after","slot":1000}}]}}"#,
+            KEY_1, KEY_2, KEY_3
+        );
+        let script = NativeScript::from_json(&json).unwrap();
+        assert_eq!(script, treasury_script());
+        assert_eq!(script.to_json().unwrap(), json);
+        assert_eq!(script.key_hashes(), vec![KEY_1, KEY_2, KEY_3]);
+    }
+
+    #[test]
+    fn test_native_script_is_satisfied() {
+        let script = treasury_script();
+        let signers: HashSet<String> = [KEY_1.to_string(), KEY_3.to_string()].into();
+        let valid_range = ValidityRange {
+            invalid_before: Some(1200),
+            invalid_hereafter: None,
+        };
+        assert!(script.is_satisfied(&signers, &valid_range));
+
+        let early_range = ValidityRange {
+            invalid_before: Some(900),
+            invalid_hereafter: None,
+        };
+        assert!(!script.is_satisfied(&signers, &early_range));
+
+        let one_signer: HashSet<String> = [KEY_2.to_string()].into();
+        assert!(!script.is_satisfied(&one_signer, &valid_range));
+
+        let before = NativeScript::Before { slot: 500 };
+        let unbounded = ValidityRange {
+            invalid_before: None,
+            invalid_hereafter: None,
+        };
+        assert!(!before.is_satisfied(&signers, &unbounded));
+        let bounded = ValidityRange {
+            invalid_before: None,
+            invalid_hereafter: Some(500),
+        };
+        assert!(before.is_satisfied(&signers, &bounded));
+    }
+}
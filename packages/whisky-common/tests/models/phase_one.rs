@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::{data::Value, models::*};
+
+    const KEY_1: &str = "6b1d7d4c0c6d3b4b2f2b1f3b1e4d5c6a7b8c9d0e1f2a3b4c5d6e7f80";
+
+    fn lovelace(quantity: u64) -> Value {
+        let mut value = Value::new();
+        value.add_asset("lovelace", quantity);
+        value
+    }
+
+    fn facts() -> Phase1Facts {
+        Phase1Facts {
+            tx_size: 300,
+            fee: 200_000,
+            min_fee: 170_000,
+            consumed: lovelace(10_000_000),
+            produced: lovelace(10_000_000),
+            outputs: vec![(9_800_000, 1_000_000)],
+            has_redeemers: false,
+            collateral_inputs: 0,
+            collateral: 0,
+            total_collateral: None,
+            invalid_before: Some(100),
+            invalid_hereafter: Some(200),
+            witness_report: WitnessReport::new(WitnessInventory::default()),
+        }
+    }
+
+    #[test]
+    fn test_phase_one_valid() {
+        assert_eq!(facts().validate(&Protocol::default(), 150), vec![]);
+    }
+
+    #[test]
+    fn test_phase_one_problems() {
+        let facts = Phase1Facts {
+            fee: 100_000,
+            produced: lovelace(9_900_000),
+            outputs: vec![(9_800_000, 1_000_000), (500_000, 900_000)],
+            has_redeemers: true,
+            collateral_inputs: 1,
+            collateral: 100_000,
+            total_collateral: Some(150_000),
+            witness_report: WitnessReport::new(WitnessInventory {
+                required_vkeys: vec![(KEY_1.to_string(), VkeySource::RequiredSigner)],
+                ..Default::default()
+            }),
+            ..facts()
+        };
+        assert_eq!(
+            facts.validate(&Protocol::default(), 200),
+            vec![
+                Phase1Error::ValueNotConserved {
+                    consumed: lovelace(10_000_000).to_asset_vec(),
+                    produced: lovelace(9_900_000).to_asset_vec(),
+                },
+                Phase1Error::FeeTooSmall {
+                    min_fee: 170_000,
+                    fee: 100_000,
+                },
+                Phase1Error::OutputTooSmall {
+                    output_index: 1,
+                    min_lovelace: 900_000,
+                    lovelace: 500_000,
+                },
+                Phase1Error::InsufficientCollateral {
+                    required: 150_000,
+                    provided: 100_000,
+                },
+                Phase1Error::IncorrectTotalCollateral {
+                    declared: 150_000,
+                    provided: 100_000,
+                },
+                Phase1Error::OutsideValidityInterval {
+                    current_slot: 200,
+                    invalid_before: Some(100),
+                    invalid_hereafter: Some(200),
+                },
+                Phase1Error::MissingVkeyWitnesses(vec![KEY_1.to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phase_one_collateral_inputs() {
+        let facts = Phase1Facts {
+            has_redeemers: true,
+            ..facts()
+        };
+        assert_eq!(
+            facts.validate(&Protocol::default(), 150),
+            vec![Phase1Error::NoCollateralInputs]
+        );
+
+        let facts = Phase1Facts {
+            collateral_inputs: 4,
+            collateral: 300_000,
+            ..facts
+        };
+        assert_eq!(
+            facts.validate(&Protocol::default(), 150),
+            vec![Phase1Error::TooManyCollateralInputs { count: 4, max: 3 }]
+        );
+    }
+}
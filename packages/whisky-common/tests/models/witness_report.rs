@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::models::*;
+
+    const KEY_1: &str = "6b1d7d4c0c6d3b4b2f2b1f3b1e4d5c6a7b8c9d0e1f2a3b4c5d6e7f80";
+    const KEY_2: &str = "7c2e8e5d1d7e4c5c3f3c2f4c2f5e6d7b8c9d0e1f2a3b4c5d6e7f8091";
+    const KEY_3: &str = "8d3f9f6e2e8f5d6d4f4d3f5d3f6f7e8c9d0e1f2a3b4c5d6e7f8091a2";
+    const SCRIPT_1: &str = "a1b2c3d4e5f60718293a4b5c6d7e8f9011223344556677889900aabb";
+    const SCRIPT_2: &str = "b2c3d4e5f60718293a4b5c6d7e8f9011223344556677889900aabbcc";
+    const DATUM_1: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+    const DATUM_2: &str = "2222222222222222222222222222222222222222222222222222222222222222";
+
+    fn input(output_index: u32) -> UtxoInput {
+        UtxoInput {
+            tx_hash: "fbd3e8091c9f0c5fb446be9e58d9235f548546a5a7d5f60ee56e389344db9c5e".to_string(),
+            output_index,
+        }
+    }
+
+    fn vkey_witness(key_hash: &str, valid: bool) -> VkeyWitnessCheck {
+        VkeyWitnessCheck {
+            vkey: "00".repeat(32),
+            key_hash: key_hash.to_string(),
+            valid,
+        }
+    }
+
+    #[test]
+    fn test_witness_report_valid() {
+        let report = WitnessReport::new(WitnessInventory {
+            required_vkeys: vec![
+                (KEY_1.to_string(), VkeySource::Input(input(0))),
+                (KEY_1.to_string(), VkeySource::RequiredSigner),
+                (KEY_1.to_string(), VkeySource::Input(input(0))),
+            ],
+            vkey_witnesses: vec![vkey_witness(KEY_1, true)],
+            required_scripts: vec![SCRIPT_1.to_string()],
+            reference_scripts: vec![SCRIPT_1.to_string()],
+            required_datums: vec![DATUM_1.to_string()],
+            allowed_datums: vec![DATUM_2.to_string()],
+            witness_datums: vec![DATUM_1.to_string(), DATUM_2.to_string()],
+            script_data_hash: ScriptDataHashCheck {
+                body: Some(DATUM_1.to_string()),
+                computed: Some(DATUM_1.to_string()),
+            },
+            ..Default::default()
+        });
+        assert_eq!(
+            report.required_vkeys,
+            vec![RequiredVkey {
+                key_hash: KEY_1.to_string(),
+                sources: vec![VkeySource::Input(input(0)), VkeySource::RequiredSigner],
+                signed: true,
+            }]
+        );
+        assert!(report.missing_scripts.is_empty());
+        assert!(report.extra_datums.is_empty());
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_witness_report_problems() {
+        let report = WitnessReport::new(WitnessInventory {
+            required_vkeys: vec![
+                (
+                    KEY_1.to_string(),
+                    VkeySource::Withdrawal("stake_test1".to_string()),
+                ),
+                (
+                    KEY_2.to_string(),
+                    VkeySource::NativeScript(SCRIPT_2.to_string()),
+                ),
+            ],
+            vkey_witnesses: vec![vkey_witness(KEY_1, false), vkey_witness(KEY_3, true)],
+            required_scripts: vec![SCRIPT_1.to_string()],
+            witness_scripts: vec![SCRIPT_2.to_string()],
+            required_datums: vec![DATUM_1.to_string()],
+            witness_datums: vec![DATUM_2.to_string()],
+            unresolved_inputs: vec![input(1)],
+            script_data_hash: ScriptDataHashCheck {
+                body: None,
+                computed: Some(DATUM_1.to_string()),
+            },
+            ..Default::default()
+        });
+        // Native script signers are reported but never required
+        assert_eq!(report.missing_vkeys(), vec![KEY_1.to_string()]);
+        assert!(!report.required_vkeys[1].signed);
+        assert_eq!(report.extraneous_vkeys, vec![KEY_3.to_string()]);
+        assert_eq!(report.invalid_signatures, vec![vkey_witness(KEY_1, false)]);
+        assert_eq!(report.missing_scripts, vec![SCRIPT_1.to_string()]);
+        assert_eq!(report.extra_scripts, vec![SCRIPT_2.to_string()]);
+        assert_eq!(report.missing_datums, vec![DATUM_1.to_string()]);
+        assert_eq!(report.extra_datums, vec![DATUM_2.to_string()]);
+        assert_eq!(report.unresolved_inputs, vec![input(1)]);
+        assert!(!report.script_data_hash.matches());
+        assert!(!report.is_valid());
+    }
+}
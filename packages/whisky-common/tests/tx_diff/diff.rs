@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::data::ValueDelta;
+    use whisky_common::*;
+
+    // CIP-19 test vectors
+    const USER: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+    const SCRIPT: &str = "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx";
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+    const POLICY_ID: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f";
+
+    fn pub_key_tx_in(tx_index: u32) -> TxIn {
+        TxIn::PubKeyTxIn(PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: TX_HASH.to_string(),
+                tx_index,
+                amount: None,
+                address: None,
+            },
+        })
+    }
+
+    fn script_tx_in(datum: &str, redeemer: Redeemer) -> TxIn {
+        TxIn::ScriptTxIn(ScriptTxIn {
+            tx_in: TxInParameter {
+                tx_hash: TX_HASH.to_string(),
+                tx_index: 0,
+                amount: None,
+                address: None,
+            },
+            script_tx_in: ScriptTxInParameter {
+                script_source: None,
+                datum_source: Some(DatumSource::ProvidedDatumSource(ProvidedDatumSource {
+                    data: datum.to_string(),
+                })),
+                redeemer: Some(redeemer),
+            },
+        })
+    }
+
+    fn redeemer(data: &str, mem: u64, steps: u64) -> Redeemer {
+        Redeemer {
+            data: data.to_string(),
+            ex_units: Budget { mem, steps },
+        }
+    }
+
+    fn output(address: &str, lovelace: &str) -> Output {
+        Output {
+            address: address.to_string(),
+            amount: vec![Asset::new_from_str("lovelace", lovelace)],
+            datum: None,
+            reference_script: None,
+        }
+    }
+
+    fn tx_body() -> TxBuilderBody {
+        let mut body = TxBuilderBody::new();
+        body.inputs = vec![
+            script_tx_in("d87980", redeemer("d87980", 1000, 2000)),
+            pub_key_tx_in(1),
+            pub_key_tx_in(2),
+        ];
+        body.outputs = vec![output(SCRIPT, "5000000"), output(USER, "24800000")];
+        body.fee = Some("200000".to_string());
+        body.required_signatures =
+            vec!["9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e".to_string()];
+        body
+    }
+
+    #[test]
+    fn test_identical_up_to_order() {
+        let before = tx_body();
+        let mut after = tx_body();
+        after.inputs.reverse();
+        after.outputs.reverse();
+        let diff = TxDiff::new(&before, &after).unwrap();
+        assert!(diff.is_empty(), "{:?}", diff);
+    }
+
+    #[test]
+    fn test_inputs_and_outputs() {
+        let before = tx_body();
+        let mut after = tx_body();
+        after.inputs.remove(2);
+        after.inputs.push(pub_key_tx_in(3));
+        after.outputs[1] = output(USER, "24600000");
+        after.outputs.push(output(USER, "1000000"));
+        after.outputs.remove(0);
+        let diff = TxDiff::new(&before, &after).unwrap();
+
+        assert_eq!(diff.inputs.added, vec![format!("{}#3", TX_HASH)]);
+        assert_eq!(diff.inputs.removed, vec![format!("{}#2", TX_HASH)]);
+
+        assert_eq!(diff.outputs_removed.len(), 1);
+        assert_eq!(diff.outputs_removed[0].index, 0);
+        assert_eq!(diff.outputs_removed[0].output.address, SCRIPT);
+
+        assert_eq!(diff.output_changes.len(), 1);
+        let change = &diff.output_changes[0];
+        assert_eq!((change.before_index, change.after_index), (1, 0));
+        let mut value = ValueDelta::new();
+        value.add_asset("lovelace", -200_000).unwrap();
+        assert_eq!(change.value, value);
+        assert_eq!(change.datum, None);
+
+        assert_eq!(diff.outputs_added.len(), 1);
+        assert_eq!(diff.outputs_added[0].index, 1);
+    }
+
+    #[test]
+    fn test_output_datum_change() {
+        let before = tx_body();
+        let mut after = tx_body();
+        after.outputs[0].datum = Some(Datum::Inline("d87980".to_string()));
+        let diff = TxDiff::new(&before, &after).unwrap();
+        assert_eq!(diff.output_changes.len(), 1);
+        assert!(diff.output_changes[0].value.is_empty());
+        assert_eq!(
+            diff.output_changes[0].datum,
+            Some(Change {
+                before: None,
+                after: Some(Datum::Inline("d87980".to_string())),
+            })
+        );
+    }
+
+    #[test]
+    fn test_redeemers_datums_and_budget() {
+        let before = tx_body();
+        let mut after = tx_body();
+        after.inputs[0] = script_tx_in("d87a80", redeemer("d87980", 1500, 2000));
+        after.mints = vec![MintItem::ScriptMint(ScriptMint {
+            mint: MintParameter {
+                policy_id: POLICY_ID.to_string(),
+                asset_name: "4d794e4654".to_string(),
+                amount: 1,
+            },
+            redeemer: Some(redeemer("d87980", 500, 1000)),
+            script_source: None,
+        })];
+        after.fee = Some("210000".to_string());
+        let diff = TxDiff::new(&before, &after).unwrap();
+
+        let spend = ScriptPurpose::Spend {
+            tx_hash: TX_HASH.to_string(),
+            tx_index: 0,
+        };
+        assert_eq!(
+            diff.redeemers,
+            vec![
+                RedeemerChange {
+                    purpose: spend,
+                    before: Some(redeemer("d87980", 1000, 2000)),
+                    after: Some(redeemer("d87980", 1500, 2000)),
+                },
+                RedeemerChange {
+                    purpose: ScriptPurpose::Mint {
+                        policy_id: POLICY_ID.to_string()
+                    },
+                    before: None,
+                    after: Some(redeemer("d87980", 500, 1000)),
+                },
+            ]
+        );
+
+        assert_eq!(diff.datums.len(), 1);
+        assert_eq!(diff.datums[0].input, format!("{}#0", TX_HASH));
+
+        let mut mint = ValueDelta::new();
+        mint.add_asset(&format!("{}4d794e4654", POLICY_ID), 1)
+            .unwrap();
+        assert_eq!(diff.mint, mint);
+
+        assert_eq!(diff.fee.as_ref().map(|fee| fee.delta), Some(10_000));
+        assert_eq!(
+            diff.ex_units_mem,
+            Some(AmountChange {
+                before: 1000,
+                after: 2000,
+                delta: 1000,
+            })
+        );
+        assert_eq!(
+            diff.ex_units_steps.as_ref().map(|steps| steps.delta),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_signers_and_witnesses() {
+        let before = tx_body();
+        let mut after = tx_body();
+        after.required_signatures = vec![];
+        let mut diff = TxDiff::new(&before, &after).unwrap();
+        assert_eq!(diff.required_signers.removed, before.required_signatures);
+
+        diff.with_vkey_witnesses(&["a".to_string()], &["a".to_string(), "b".to_string()]);
+        assert_eq!(diff.vkey_witnesses.added, vec!["b".to_string()]);
+        assert!(diff.vkey_witnesses.removed.is_empty());
+        assert!(diff.to_json().unwrap().contains("\"vkeyWitnesses\""));
+    }
+}
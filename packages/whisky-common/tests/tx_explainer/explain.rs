@@ -0,0 +1,310 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::utils::SlotConfig;
+    use whisky_common::*;
+
+    // CIP-19 test vectors
+    const USER: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+    const SCRIPT: &str = "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx";
+    const SCRIPT_HASH: &str = "c37b1b5dc0669f1d3c61a6fddb2e8fde96be87b881c60bce8e8d542f";
+    const STAKE: &str = "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+    const POLICY_ID: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f";
+    const TOKEN: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f4d794e4654";
+
+    fn redeemer() -> Redeemer {
+        Redeemer {
+            data: "d87980".to_string(),
+            ex_units: Budget {
+                mem: 7000000,
+                steps: 3000000000,
+            },
+        }
+    }
+
+    /// The user unlocks 5 ADA and a token from the script, mints another token, registers
+    /// a stake address and pays the fee
+    fn tx_body() -> TxBuilderBody {
+        let mut body = TxBuilderBody::new();
+        body.inputs = vec![
+            TxIn::ScriptTxIn(ScriptTxIn {
+                tx_in: TxInParameter {
+                    tx_hash: TX_HASH.to_string(),
+                    tx_index: 0,
+                    amount: Some(vec![
+                        Asset::new_from_str("lovelace", "10000000"),
+                        Asset::new_from_str(TOKEN, "1"),
+                    ]),
+                    address: Some(SCRIPT.to_string()),
+                },
+                script_tx_in: ScriptTxInParameter {
+                    script_source: Some(ScriptSource::InlineScriptSource(InlineScriptSource {
+                        ref_tx_in: RefTxIn {
+                            tx_hash: TX_HASH.to_string(),
+                            tx_index: 2,
+                            script_size: None,
+                        },
+                        script_hash: SCRIPT_HASH.to_string(),
+                        language_version: LanguageVersion::V3,
+                        script_size: 100,
+                    })),
+                    datum_source: None,
+                    redeemer: Some(redeemer()),
+                },
+            }),
+            TxIn::PubKeyTxIn(PubKeyTxIn {
+                tx_in: TxInParameter {
+                    tx_hash: TX_HASH.to_string(),
+                    tx_index: 1,
+                    amount: None,
+                    address: None,
+                },
+            }),
+        ];
+        body.outputs = vec![
+            Output {
+                address: SCRIPT.to_string(),
+                amount: vec![Asset::new_from_str("lovelace", "5000000")],
+                datum: None,
+                reference_script: None,
+            },
+            Output {
+                address: USER.to_string(),
+                amount: vec![
+                    Asset::new_from_str("lovelace", "22800000"),
+                    Asset::new_from_str(TOKEN, "2"),
+                ],
+                datum: None,
+                reference_script: None,
+            },
+        ];
+        body.mints = vec![MintItem::ScriptMint(ScriptMint {
+            mint: MintParameter {
+                policy_id: POLICY_ID.to_string(),
+                asset_name: "4d794e4654".to_string(),
+                amount: 1,
+            },
+            redeemer: Some(redeemer()),
+            script_source: Some(ScriptSource::ProvidedScriptSource(ProvidedScriptSource {
+                script_cbor: "4e4d01000033222220051200120011".to_string(),
+                language_version: LanguageVersion::V2,
+            })),
+        })];
+        body.certificates = vec![Certificate::BasicCertificate(
+            CertificateType::RegisterStake(RegisterStake {
+                stake_key_address: STAKE.to_string(),
+                coin: 2_000_000,
+            }),
+        )];
+        body.fee = Some("200000".to_string());
+        body.validity_range.invalid_hereafter = Some(4492801);
+        body.required_signatures =
+            vec!["9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e".to_string()];
+        body
+    }
+
+    fn user_utxo() -> UTxO {
+        UTxO {
+            input: UtxoInput {
+                output_index: 1,
+                tx_hash: TX_HASH.to_string(),
+            },
+            output: UtxoOutput {
+                address: USER.to_string(),
+                amount: vec![Asset::new_from_str("lovelace", "20000000")],
+                data_hash: None,
+                plutus_data: None,
+                script_ref: None,
+                script_hash: None,
+            },
+        }
+    }
+
+    fn explain() -> TxExplanation {
+        TxExplanation::new(
+            &tx_body(),
+            &[user_utxo()],
+            &Protocol::default(),
+            &SlotConfig::mainnet(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_transfers() {
+        let explanation = explain();
+        assert_eq!(explanation.transfers.len(), 2);
+
+        let script = &explanation.transfers[0];
+        assert_eq!(script.address, SCRIPT);
+        assert!(script.received.is_empty());
+        assert_eq!(script.sent.len(), 2);
+        assert!(script
+            .sent
+            .contains(&Asset::new_from_str("lovelace", "5000000")));
+        assert!(script.sent.contains(&Asset::new_from_str(TOKEN, "1")));
+
+        let user = &explanation.transfers[1];
+        assert_eq!(user.address, USER);
+        assert!(user.sent.is_empty());
+        assert!(user
+            .received
+            .contains(&Asset::new_from_str("lovelace", "2800000")));
+        assert!(user.received.contains(&Asset::new_from_str(TOKEN, "2")));
+    }
+
+    #[test]
+    fn test_unresolved_input() {
+        let err = TxExplanation::new(
+            &tx_body(),
+            &[],
+            &Protocol::default(),
+            &SlotConfig::mainnet(),
+        )
+        .unwrap_err();
+        assert!(format!("{:?}", err).contains("is unresolved"));
+    }
+
+    #[test]
+    fn test_mints() {
+        let explanation = explain();
+        assert_eq!(
+            explanation.mints,
+            vec![MintedAsset {
+                policy_id: POLICY_ID.to_string(),
+                asset_name: "4d794e4654".to_string(),
+                asset_name_text: Some("MyNFT".to_string()),
+                quantity: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_scripts() {
+        let explanation = explain();
+        assert_eq!(explanation.scripts.len(), 2);
+
+        let spend = &explanation.scripts[0];
+        assert_eq!(
+            spend.purpose,
+            ScriptPurpose::Spend {
+                tx_hash: TX_HASH.to_string(),
+                tx_index: 0
+            }
+        );
+        assert_eq!(spend.script_hash, Some(SCRIPT_HASH.to_string()));
+        assert_eq!(spend.language, "PlutusV3");
+        assert_eq!(spend.reference_input, Some(format!("{}#2", TX_HASH)));
+        assert!(spend.redeemer.is_some());
+        assert_eq!(spend.ex_units, Some(redeemer().ex_units));
+
+        let mint = &explanation.scripts[1];
+        assert_eq!(
+            mint.purpose,
+            ScriptPurpose::Mint {
+                policy_id: POLICY_ID.to_string()
+            }
+        );
+        assert_eq!(mint.script_hash, Some(POLICY_ID.to_string()));
+        assert_eq!(mint.language, "PlutusV2");
+        assert_eq!(mint.reference_input, None);
+    }
+
+    #[test]
+    fn test_certificates_and_deposits() {
+        let explanation = explain();
+        assert_eq!(
+            explanation.certificates,
+            vec![format!(
+                "Register stake address {} with a deposit of 2000000 lovelace",
+                STAKE
+            )]
+        );
+        assert_eq!(explanation.deposits, 2_000_000);
+        assert_eq!(explanation.refunds, 0);
+        assert_eq!(explanation.fee, Some(200_000));
+    }
+
+    #[test]
+    fn test_proposals_and_donation() {
+        let mut body = tx_body();
+        body.proposals = vec![Proposal::BasicProposal(ProposalType {
+            deposit: 100_000_000_000,
+            reward_account: STAKE.to_string(),
+            gov_action: "8106".to_string(),
+            anchor: Anchor {
+                anchor_url: "https://example.com/proposal.jsonld".to_string(),
+                anchor_data_hash: "00".repeat(32),
+            },
+        })];
+        body.donation = Some(1_000_000);
+        let explanation = TxExplanation::new(
+            &body,
+            &[user_utxo()],
+            &Protocol::default(),
+            &SlotConfig::mainnet(),
+        )
+        .unwrap();
+        assert_eq!(
+            explanation.proposals,
+            vec![format!(
+                "Propose a governance action with a deposit of 100000000000 lovelace, refunded to {}",
+                STAKE
+            )]
+        );
+        assert_eq!(explanation.deposits, 100_002_000_000);
+        assert_eq!(explanation.donation, Some(1_000_000));
+
+        let markdown = explanation.to_markdown();
+        assert!(markdown.contains("## Proposals"));
+        assert!(markdown.contains("## Treasury donation\n\n1000000 lovelace"));
+    }
+
+    #[test]
+    fn test_validity() {
+        let explanation = explain();
+        assert_eq!(explanation.validity.invalid_before, None);
+        assert_eq!(
+            explanation.validity.invalid_hereafter,
+            Some(SlotTime {
+                slot: 4492801,
+                unix_time: 1596059092000,
+                utc: "2020-07-29T21:44:52Z".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let explanation = explain();
+        let json = explanation.to_json().unwrap();
+        assert!(json.contains("\"type\": \"spend\""));
+        let parsed: TxExplanation = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, explanation);
+    }
+
+    #[test]
+    fn test_markdown() {
+        let markdown = explain().to_markdown();
+        assert!(markdown.starts_with("# Transaction summary\n"));
+        for section in [
+            "## Transfers",
+            "## Minted and burned",
+            "## Scripts executed",
+            "## Certificates",
+            "## Deposits and refunds",
+            "## Fee",
+            "## Validity",
+            "## Required signers",
+        ] {
+            assert!(markdown.contains(section), "missing {}", section);
+        }
+        assert!(!markdown.contains("## Votes"));
+        assert!(!markdown.contains("## Withdrawals"));
+        assert!(!markdown.contains("## Proposals"));
+        assert!(markdown.contains(
+            "- Mint 1 `5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f4d794e4654` (MyNFT)"
+        ));
+        assert!(markdown.contains("- Valid until 2020-07-29T21:44:52Z (slot 4492801)"));
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::data::ValueDelta;
+    use whisky_common::*;
+
+    // CIP-19 test vectors
+    const USER: &str = "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3n0d3vllmyqwsx5wktcd8cc3sq835lu7drv2xwl2wywfgse35a3x";
+    const SCRIPT: &str = "addr1w8phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcyjy7wx";
+    const USER_KEY_HASH: &str = "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e";
+    const USER_STAKE_KEY_HASH: &str = "337b62cfff6403a06a3acbc34f8c46003c69fe79a3628cefa9c47251";
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+    const TOKEN: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f4d794e4654";
+
+    fn tx_in(tx_index: u32, address: Option<&str>, amount: Option<Vec<Asset>>) -> TxIn {
+        TxIn::PubKeyTxIn(PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: TX_HASH.to_string(),
+                tx_index,
+                amount,
+                address: address.map(str::to_string),
+            },
+        })
+    }
+
+    fn output(address: &str, amount: Vec<Asset>) -> Output {
+        Output {
+            address: address.to_string(),
+            amount,
+            datum: None,
+            reference_script: None,
+        }
+    }
+
+    fn delta(assets: &[(&str, i128)]) -> ValueDelta {
+        let mut delta = ValueDelta::new();
+        for (unit, quantity) in assets {
+            delta.add_asset(unit, *quantity).unwrap();
+        }
+        delta
+    }
+
+    /// The user unlocks 5 ADA and a token from the script and pays the fee
+    fn tx_body() -> TxBuilderBody {
+        let mut body = TxBuilderBody::new();
+        body.inputs = vec![
+            tx_in(
+                0,
+                Some(SCRIPT),
+                Some(vec![
+                    Asset::new_from_str("lovelace", "10000000"),
+                    Asset::new_from_str(TOKEN, "1"),
+                ]),
+            ),
+            tx_in(1, None, None),
+        ];
+        body.outputs = vec![
+            output(SCRIPT, vec![Asset::new_from_str("lovelace", "5000000")]),
+            output(
+                USER,
+                vec![
+                    Asset::new_from_str("lovelace", "24800000"),
+                    Asset::new_from_str(TOKEN, "1"),
+                ],
+            ),
+        ];
+        body.fee = Some("200000".to_string());
+        body
+    }
+
+    fn user_utxo() -> UTxO {
+        UTxO {
+            input: UtxoInput {
+                output_index: 1,
+                tx_hash: TX_HASH.to_string(),
+            },
+            output: UtxoOutput {
+                address: USER.to_string(),
+                amount: vec![Asset::new_from_str("lovelace", "20000000")],
+                data_hash: None,
+                plutus_data: None,
+                script_ref: None,
+                script_hash: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_address_balance_change() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .with_utxos(&[user_utxo()])
+            .address_balance_change(SCRIPT, &delta(&[("lovelace", -5_000_000), (TOKEN, -1)]))
+            .address_balance_change(USER, &delta(&[("lovelace", 4_800_000), (TOKEN, 1)]));
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.address_balance_change(SCRIPT, &delta(&[("lovelace", -5_000_000)]));
+        assert!(tester.errors().contains("difference"));
+    }
+
+    #[test]
+    fn test_credential_balance_change() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .with_utxos(&[user_utxo()])
+            .payment_credential_balance_change(
+                &Credential::KeyHash(USER_KEY_HASH.to_string()),
+                &delta(&[("lovelace", 4_800_000), (TOKEN, 1)]),
+            )
+            .stake_credential_balance_change(
+                &Credential::KeyHash(USER_STAKE_KEY_HASH.to_string()),
+                &delta(&[("lovelace", 4_800_000), (TOKEN, 1)]),
+            );
+        assert!(tester.success(), "{}", tester.errors());
+    }
+
+    #[test]
+    fn test_unresolved_input() {
+        let mut tester = TxTester::new(&tx_body());
+        tester.address_balance_change(USER, &ValueDelta::new());
+        assert!(tester.errors().contains("with_utxos"));
+
+        let mut tester = TxTester::new(&tx_body());
+        tester.value_conserved(&Protocol::default());
+        assert!(tester.errors().contains("missing amount"));
+    }
+
+    #[test]
+    fn test_value_conserved() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .with_utxos(&[user_utxo()])
+            .value_conserved(&Protocol::default());
+        assert!(tester.success(), "{}", tester.errors());
+
+        let mut body = tx_body();
+        body.fee = Some("100000".to_string());
+        let mut tester = TxTester::new(&body);
+        tester
+            .with_utxos(&[user_utxo()])
+            .value_conserved(&Protocol::default());
+        assert!(tester.errors().contains("not balanced"));
+    }
+
+    #[test]
+    fn test_value_conserved_with_deposit() {
+        let mut body = tx_body();
+        body.certificates = vec![Certificate::BasicCertificate(
+            CertificateType::RegisterStake(RegisterStake {
+                stake_key_address: "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw"
+                    .to_string(),
+                coin: 2_000_000,
+            }),
+        )];
+        body.outputs[1].amount[0] = Asset::new_from_str("lovelace", "22800000");
+        let mut tester = TxTester::new(&body);
+        tester
+            .with_utxos(&[user_utxo()])
+            .value_conserved(&Protocol::default());
+        assert!(tester.success(), "{}", tester.errors());
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky_common::data::{
+        byte_string, constr0, integer, ByteString, Constr, Int, PlutusDataJson,
+    };
+    use whisky_common::*;
+
+    type OwnerDatum = Constr<Box<(ByteString, Int)>>;
+
+    const ADDRESS: &str = "addr_test1wpnlxv2xv9a9ucvnvzqakwepzl9ltx7jzgm53av2e9ncv4sysemm8";
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+    const DATUM_HASH: &str = "923918e403bf43c34b4ef6b48eb2ee04babed17320d8d1b9ff9ad086e86f44ec";
+
+    fn datum(owner: &str, amount: i128) -> String {
+        Constr::new(0, Box::new((ByteString::new(owner), Int::new(amount))))
+            .to_cbor()
+            .unwrap()
+    }
+
+    fn output(datum: Option<Datum>) -> Output {
+        Output {
+            address: ADDRESS.to_string(),
+            amount: vec![Asset::new_from_str("lovelace", "2000000")],
+            datum,
+            reference_script: None,
+        }
+    }
+
+    fn script_input(tx_index: u32, datum_source: Option<DatumSource>) -> TxIn {
+        TxIn::ScriptTxIn(ScriptTxIn {
+            tx_in: TxInParameter {
+                tx_hash: TX_HASH.to_string(),
+                tx_index,
+                amount: None,
+                address: Some(ADDRESS.to_string()),
+            },
+            script_tx_in: ScriptTxInParameter {
+                script_source: None,
+                datum_source,
+                redeemer: None,
+            },
+        })
+    }
+
+    #[test]
+    fn test_outputs_datum_satisfy() {
+        let mut body = TxBuilderBody::new();
+        body.outputs = vec![output(None), output(Some(Datum::Inline(datum("abcd", 10))))];
+        let mut tester = TxTester::new(&body);
+        tester
+            .all_outputs()
+            .outputs_datum_satisfy(|datum: &OwnerDatum| datum.fields.1.int == 10);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.outputs_datum_satisfy(|datum: &OwnerDatum| datum.fields.1.int > 10);
+        let errors = tester.errors();
+        assert!(errors.contains("output 0 at"), "{}", errors);
+        assert!(errors.contains("no datum"), "{}", errors);
+        assert!(
+            errors.contains("does not satisfy the predicate"),
+            "{}",
+            errors
+        );
+    }
+
+    #[test]
+    fn test_outputs_datum_of_wrong_type() {
+        let mut body = TxBuilderBody::new();
+        body.outputs = vec![output(Some(Datum::Inline(Int::new(1).to_cbor().unwrap())))];
+        let mut tester = TxTester::new(&body);
+        tester
+            .all_outputs()
+            .outputs_datum_satisfy(|_: &OwnerDatum| true);
+        assert!(tester.errors().contains("does not decode into"));
+    }
+
+    #[test]
+    fn test_outputs_datum_by_hash() {
+        let mut body = TxBuilderBody::new();
+        body.outputs = vec![output(Some(Datum::Hash(DATUM_HASH.to_string())))];
+        let mut tester = TxTester::new(&body);
+        tester
+            .all_outputs()
+            .outputs_datum_match(&constr0(vec![json!(null), integer(10)]));
+        assert!(tester.errors().contains("unresolved"));
+
+        let mut tester = TxTester::new(&body);
+        tester
+            .with_datum(DATUM_HASH, &datum("abcd", 10))
+            .all_outputs()
+            .outputs_datum_match(&constr0(vec![json!(null), integer(10)]));
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.outputs_datum_match(&constr0(vec![byte_string("ef"), json!(null)]));
+        assert!(tester.errors().contains("$.fields[0]"));
+    }
+
+    #[test]
+    fn test_inputs_datum() {
+        let mut body = TxBuilderBody::new();
+        body.inputs = vec![
+            script_input(
+                0,
+                Some(DatumSource::ProvidedDatumSource(ProvidedDatumSource {
+                    data: datum("abcd", 10),
+                })),
+            ),
+            script_input(
+                1,
+                Some(DatumSource::InlineDatumSource(InlineDatumSource {
+                    tx_hash: TX_HASH.to_string(),
+                    tx_index: 1,
+                })),
+            ),
+        ];
+        let mut tester = TxTester::new(&body);
+        tester
+            .all_inputs()
+            .inputs_datum_satisfy(|datum: &OwnerDatum| datum.fields.1.int == 10);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.inputs_datum_match(&constr0(vec![json!(null), integer(20)]));
+        assert!(tester.errors().contains("with_utxos"));
+
+        let mut tester = TxTester::new(&body);
+        tester
+            .with_utxos(&[UTxO {
+                input: UtxoInput {
+                    output_index: 1,
+                    tx_hash: TX_HASH.to_string(),
+                },
+                output: UtxoOutput {
+                    address: ADDRESS.to_string(),
+                    amount: vec![],
+                    data_hash: None,
+                    plutus_data: Some(datum("abcd", 20)),
+                    script_ref: None,
+                    script_hash: None,
+                },
+            }])
+            .all_inputs()
+            .inputs_datum_match(&constr0(vec![json!(null), integer(20)]));
+        assert!(tester.success(), "{}", tester.errors());
+    }
+}
@@ -0,0 +1,4 @@
+mod balance;
+mod datums;
+mod report;
+mod tx_body;
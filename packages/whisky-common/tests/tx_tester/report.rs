@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::data::Value;
+    use whisky_common::*;
+
+    const KEY_HASH: &str = "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e";
+    const ADDRESS: &str = "addr_test1wpnlxv2xv9a9ucvnvzqakwepzl9ltx7jzgm53av2e9ncv4sysemm8";
+
+    fn tester() -> TxTester {
+        let mut body = TxBuilderBody::new();
+        body.required_signatures = vec![KEY_HASH.to_string()];
+        body.outputs = vec![Output {
+            address: ADDRESS.to_string(),
+            amount: vec![Asset::new_from_str("lovelace", "2000000")],
+            datum: None,
+            reference_script: None,
+        }];
+        body.fee = Some("180000".to_string());
+        let mut tester = TxTester::new(&body);
+        tester
+            .key_signed(KEY_HASH)
+            .all_outputs()
+            .outputs_value(Value::from_asset(&Asset::new_from_str(
+                "lovelace", "1000000",
+            )))
+            .fee_within(0, 200000);
+        tester
+    }
+
+    #[test]
+    fn test_report_records_every_assertion() {
+        let report = tester().report();
+        let names: Vec<_> = report
+            .assertions
+            .iter()
+            .map(|assertion| (assertion.name.as_str(), assertion.passed))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("key_signed", true),
+                ("outputs_value", false),
+                ("fee_within", true)
+            ]
+        );
+        assert!(!report.success());
+        assert_eq!(report.passed().len(), 2);
+
+        let failed = report.failed()[0];
+        assert!(failed.expected.as_ref().unwrap().contains("1000000"));
+        assert!(failed.actual.as_ref().unwrap().contains("2000000"));
+        assert_eq!(failed.outputs_evaluating, vec![format!("#0 {}", ADDRESS)]);
+        assert!(report.assertions[0].outputs_evaluating.is_empty());
+    }
+
+    #[test]
+    fn test_report_json_round_trip() {
+        let report = tester().report();
+        let json = report.to_json().unwrap();
+        assert!(json.contains("\"outputsEvaluating\""));
+        assert_eq!(TesterReport::from_json(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_report_junit_xml() {
+        let xml = tester().report().to_junit_xml("escrow <unlock>");
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(
+            xml.contains("<testsuite name=\"escrow &lt;unlock&gt;\" tests=\"3\" failures=\"1\">")
+        );
+        assert!(xml.contains("name=\"1. key_signed\"/>"));
+        assert!(xml.contains("<failure message=\"tx outputs"));
+        assert!(xml.contains("outputs evaluating: #0 addr_test1"));
+        assert_eq!(xml.matches("<testcase").count(), 3);
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use whisky_common::*;
+
+    const STAKE_ADDRESS: &str = "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw";
+    const SCRIPT_STAKE_ADDRESS: &str =
+        "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5";
+    const POOL_ID: &str = "pool1pu5jlj4q9w9jlxeu370a3c9myx47md5j5m2str0naunn2q3lkdy";
+    const DREP_CIP129: &str = "drep1ygehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gr3fr2p";
+    const DREP_CIP105: &str = "drep_vkh1xdak9nllvsp6q636e0p5lrzxqq7xnlne5d3gemafc3e9znyulrx";
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+
+    fn tx_body() -> TxBuilderBody {
+        let mut body = TxBuilderBody::new();
+        body.certificates = vec![
+            Certificate::BasicCertificate(CertificateType::DelegateStake(DelegateStake {
+                stake_key_address: STAKE_ADDRESS.to_string(),
+                pool_id: POOL_ID.to_string(),
+            })),
+            Certificate::BasicCertificate(CertificateType::VoteDelegation(VoteDelegation {
+                stake_key_address: STAKE_ADDRESS.to_string(),
+                drep: DRep::DRepId(DREP_CIP129.to_string()),
+            })),
+        ];
+        body.withdrawals = vec![Withdrawal::PlutusScriptWithdrawal(PlutusScriptWithdrawal {
+            address: SCRIPT_STAKE_ADDRESS.to_string(),
+            coin: 5_000_000,
+            script_source: None,
+            redeemer: None,
+        })];
+        body.votes = vec![Vote::BasicVote(VoteType {
+            voter: Voter::DRepId(DREP_CIP129.to_string()),
+            gov_action_id: RefTxIn {
+                tx_hash: TX_HASH.to_string(),
+                tx_index: 0,
+                script_size: None,
+            },
+            voting_procedure: VotingProcedure {
+                vote_kind: VoteKind::Yes,
+                anchor: None,
+            },
+        })];
+        body.reference_inputs = vec![RefTxIn {
+            tx_hash: TX_HASH.to_string(),
+            tx_index: 1,
+            script_size: None,
+        }];
+        body.collaterals = vec![PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: TX_HASH.to_string(),
+                tx_index: 2,
+                amount: None,
+                address: None,
+            },
+        }];
+        let mut cip25 = Cip25Metadata::new(Cip25Version::V1);
+        cip25.add_asset(
+            "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f",
+            &hex::encode("MyNFT"),
+            Cip25Asset::new("My NFT", "ipfs://x"),
+        );
+        body.metadata = vec![cip25.to_metadata().unwrap()];
+        body.fee = Some("180000".to_string());
+        body
+    }
+
+    #[test]
+    fn test_certificate_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .stake_delegated(STAKE_ADDRESS, POOL_ID)
+            .vote_delegated(STAKE_ADDRESS, &DRep::DRepId(DREP_CIP105.to_string()))
+            .certificates_count(2);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.stake_registered(STAKE_ADDRESS);
+        assert!(!tester.success());
+    }
+
+    #[test]
+    fn test_withdrawal_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .withdrawal(SCRIPT_STAKE_ADDRESS, 5_000_000)
+            .script_withdrawal(SCRIPT_STAKE_ADDRESS, 5_000_000);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.script_withdrawal(SCRIPT_STAKE_ADDRESS, 4_000_000);
+        assert!(tester.errors().contains("script withdrawal of 5000000"));
+    }
+
+    #[test]
+    fn test_vote_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester.drep_voted(DREP_CIP105, TX_HASH, 0, VoteKind::Yes);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.drep_voted(DREP_CIP129, TX_HASH, 0, VoteKind::No);
+        assert!(!tester.success());
+    }
+
+    #[test]
+    fn test_reference_input_and_collateral_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester
+            .reference_input_exists(TX_HASH, 1)
+            .collateral_exists(TX_HASH, 2);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.reference_input_exists(TX_HASH, 2);
+        assert!(!tester.success());
+    }
+
+    #[test]
+    fn test_metadata_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester.metadata_exists(CIP25_LABEL);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.metadata_exists(CIP20_LABEL);
+        assert!(!tester.success());
+    }
+
+    #[test]
+    fn test_metadata_value_from_json() {
+        let mut body = TxBuilderBody::new();
+        body.metadata = vec![Metadata {
+            tag: "674".to_string(),
+            metadata: r#"{"msg":["hello"]}"#.to_string(),
+            metadatum: None,
+        }];
+        let mut tester = TxTester::new(&body);
+        tester.metadata_value(CIP20_LABEL, &cip20_message(&["hello"]));
+        assert!(tester.success(), "{}", tester.errors());
+    }
+
+    #[test]
+    fn test_fee_assertions() {
+        let mut tester = TxTester::new(&tx_body());
+        tester.fee_within(170_000, 200_000);
+        assert!(tester.success(), "{}", tester.errors());
+
+        tester.fee_within(0, 100_000);
+        assert!(tester.errors().contains("not within"));
+
+        let mut tester = TxTester::new(&TxBuilderBody::new());
+        tester.fee_within(0, 100_000);
+        assert!(tester.errors().contains("not set"));
+    }
+}
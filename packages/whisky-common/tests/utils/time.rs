@@ -3,7 +3,7 @@ mod tests {
     use whisky_common::{
         utils::{
             get_slot_config, resolve_epoch_no, resolve_slot_no, slot_to_begin_unix_time,
-            unix_time_to_enclosing_slot, SlotConfig,
+            unix_time_to_enclosing_slot, unix_time_to_utc, SlotConfig,
         },
         Network,
     };
@@ -86,6 +86,13 @@ mod tests {
         assert_eq!(epoch, Some(0));
     }
 
+    #[test]
+    fn test_unix_time_to_utc() {
+        assert_eq!(unix_time_to_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(unix_time_to_utc(1596059091000), "2020-07-29T21:44:51Z");
+        assert_eq!(unix_time_to_utc(1709251199999), "2024-02-29T23:59:59.999Z");
+    }
+
     #[test]
     fn test_get_slot_config_custom_returns_none() {
         let custom_network = Network::Custom(vec![]);
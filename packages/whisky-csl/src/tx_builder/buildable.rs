@@ -1,6 +1,6 @@
 use whisky_common::{TxBuildable, *};
 
-use crate::WhiskyCSL;
+use crate::{add_vkey_witnesses, calculate_tx_hash, validate_phase_one, WhiskyCSL};
 
 impl TxBuildable for WhiskyCSL {
     fn reset_builder(&mut self) {
@@ -42,6 +42,7 @@ impl TxBuildable for WhiskyCSL {
             .add_all_mints()?
             .add_all_certificates()?
             .add_all_votes()?
+            .add_all_proposals()?
             .add_validity_range()?
             .add_all_required_signature()?
             .add_all_metadata()?
@@ -50,7 +51,8 @@ impl TxBuildable for WhiskyCSL {
             .add_collateral_return()?
             .add_change_utxo()?;
 
-        self.core.build_tx(true)
+        self.core.build_tx(true)?;
+        self.add_all_key_witnesses()
     }
 
     fn unbalanced_serialize_tx_body(&mut self) -> Result<String, WError> {
@@ -68,6 +70,7 @@ impl TxBuildable for WhiskyCSL {
             .add_all_mints()?
             .add_all_certificates()?
             .add_all_votes()?
+            .add_all_proposals()?
             .add_validity_range()?
             .add_all_required_signature()?
             .add_all_metadata()?
@@ -75,7 +78,8 @@ impl TxBuildable for WhiskyCSL {
             .set_fee_if_needed()?
             .add_collateral_return()?;
 
-        self.core.build_tx(false)
+        self.core.build_tx(false)?;
+        self.add_all_key_witnesses()
     }
 
     /// ## Transaction building method
@@ -96,6 +100,22 @@ impl TxBuildable for WhiskyCSL {
         Ok(self.core.tx_hex.to_string())
     }
 
+    fn tx_hash(&mut self) -> Result<String, WError> {
+        calculate_tx_hash(&self.core.tx_hex)
+    }
+
+    /// ## Transaction building method
+    ///
+    /// Add vkey witnesses produced outside of the builder, e.g. by a remote signer
+    ///
+    /// ### Returns
+    ///
+    /// * `String` - The signed transaction in hex
+    fn add_vkey_witnesses(&mut self, vkey_witnesses: Vec<VkeyWitness>) -> Result<String, WError> {
+        self.core.tx_hex = add_vkey_witnesses(&self.core.tx_hex, &vkey_witnesses)?;
+        Ok(self.core.tx_hex.to_string())
+    }
+
     fn set_tx_hex(&mut self, tx_hex: String) {
         self.core.tx_hex = tx_hex;
     }
@@ -111,4 +131,15 @@ impl TxBuildable for WhiskyCSL {
     fn add_tx_in(&mut self, input: PubKeyTxIn) -> Result<(), WError> {
         self.core.add_tx_in(input)
     }
+
+    fn validate_phase_one(
+        &self,
+        tx_hex: &str,
+        utxos: &[UTxO],
+        protocol: &Protocol,
+        network: &Network,
+        current_slot: u64,
+    ) -> Result<Vec<Phase1Error>, WError> {
+        validate_phase_one(tx_hex, utxos, protocol, network, current_slot)
+    }
 }
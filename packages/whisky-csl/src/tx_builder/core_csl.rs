@@ -11,6 +11,7 @@ pub struct CoreCSL {
     pub mint_builder: csl::MintBuilder,
     pub certificates_builder: csl::CertificatesBuilder,
     pub vote_builder: csl::VotingBuilder,
+    pub proposal_builder: csl::VotingProposalBuilder,
     pub tx_withdrawals_builder: csl::WithdrawalsBuilder,
     pub protocol_params: Protocol,
 }
@@ -26,6 +27,7 @@ impl CoreCSL {
             mint_builder: csl::MintBuilder::new(),
             certificates_builder: csl::CertificatesBuilder::new(),
             vote_builder: csl::VotingBuilder::new(),
+            proposal_builder: csl::VotingProposalBuilder::new(),
             tx_withdrawals_builder: csl::WithdrawalsBuilder::new(),
             protocol_params: params.unwrap_or_default(),
         })
@@ -38,15 +40,24 @@ impl CoreCSL {
         self.mint_builder = csl::MintBuilder::new();
         self.certificates_builder = csl::CertificatesBuilder::new();
         self.vote_builder = csl::VotingBuilder::new();
+        self.proposal_builder = csl::VotingProposalBuilder::new();
         self.tx_withdrawals_builder = csl::WithdrawalsBuilder::new();
     }
 }
 
 impl CoreCSL {
     pub fn add_tx_in(&mut self, input: PubKeyTxIn) -> Result<(), WError> {
+        let address = input.tx_in.address.unwrap();
+        let mut input_address = csl::Address::from_bech32(&address);
+        // If the address is not in bech32 format, it might be a Byron address,
+        // which CSL witnesses with a bootstrap witness when estimating the fee
+        if input_address.is_err() {
+            input_address =
+                csl::ByronAddress::from_base58(&address).map(|byron_addr| byron_addr.to_address());
+        }
         self.tx_inputs_builder
             .add_regular_input(
-                &csl::Address::from_bech32(&input.tx_in.address.unwrap())
+                &input_address
                     .map_err(WError::from_err("CoreCSL - add_tx_in - invalid address"))?,
                 &csl::TransactionInput::new(
                     &csl::TransactionHash::from_hex(&input.tx_in.tx_hash)
@@ -294,11 +305,18 @@ impl CoreCSL {
     }
 
     pub fn add_collateral(&mut self, collateral: PubKeyTxIn) -> Result<(), WError> {
+        let address = collateral.tx_in.address.unwrap();
+        let mut collateral_address = csl::Address::from_bech32(&address);
+        // If the address is not in bech32 format, it might be a Byron address
+        if collateral_address.is_err() {
+            collateral_address =
+                csl::ByronAddress::from_base58(&address).map(|byron_addr| byron_addr.to_address());
+        }
         self.collateral_builder
             .add_regular_input(
-                &csl::Address::from_bech32(&collateral.tx_in.address.unwrap()).map_err(
-                    WError::from_err("CoreCSL - add_collateral - invalid address"),
-                )?,
+                &collateral_address.map_err(WError::from_err(
+                    "CoreCSL - add_collateral - invalid address",
+                ))?,
                 &csl::TransactionInput::new(
                     &csl::TransactionHash::from_hex(&collateral.tx_in.tx_hash).map_err(
                         WError::from_err("CoreCSL - add_collateral - invalid tx_hash"),
@@ -716,6 +734,70 @@ impl CoreCSL {
         Ok(())
     }
 
+    pub fn add_proposal(&mut self, proposal: Proposal, index: u64) -> Result<(), WError> {
+        match proposal {
+            Proposal::BasicProposal(proposal_type) => {
+                self.proposal_builder
+                    .add(&to_csl_voting_proposal(&proposal_type)?)
+                    .map_err(WError::from_err("CoreCSL - add_proposal - add"))?;
+            }
+            Proposal::ScriptProposal(script_proposal) => {
+                let proposal_script_source: csl::PlutusScriptSource =
+                    match script_proposal.script_source {
+                        Some(script_source) => to_csl_script_source(script_source)?,
+                        None => {
+                            return Err(WError::new(
+                                "CoreCSL - add_proposal",
+                                "Missing Plutus Script Source in Plutus Proposal",
+                            ))
+                        }
+                    };
+                let proposal_redeemer = match script_proposal.redeemer {
+                    Some(redeemer) => to_csl_redeemer(RedeemerTag::Propose, redeemer, index)?,
+                    None => {
+                        return Err(WError::new(
+                            "CoreCSL - add_proposal",
+                            "Missing Redeemer in Plutus Proposal",
+                        ))
+                    }
+                };
+                let csl_plutus_witness: csl::PlutusWitness =
+                    csl::PlutusWitness::new_with_ref_without_datum(
+                        &proposal_script_source,
+                        &proposal_redeemer,
+                    );
+                self.proposal_builder
+                    .add_with_plutus_witness(
+                        &to_csl_voting_proposal(&script_proposal.proposal)?,
+                        &csl_plutus_witness,
+                    )
+                    .map_err(WError::from_err(
+                        "CoreCSL - add_proposal - add_with_plutus_witness",
+                    ))?;
+            }
+        };
+        Ok(())
+    }
+
+    pub fn set_treasury_value(&mut self, treasury_value: u64) -> Result<(), WError> {
+        self.tx_builder
+            .set_current_treasury_value(&to_bignum(treasury_value).map_err(
+                WError::add_err_trace("CoreCSL - set_treasury_value - invalid treasury_value"),
+            )?)
+            .map_err(WError::from_err(
+                "CoreCSL - set_treasury_value - set_current_treasury_value",
+            ))?;
+        Ok(())
+    }
+
+    pub fn set_donation(&mut self, donation: u64) -> Result<(), WError> {
+        self.tx_builder
+            .set_donation(&to_bignum(donation).map_err(WError::add_err_trace(
+                "CoreCSL - set_donation - invalid donation",
+            ))?);
+        Ok(())
+    }
+
     pub fn add_invalid_before(&mut self, invalid_before: u64) -> Result<(), WError> {
         self.tx_builder
             .set_validity_start_interval_bignum(to_bignum(invalid_before).map_err(
@@ -783,6 +865,16 @@ impl CoreCSL {
         Ok(())
     }
 
+    pub fn add_key_witnesses(
+        &mut self,
+        vkey_witnesses: &[VkeyWitness],
+        bootstrap_witnesses: &[BootstrapWitness],
+    ) -> Result<(), WError> {
+        self.tx_hex = add_vkey_witnesses(&self.tx_hex, vkey_witnesses)?;
+        self.tx_hex = add_bootstrap_witnesses(&self.tx_hex, bootstrap_witnesses)?;
+        Ok(())
+    }
+
     pub fn add_required_signature(&mut self, pub_key_hash: &str) -> Result<(), WError> {
         self.tx_builder
             .add_required_signer(&csl::Ed25519KeyHash::from_hex(pub_key_hash).map_err(
@@ -792,16 +884,28 @@ impl CoreCSL {
     }
 
     pub fn add_metadata(&mut self, metadata: Metadata) -> Result<(), WError> {
-        self.tx_builder
-            .add_json_metadatum(
-                &csl::BigNum::from_str(&metadata.tag).map_err(WError::from_err(
-                    "CoreCSL - add_metadata - invalid metadata tag",
+        let tag = csl::BigNum::from_str(&metadata.tag).map_err(WError::from_err(
+            "CoreCSL - add_metadata - invalid metadata tag",
+        ))?;
+        match metadata.metadatum {
+            // The typed form tells text from bytes, which plain JSON cannot
+            Some(metadatum) => self
+                .tx_builder
+                .add_json_metadatum_with_schema(
+                    &tag,
+                    metadatum.to_detailed_json().to_string(),
+                    csl::MetadataJsonSchema::DetailedSchema,
+                )
+                .map_err(WError::from_err(
+                    "CoreCSL - add_metadata - add_json_metadatum_with_schema",
                 ))?,
-                metadata.metadata,
-            )
-            .map_err(WError::from_err(
-                "CoreCSL - add_metadata - add_json_metadatum",
-            ))?;
+            None => self
+                .tx_builder
+                .add_json_metadatum(&tag, metadata.metadata)
+                .map_err(WError::from_err(
+                    "CoreCSL - add_metadata - add_json_metadatum",
+                ))?,
+        }
         Ok(())
     }
 
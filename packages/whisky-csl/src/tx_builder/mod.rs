@@ -40,6 +40,24 @@ impl WhiskyCSL {
         Ok(())
     }
 
+    /// ## Internal method
+    ///
+    /// Add the vkey and bootstrap witnesses carried by the TxBuilder instance, e.g. from a parsed
+    /// transaction, to the built transaction
+    ///
+    /// ### Returns
+    ///
+    /// * `String` - The transaction with the witnesses in hex
+    pub fn add_all_key_witnesses(&mut self) -> Result<String, WError> {
+        let vkey_witnesses = &self.tx_builder_body.vkey_witnesses;
+        let bootstrap_witnesses = &self.tx_builder_body.bootstrap_witnesses;
+        if !vkey_witnesses.is_empty() || !bootstrap_witnesses.is_empty() {
+            self.core
+                .add_key_witnesses(vkey_witnesses, bootstrap_witnesses)?;
+        }
+        Ok(self.core.tx_hex.to_string())
+    }
+
     /// ## Internal method
     ///
     /// Add multiple inputs to the TxBuilder instance
@@ -170,6 +188,28 @@ impl WhiskyCSL {
         Ok(self)
     }
 
+    /// ## Internal method
+    ///
+    /// Add multiple proposals, the treasury value and the donation to the TxBuilder instance
+    pub fn add_all_proposals(&mut self) -> Result<&mut Self, WError> {
+        let proposals = self.tx_builder_body.proposals.clone();
+        if !proposals.is_empty() {
+            for (index, proposal) in proposals.into_iter().enumerate() {
+                self.core.add_proposal(proposal, index as u64)?
+            }
+            self.core
+                .tx_builder
+                .set_voting_proposal_builder(&self.core.proposal_builder);
+        }
+        if let Some(treasury_value) = self.tx_builder_body.treasury_value {
+            self.core.set_treasury_value(treasury_value)?;
+        }
+        if let Some(donation) = self.tx_builder_body.donation {
+            self.core.set_donation(donation)?;
+        }
+        Ok(self)
+    }
+
     /// ## Internal method
     ///
     /// Add a validity range to the TxBuilder instance
@@ -0,0 +1,37 @@
+use whisky_common::{BootstrapWitness, VkeyWitness, WError};
+
+use super::CSLParser;
+
+impl CSLParser {
+    pub fn get_vkey_witnesses(&self) -> &Vec<VkeyWitness> {
+        &self.tx_body.vkey_witnesses
+    }
+
+    pub fn get_bootstrap_witnesses(&self) -> &Vec<BootstrapWitness> {
+        &self.tx_body.bootstrap_witnesses
+    }
+
+    pub(super) fn extract_key_witnesses(&mut self) -> Result<(), WError> {
+        if let Some(vkey_witnesses) = self.csl_witness_set.vkeys() {
+            for i in 0..vkey_witnesses.len() {
+                let vkey_witness = vkey_witnesses.get(i);
+                self.tx_body.vkey_witnesses.push(VkeyWitness {
+                    vkey: vkey_witness.vkey().public_key().to_hex(),
+                    signature: vkey_witness.signature().to_hex(),
+                });
+            }
+        }
+        if let Some(bootstrap_witnesses) = self.csl_witness_set.bootstraps() {
+            for i in 0..bootstrap_witnesses.len() {
+                let bootstrap_witness = bootstrap_witnesses.get(i);
+                self.tx_body.bootstrap_witnesses.push(BootstrapWitness {
+                    vkey: bootstrap_witness.vkey().public_key().to_hex(),
+                    signature: bootstrap_witness.signature().to_hex(),
+                    chain_code: hex::encode(bootstrap_witness.chain_code()),
+                    attributes: hex::encode(bootstrap_witness.attributes()),
+                });
+            }
+        }
+        Ok(())
+    }
+}
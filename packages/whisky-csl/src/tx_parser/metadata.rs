@@ -1,5 +1,5 @@
 use cardano_serialization_lib as csl;
-use whisky_common::{Metadata, WError};
+use whisky_common::{Metadata, TransactionMetadatum, WError};
 
 use super::CSLParser;
 
@@ -39,8 +39,26 @@ fn csl_aux_data_to_metadata(aux_data: &csl::AuxiliaryData) -> Result<Vec<Metadat
                     &format!("Failed to decode metadata: {:?}", e),
                 )
             })?,
+            metadatum: Some(csl_metadatum_to_typed(&metadata)?),
         };
         metadata_list.push(metadata);
     }
     Ok(metadata_list)
 }
+
+fn csl_metadatum_to_typed(
+    metadatum: &csl::TransactionMetadatum,
+) -> Result<TransactionMetadatum, WError> {
+    let detailed_json =
+        csl::decode_metadatum_to_json_str(metadatum, csl::MetadataJsonSchema::DetailedSchema)
+            .map_err(|e| {
+                WError::new(
+                    "csl_metadatum_to_typed",
+                    &format!("Failed to decode metadata: {:?}", e),
+                )
+            })?;
+    let value: serde_json::Value =
+        serde_json::from_str(&detailed_json).map_err(WError::from_err("csl_metadatum_to_typed"))?;
+    TransactionMetadatum::from_detailed_json(&value)
+        .map_err(WError::add_err_trace("csl_metadatum_to_typed"))
+}
@@ -4,10 +4,12 @@ mod change_datum;
 mod collaterals;
 mod context;
 mod inputs;
+mod key_witnesses;
 mod metadata;
 mod mints;
 mod outputs;
 mod parsable;
+mod proposals;
 mod reference_inputs;
 mod required_signatures;
 mod static_methods;
@@ -15,6 +17,7 @@ mod utxo_converter;
 mod validity_range;
 mod votes;
 mod withdrawals;
+mod witnesses;
 
 use crate::tx_parser::context::ParserContext;
 use cardano_serialization_lib::{self as csl};
@@ -162,6 +165,10 @@ impl CSLParser {
             .map_err(WError::from_err("CSLParser - new - certificates"))?;
         self.extract_votes()
             .map_err(WError::from_err("CSLParser - new - votes"))?;
+        self.extract_proposals()
+            .map_err(WError::from_err("CSLParser - new - proposals"))?;
+        self.extract_key_witnesses()
+            .map_err(WError::from_err("CSLParser - new - key witnesses"))?;
 
         Ok(self)
     }
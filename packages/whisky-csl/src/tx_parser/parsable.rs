@@ -1,4 +1,6 @@
-use whisky_common::{TxBuilderBody, TxParsable, TxTester, UTxO, UtxoInput, WError};
+use whisky_common::{
+    Network, TxBuilderBody, TxParsable, TxTester, UTxO, UtxoInput, WError, WitnessReport,
+};
 
 use crate::WhiskyCSL;
 
@@ -32,7 +34,17 @@ impl TxParsable for WhiskyCSL {
         tx_body
     }
 
+    fn get_fee(&self) -> u64 {
+        self.parser.csl_tx_body.fee().into()
+    }
+
     fn to_tester(&self) -> TxTester {
         TxTester::new(&self.parser.tx_body)
     }
+
+    fn witness_report(&self, network: &Network) -> Result<WitnessReport, WError> {
+        self.parser
+            .witness_report(network)
+            .map_err(WError::add_err_trace("WhiskyCSL - witness_report"))
+    }
 }
@@ -0,0 +1,114 @@
+use whisky_common::{Anchor, Proposal, ProposalType, ScriptProposal, ScriptSource, WError};
+
+use super::{
+    context::{ParserContext, RedeemerIndex, Script},
+    CSLParser,
+};
+use cardano_serialization_lib as csl;
+
+impl CSLParser {
+    pub fn get_proposals(&self) -> &Vec<Proposal> {
+        &self.tx_body.proposals
+    }
+
+    pub fn get_treasury_value(&self) -> Option<u64> {
+        self.tx_body.treasury_value
+    }
+
+    pub fn get_donation(&self) -> Option<u64> {
+        self.tx_body.donation
+    }
+
+    pub(super) fn extract_proposals(&mut self) -> Result<(), WError> {
+        let proposals = self.csl_tx_body.voting_proposals();
+        if let Some(proposals) = proposals {
+            self.tx_body.proposals = csl_proposals_to_proposals(&proposals, &self.context)?;
+        }
+        if let Some(treasury_value) = self.csl_tx_body.current_treasury_value() {
+            self.tx_body.treasury_value = Some(csl_coin_to_u64(&treasury_value)?);
+        }
+        if let Some(donation) = self.csl_tx_body.donation() {
+            self.tx_body.donation = Some(csl_coin_to_u64(&donation)?);
+        }
+        Ok(())
+    }
+}
+
+pub fn csl_proposals_to_proposals(
+    proposals: &csl::VotingProposals,
+    context: &ParserContext,
+) -> Result<Vec<Proposal>, WError> {
+    let mut result = Vec::new();
+    for i in 0..proposals.len() {
+        let proposal = proposals.get(i);
+        let gov_action = proposal.governance_action();
+        let reward_account = proposal
+            .reward_account()
+            .to_address()
+            .to_bech32(None)
+            .map_err(|e| {
+                WError::new(
+                    "csl_proposals_to_proposals",
+                    &format!("Failed to convert reward account to bech32: {:?}", e),
+                )
+            })?;
+        let anchor = proposal.anchor();
+        let proposal_type = ProposalType {
+            deposit: csl_coin_to_u64(&proposal.deposit())?,
+            reward_account,
+            gov_action: gov_action.to_hex(),
+            anchor: Anchor {
+                anchor_url: anchor.url().url(),
+                anchor_data_hash: anchor.anchor_data_hash().to_hex(),
+            },
+        };
+
+        let policy_hash = match gov_action.kind() {
+            csl::GovernanceActionKind::ParameterChangeAction => gov_action
+                .as_parameter_change_action()
+                .and_then(|action| action.policy_hash()),
+            csl::GovernanceActionKind::TreasuryWithdrawalsAction => gov_action
+                .as_treasury_withdrawals_action()
+                .and_then(|action| action.policy_hash()),
+            _ => None,
+        };
+
+        let script_source = policy_hash
+            .and_then(|hash| context.script_witness.scripts.get(&hash))
+            .and_then(|script| match script {
+                Script::ProvidedPlutus(plutus_script) => {
+                    Some(ScriptSource::ProvidedScriptSource(plutus_script.clone()))
+                }
+                Script::ReferencedPlutus(inline_script) => {
+                    Some(ScriptSource::InlineScriptSource(inline_script.clone()))
+                }
+                Script::ProvidedNative(_) | Script::ReferencedNative(_) => None,
+            });
+
+        match script_source {
+            Some(script_source) => {
+                let redeemer = context
+                    .script_witness
+                    .redeemers
+                    .get(&RedeemerIndex::VotingProposal(i))
+                    .cloned();
+                result.push(Proposal::ScriptProposal(ScriptProposal {
+                    proposal: proposal_type,
+                    redeemer,
+                    script_source: Some(script_source),
+                }));
+            }
+            None => result.push(Proposal::BasicProposal(proposal_type)),
+        }
+    }
+    Ok(result)
+}
+
+fn csl_coin_to_u64(coin: &csl::Coin) -> Result<u64, WError> {
+    coin.to_str().parse::<u64>().map_err(|e| {
+        WError::new(
+            "csl_coin_to_u64",
+            &format!("Failed to parse coin amount: {:?}", e),
+        )
+    })
+}
@@ -70,6 +70,11 @@ pub fn utxo_to_tx_in(
         address: Some(utxo.output.address.clone()),
     };
 
+    // Byron addresses can only be spent with a bootstrap witness, i.e. a key witness
+    if csl::ByronAddress::is_valid(&utxo.output.address) {
+        return Ok(TxIn::PubKeyTxIn(PubKeyTxIn { tx_in: tx_in_param }));
+    }
+
     let address = csl::Address::from_bech32(&utxo.output.address).map_err(|e| {
         WError::new(
             "utxo_to_tx_in",
@@ -0,0 +1,392 @@
+use cardano_serialization_lib as csl;
+use whisky_common::{
+    LanguageVersion, Network, ScriptDataHashCheck, UtxoInput, VkeySource, VkeyWitnessCheck, WError,
+    WitnessInventory, WitnessReport,
+};
+
+use super::{context::Script, CSLParser};
+use crate::{blake2b256, build_csl_cost_models};
+
+impl CSLParser {
+    /// Audit the witness set of the parsed transaction against everything its body requires,
+    /// i.e. vkeys, scripts, datums and the script data hash under the network's cost models.
+    /// Bootstrap witnesses of Byron inputs are not checked.
+    pub fn witness_report(&self, network: &Network) -> Result<WitnessReport, WError> {
+        let mut inventory = WitnessInventory::default();
+
+        self.collect_input_requirements(&mut inventory)?;
+        self.collect_certificate_requirements(&mut inventory);
+        self.collect_withdrawal_requirements(&mut inventory)?;
+        self.collect_vote_requirements(&mut inventory);
+
+        if let Some(mint) = self.csl_tx_body.mint() {
+            let policy_ids = mint.keys();
+            for i in 0..policy_ids.len() {
+                inventory.required_scripts.push(policy_ids.get(i).to_hex());
+            }
+        }
+
+        if let Some(proposals) = self.csl_tx_body.voting_proposals() {
+            for i in 0..proposals.len() {
+                let gov_action = proposals.get(i).governance_action();
+                let policy_hash = match gov_action.kind() {
+                    csl::GovernanceActionKind::ParameterChangeAction => gov_action
+                        .as_parameter_change_action()
+                        .and_then(|action| action.policy_hash()),
+                    csl::GovernanceActionKind::TreasuryWithdrawalsAction => gov_action
+                        .as_treasury_withdrawals_action()
+                        .and_then(|action| action.policy_hash()),
+                    _ => None,
+                };
+                if let Some(policy_hash) = policy_hash {
+                    inventory.required_scripts.push(policy_hash.to_hex());
+                }
+            }
+        }
+
+        if let Some(required_signers) = self.csl_tx_body.required_signers() {
+            for i in 0..required_signers.len() {
+                inventory
+                    .required_vkeys
+                    .push((required_signers.get(i).to_hex(), VkeySource::RequiredSigner));
+            }
+        }
+
+        self.collect_witness_set(&mut inventory)?;
+        inventory.script_data_hash = self.check_script_data_hash(&inventory, network)?;
+
+        Ok(WitnessReport::new(inventory))
+    }
+
+    fn collect_input_requirements(&self, inventory: &mut WitnessInventory) -> Result<(), WError> {
+        let collaterals = self
+            .csl_tx_body
+            .collateral()
+            .unwrap_or(csl::TransactionInputs::new());
+        let spent = self.csl_tx_body.inputs();
+        let inputs = spent
+            .into_iter()
+            .map(|input| (input.clone(), false))
+            .chain(collaterals.into_iter().map(|input| (input.clone(), true)));
+
+        for (input, is_collateral) in inputs {
+            let utxo_input = UtxoInput {
+                tx_hash: input.transaction_id().to_hex(),
+                output_index: input.index(),
+            };
+            let utxo = match self.context.resolved_utxos.get(&input) {
+                Some(utxo) => utxo,
+                None => {
+                    inventory.unresolved_inputs.push(utxo_input);
+                    continue;
+                }
+            };
+            if csl::ByronAddress::is_valid(&utxo.output.address) {
+                continue;
+            }
+            let payment_cred = csl::Address::from_bech32(&utxo.output.address)
+                .map_err(|e| {
+                    WError::new(
+                        "CSLParser - witness_report - inputs",
+                        &format!("Failed to parse address: {:?}", e),
+                    )
+                })?
+                .payment_cred();
+            let payment_cred = match payment_cred {
+                Some(payment_cred) => payment_cred,
+                None => continue,
+            };
+
+            if let Some(key_hash) = payment_cred.to_keyhash() {
+                let source = if is_collateral {
+                    VkeySource::Collateral(utxo_input)
+                } else {
+                    VkeySource::Input(utxo_input)
+                };
+                inventory.required_vkeys.push((key_hash.to_hex(), source));
+            } else if let Some(script_hash) = payment_cred.to_scripthash() {
+                if is_collateral {
+                    continue;
+                }
+                let is_native = matches!(
+                    self.context.script_witness.scripts.get(&script_hash),
+                    Some(Script::ProvidedNative(_)) | Some(Script::ReferencedNative(_))
+                );
+                inventory.required_scripts.push(script_hash.to_hex());
+                if let (Some(data_hash), None, false) =
+                    (&utxo.output.data_hash, &utxo.output.plutus_data, is_native)
+                {
+                    inventory.required_datums.push(data_hash.clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_certificate_requirements(&self, inventory: &mut WitnessInventory) {
+        let certs = match self.csl_tx_body.certs() {
+            Some(certs) => certs,
+            None => return,
+        };
+        for i in 0..certs.len() {
+            let cert = certs.get(i);
+            let credential = match cert.kind() {
+                csl::CertificateKind::StakeRegistration => cert
+                    .as_stake_registration()
+                    .filter(|reg| reg.coin().is_some())
+                    .map(|reg| reg.stake_credential()),
+                csl::CertificateKind::StakeDeregistration => cert
+                    .as_stake_deregistration()
+                    .map(|dereg| dereg.stake_credential()),
+                csl::CertificateKind::StakeDelegation => cert
+                    .as_stake_delegation()
+                    .map(|deleg| deleg.stake_credential()),
+                csl::CertificateKind::StakeAndVoteDelegation => cert
+                    .as_stake_and_vote_delegation()
+                    .map(|deleg| deleg.stake_credential()),
+                csl::CertificateKind::StakeRegistrationAndDelegation => cert
+                    .as_stake_registration_and_delegation()
+                    .map(|reg| reg.stake_credential()),
+                csl::CertificateKind::StakeVoteRegistrationAndDelegation => cert
+                    .as_stake_vote_registration_and_delegation()
+                    .map(|reg| reg.stake_credential()),
+                csl::CertificateKind::VoteDelegation => cert
+                    .as_vote_delegation()
+                    .map(|deleg| deleg.stake_credential()),
+                csl::CertificateKind::VoteRegistrationAndDelegation => cert
+                    .as_vote_registration_and_delegation()
+                    .map(|reg| reg.stake_credential()),
+                csl::CertificateKind::CommitteeHotAuth => cert
+                    .as_committee_hot_auth()
+                    .map(|auth| auth.committee_cold_credential()),
+                csl::CertificateKind::CommitteeColdResign => cert
+                    .as_committee_cold_resign()
+                    .map(|resign| resign.committee_cold_credential()),
+                csl::CertificateKind::DRepRegistration => cert
+                    .as_drep_registration()
+                    .map(|reg| reg.voting_credential()),
+                csl::CertificateKind::DRepDeregistration => cert
+                    .as_drep_deregistration()
+                    .map(|dereg| dereg.voting_credential()),
+                csl::CertificateKind::DRepUpdate => cert
+                    .as_drep_update()
+                    .map(|update| update.voting_credential()),
+                csl::CertificateKind::PoolRegistration => {
+                    if let Some(pool_reg) = cert.as_pool_registration() {
+                        let pool_params = pool_reg.pool_params();
+                        inventory
+                            .required_vkeys
+                            .push((pool_params.operator().to_hex(), VkeySource::Certificate(i)));
+                        let owners = pool_params.pool_owners();
+                        for j in 0..owners.len() {
+                            inventory
+                                .required_vkeys
+                                .push((owners.get(j).to_hex(), VkeySource::Certificate(i)));
+                        }
+                    }
+                    None
+                }
+                csl::CertificateKind::PoolRetirement => {
+                    if let Some(pool_ret) = cert.as_pool_retirement() {
+                        inventory
+                            .required_vkeys
+                            .push((pool_ret.pool_keyhash().to_hex(), VkeySource::Certificate(i)));
+                    }
+                    None
+                }
+                csl::CertificateKind::GenesisKeyDelegation => None,
+                csl::CertificateKind::MoveInstantaneousRewardsCert => None,
+            };
+            if let Some(credential) = credential {
+                push_credential(inventory, &credential, VkeySource::Certificate(i));
+            }
+        }
+    }
+
+    fn collect_withdrawal_requirements(
+        &self,
+        inventory: &mut WitnessInventory,
+    ) -> Result<(), WError> {
+        let withdrawals = match self.csl_tx_body.withdrawals() {
+            Some(withdrawals) => withdrawals,
+            None => return Ok(()),
+        };
+        let reward_addresses = withdrawals.keys();
+        for i in 0..reward_addresses.len() {
+            let reward_address = reward_addresses.get(i);
+            let address = reward_address.to_address().to_bech32(None).map_err(|e| {
+                WError::new(
+                    "CSLParser - witness_report - withdrawals",
+                    &format!("Failed to convert reward address to bech32: {:?}", e),
+                )
+            })?;
+            push_credential(
+                inventory,
+                &reward_address.payment_cred(),
+                VkeySource::Withdrawal(address),
+            );
+        }
+        Ok(())
+    }
+
+    fn collect_vote_requirements(&self, inventory: &mut WitnessInventory) {
+        let votes = match self.csl_tx_body.voting_procedures() {
+            Some(votes) => votes,
+            None => return,
+        };
+        let voters = votes.get_voters();
+        for i in 0..voters.len() {
+            let voter = match voters.get(i) {
+                Some(voter) => voter,
+                None => continue,
+            };
+            if let Some(key_hash) = voter.to_stake_pool_key_hash() {
+                inventory
+                    .required_vkeys
+                    .push((key_hash.to_hex(), VkeySource::Vote(i)));
+            } else if let Some(credential) = voter
+                .to_constitutional_committee_hot_credential()
+                .or_else(|| voter.to_drep_credential())
+            {
+                push_credential(inventory, &credential, VkeySource::Vote(i));
+            }
+        }
+    }
+
+    fn collect_witness_set(&self, inventory: &mut WitnessInventory) -> Result<(), WError> {
+        let fixed_tx = csl::FixedTransaction::from_hex(&self.tx_hex)
+            .map_err(WError::from_err("CSLParser - witness_report - from_hex"))?;
+        let body_hash = blake2b256(&fixed_tx.raw_body());
+
+        if let Some(vkeys) = self.csl_witness_set.vkeys() {
+            for i in 0..vkeys.len() {
+                let vkey_witness = vkeys.get(i);
+                let pub_key = vkey_witness.vkey().public_key();
+                inventory.vkey_witnesses.push(VkeyWitnessCheck {
+                    vkey: hex::encode(pub_key.as_bytes()),
+                    key_hash: pub_key.hash().to_hex(),
+                    valid: pub_key.verify(&body_hash, &vkey_witness.signature()),
+                });
+            }
+        }
+
+        if let Some(native_scripts) = self.csl_witness_set.native_scripts() {
+            for i in 0..native_scripts.len() {
+                let native_script = native_scripts.get(i);
+                let script_hash = native_script.hash().to_hex();
+                let signers = native_script.get_required_signers();
+                for j in 0..signers.len() {
+                    inventory.required_vkeys.push((
+                        signers.get(j).to_hex(),
+                        VkeySource::NativeScript(script_hash.clone()),
+                    ));
+                }
+                inventory.witness_scripts.push(script_hash);
+            }
+        }
+
+        if let Some(plutus_scripts) = self.csl_witness_set.plutus_scripts() {
+            for i in 0..plutus_scripts.len() {
+                inventory
+                    .witness_scripts
+                    .push(plutus_scripts.get(i).hash().to_hex());
+            }
+        }
+
+        for (script_hash, script) in &self.context.script_witness.scripts {
+            if let Script::ReferencedNative(_) | Script::ReferencedPlutus(_) = script {
+                inventory.reference_scripts.push(script_hash.to_hex());
+            }
+        }
+
+        if let Some(plutus_data) = self.csl_witness_set.plutus_data() {
+            for i in 0..plutus_data.len() {
+                inventory
+                    .witness_datums
+                    .push(csl::hash_plutus_data(&plutus_data.get(i)).to_hex());
+            }
+        }
+
+        let outputs = self.csl_tx_body.outputs();
+        for i in 0..outputs.len() {
+            if let Some(data_hash) = outputs.get(i).data_hash() {
+                inventory.allowed_datums.push(data_hash.to_hex());
+            }
+        }
+        if let Some(reference_inputs) = self.csl_tx_body.reference_inputs() {
+            for input in reference_inputs.into_iter() {
+                if let Some(data_hash) = self
+                    .context
+                    .resolved_utxos
+                    .get(input)
+                    .and_then(|utxo| utxo.output.data_hash.clone())
+                {
+                    inventory.allowed_datums.push(data_hash);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_script_data_hash(
+        &self,
+        inventory: &WitnessInventory,
+        network: &Network,
+    ) -> Result<ScriptDataHashCheck, WError> {
+        let body = self
+            .csl_tx_body
+            .script_data_hash()
+            .map(|hash| hash.to_hex());
+        let redeemers = self.csl_witness_set.redeemers();
+        let datums = self.csl_witness_set.plutus_data();
+        if redeemers.is_none() && datums.is_none() {
+            return Ok(ScriptDataHashCheck {
+                body,
+                computed: None,
+            });
+        }
+
+        // Only the cost models of the languages actually executed are part of the hash
+        let mut languages = csl::Languages::new();
+        for script_hash in &inventory.required_scripts {
+            let script_hash = csl::ScriptHash::from_hex(script_hash).map_err(WError::from_err(
+                "CSLParser - witness_report - script_data_hash",
+            ))?;
+            let language_version = match self.context.script_witness.scripts.get(&script_hash) {
+                Some(Script::ProvidedPlutus(script)) => &script.language_version,
+                Some(Script::ReferencedPlutus(script)) => &script.language_version,
+                _ => continue,
+            };
+            let language = match language_version {
+                LanguageVersion::V1 => csl::Language::new_plutus_v1(),
+                LanguageVersion::V2 => csl::Language::new_plutus_v2(),
+                LanguageVersion::V3 => csl::Language::new_plutus_v3(),
+            };
+            if !(0..languages.len()).any(|i| languages.get(i) == language) {
+                languages.add(language);
+            }
+        }
+        let cost_models = build_csl_cost_models(network).retain_language_versions(&languages);
+        let computed = csl::hash_script_data(
+            &redeemers.unwrap_or(csl::Redeemers::new()),
+            &cost_models,
+            datums,
+        );
+        Ok(ScriptDataHashCheck {
+            body,
+            computed: Some(computed.to_hex()),
+        })
+    }
+}
+
+fn push_credential(
+    inventory: &mut WitnessInventory,
+    credential: &csl::Credential,
+    source: VkeySource,
+) {
+    if let Some(key_hash) = credential.to_keyhash() {
+        inventory.required_vkeys.push((key_hash.to_hex(), source));
+    } else if let Some(script_hash) = credential.to_scripthash() {
+        inventory.required_scripts.push(script_hash.to_hex());
+    }
+}
@@ -332,29 +332,44 @@ fn to_stake_vote_registration_and_delegation_cert(
     )
 }
 
+// The committee credential is given either as a `cc_cold` / `cc_hot` identifier, or as an address
+fn to_csl_committee_credential(
+    key_address: &str,
+    error_origin: &'static str,
+) -> Result<csl::Credential, WError> {
+    if let Ok(governance_id) = GovernanceId::from_bech32(key_address) {
+        return match governance_id.credential {
+            Credential::KeyHash(key_hash) => Ok(csl::Credential::from_keyhash(
+                &csl::Ed25519KeyHash::from_hex(&key_hash)
+                    .map_err(WError::from_err(error_origin))?,
+            )),
+            Credential::ScriptHash(script_hash) => Ok(csl::Credential::from_scripthash(
+                &csl::ScriptHash::from_hex(&script_hash).map_err(WError::from_err(error_origin))?,
+            )),
+        };
+    }
+    csl::Address::from_bech32(key_address)
+        .map_err(WError::from_err(error_origin))?
+        .payment_cred()
+        .ok_or_else(WError::from_opt(
+            error_origin,
+            "Invalid committee key address",
+        ))
+}
+
 fn to_committee_hot_auth_cert(
     committee_hot_auth: CommitteeHotAuth,
 ) -> Result<csl::Certificate, WError> {
     Ok(csl::Certificate::new_committee_hot_auth(
         &csl::CommitteeHotAuth::new(
-            &csl::Address::from_bech32(&committee_hot_auth.committee_cold_key_address)
-                .map_err(WError::from_err(
-                    "to_committee_hot_auth_cert - invalid committee cold key address",
-                ))?
-                .payment_cred()
-                .ok_or_else(WError::from_opt(
-                    "to_committee_hot_auth_cert - invalid committee cold key address",
-                    "Invalid committee cold key address",
-                ))?,
-            &csl::Address::from_bech32(&committee_hot_auth.committee_hot_key_address)
-                .map_err(WError::from_err(
-                    "to_committee_hot_auth_cert - invalid committee hot key address",
-                ))?
-                .payment_cred()
-                .ok_or_else(WError::from_opt(
-                    "to_committee_hot_auth_cert - invalid committee hot key address",
-                    "Invalid committee hot key address",
-                ))?,
+            &to_csl_committee_credential(
+                &committee_hot_auth.committee_cold_key_address,
+                "to_committee_hot_auth_cert - invalid committee cold key address",
+            )?,
+            &to_csl_committee_credential(
+                &committee_hot_auth.committee_hot_key_address,
+                "to_committee_hot_auth_cert - invalid committee hot key address",
+            )?,
         ),
     ))
 }
@@ -362,16 +377,10 @@ fn to_committee_hot_auth_cert(
 fn to_commitee_cold_resign_cert(
     committee_cold_resign: CommitteeColdResign,
 ) -> Result<csl::Certificate, WError> {
-    let committee_cold_key =
-        &csl::Address::from_bech32(&committee_cold_resign.committee_cold_key_address)
-            .map_err(WError::from_err(
-                "to_commitee_cold_resign_cert - invalid committee cold key address",
-            ))?
-            .payment_cred()
-            .ok_or_else(WError::from_opt(
-                "to_commitee_cold_resign_cert - invalid committee cold key address",
-                "Invalid committee cold key address",
-            ))?;
+    let committee_cold_key = &to_csl_committee_credential(
+        &committee_cold_resign.committee_cold_key_address,
+        "to_commitee_cold_resign_cert - invalid committee cold key address",
+    )?;
     match committee_cold_resign.anchor {
         Some(anchor) => Ok(csl::Certificate::new_committee_cold_resign(
             &csl::CommitteeColdResign::new_with_anchor(
@@ -2,6 +2,7 @@ mod address;
 mod aiken;
 mod certificates;
 mod evaluator;
+mod phase_one;
 mod phase_two;
 mod plutus_data;
 mod redeemer;
@@ -17,6 +18,7 @@ pub use address::*;
 pub use aiken::*;
 pub use certificates::*;
 pub use evaluator::*;
+pub use phase_one::*;
 pub use plutus_data::*;
 pub use redeemer::*;
 pub use script::*;
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use cardano_serialization_lib as csl;
+use whisky_common::{data::Value, *};
+
+use crate::{to_bignum, tx_parser::CSLParser};
+
+/// Validate a transaction against the phase-1 ledger rules, i.e. value conservation, fee,
+/// minimum output lovelace, size, collateral, validity interval and witnesses. `utxos` must
+/// resolve every spent, collateral and reference input. Pool registrations are assumed to
+/// register new pools, so their deposit is always counted.
+pub fn validate_phase_one(
+    tx_hex: &str,
+    utxos: &[UTxO],
+    protocol: &Protocol,
+    network: &Network,
+    current_slot: u64,
+) -> Result<Vec<Phase1Error>, WError> {
+    let fixed_tx = csl::FixedTransaction::from_hex(tx_hex)
+        .map_err(WError::from_err("validate_phase_one - invalid tx hex"))?;
+    let body = fixed_tx.body();
+
+    let unresolved = unresolved_inputs(&body, utxos);
+    if !unresolved.is_empty() {
+        return Ok(vec![Phase1Error::BadInputs(unresolved)]);
+    }
+
+    let mut parser = CSLParser::new();
+    parser
+        .parse(tx_hex, utxos)
+        .map_err(WError::add_err_trace("validate_phase_one - parse"))?;
+    let facts = Phase1Facts {
+        tx_size: fixed_tx.to_bytes().len() as u64,
+        fee: bignum_to_u64(&body.fee())?,
+        min_fee: min_fee(&fixed_tx, utxos, protocol)?,
+        consumed: consumed(&parser, utxos, protocol)?,
+        produced: produced(&parser, protocol)?,
+        outputs: outputs(&parser, protocol)?,
+        has_redeemers: fixed_tx
+            .witness_set()
+            .redeemers()
+            .is_some_and(|redeemers| redeemers.len() > 0),
+        collateral_inputs: body.collateral().map_or(0, |inputs| inputs.len() as u64),
+        collateral: collateral(&body, utxos)?,
+        total_collateral: body
+            .total_collateral()
+            .map(|coin| bignum_to_u64(&coin))
+            .transpose()?,
+        invalid_before: parser.tx_body.validity_range.invalid_before,
+        invalid_hereafter: parser.tx_body.validity_range.invalid_hereafter,
+        witness_report: parser
+            .witness_report(network)
+            .map_err(WError::add_err_trace("validate_phase_one"))?,
+    };
+    Ok(facts.validate(protocol, current_slot))
+}
+
+fn unresolved_inputs(body: &csl::TransactionBody, utxos: &[UTxO]) -> Vec<UtxoInput> {
+    let inputs: Vec<UtxoInput> = body
+        .inputs()
+        .into_iter()
+        .chain(&body.collateral().unwrap_or(csl::TransactionInputs::new()))
+        .chain(
+            &body
+                .reference_inputs()
+                .unwrap_or(csl::TransactionInputs::new()),
+        )
+        .map(to_utxo_input)
+        .collect();
+    let mut unresolved: Vec<UtxoInput> = vec![];
+    for input in inputs {
+        if find_utxo(utxos, &input).is_none() && !unresolved.contains(&input) {
+            unresolved.push(input);
+        }
+    }
+    unresolved
+}
+
+fn find_utxo<'a>(utxos: &'a [UTxO], input: &UtxoInput) -> Option<&'a UTxO> {
+    utxos.iter().find(|utxo| &utxo.input == input)
+}
+
+fn to_utxo_input(input: &csl::TransactionInput) -> UtxoInput {
+    UtxoInput {
+        tx_hash: input.transaction_id().to_hex(),
+        output_index: input.index(),
+    }
+}
+
+fn bignum_to_u64(value: &csl::BigNum) -> Result<u64, WError> {
+    value
+        .to_str()
+        .parse::<u64>()
+        .map_err(WError::from_err("validate_phase_one - invalid coin"))
+}
+
+fn utxo_lovelace(utxo: &UTxO) -> u64 {
+    Value::from_asset_vec(&utxo.output.amount).get("lovelace")
+}
+
+fn min_fee(
+    fixed_tx: &csl::FixedTransaction,
+    utxos: &[UTxO],
+    protocol: &Protocol,
+) -> Result<u64, WError> {
+    let size_fee = protocol.min_fee_a * fixed_tx.to_bytes().len() as u64 + protocol.min_fee_b;
+
+    let script_fee = match fixed_tx.witness_set().redeemers() {
+        Some(redeemers) => {
+            let ex_units = redeemers
+                .total_ex_units()
+                .map_err(WError::from_err("validate_phase_one - invalid ex units"))?;
+            (bignum_to_u64(&ex_units.mem())? as f64 * protocol.price_mem
+                + bignum_to_u64(&ex_units.steps())? as f64 * protocol.price_step)
+                .ceil() as u64
+        }
+        None => 0,
+    };
+
+    let body = fixed_tx.body();
+    // Inputs that are also referenced only count once towards the reference script size
+    let inputs: HashSet<UtxoInput> = body
+        .inputs()
+        .into_iter()
+        .chain(
+            &body
+                .reference_inputs()
+                .unwrap_or(csl::TransactionInputs::new()),
+        )
+        .map(to_utxo_input)
+        .collect();
+    let mut ref_script_size = 0;
+    for input in inputs {
+        if let Some(script_ref) =
+            find_utxo(utxos, &input).and_then(|u| u.output.script_ref.as_ref())
+        {
+            ref_script_size += script_ref.len() / 2;
+        }
+    }
+    let ref_script_fee = csl::min_ref_script_fee(
+        ref_script_size,
+        &csl::UnitInterval::new(
+            &to_bignum(protocol.min_fee_ref_script_cost_per_byte)?,
+            &to_bignum(1)?,
+        ),
+    )
+    .map_err(WError::from_err("validate_phase_one - ref script fee"))?;
+
+    Ok(size_fee + script_fee + bignum_to_u64(&ref_script_fee)?)
+}
+
+fn consumed(parser: &CSLParser, utxos: &[UTxO], protocol: &Protocol) -> Result<Value, WError> {
+    let mut consumed = Value::new();
+    for input in parser.csl_tx_body.inputs().into_iter() {
+        if let Some(utxo) = find_utxo(utxos, &to_utxo_input(input)) {
+            consumed.add_assets(&utxo.output.amount);
+        }
+    }
+    let implicit_input = csl::get_implicit_input(
+        &parser.csl_tx_body,
+        &to_bignum(protocol.pool_deposit)?,
+        &to_bignum(protocol.key_deposit)?,
+    )
+    .map_err(WError::from_err("validate_phase_one - implicit input"))?;
+    consumed.add_asset("lovelace", bignum_to_u64(&implicit_input.coin())?);
+    for mint in &parser.tx_body.mints {
+        let mint = mint.get_mint_parameter();
+        if mint.amount > 0 {
+            consumed.add_asset(
+                &format!("{}{}", mint.policy_id, mint.asset_name),
+                mint.amount as u64,
+            );
+        }
+    }
+    Ok(consumed)
+}
+
+fn produced(parser: &CSLParser, protocol: &Protocol) -> Result<Value, WError> {
+    let mut produced = Value::new();
+    for output in &parser.tx_body.outputs {
+        produced.add_assets(&output.amount);
+    }
+    let deposit = csl::get_deposit(
+        &parser.csl_tx_body,
+        &to_bignum(protocol.pool_deposit)?,
+        &to_bignum(protocol.key_deposit)?,
+    )
+    .map_err(WError::from_err("validate_phase_one - deposit"))?;
+    produced.add_asset("lovelace", bignum_to_u64(&parser.csl_tx_body.fee())?);
+    produced.add_asset("lovelace", bignum_to_u64(&deposit)?);
+    if let Some(donation) = parser.csl_tx_body.donation() {
+        produced.add_asset("lovelace", bignum_to_u64(&donation)?);
+    }
+    for mint in &parser.tx_body.mints {
+        let mint = mint.get_mint_parameter();
+        if mint.amount < 0 {
+            produced.add_asset(
+                &format!("{}{}", mint.policy_id, mint.asset_name),
+                mint.amount.unsigned_abs() as u64,
+            );
+        }
+    }
+    Ok(produced)
+}
+
+fn outputs(parser: &CSLParser, protocol: &Protocol) -> Result<Vec<(u64, u64)>, WError> {
+    let data_cost = csl::DataCost::new_coins_per_byte(&to_bignum(protocol.coins_per_utxo_size)?);
+    let mut outputs: Vec<(u64, u64)> = vec![];
+    for output in parser.csl_tx_body.outputs().into_iter() {
+        let min_lovelace = csl::min_ada_for_output(output, &data_cost)
+            .map_err(WError::from_err("validate_phase_one - min ada for output"))?;
+        outputs.push((
+            bignum_to_u64(&output.amount().coin())?,
+            bignum_to_u64(&min_lovelace)?,
+        ));
+    }
+    Ok(outputs)
+}
+
+fn collateral(body: &csl::TransactionBody, utxos: &[UTxO]) -> Result<u64, WError> {
+    let mut collateral: u64 = 0;
+    if let Some(inputs) = body.collateral() {
+        for input in inputs.into_iter() {
+            if let Some(utxo) = find_utxo(utxos, &to_utxo_input(input)) {
+                collateral += utxo_lovelace(utxo);
+            }
+        }
+    }
+    if let Some(collateral_return) = body.collateral_return() {
+        collateral = collateral.saturating_sub(bignum_to_u64(&collateral_return.amount().coin())?);
+    }
+    Ok(collateral)
+}
@@ -1,12 +1,31 @@
-use cardano_serialization_lib as csl;
-use whisky_common::data::PlutusDataJson;
+use whisky_common::data::blueprint::{Blueprint, SchemaMismatch};
+use whisky_common::data::decode_plutus_data;
 use whisky_common::WError;
 
-/// Extension trait that adds CBOR serialization/deserialization capabilities
-/// to any type implementing `PlutusDataJson`.
+/// Decode PlutusData CBOR hex into its detailed schema JSON
+pub fn plutus_data_cbor_to_json(cbor_hex: &str) -> Result<serde_json::Value, WError> {
+    let cbor = hex::decode(cbor_hex).map_err(WError::from_err(
+        "plutus_data_cbor_to_json - invalid CBOR hex",
+    ))?;
+    decode_plutus_data(&cbor).map_err(WError::add_err_trace("plutus_data_cbor_to_json"))
+}
+
+/// Validate PlutusData CBOR hex against a blueprint definition,
+/// see [`Blueprint::validate_plutus_data`]
+pub fn validate_plutus_data_cbor(
+    blueprint: &Blueprint,
+    reference: &str,
+    cbor_hex: &str,
+) -> Result<Vec<SchemaMismatch>, WError> {
+    let json_value = plutus_data_cbor_to_json(cbor_hex)
+        .map_err(WError::add_err_trace("validate_plutus_data_cbor"))?;
+    blueprint.validate_plutus_data(reference, &json_value)
+}
+
+/// CBOR serialization/deserialization of any type implementing `PlutusDataJson`.
 ///
-/// This trait is implemented automatically for all types that implement `PlutusDataJson`,
-/// allowing direct conversion to/from CBOR hex strings.
+/// `to_cbor`/`from_cbor` are now provided natively by `PlutusDataJson`, so this is
+/// kept as an alias of that trait for existing imports.
 ///
 /// # Example
 ///
@@ -27,43 +46,4 @@ use whisky_common::WError;
 /// // Serialize back to CBOR
 /// let cbor_out = intent.to_cbor()?;
 /// ```
-pub trait PlutusDataCbor: PlutusDataJson {
-    /// Parse from CBOR hex string.
-    ///
-    /// This method converts the CBOR hex to JSON using CSL's DetailedSchema,
-    /// then parses it using the type's `from_json` implementation.
-    fn from_cbor(cbor_hex: &str) -> Result<Self, WError>;
-
-    /// Serialize to CBOR hex string.
-    ///
-    /// This method converts the type to JSON using `to_json`,
-    /// then serializes it to CBOR hex using CSL's DetailedSchema.
-    fn to_cbor(&self) -> Result<String, WError>;
-}
-
-impl<T: PlutusDataJson> PlutusDataCbor for T {
-    fn from_cbor(cbor_hex: &str) -> Result<Self, WError> {
-        let csl_data = csl::PlutusData::from_hex(cbor_hex)
-            .map_err(WError::from_err("PlutusDataCbor::from_cbor - invalid CBOR hex"))?;
-
-        let json_str = csl_data
-            .to_json(csl::PlutusDatumSchema::DetailedSchema)
-            .map_err(WError::from_err(
-                "PlutusDataCbor::from_cbor - failed to convert to JSON",
-            ))?;
-
-        let json_value: serde_json::Value = serde_json::from_str(&json_str)
-            .map_err(WError::from_err("PlutusDataCbor::from_cbor - invalid JSON"))?;
-
-        Self::from_json(&json_value)
-    }
-
-    fn to_cbor(&self) -> Result<String, WError> {
-        let json_str = self.to_json_string();
-
-        let csl_data = csl::PlutusData::from_json(&json_str, csl::PlutusDatumSchema::DetailedSchema)
-            .map_err(WError::from_err("PlutusDataCbor::to_cbor - failed to parse JSON"))?;
-
-        Ok(csl_data.to_hex())
-    }
-}
+pub use whisky_common::data::PlutusDataJson as PlutusDataCbor;
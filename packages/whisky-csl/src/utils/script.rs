@@ -11,6 +11,45 @@ pub fn get_native_script_hash(script: &str) -> Result<String, WError> {
     Ok(script_hash)
 }
 
+pub fn to_csl_native_script(script: &NativeScript) -> Result<csl::NativeScript, WError> {
+    let to_csl_native_scripts = |scripts: &[NativeScript]| -> Result<csl::NativeScripts, WError> {
+        let mut csl_scripts = csl::NativeScripts::new();
+        for script in scripts {
+            csl_scripts.add(&to_csl_native_script(script)?);
+        }
+        Ok(csl_scripts)
+    };
+    match script {
+        NativeScript::Sig { key_hash } => Ok(csl::NativeScript::new_script_pubkey(
+            &csl::ScriptPubkey::new(
+                &csl::Ed25519KeyHash::from_hex(key_hash)
+                    .map_err(WError::from_err("to_csl_native_script - invalid key hash"))?,
+            ),
+        )),
+        NativeScript::All { scripts } => Ok(csl::NativeScript::new_script_all(
+            &csl::ScriptAll::new(&to_csl_native_scripts(scripts)?),
+        )),
+        NativeScript::Any { scripts } => Ok(csl::NativeScript::new_script_any(
+            &csl::ScriptAny::new(&to_csl_native_scripts(scripts)?),
+        )),
+        NativeScript::AtLeast { required, scripts } => Ok(csl::NativeScript::new_script_n_of_k(
+            &csl::ScriptNOfK::new(*required, &to_csl_native_scripts(scripts)?),
+        )),
+        NativeScript::Before { slot } => Ok(csl::NativeScript::new_timelock_expiry(
+            &csl::TimelockExpiry::new_timelockexpiry(&csl::BigNum::from(*slot)),
+        )),
+        NativeScript::After { slot } => Ok(csl::NativeScript::new_timelock_start(
+            &csl::TimelockStart::new_timelockstart(&csl::BigNum::from(*slot)),
+        )),
+    }
+}
+
+pub fn get_native_script_cbor(script: &NativeScript) -> Result<String, WError> {
+    Ok(to_csl_native_script(script)
+        .map_err(WError::from_err("get_native_script_cbor"))?
+        .to_hex())
+}
+
 pub fn get_script_hash(script: &str, version: LanguageVersion) -> Result<String, WError> {
     let language_version = match version {
         LanguageVersion::V1 => csl::Language::new_plutus_v1(),
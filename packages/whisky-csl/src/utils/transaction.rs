@@ -1,6 +1,6 @@
 use cardano_serialization_lib::{self as csl};
 use cryptoxide::blake2b::Blake2b;
-use whisky_common::WError;
+use whisky_common::{BootstrapWitness, ValidityRange, VkeyWitness, WError};
 
 pub(crate) fn blake2b256(data: &[u8]) -> [u8; 32] {
     let mut out = [0; 32];
@@ -63,3 +63,169 @@ pub fn sign_transaction(tx_hex: &str, signing_keys: &[&str]) -> Result<String, W
     };
     Ok(signed_transaction.to_hex())
 }
+
+pub fn get_vkey_witness_key_hashes(tx_hex: &str) -> Result<Vec<String>, WError> {
+    let tx = csl::FixedTransaction::from_hex(tx_hex).map_err(WError::from_err(
+        "get_vkey_witness_key_hashes - invalid transaction hex",
+    ))?;
+    let mut key_hashes: Vec<String> = vec![];
+    if let Some(vkey_witnesses) = tx.witness_set().vkeys() {
+        for i in 0..vkey_witnesses.len() {
+            let key_hash = vkey_witnesses.get(i).vkey().public_key().hash().to_hex();
+            if !key_hashes.contains(&key_hash) {
+                key_hashes.push(key_hash);
+            }
+        }
+    }
+    Ok(key_hashes)
+}
+
+pub fn merge_vkey_witnesses(
+    tx_hex: &str,
+    partially_signed_tx_hexes: &[&str],
+) -> Result<String, WError> {
+    let tx = csl::FixedTransaction::from_hex(tx_hex).map_err(WError::from_err(
+        "merge_vkey_witnesses - invalid transaction hex",
+    ))?;
+    let raw_body = tx.raw_body();
+    let mut witness_set = tx.witness_set();
+    let mut vkey_witnesses = witness_set
+        .vkeys()
+        .unwrap_or_else(csl::Vkeywitnesses::new)
+        .clone();
+    let mut signed_keys: Vec<String> = (0..vkey_witnesses.len())
+        .map(|i| vkey_witnesses.get(i).vkey().public_key().to_hex())
+        .collect();
+
+    for partially_signed_tx_hex in partially_signed_tx_hexes {
+        let partially_signed_tx = csl::FixedTransaction::from_hex(partially_signed_tx_hex)
+            .map_err(WError::from_err(
+                "merge_vkey_witnesses - invalid partially signed transaction hex",
+            ))?;
+        if partially_signed_tx.raw_body() != raw_body {
+            return Err(WError::new(
+                "merge_vkey_witnesses",
+                &format!(
+                    "transaction body mismatch, expected tx hash {} but got {}",
+                    tx.transaction_hash().to_hex(),
+                    partially_signed_tx.transaction_hash().to_hex()
+                ),
+            ));
+        }
+        if let Some(partial_vkey_witnesses) = partially_signed_tx.witness_set().vkeys() {
+            for i in 0..partial_vkey_witnesses.len() {
+                let vkey_witness = partial_vkey_witnesses.get(i);
+                let public_key = vkey_witness.vkey().public_key().to_hex();
+                if !signed_keys.contains(&public_key) {
+                    signed_keys.push(public_key);
+                    vkey_witnesses.add(&vkey_witness);
+                }
+            }
+        }
+    }
+    witness_set.set_vkeys(&vkey_witnesses);
+
+    let merged_transaction =
+        with_witness_set(&tx, &witness_set).map_err(WError::from_err("merge_vkey_witnesses"))?;
+    Ok(merged_transaction.to_hex())
+}
+
+/// Adds vkey witnesses produced elsewhere, e.g. by a remote signer, to the transaction.
+///
+/// The transaction body is kept byte for byte, and witnesses of keys which already signed are skipped.
+pub fn add_vkey_witnesses(
+    tx_hex: &str,
+    new_vkey_witnesses: &[VkeyWitness],
+) -> Result<String, WError> {
+    let mut tx = csl::FixedTransaction::from_hex(tx_hex).map_err(WError::from_err(
+        "add_vkey_witnesses - invalid transaction hex",
+    ))?;
+    let mut signed_keys: Vec<String> = match tx.witness_set().vkeys() {
+        Some(vkey_witnesses) => (0..vkey_witnesses.len())
+            .map(|i| vkey_witnesses.get(i).vkey().public_key().to_hex())
+            .collect(),
+        None => vec![],
+    };
+    for vkey_witness in new_vkey_witnesses {
+        if signed_keys.contains(&vkey_witness.vkey) {
+            continue;
+        }
+        let public_key = csl::PublicKey::from_hex(&vkey_witness.vkey)
+            .map_err(WError::from_err("add_vkey_witnesses - invalid vkey"))?;
+        let signature = csl::Ed25519Signature::from_hex(&vkey_witness.signature)
+            .map_err(WError::from_err("add_vkey_witnesses - invalid signature"))?;
+        // Added the same way as `FixedTransaction::sign_and_add_vkey_signature`, so the
+        // witness set is encoded as if the keys had signed locally
+        tx.add_vkey_witness(&csl::Vkeywitness::new(
+            &csl::Vkey::new(&public_key),
+            &signature,
+        ));
+        signed_keys.push(vkey_witness.vkey.clone());
+    }
+    Ok(tx.to_hex())
+}
+
+pub fn add_bootstrap_witnesses(
+    tx_hex: &str,
+    new_bootstrap_witnesses: &[BootstrapWitness],
+) -> Result<String, WError> {
+    let mut tx = csl::FixedTransaction::from_hex(tx_hex).map_err(WError::from_err(
+        "add_bootstrap_witnesses - invalid transaction hex",
+    ))?;
+    for bootstrap_witness in new_bootstrap_witnesses {
+        let public_key = csl::PublicKey::from_hex(&bootstrap_witness.vkey)
+            .map_err(WError::from_err("add_bootstrap_witnesses - invalid vkey"))?;
+        let signature = csl::Ed25519Signature::from_hex(&bootstrap_witness.signature).map_err(
+            WError::from_err("add_bootstrap_witnesses - invalid signature"),
+        )?;
+        let chain_code = hex::decode(&bootstrap_witness.chain_code).map_err(WError::from_err(
+            "add_bootstrap_witnesses - invalid chain code",
+        ))?;
+        let attributes = hex::decode(&bootstrap_witness.attributes).map_err(WError::from_err(
+            "add_bootstrap_witnesses - invalid attributes",
+        ))?;
+        tx.add_bootstrap_witness(&csl::BootstrapWitness::new(
+            &csl::Vkey::new(&public_key),
+            &signature,
+            chain_code,
+            attributes,
+        ));
+    }
+    Ok(tx.to_hex())
+}
+
+// Rebuilds the transaction with a new witness set, keeping the raw body and auxiliary data
+fn with_witness_set(
+    tx: &csl::FixedTransaction,
+    witness_set: &csl::TransactionWitnessSet,
+) -> Result<csl::FixedTransaction, WError> {
+    match &tx.raw_auxiliary_data() {
+        Some(raw_auxiliary_data) => csl::FixedTransaction::new_with_auxiliary(
+            &tx.raw_body(),
+            &witness_set.to_bytes(),
+            raw_auxiliary_data,
+            tx.is_valid(),
+        )
+        .map_err(WError::from_err(
+            "failed to create transaction with auxiliary data",
+        )),
+        None => csl::FixedTransaction::new(&tx.raw_body(), &witness_set.to_bytes(), tx.is_valid())
+            .map_err(WError::from_err("failed to create transaction")),
+    }
+}
+
+pub fn get_tx_validity_range(tx_hex: &str) -> Result<ValidityRange, WError> {
+    let tx_body = csl::FixedTransaction::from_hex(tx_hex)
+        .map_err(WError::from_err(
+            "get_tx_validity_range - invalid transaction hex",
+        ))?
+        .body();
+    Ok(ValidityRange {
+        invalid_before: tx_body
+            .validity_start_interval_bignum()
+            .and_then(|b| b.to_str().parse::<u64>().ok()),
+        invalid_hereafter: tx_body
+            .ttl_bignum()
+            .and_then(|b| b.to_str().parse::<u64>().ok()),
+    })
+}
@@ -2,6 +2,8 @@ use cardano_serialization_lib::{self as csl};
 
 use whisky_common::*;
 
+use super::{to_bignum, to_csl_anchor};
+
 pub fn to_csl_voter(voter: Voter) -> Result<csl::Voter, WError> {
     match voter {
         Voter::ConstitutionalCommitteeHotCred(cred) => match cred {
@@ -50,3 +52,27 @@ pub fn to_csl_vote_kind(vote_kind: VoteKind) -> csl::VoteKind {
         VoteKind::Abstain => csl::VoteKind::Abstain,
     }
 }
+
+pub fn to_csl_voting_proposal(proposal: &ProposalType) -> Result<csl::VotingProposal, WError> {
+    let reward_account = csl::RewardAddress::from_address(
+        &csl::Address::from_bech32(&proposal.reward_account).map_err(WError::from_err(
+            "to_csl_voting_proposal - invalid reward account",
+        ))?,
+    )
+    .ok_or_else(|| {
+        WError::new(
+            "to_csl_voting_proposal",
+            "reward account is not a stake address",
+        )
+    })?;
+    Ok(csl::VotingProposal::new(
+        &csl::GovernanceAction::from_hex(&proposal.gov_action).map_err(WError::from_err(
+            "to_csl_voting_proposal - invalid governance action",
+        ))?,
+        &to_csl_anchor(&proposal.anchor)?,
+        &reward_account,
+        &to_bignum(proposal.deposit).map_err(WError::add_err_trace(
+            "to_csl_voting_proposal - invalid deposit",
+        ))?,
+    ))
+}
@@ -143,4 +143,44 @@ mod tests {
         assert_eq!(parsed.fields.items[0].int, 10);
         assert_eq!(parsed.fields.items[1].int, 20);
     }
+
+    #[test]
+    fn test_native_cbor_matches_csl() {
+        use whisky_common::data::{decode_plutus_data, encode_plutus_data};
+        use whisky_csl::csl;
+
+        let data = serde_json::json!({
+            "constructor": 8,
+            "fields": [
+                { "bytes": "ab".repeat(100) },
+                { "list": [] },
+                { "list": [{ "int": -300 }, { "int": 70000 }] },
+                { "map": [{ "k": { "bytes": "" }, "v": { "constructor": 300, "fields": [] } }] },
+                { "constructor": 2, "fields": [{ "int": 4294967296u64 }] }
+            ]
+        });
+        let csl_cbor =
+            csl::PlutusData::from_json(&data.to_string(), csl::PlutusDatumSchema::DetailedSchema)
+                .unwrap()
+                .to_hex();
+        let native_cbor = hex::encode(encode_plutus_data(&data).unwrap());
+        assert_eq!(native_cbor, csl_cbor);
+        assert_eq!(
+            decode_plutus_data(&hex::decode(&csl_cbor).unwrap()).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_plutus_data_cbor_to_json_bignum() {
+        // Constructor 0 holding 2^64, beyond the 64-bit ints of the CSL JSON conversion
+        let json = whisky_csl::plutus_data_cbor_to_json("d8799fc249010000000000000000ff").unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "constructor": 0,
+                "fields": [{ "int": "18446744073709551616" }]
+            })
+        );
+    }
 }
@@ -0,0 +1,49 @@
+use crate::*;
+use whisky_common::data::blueprint::Blueprint;
+use whisky_common::WError;
+use whisky_csl::validate_plutus_data_cbor;
+
+use wasm::WasmResult;
+
+/// Validate PlutusData against a blueprint definition.
+///
+/// `data_type` is either `"json"` (detailed schema) or `"cbor"` (hex).
+/// The result data is a JSON array of `{ path, message }` mismatches.
+#[wasm_bindgen]
+pub fn js_validate_plutus_data(
+    blueprint: &str,
+    reference: &str,
+    data: &str,
+    data_type: &str,
+) -> WasmResult {
+    let result = validate_plutus_data(blueprint, reference, data, data_type);
+    WasmResult::from_result(result)
+}
+
+fn validate_plutus_data(
+    blueprint: &str,
+    reference: &str,
+    data: &str,
+    data_type: &str,
+) -> Result<String, WError> {
+    let blueprint: Blueprint = serde_json::from_str(blueprint).map_err(WError::from_err(
+        "js_validate_plutus_data - invalid blueprint",
+    ))?;
+    let mismatches = match data_type {
+        "json" => {
+            let json_value: serde_json::Value = serde_json::from_str(data)
+                .map_err(WError::from_err("js_validate_plutus_data - invalid JSON"))?;
+            blueprint.validate_plutus_data(reference, &json_value)?
+        }
+        "cbor" => validate_plutus_data_cbor(&blueprint, reference, data)?,
+        _ => {
+            return Err(WError::new(
+                "js_validate_plutus_data",
+                "Invalid data type, expected json or cbor",
+            ))
+        }
+    };
+    serde_json::to_string(&mismatches).map_err(WError::from_err(
+        "js_validate_plutus_data - serialize mismatches",
+    ))
+}
@@ -1,3 +1,4 @@
 pub mod address;
 pub mod aiken;
+pub mod blueprint;
 pub mod script;
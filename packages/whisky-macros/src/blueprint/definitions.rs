@@ -0,0 +1,547 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::quote;
+use serde_json::Value;
+
+use super::error;
+use super::naming::{camel_case, full_type_name, ident, type_name, unique_snake_names};
+
+// Builtin definitions which map to whisky primitives instead of getting an alias
+const PRIMITIVE_NAMES: &[&str] = &["Int", "Integer", "ByteArray", "Bytes", "ByteString"];
+
+struct Constructor {
+    title: Option<String>,
+    index: u64,
+    fields: Vec<(Option<String>, Value)>,
+}
+
+/// Keeps track of the Rust names given to blueprint definitions, and resolves schemas to Rust types
+pub struct TypeRegistry {
+    definitions: BTreeMap<String, Value>,
+    names: HashMap<String, String>,
+    used_names: HashSet<String>,
+    span: Span,
+}
+
+impl TypeRegistry {
+    pub fn new(blueprint: &Value, span: Span) -> syn::Result<Self> {
+        let definitions: BTreeMap<String, Value> = match blueprint.get("definitions") {
+            Some(Value::Object(definitions)) => definitions
+                .iter()
+                .map(|(key, definition)| (key.clone(), definition.clone()))
+                .collect(),
+            Some(_) => {
+                return Err(error(
+                    span,
+                    "blueprint definitions must be an object".into(),
+                ))
+            }
+            None => BTreeMap::new(),
+        };
+        let mut registry = Self {
+            definitions,
+            names: HashMap::new(),
+            used_names: HashSet::new(),
+            span,
+        };
+
+        let mut keys_by_name: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (key, definition) in &registry.definitions {
+            if is_item(definition) || is_alias(key, definition) {
+                keys_by_name
+                    .entry(type_name(key))
+                    .or_default()
+                    .push(key.clone());
+            }
+        }
+        for (name, keys) in keys_by_name {
+            for key in &keys {
+                let name = if keys.len() == 1 {
+                    name.clone()
+                } else {
+                    full_type_name(key)
+                };
+                registry.used_names.insert(name.clone());
+                registry.names.insert(key.clone(), name);
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Reserves a name for an item generated outside of the definitions, e.g. a validator.
+    /// Returns `name` if it is free, otherwise `name` with the suffix appended.
+    pub fn reserve_name(&mut self, name: String, suffix: &str) -> String {
+        let mut name = name;
+        while self.used_names.contains(&name) {
+            name.push_str(suffix);
+        }
+        self.used_names.insert(name.clone());
+        name
+    }
+
+    /// Emits a struct or an enum for every constructor definition, and a type alias for
+    /// every named integer or bytes definition.
+    pub fn expand_definitions(&self) -> syn::Result<TokenStream2> {
+        let mut items = vec![];
+        for (key, definition) in &self.definitions {
+            if is_item(definition) {
+                items.push(self.expand_item(key, definition)?);
+            } else if is_alias(key, definition) {
+                let name = ident(&self.names[key]);
+                let ty = self.type_of(definition)?;
+                let docs = docs(definition);
+                items.push(quote! {
+                    #docs
+                    pub type #name = #ty;
+                });
+            }
+        }
+        Ok(quote! { #(#items)* })
+    }
+
+    /// The Rust type of a schema, either a `$ref` to a definition or an inline schema
+    pub fn type_of(&self, schema: &Value) -> syn::Result<TokenStream2> {
+        if let Some(key) = self.ref_key(schema)? {
+            if let Some(name) = self.names.get(&key) {
+                let name = ident(name);
+                return Ok(quote! { #name });
+            }
+            return self.type_of(&self.definitions[&key]);
+        }
+        match schema.get("dataType").and_then(Value::as_str) {
+            Some("integer") => Ok(quote! { ::whisky::data::Int }),
+            Some("bytes") => Ok(quote! { ::whisky::data::ByteString }),
+            Some("list") => match schema.get("items") {
+                Some(Value::Array(items)) => {
+                    let items = items
+                        .iter()
+                        .map(|item| self.type_of(item))
+                        .collect::<syn::Result<Vec<_>>>()?;
+                    Ok(quote! { ::whisky::data::Tuple<(#(#items,)*)> })
+                }
+                Some(item) => {
+                    let item = self.type_of(item)?;
+                    Ok(quote! { ::whisky::data::List<#item> })
+                }
+                None => Ok(quote! { ::whisky::data::List<::whisky::data::PlutusData> }),
+            },
+            Some("map") => {
+                let keys = match schema.get("keys") {
+                    Some(keys) => self.type_of(keys)?,
+                    None => quote! { ::whisky::data::PlutusData },
+                };
+                let values = match schema.get("values") {
+                    Some(values) => self.type_of(values)?,
+                    None => quote! { ::whisky::data::PlutusData },
+                };
+                Ok(quote! { ::whisky::data::Map<#keys, #values> })
+            }
+            Some(other) => Err(error(
+                self.span,
+                format!(
+                    "unsupported inline schema with dataType `{}`, only schemas in definitions can be constructors",
+                    other
+                ),
+            )),
+            None if is_bool(schema) => Ok(quote! { ::whisky::data::Bool }),
+            None if schema.get("anyOf").is_some() => Err(error(
+                self.span,
+                "inline anyOf schemas are not supported, only schemas in definitions can have constructors".into(),
+            )),
+            None => Ok(quote! { ::whisky::data::PlutusData }),
+        }
+    }
+
+    fn ref_key(&self, schema: &Value) -> syn::Result<Option<String>> {
+        let reference = match schema.get("$ref").and_then(Value::as_str) {
+            Some(reference) => reference,
+            None => return Ok(None),
+        };
+        let key = reference
+            .strip_prefix("#/definitions/")
+            .ok_or_else(|| error(self.span, format!("unsupported reference `{}`", reference)))?
+            .replace("~1", "/")
+            .replace("~0", "~");
+        if !self.definitions.contains_key(&key) {
+            return Err(error(
+                self.span,
+                format!("reference `{}` is not in definitions", reference),
+            ));
+        }
+        Ok(Some(key))
+    }
+
+    // Fields referring back to their own type, directly or through other constructors,
+    // are boxed to keep the type sized
+    fn field_type(&self, item_key: &str, schema: &Value) -> syn::Result<TokenStream2> {
+        let ty = self.type_of(schema)?;
+        if let Some(key) = self.ref_key(schema)? {
+            if is_item(&self.definitions[&key]) && self.reaches(&key, item_key)? {
+                return Ok(quote! { ::std::boxed::Box<#ty> });
+            }
+        }
+        Ok(ty)
+    }
+
+    fn reaches(&self, from: &str, to: &str) -> syn::Result<bool> {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut stack = vec![from.to_string()];
+        while let Some(key) = stack.pop() {
+            if key == to {
+                return Ok(true);
+            }
+            if !visited.insert(key.clone()) {
+                continue;
+            }
+            for constructor in constructors(&self.definitions[&key]).unwrap_or_default() {
+                for (_, schema) in &constructor.fields {
+                    self.collect_direct_items(schema, &mut stack)?;
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    // Items stored inline in a field, i.e. not behind the indirection of a list or a map
+    fn collect_direct_items(&self, schema: &Value, items: &mut Vec<String>) -> syn::Result<()> {
+        if let Some(key) = self.ref_key(schema)? {
+            let definition = &self.definitions[&key];
+            if is_item(definition) {
+                items.push(key);
+                return Ok(());
+            }
+            return self.collect_direct_items(definition, items);
+        }
+        if let Some(Value::Array(tuple_items)) = schema.get("items") {
+            for item in tuple_items {
+                self.collect_direct_items(item, items)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn expand_item(&self, key: &str, definition: &Value) -> syn::Result<TokenStream2> {
+        let name = ident(&self.names[key]);
+        let origin = format!("{}::from_json", self.names[key]);
+        let docs = docs(definition);
+        let constructors = constructors(definition)
+            .ok_or_else(|| error(self.span, format!("invalid constructors in `{}`", key)))?;
+
+        let (item, to_json, from_json) = if constructors.len() == 1 {
+            self.expand_struct(key, &constructors[0], &origin)?
+        } else {
+            self.expand_enum(key, &constructors, &origin)?
+        };
+
+        Ok(quote! {
+            #docs
+            #[derive(Clone, Debug)]
+            #item
+
+            impl ::whisky::data::PlutusDataJson for #name {
+                fn to_json(&self) -> ::serde_json::Value {
+                    #to_json
+                }
+
+                fn from_json(value: &::serde_json::Value) -> ::std::result::Result<Self, ::whisky::WError> {
+                    let tag = value
+                        .get("constructor")
+                        .and_then(|tag| tag.as_u64())
+                        .ok_or_else(|| ::whisky::WError::new(#origin, "missing 'constructor' field"))?;
+                    let fields = value
+                        .get("fields")
+                        .and_then(|fields| fields.as_array())
+                        .ok_or_else(|| ::whisky::WError::new(#origin, "missing 'fields' field"))?;
+                    #from_json
+                }
+            }
+
+            impl ::whisky::data::PlutusDataJson for ::std::boxed::Box<#name> {
+                fn to_json(&self) -> ::serde_json::Value {
+                    <#name as ::whisky::data::PlutusDataJson>::to_json(self.as_ref())
+                }
+
+                fn from_json(value: &::serde_json::Value) -> ::std::result::Result<Self, ::whisky::WError> {
+                    <#name as ::whisky::data::PlutusDataJson>::from_json(value).map(::std::boxed::Box::new)
+                }
+            }
+        })
+    }
+
+    fn expand_struct(
+        &self,
+        key: &str,
+        constructor: &Constructor,
+        origin: &str,
+    ) -> syn::Result<(TokenStream2, TokenStream2, TokenStream2)> {
+        let name = ident(&self.names[key]);
+        let types = self.field_types(key, constructor)?;
+        let parsers = field_parsers(&types, origin);
+        let check = check_fields(constructor.index, types.len(), origin);
+
+        let (item, field_jsons, construct) = match field_names(constructor) {
+            _ if types.is_empty() => (quote! { pub struct #name; }, vec![], quote! { Self }),
+            Some(names) => {
+                let names: Vec<_> = names.iter().map(|name| ident(name)).collect();
+                (
+                    quote! { pub struct #name { #(pub #names: #types,)* } },
+                    names
+                        .iter()
+                        .map(|name| to_json_expr(quote! { &self.#name }))
+                        .collect(),
+                    quote! { Self { #(#names: #parsers,)* } },
+                )
+            }
+            None => {
+                let indices = (0..types.len()).map(syn::Index::from);
+                (
+                    quote! { pub struct #name(#(pub #types,)*); },
+                    indices.map(|i| to_json_expr(quote! { &self.#i })).collect(),
+                    quote! { Self(#(#parsers,)*) },
+                )
+            }
+        };
+
+        let to_json = constr_json(constructor.index, &field_jsons);
+        let index = Literal::u64_unsuffixed(constructor.index);
+        let from_json = quote! {
+            if tag != #index {
+                return Err(::whisky::WError::new(
+                    #origin,
+                    &format!("expected constructor tag {}, got {}", #index, tag),
+                ));
+            }
+            #check
+            Ok(#construct)
+        };
+        Ok((item, to_json, from_json))
+    }
+
+    fn expand_enum(
+        &self,
+        key: &str,
+        constructors: &[Constructor],
+        origin: &str,
+    ) -> syn::Result<(TokenStream2, TokenStream2, TokenStream2)> {
+        let name = ident(&self.names[key]);
+        let mut variant_names: Vec<String> = vec![];
+        let mut variants = vec![];
+        let mut to_json_arms = vec![];
+        let mut from_json_arms = vec![];
+
+        for constructor in constructors {
+            let mut variant_name = match &constructor.title {
+                Some(title) if !camel_case(title).is_empty() => camel_case(title),
+                _ => format!("Constr{}", constructor.index),
+            };
+            if variant_names.contains(&variant_name) {
+                variant_name = format!("{}{}", variant_name, constructor.index);
+            }
+            variant_names.push(variant_name.clone());
+            let variant = ident(&variant_name);
+
+            let types = self.field_types(key, constructor)?;
+            let parsers = field_parsers(&types, origin);
+            let check = check_fields(constructor.index, types.len(), origin);
+            let index = Literal::u64_unsuffixed(constructor.index);
+
+            let (declaration, pattern, field_jsons, construct) = match field_names(constructor) {
+                _ if types.is_empty() => (
+                    quote! { #variant },
+                    quote! { Self::#variant },
+                    vec![],
+                    quote! { Self::#variant },
+                ),
+                Some(names) => {
+                    let names: Vec<_> = names.iter().map(|name| ident(name)).collect();
+                    (
+                        quote! { #variant { #(#names: #types,)* } },
+                        quote! { Self::#variant { #(#names,)* } },
+                        names
+                            .iter()
+                            .map(|name| to_json_expr(quote! { #name }))
+                            .collect(),
+                        quote! { Self::#variant { #(#names: #parsers,)* } },
+                    )
+                }
+                None => {
+                    let bindings: Vec<_> = (0..types.len())
+                        .map(|i| ident(&format!("field{}", i)))
+                        .collect();
+                    (
+                        quote! { #variant(#(#types,)*) },
+                        quote! { Self::#variant(#(#bindings,)*) },
+                        bindings
+                            .iter()
+                            .map(|name| to_json_expr(quote! { #name }))
+                            .collect(),
+                        quote! { Self::#variant(#(#parsers,)*) },
+                    )
+                }
+            };
+
+            variants.push(declaration);
+            let json = constr_json(constructor.index, &field_jsons);
+            to_json_arms.push(quote! { #pattern => #json });
+            from_json_arms.push(quote! {
+                #index => {
+                    #check
+                    Ok(#construct)
+                }
+            });
+        }
+
+        let item = quote! { pub enum #name { #(#variants,)* } };
+        let to_json = quote! {
+            match self {
+                #(#to_json_arms)*
+            }
+        };
+        let from_json = quote! {
+            match tag {
+                #(#from_json_arms)*
+                _ => Err(::whisky::WError::new(
+                    #origin,
+                    &format!("unknown constructor tag: {}", tag),
+                )),
+            }
+        };
+        Ok((item, to_json, from_json))
+    }
+
+    fn field_types(&self, key: &str, constructor: &Constructor) -> syn::Result<Vec<TokenStream2>> {
+        constructor
+            .fields
+            .iter()
+            .map(|(_, schema)| self.field_type(key, schema))
+            .collect()
+    }
+}
+
+fn is_item(definition: &Value) -> bool {
+    constructors(definition).is_some() && !is_bool(definition)
+}
+
+fn is_alias(key: &str, definition: &Value) -> bool {
+    matches!(
+        definition.get("dataType").and_then(Value::as_str),
+        Some("integer") | Some("bytes")
+    ) && !PRIMITIVE_NAMES.contains(&type_name(key).as_str())
+}
+
+fn is_bool(definition: &Value) -> bool {
+    let any_of = match definition.get("anyOf").and_then(Value::as_array) {
+        Some(any_of) if any_of.len() == 2 => any_of,
+        _ => return false,
+    };
+    any_of
+        .iter()
+        .zip(["False", "True"])
+        .all(|(constructor, title)| {
+            constructor.get("title").and_then(Value::as_str) == Some(title)
+                && constructor
+                    .get("fields")
+                    .and_then(Value::as_array)
+                    .is_none_or(|fields| fields.is_empty())
+        })
+}
+
+fn constructors(definition: &Value) -> Option<Vec<Constructor>> {
+    let schemas: Vec<&Value> = match definition.get("anyOf") {
+        Some(Value::Array(any_of)) => any_of.iter().collect(),
+        Some(_) => return None,
+        None if definition.get("dataType").and_then(Value::as_str) == Some("constructor") => {
+            vec![definition]
+        }
+        None => return None,
+    };
+    schemas
+        .iter()
+        .enumerate()
+        .map(|(position, schema)| {
+            if schema.get("dataType").and_then(Value::as_str) != Some("constructor") {
+                return None;
+            }
+            let fields = schema
+                .get("fields")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|field| {
+                    let title = field.get("title").and_then(Value::as_str).map(String::from);
+                    (title, field)
+                })
+                .collect();
+            Some(Constructor {
+                title: schema
+                    .get("title")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+                index: schema
+                    .get("index")
+                    .and_then(Value::as_u64)
+                    .unwrap_or(position as u64),
+                fields,
+            })
+        })
+        .collect()
+}
+
+// Field names when all fields are titled, otherwise the constructor is emitted with positional fields
+fn field_names(constructor: &Constructor) -> Option<Vec<String>> {
+    let titles: Vec<String> = constructor
+        .fields
+        .iter()
+        .map(|(title, _)| title.clone())
+        .collect::<Option<Vec<String>>>()?;
+    Some(unique_snake_names(&titles, &[]))
+}
+
+fn docs(definition: &Value) -> TokenStream2 {
+    let lines: Vec<&str> = ["title", "description"]
+        .iter()
+        .filter_map(|key| definition.get(*key).and_then(Value::as_str))
+        .collect();
+    quote! { #(#[doc = #lines])* }
+}
+
+fn to_json_expr(field: TokenStream2) -> TokenStream2 {
+    quote! { ::whisky::data::PlutusDataJson::to_json(#field) }
+}
+
+fn constr_json(index: u64, field_jsons: &[TokenStream2]) -> TokenStream2 {
+    let index = Literal::u64_unsuffixed(index);
+    quote! {{
+        let fields: ::std::vec::Vec<::serde_json::Value> = ::std::vec![#(#field_jsons),*];
+        ::serde_json::json!({ "constructor": #index, "fields": fields })
+    }}
+}
+
+fn check_fields(index: u64, count: usize, origin: &str) -> TokenStream2 {
+    let index = Literal::u64_unsuffixed(index);
+    let count = Literal::usize_unsuffixed(count);
+    quote! {
+        let expected: usize = #count;
+        if fields.len() != expected {
+            return Err(::whisky::WError::new(
+                #origin,
+                &format!("expected {} fields in constructor {}, got {}", expected, #index, fields.len()),
+            ));
+        }
+    }
+}
+
+fn field_parsers(types: &[TokenStream2], origin: &str) -> Vec<TokenStream2> {
+    types
+        .iter()
+        .enumerate()
+        .map(|(i, ty)| {
+            quote! {
+                <#ty as ::whisky::data::PlutusDataJson>::from_json(&fields[#i])
+                    .map_err(::whisky::WError::add_err_trace(#origin))?
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+mod definitions;
+mod naming;
+mod validators;
+
+use std::path::Path;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+use definitions::TypeRegistry;
+
+/// Generate Rust types and validator blueprints from a CIP-57 `plutus.json`.
+///
+/// The path is relative to the `CARGO_MANIFEST_DIR` of the crate invoking the macro.
+pub fn blueprint_macro(input: TokenStream) -> TokenStream {
+    let path_lit = parse_macro_input!(input as LitStr);
+    match expand(&path_lit) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(path_lit: &LitStr) -> syn::Result<TokenStream2> {
+    let span = path_lit.span();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new(span, "CARGO_MANIFEST_DIR is not set"))?;
+    let path = Path::new(&manifest_dir).join(path_lit.value());
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| syn::Error::new(span, format!("failed to read {}: {}", path.display(), e)))?;
+    let blueprint: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+        syn::Error::new(span, format!("invalid blueprint {}: {}", path.display(), e))
+    })?;
+
+    let mut registry = TypeRegistry::new(&blueprint, span)?;
+    let types = registry.expand_definitions()?;
+    let validators = validators::expand_validators(&blueprint, &mut registry)?;
+
+    // Rebuild whenever the blueprint changes
+    let path_str = path.to_string_lossy().to_string();
+    Ok(quote! {
+        const _: &[u8] = include_bytes!(#path_str);
+
+        #types
+        #validators
+    })
+}
+
+fn error(span: Span, message: String) -> syn::Error {
+    syn::Error::new(span, message)
+}
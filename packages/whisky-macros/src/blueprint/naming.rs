@@ -0,0 +1,100 @@
+use proc_macro2::Span;
+use syn::Ident;
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// `cardano/transaction/OutputReference` -> `OutputReference`,
+/// `Option$cardano/address/Credential` -> `OptionCredential`,
+/// `Pairs$ByteArray_Int` -> `PairsByteArrayInt`
+pub fn type_name(definition_key: &str) -> String {
+    let mut parts = definition_key.split('$');
+    let mut name = camel_case(last_segment(parts.next().unwrap_or_default()));
+    for generic in parts {
+        for arg in generic.split('_') {
+            name.push_str(&camel_case(last_segment(arg)));
+        }
+    }
+    valid_type_name(name)
+}
+
+/// The whole definition key in camel case, used when the short names of two definitions collide
+pub fn full_type_name(definition_key: &str) -> String {
+    valid_type_name(camel_case(definition_key))
+}
+
+pub fn camel_case(value: &str) -> String {
+    value
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| {
+            let mut chars = chunk.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+pub fn snake_case(value: &str) -> String {
+    let mut snake = String::new();
+    let mut previous: Option<char> = None;
+    for c in value.chars() {
+        if c.is_ascii_uppercase() {
+            if matches!(previous, Some(p) if p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            snake.push(c);
+        } else if !snake.ends_with('_') {
+            snake.push('_');
+        }
+        previous = Some(c);
+    }
+    let snake = snake.trim_matches('_').to_string();
+    if snake.is_empty() {
+        "field".to_string()
+    } else if snake.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", snake)
+    } else if KEYWORDS.contains(&snake.as_str()) {
+        format!("{}_", snake)
+    } else {
+        snake
+    }
+}
+
+/// Snake case names for a list of titles, made unique by suffixing the position
+pub fn unique_snake_names(titles: &[String], reserved: &[&str]) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for (i, title) in titles.iter().enumerate() {
+        let mut name = snake_case(title);
+        if names.contains(&name) || reserved.contains(&name.as_str()) {
+            name = format!("{}_{}", name, i);
+        }
+        names.push(name);
+    }
+    names
+}
+
+pub fn ident(name: &str) -> Ident {
+    Ident::new(name, Span::call_site())
+}
+
+fn last_segment(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn valid_type_name(name: String) -> String {
+    if name.is_empty() {
+        "Unnamed".to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) || name == "Self" {
+        format!("T{}", name)
+    } else {
+        name
+    }
+}
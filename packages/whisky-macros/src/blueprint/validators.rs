@@ -0,0 +1,204 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use serde_json::Value;
+
+use super::definitions::TypeRegistry;
+use super::error;
+use super::naming::{camel_case, ident, unique_snake_names};
+
+/// Emits a struct per validator, holding the compiled code and the unparameterized hash, with
+/// a typed `blueprint` constructor for spending, minting and withdrawal validators.
+pub fn expand_validators(
+    blueprint: &Value,
+    registry: &mut TypeRegistry,
+) -> syn::Result<TokenStream2> {
+    let span = Span::call_site();
+    let version = match blueprint
+        .pointer("/preamble/plutusVersion")
+        .and_then(Value::as_str)
+    {
+        Some("v1") => quote! { ::whisky::LanguageVersion::V1 },
+        Some("v2") => quote! { ::whisky::LanguageVersion::V2 },
+        Some("v3") | None => quote! { ::whisky::LanguageVersion::V3 },
+        Some(other) => {
+            return Err(error(
+                span,
+                format!("unsupported plutusVersion `{}`", other),
+            ))
+        }
+    };
+    let validators = match blueprint.get("validators") {
+        Some(Value::Array(validators)) => validators.clone(),
+        Some(_) => return Err(error(span, "blueprint validators must be an array".into())),
+        None => vec![],
+    };
+
+    let mut items = vec![];
+    for validator in &validators {
+        if let Some(item) = expand_validator(validator, &version, registry)? {
+            items.push(item);
+        }
+    }
+    Ok(quote! { #(#items)* })
+}
+
+fn expand_validator(
+    validator: &Value,
+    version: &TokenStream2,
+    registry: &mut TypeRegistry,
+) -> syn::Result<Option<TokenStream2>> {
+    let span = Span::call_site();
+    let title = validator
+        .get("title")
+        .and_then(Value::as_str)
+        .ok_or_else(|| error(span, "validator without title".into()))?;
+    let compiled_code = validator
+        .get("compiledCode")
+        .and_then(Value::as_str)
+        .ok_or_else(|| error(span, format!("validator `{}` without compiledCode", title)))?;
+    let hash = validator
+        .get("hash")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    // Aiken v1.1+ titles are `module.validator.purpose`, older ones are `module.validator`
+    let segments: Vec<&str> = title.split('.').collect();
+    let purpose = match segments.len() {
+        n if n >= 3 => segments[n - 1],
+        _ if validator.get("datum").is_some() => "spend",
+        _ => "mint",
+    };
+    if purpose == "else" {
+        return Ok(None);
+    }
+
+    let name = ident(&registry.reserve_name(camel_case(title), "Validator"));
+    let parameters = validator
+        .get("parameters")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let param_titles: Vec<String> = parameters
+        .iter()
+        .enumerate()
+        .map(|(i, parameter)| {
+            parameter
+                .get("title")
+                .and_then(Value::as_str)
+                .map(String::from)
+                .unwrap_or_else(|| format!("param{}", i))
+        })
+        .collect();
+    let param_names: Vec<_> = unique_snake_names(&param_titles, &["network_id", "stake_hash"])
+        .iter()
+        .map(|name| ident(name))
+        .collect();
+    let param_types = parameters
+        .iter()
+        .map(|parameter| registry.type_of(parameter.get("schema").unwrap_or(&Value::Null)))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // The blueprints carry a single parameter type, so mixed parameters fall back to PlutusData
+    let param_type = match param_types.first() {
+        None => quote! { () },
+        Some(first)
+            if param_types
+                .iter()
+                .all(|ty| ty.to_string() == first.to_string()) =>
+        {
+            quote! { #first }
+        }
+        Some(_) => quote! { ::whisky::data::PlutusData },
+    };
+    let redeemer_type = schema_type(validator, "redeemer", registry)?;
+    let datum_type = schema_type(validator, "datum", registry)?;
+
+    let params = quote! {
+        let params = [#(::whisky::data::PlutusDataJson::to_json_string(&#param_names)),*];
+        let params: ::std::vec::Vec<&str> = params.iter().map(|param| param.as_str()).collect();
+    };
+    let apply_params = if param_names.is_empty() {
+        quote! { blueprint.no_param_script(Self::COMPILED_CODE)?; }
+    } else {
+        quote! {
+            #params
+            blueprint.param_script(Self::COMPILED_CODE, &params, ::whisky::BuilderDataType::JSON)?;
+        }
+    };
+
+    let blueprint_fn = match purpose {
+        "spend" => quote! {
+            /// Creates the spending blueprint with the parameters applied
+            pub fn blueprint(
+                network_id: u8,
+                stake_hash: ::std::option::Option<(::std::string::String, bool)>,
+                #(#param_names: #param_types,)*
+            ) -> ::std::result::Result<::whisky::SpendingBlueprint<#param_type, #redeemer_type, #datum_type>, ::whisky::WError> {
+                let mut blueprint = ::whisky::SpendingBlueprint::new(#version, network_id, stake_hash);
+                #apply_params
+                Ok(blueprint)
+            }
+        },
+        "mint" => quote! {
+            /// Creates the minting blueprint with the parameters applied
+            pub fn blueprint(
+                #(#param_names: #param_types,)*
+            ) -> ::std::result::Result<::whisky::MintingBlueprint<#param_type, #redeemer_type>, ::whisky::WError> {
+                let mut blueprint = ::whisky::MintingBlueprint::new(#version);
+                #apply_params
+                Ok(blueprint)
+            }
+        },
+        "withdraw" => quote! {
+            /// Creates the withdrawal blueprint with the parameters applied
+            pub fn blueprint(
+                network_id: u8,
+                #(#param_names: #param_types,)*
+            ) -> ::std::result::Result<::whisky::WithdrawalBlueprint<#param_type, #redeemer_type>, ::whisky::WError> {
+                let mut blueprint = ::whisky::WithdrawalBlueprint::new(#version, network_id);
+                #apply_params
+                Ok(blueprint)
+            }
+        },
+        _ => quote! {},
+    };
+
+    let cbor_body = if param_names.is_empty() {
+        quote! {
+            ::whisky::apply_params_to_script(Self::COMPILED_CODE, &[], ::whisky::BuilderDataType::CBOR)
+        }
+    } else {
+        quote! {
+            #params
+            ::whisky::apply_params_to_script(Self::COMPILED_CODE, &params, ::whisky::BuilderDataType::JSON)
+        }
+    };
+
+    Ok(Some(quote! {
+        #[doc = #title]
+        pub struct #name;
+
+        impl #name {
+            pub const TITLE: &'static str = #title;
+            pub const COMPILED_CODE: &'static str = #compiled_code;
+            /// The script hash before any parameter is applied
+            pub const HASH: &'static str = #hash;
+
+            /// Applies the parameters to the compiled code, returning the script CBOR
+            pub fn cbor(
+                #(#param_names: #param_types,)*
+            ) -> ::std::result::Result<::std::string::String, ::whisky::WError> {
+                #cbor_body
+            }
+
+            #blueprint_fn
+        }
+    }))
+}
+
+fn schema_type(validator: &Value, key: &str, registry: &TypeRegistry) -> syn::Result<TokenStream2> {
+    match validator.get(key).and_then(|value| value.get("schema")) {
+        Some(schema) => registry.type_of(schema),
+        None => Ok(quote! { ::whisky::data::PlutusData }),
+    }
+}
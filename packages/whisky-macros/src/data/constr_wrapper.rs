@@ -6,16 +6,27 @@ pub fn derive_constr_wrapper(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
-    // Extract the inner type from the newtype struct
-    let inner_ty = match &input.data {
+    // Extract the inner type from the newtype struct, either `Name(T)` or `Name { inner: T }`
+    let (inner_ty, accessor, construct) = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                &fields.unnamed.first().unwrap().ty
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+                &fields.unnamed.first().unwrap().ty,
+                quote! { self.0 },
+                quote! { #name(inner) },
+            ),
+            Fields::Named(fields) if fields.named.len() == 1 => {
+                let field = fields.named.first().unwrap();
+                let ident = &field.ident;
+                (
+                    &field.ty,
+                    quote! { self.#ident },
+                    quote! { #name { #ident: inner } },
+                )
             }
             _ => {
                 return syn::Error::new_spanned(
                     name,
-                    "ConstrWrapper can only be derived for newtype structs with a single unnamed field",
+                    "ConstrWrapper can only be derived for newtype structs with a single field",
                 )
                 .to_compile_error()
                 .into();
@@ -31,7 +42,7 @@ pub fn derive_constr_wrapper(input: TokenStream) -> TokenStream {
     let expanded = quote! {
         impl ::whisky::data::PlutusDataJson for #name {
             fn to_json(&self) -> ::serde_json::Value {
-                self.0.to_json()
+                #accessor.to_json()
             }
 
             fn to_json_string(&self) -> String {
@@ -39,13 +50,13 @@ pub fn derive_constr_wrapper(input: TokenStream) -> TokenStream {
             }
 
             fn to_constr_field(&self) -> Vec<::serde_json::Value> {
-                vec![self.0.to_json()]
+                vec![#accessor.to_json()]
             }
 
             fn from_json(value: &::serde_json::Value) -> Result<Self, ::whisky::WError> {
                 let inner = <#inner_ty>::from_json(value)
                     .map_err(::whisky::WError::add_err_trace(concat!(stringify!(#name), "::from_json")))?;
-                Ok(#name(inner))
+                Ok(#construct)
             }
         }
 
@@ -4,8 +4,17 @@ use syn::{
     parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericParam, TypeParamBound,
 };
 
+use super::fields::{constr_index, fields_from_json, fields_pattern, fields_to_json, parse_fields};
+
 pub fn derive_plutus_data_to_json(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = &input.ident;
 
     // Extract generics and add PlutusDataJson bound to all type parameters
@@ -18,136 +27,106 @@ pub fn derive_plutus_data_to_json(input: TokenStream) -> TokenStream {
     }
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let implementation = match &input.data {
-        Data::Enum(data_enum) => {
-            let to_json_match_arms = data_enum.variants.iter().enumerate().map(|(index, variant)| {
-                let variant_name = &variant.ident;
-                let full_variant_path = quote! { #name::#variant_name };
-                match &variant.fields {
-                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                        // Single field tuple variant like UserSpotAccount(Account)
-                        quote! {
-                            #full_variant_path(field) => ::whisky::data::Constr::new(#index as u64, field.clone()).to_json()
-                        }
-                    }
-                    Fields::Unnamed(fields) => {
-                        // Multiple fields tuple variant like MintCancelOrderIntent(UserAccount, ByteString)
-                        let field_count = fields.unnamed.len();
-                        let field_names: Vec<_> = (0..field_count).map(|i| syn::Ident::new(&format!("field{}", i), proc_macro2::Span::call_site())).collect();
-                        let pattern = quote! { #(#field_names),* };
-                        let tuple = quote! { (#(#field_names.clone()),*) };
-                        quote! {#full_variant_path(#pattern) => ::whisky::data::Constr::new(#index as u64, Box::new(#tuple)).to_json()}
-                    }
-                    Fields::Named(_) => {
-                        // Named fields - you can extend this if needed
-                        panic!("Named fields not supported yet");
-                    }
-                    Fields::Unit => {
-                        // Unit variant like SomeVariant
-                        quote! {
-                            #full_variant_path => ::whisky::data::Constr::new(#index as u64, ()).to_json()
-                        }
-                    }
-                }
-            });
+    let data_enum = match &input.data {
+        Data::Enum(data_enum) => data_enum,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "ConstrEnum can only be derived for enums, use ImplConstr for structs",
+            ))
+        }
+    };
 
-            // Generate from_json match arms
-            let from_json_match_arms = data_enum.variants.iter().enumerate().map(|(index, variant)| {
-                let variant_name = &variant.ident;
-                let full_variant_path = quote! { #name::#variant_name };
-                let index_u64 = index as u64;
-                match &variant.fields {
-                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                        // Single field tuple variant
-                        let field_ty = &fields.unnamed.first().unwrap().ty;
-                        quote! {
-                            #index_u64 => {
-                                let field = <#field_ty>::from_json(fields_json)
-                                    .map_err(::whisky::WError::add_err_trace(concat!(stringify!(#name), "::", stringify!(#variant_name), "::from_json")))?;
-                                Ok(#full_variant_path(field))
-                            }
-                        }
-                    }
-                    Fields::Unnamed(fields) => {
-                        // Multiple fields tuple variant
-                        let field_count = fields.unnamed.len();
-                        let field_tys: Vec<_> = fields.unnamed.iter().map(|f| &f.ty).collect();
-                        let field_names: Vec<_> = (0..field_count).map(|i| syn::Ident::new(&format!("field{}", i), proc_macro2::Span::call_site())).collect();
+    let mut to_json_match_arms = vec![];
+    let mut from_json_match_arms = vec![];
+    let mut indexes: Vec<u64> = vec![];
+    for (position, variant) in data_enum.variants.iter().enumerate() {
+        let variant_name = &variant.ident;
+        let full_variant_path = quote! { #name::#variant_name };
+        let origin = quote! {
+            concat!(stringify!(#name), "::", stringify!(#variant_name), "::from_json")
+        };
 
-                        let field_parsers = field_tys.iter().zip(field_names.iter()).enumerate().map(|(i, (ty, name))| {
-                            quote! {
-                                let #name = {
-                                    let item = fields_arr.get(#i)
-                                        .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::", stringify!(#variant_name), "::from_json"), "not enough fields"))?;
-                                    <#ty>::from_json(item)
-                                        .map_err(::whisky::WError::add_err_trace(concat!(stringify!(#name), "::", stringify!(#variant_name), "::from_json")))?
-                                };
-                            }
-                        });
+        // The constructor index defaults to the declaration order
+        let index = constr_index(&variant.attrs)?.unwrap_or(position as u64);
+        if indexes.contains(&index) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("duplicate constructor index {}", index),
+            ));
+        }
+        indexes.push(index);
 
-                        quote! {
-                            #index_u64 => {
-                                let fields_arr = fields_json.as_array()
-                                    .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::", stringify!(#variant_name), "::from_json"), "expected array for fields"))?;
-                                #(#field_parsers)*
-                                Ok(#full_variant_path(#(#field_names),*))
-                            }
-                        }
-                    }
-                    Fields::Named(_) => {
-                        panic!("Named fields not supported yet");
+        let fields = parse_fields(&variant.fields)?;
+        match &variant.fields {
+            Fields::Unnamed(unnamed)
+                if unnamed.unnamed.len() == 1 && !fields[0].has_attributes() =>
+            {
+                // Single field tuple variant like UserSpotAccount(Account), the field
+                // contributes its own constructor fields
+                let field_ty = &fields[0].ty;
+                to_json_match_arms.push(quote! {
+                    #full_variant_path(field) => ::whisky::data::Constr::new(#index, field.clone()).to_json()
+                });
+                from_json_match_arms.push(quote! {
+                    #index => {
+                        let field = <#field_ty as ::whisky::data::PlutusDataJson>::from_constr_field(fields_json)
+                            .map_err(::whisky::WError::add_err_trace(#origin))?;
+                        Ok(#full_variant_path(field))
                     }
-                    Fields::Unit => {
-                        // Unit variant
-                        quote! {
-                            #index_u64 => Ok(#full_variant_path)
-                        }
-                    }
-                }
-            });
+                });
+            }
+            _ => {
+                // Unit, named and multiple field variants, one constructor field per Rust field
+                let pattern = fields_pattern(&full_variant_path, &variant.fields, &fields);
+                let to_json_fields = fields_to_json(&fields);
+                to_json_match_arms.push(quote! {
+                    #pattern => ::whisky::data::constr(#index, #to_json_fields)
+                });
+                let from_json_body =
+                    fields_from_json(&full_variant_path, &variant.fields, &fields, &origin);
+                from_json_match_arms.push(quote! {
+                    #index => { #from_json_body }
+                });
+            }
+        }
+    }
 
-            quote! {
-                impl #impl_generics ::whisky::data::PlutusDataJson for #name #ty_generics #where_clause {
-                    fn to_json(&self) -> ::serde_json::Value {
-                        match self {
-                            #(#to_json_match_arms,)*
-                        }
-                    }
+    Ok(quote! {
+        impl #impl_generics ::whisky::data::PlutusDataJson for #name #ty_generics #where_clause {
+            fn to_json(&self) -> ::serde_json::Value {
+                match self {
+                    #(#to_json_match_arms,)*
+                }
+            }
 
-                    fn to_json_string(&self) -> String {
-                        self.to_json().to_string()
-                    }
+            fn to_json_string(&self) -> String {
+                self.to_json().to_string()
+            }
 
-                    fn to_constr_field(&self) -> Vec<::serde_json::Value> {
-                        vec![self.to_json()]
-                    }
+            fn to_constr_field(&self) -> Vec<::serde_json::Value> {
+                vec![self.to_json()]
+            }
 
-                    fn from_json(value: &::serde_json::Value) -> Result<Self, ::whisky::WError> {
-                        let tag = value
-                            .get("constructor")
-                            .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "missing 'constructor' field"))?
-                            .as_u64()
-                            .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "invalid 'constructor' value"))?;
+            fn from_json(value: &::serde_json::Value) -> Result<Self, ::whisky::WError> {
+                let tag = value
+                    .get("constructor")
+                    .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "missing 'constructor' field"))?
+                    .as_u64()
+                    .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "invalid 'constructor' value"))?;
 
-                        let fields_json = value
-                            .get("fields")
-                            .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "missing 'fields' field"))?;
+                let fields_json = value
+                    .get("fields")
+                    .ok_or_else(|| ::whisky::WError::new(concat!(stringify!(#name), "::from_json"), "missing 'fields' field"))?;
 
-                        match tag {
-                            #(#from_json_match_arms,)*
-                            _ => Err(::whisky::WError::new(
-                                concat!(stringify!(#name), "::from_json"),
-                                &format!("unknown constructor tag: {}", tag),
-                            ))
-                        }
-                    }
+                match tag {
+                    #(#from_json_match_arms,)*
+                    _ => Err(::whisky::WError::new(
+                        concat!(stringify!(#name), "::from_json"),
+                        &format!("unknown constructor tag: {}", tag),
+                    ))
                 }
             }
         }
-        _ => {
-            panic!("PlutusDataToJson can only be derived for enums");
-        }
-    };
-
-    implementation.into()
+    })
 }
@@ -0,0 +1,248 @@
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::ParseStream, Attribute, Fields, GenericArgument, Ident, LitInt, LitStr, PathArguments,
+    Token, Type,
+};
+
+/// A struct or variant field with its `#[plutus(...)]` options
+pub struct PlutusField {
+    /// `Some` for named fields
+    pub name: Option<Ident>,
+    /// Local binding used in generated patterns
+    pub binding: Ident,
+    pub ty: Type,
+    /// `#[plutus(skip)]`: not serialized, restored with `Default::default()`
+    pub skip: bool,
+    /// `#[plutus(as = "Int")]`: serialized through another PlutusDataJson type
+    pub as_ty: Option<Type>,
+}
+
+impl PlutusField {
+    pub fn has_attributes(&self) -> bool {
+        self.skip || self.as_ty.is_some()
+    }
+}
+
+pub fn parse_fields(fields: &Fields) -> syn::Result<Vec<PlutusField>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let (skip, as_ty) = parse_plutus_attrs(&field.attrs)?;
+            let binding = match &field.ident {
+                Some(ident) => ident.clone(),
+                None => Ident::new(&format!("field{}", i), Span::call_site()),
+            };
+            Ok(PlutusField {
+                name: field.ident.clone(),
+                binding,
+                ty: field.ty.clone(),
+                skip,
+                as_ty,
+            })
+        })
+        .collect()
+}
+
+fn parse_plutus_attrs(attrs: &[Attribute]) -> syn::Result<(bool, Option<Type>)> {
+    let mut skip = false;
+    let mut as_ty = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("plutus")) {
+        attr.parse_args_with(|input: ParseStream| {
+            while !input.is_empty() {
+                if input.peek(Token![as]) {
+                    input.parse::<Token![as]>()?;
+                    input.parse::<Token![=]>()?;
+                    let lit: LitStr = input.parse()?;
+                    as_ty = Some(lit.parse::<Type>()?);
+                } else {
+                    let ident: Ident = input.parse()?;
+                    if ident != "skip" {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            "unknown plutus attribute, expected `skip` or `as = \"Type\"`",
+                        ));
+                    }
+                    skip = true;
+                }
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(())
+        })?;
+        if skip && as_ty.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`skip` and `as` cannot be combined on the same field",
+            ));
+        }
+    }
+    Ok((skip, as_ty))
+}
+
+/// Parse the constructor index from `#[constr(N)]` or `#[constr(index = N)]`
+pub fn constr_index(attrs: &[Attribute]) -> syn::Result<Option<u64>> {
+    let mut index = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("constr")) {
+        let lit = attr.parse_args_with(|input: ParseStream| {
+            if input.peek(LitInt) {
+                return input.parse::<LitInt>();
+            }
+            let key: Ident = input.parse()?;
+            if key != "index" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "expected `#[constr(N)]` or `#[constr(index = N)]`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            input.parse::<LitInt>()
+        })?;
+        if index.is_some() {
+            return Err(syn::Error::new_spanned(attr, "duplicate constr attribute"));
+        }
+        index = Some(lit.base10_parse::<u64>()?);
+    }
+    Ok(index)
+}
+
+/// Pattern destructuring the serialized fields of `path`, skipped fields are ignored
+pub fn fields_pattern(
+    path: &TokenStream2,
+    fields: &Fields,
+    parsed: &[PlutusField],
+) -> TokenStream2 {
+    match fields {
+        Fields::Named(_) => {
+            let bindings = parsed.iter().filter(|f| !f.skip).map(|f| &f.binding);
+            quote! { #path { #(#bindings,)* .. } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings = parsed.iter().map(|f| {
+                if f.skip {
+                    quote! { _ }
+                } else {
+                    f.binding.to_token_stream()
+                }
+            });
+            quote! { #path(#(#bindings),*) }
+        }
+        Fields::Unit => quote! { #path },
+    }
+}
+
+/// Expression building the `Vec<serde_json::Value>` of constructor fields from the bindings
+pub fn fields_to_json(parsed: &[PlutusField]) -> TokenStream2 {
+    let items = parsed.iter().filter(|f| !f.skip).map(|f| {
+        let binding = &f.binding;
+        let ty = &f.ty;
+        match &f.as_ty {
+            Some(as_ty) => quote! {
+                ::whisky::data::PlutusDataJson::to_json(
+                    &<#as_ty as ::std::convert::From<#ty>>::from(::std::clone::Clone::clone(#binding))
+                )
+            },
+            None => match boxed_type(ty) {
+                Some(_) => quote! { ::whisky::data::PlutusDataJson::to_json(&**#binding) },
+                None => quote! { ::whisky::data::PlutusDataJson::to_json(#binding) },
+            },
+        }
+    });
+    quote! { ::std::vec::Vec::<::serde_json::Value>::from([#(#items),*]) }
+}
+
+/// The `T` of a `Box<T>` field, so recursive types serialize through `T`. Boxed tuples are
+/// left alone as they have their own constructor field encoding.
+fn boxed_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Box" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(Type::Tuple(_)) => None,
+        GenericArgument::Type(inner) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Statements parsing `fields_json` into the bindings, followed by the construction of `path`
+pub fn fields_from_json(
+    path: &TokenStream2,
+    fields: &Fields,
+    parsed: &[PlutusField],
+    origin: &TokenStream2,
+) -> TokenStream2 {
+    let expected = parsed.iter().filter(|f| !f.skip).count();
+    let mut position = 0usize;
+    let parsers = parsed.iter().map(|f| {
+        let binding = &f.binding;
+        let ty = &f.ty;
+        if f.skip {
+            return quote! { let #binding: #ty = ::std::default::Default::default(); };
+        }
+        let index = position;
+        position += 1;
+        let item = quote! { &fields_arr[#index] };
+        match &f.as_ty {
+            Some(as_ty) => {
+                let field_name = f.binding.to_string();
+                quote! {
+                    let #binding = {
+                        let value = <#as_ty as ::whisky::data::PlutusDataJson>::from_json(#item)
+                            .map_err(::whisky::WError::add_err_trace(#origin))?;
+                        <#ty as ::std::convert::TryFrom<#as_ty>>::try_from(value).map_err(|_| {
+                            ::whisky::WError::new(#origin, concat!("value out of range for field `", #field_name, "`"))
+                        })?
+                    };
+                }
+            }
+            None => match boxed_type(ty) {
+                Some(inner) => quote! {
+                    let #binding = ::std::boxed::Box::new(
+                        <#inner as ::whisky::data::PlutusDataJson>::from_json(#item)
+                            .map_err(::whisky::WError::add_err_trace(#origin))?,
+                    );
+                },
+                None => quote! {
+                    let #binding = <#ty as ::whisky::data::PlutusDataJson>::from_json(#item)
+                        .map_err(::whisky::WError::add_err_trace(#origin))?;
+                },
+            },
+        }
+    });
+    let parsers: Vec<_> = parsers.collect();
+
+    let construct = match fields {
+        Fields::Named(_) => {
+            let names = parsed.iter().map(|f| &f.name);
+            quote! { #path { #(#names),* } }
+        }
+        Fields::Unnamed(_) => {
+            let bindings = parsed.iter().map(|f| &f.binding);
+            quote! { #path(#(#bindings),*) }
+        }
+        Fields::Unit => quote! { #path },
+    };
+
+    quote! {
+        let fields_arr = fields_json
+            .as_array()
+            .ok_or_else(|| ::whisky::WError::new(#origin, "expected array for fields"))?;
+        if fields_arr.len() != #expected {
+            return Err(::whisky::WError::new(
+                #origin,
+                &format!("expected {} fields, got {}", #expected, fields_arr.len()),
+            ));
+        }
+        #(#parsers)*
+        Ok(#construct)
+    }
+}
@@ -1,8 +1,13 @@
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
 use syn::{
-    parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type, TypePath,
+    parse_macro_input, parse_quote, Data, DeriveInput, Fields, GenericArgument, GenericParam,
+    PathArguments, Type, TypeParamBound, TypePath,
+};
+
+use super::fields::{
+    constr_index, fields_from_json, fields_pattern, fields_to_json, parse_fields, PlutusField,
 };
 
 /// Extract the input type for a type's `new()` method by looking at common patterns
@@ -102,7 +107,7 @@ fn generate_field_init(ty: &Type, param_name: &syn::Ident) -> proc_macro2::Token
 }
 
 /// Extract tuple fields from Box<(A, B, C)> or (A, B, C)
-fn extract_tuple_fields(ty: &Type) -> Option<Vec<Type>> {
+pub fn extract_tuple_fields(ty: &Type) -> Option<Vec<Type>> {
     match ty {
         Type::Path(TypePath { path, .. }) => {
             let last_segment = path.segments.last()?;
@@ -127,7 +132,7 @@ fn extract_tuple_fields(ty: &Type) -> Option<Vec<Type>> {
 }
 
 /// Determine constructor type and tag from the field type
-fn analyze_constructor(ty: &Type) -> Option<(String, Option<u32>, Vec<Type>)> {
+pub fn analyze_constructor(ty: &Type) -> Option<(String, Option<u32>, Vec<Type>)> {
     match ty {
         Type::Path(TypePath { path, .. }) => {
             let last_segment = path.segments.last()?;
@@ -168,53 +173,62 @@ fn analyze_constructor(ty: &Type) -> Option<(String, Option<u32>, Vec<Type>)> {
 
 pub fn derive_impl_constr(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
     let name = &input.ident;
 
-    // Check for #[constr(N)] attribute for custom tag
-    let custom_tag = input.attrs.iter().find_map(|attr| {
-        if attr.path.is_ident("constr") {
-            attr.parse_args::<syn::LitInt>()
-                .ok()
-                .and_then(|lit| lit.base10_parse::<u64>().ok())
-        } else {
-            None
-        }
-    });
-
-    // Extract the newtype field
-    let field_ty = match &input.data {
-        Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                &fields.unnamed.first().unwrap().ty
-            }
-            _ => {
-                return syn::Error::new_spanned(
-                    name,
-                    "ImplConstr can only be derived for newtype structs with a single unnamed field",
-                )
-                .to_compile_error()
-                .into();
-            }
-        },
+    // Check for #[constr(N)] / #[constr(index = N)] attribute for custom tag
+    let custom_tag = constr_index(&input.attrs)?;
+
+    let data_fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
         _ => {
-            return syn::Error::new_spanned(name, "ImplConstr can only be derived for structs")
-                .to_compile_error()
-                .into();
+            return Err(syn::Error::new_spanned(
+                name,
+                "ImplConstr can only be derived for structs",
+            ))
         }
     };
-
-    // Try to analyze the constructor type (for ConstrN types)
-    match analyze_constructor(field_ty) {
-        Some((constr_name, tag_opt, fields)) => {
-            // Inner type is a ConstrN type - use existing behavior
-            generate_constr_wrapper_impl(name, field_ty, &constr_name, tag_opt, custom_tag, &fields)
-        }
-        None => {
-            // Inner type is not a ConstrN type - treat it as a PlutusDataJson implementor
-            // Default tag is 0 unless specified via #[constr(N)]
-            let tag = custom_tag.unwrap_or(0);
-            generate_passthrough_impl(name, field_ty, tag)
+    let fields = parse_fields(data_fields)?;
+
+    match data_fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 && !fields[0].has_attributes() => {
+            // Extract the newtype field
+            let field_ty = &fields[0].ty;
+
+            // Try to analyze the constructor type (for ConstrN types)
+            match analyze_constructor(field_ty) {
+                Some((constr_name, tag_opt, constr_fields)) => {
+                    // Inner type is a ConstrN type - use existing behavior
+                    generate_constr_wrapper_impl(
+                        name,
+                        field_ty,
+                        &constr_name,
+                        tag_opt,
+                        custom_tag,
+                        &constr_fields,
+                    )
+                }
+                None => {
+                    // Inner type is not a ConstrN type - treat it as a PlutusDataJson implementor
+                    // Default tag is 0 unless specified via #[constr(N)]
+                    let tag = custom_tag.unwrap_or(0);
+                    Ok(generate_passthrough_impl(name, field_ty, tag))
+                }
+            }
         }
+        // Named fields, several unnamed fields or a unit struct
+        _ => Ok(generate_fields_impl(
+            input,
+            data_fields,
+            &fields,
+            custom_tag.unwrap_or(0),
+        )),
     }
 }
 
@@ -226,19 +240,17 @@ fn generate_constr_wrapper_impl(
     tag_opt: Option<u32>,
     custom_tag: Option<u64>,
     fields: &[Type],
-) -> TokenStream {
+) -> syn::Result<TokenStream2> {
     // Determine the final tag
     let tag = if let Some(t) = tag_opt {
         t as u64
     } else if let Some(t) = custom_tag {
         t
     } else {
-        return syn::Error::new_spanned(
+        return Err(syn::Error::new_spanned(
             name,
             "Constr type requires #[constr(tag)] attribute to specify the constructor tag",
-        )
-        .to_compile_error()
-        .into();
+        ));
     };
 
     // Generate parameter names and inferred types
@@ -346,12 +358,12 @@ fn generate_constr_wrapper_impl(
         }
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
 /// Generate implementation for newtype wrapping a type that implements PlutusDataJson
 /// (e.g., types deriving ConstrEnum). Wraps the inner value in a Constr with specified tag.
-fn generate_passthrough_impl(name: &syn::Ident, field_ty: &Type, tag: u64) -> TokenStream {
+fn generate_passthrough_impl(name: &syn::Ident, field_ty: &Type, tag: u64) -> TokenStream2 {
     let expanded = quote! {
         impl #name {
             /// Create a new instance from the inner type
@@ -433,5 +445,90 @@ fn generate_passthrough_impl(name: &syn::Ident, field_ty: &Type, tag: u64) -> To
         }
     };
 
-    TokenStream::from(expanded)
+    expanded
+}
+
+/// Generate implementation for structs with named fields, several unnamed fields or no fields,
+/// serialized as a constructor with one field per (non-skipped) Rust field
+fn generate_fields_impl(
+    input: &DeriveInput,
+    data_fields: &Fields,
+    fields: &[PlutusField],
+    tag: u64,
+) -> TokenStream2 {
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            let bound: TypeParamBound = parse_quote!(::whisky::data::PlutusDataJson);
+            type_param.bounds.push(bound);
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let path = quote! { #name };
+    let origin = quote! { concat!(stringify!(#name), "::from_json") };
+    let pattern = fields_pattern(&path, data_fields, fields);
+    let to_json_fields = fields_to_json(fields);
+    let from_json_body = fields_from_json(&path, data_fields, fields, &origin);
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::whisky::data::PlutusDataJson for #name #ty_generics #where_clause {
+            fn to_json(&self) -> ::serde_json::Value {
+                let #pattern = self;
+                ::whisky::data::constr(#tag, #to_json_fields)
+            }
+
+            fn to_json_string(&self) -> ::std::string::String {
+                self.to_json().to_string()
+            }
+
+            fn to_constr_field(&self) -> ::std::vec::Vec<::serde_json::Value> {
+                ::std::vec![self.to_json()]
+            }
+
+            fn from_json(value: &::serde_json::Value) -> Result<Self, ::whisky::WError> {
+                let actual_tag = value
+                    .get("constructor")
+                    .ok_or_else(|| ::whisky::WError::new(#origin, "missing 'constructor' field"))?
+                    .as_u64()
+                    .ok_or_else(|| ::whisky::WError::new(#origin, "invalid 'constructor' value"))?;
+
+                if actual_tag != #tag {
+                    return Err(::whisky::WError::new(
+                        #origin,
+                        &format!("expected constructor tag {}, got {}", #tag, actual_tag),
+                    ));
+                }
+
+                let fields_json = value
+                    .get("fields")
+                    .ok_or_else(|| ::whisky::WError::new(#origin, "missing 'fields' field"))?;
+
+                #from_json_body
+            }
+        }
+
+        // Also implement PlutusDataJson for Box<Type> to support nested boxing
+        #[automatically_derived]
+        impl #impl_generics ::whisky::data::PlutusDataJson for ::std::boxed::Box<#name #ty_generics> #where_clause {
+            fn to_json(&self) -> ::serde_json::Value {
+                self.as_ref().to_json()
+            }
+
+            fn to_json_string(&self) -> ::std::string::String {
+                self.to_json().to_string()
+            }
+
+            fn to_constr_field(&self) -> ::std::vec::Vec<::serde_json::Value> {
+                ::std::vec![self.to_json()]
+            }
+
+            fn from_json(value: &::serde_json::Value) -> Result<Self, ::whisky::WError> {
+                <#name #ty_generics as ::whisky::data::PlutusDataJson>::from_json(value).map(::std::boxed::Box::new)
+            }
+        }
+    }
 }
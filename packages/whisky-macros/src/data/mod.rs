@@ -1,4 +1,6 @@
 pub mod constr_wrapper;
 pub mod enum_constr;
+pub mod fields;
 pub mod impl_constr_derive;
 pub mod impl_constr_type;
+pub mod schema;
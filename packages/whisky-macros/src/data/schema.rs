@@ -0,0 +1,210 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse::ParseStream, parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields,
+    GenericParam, Ident, LitStr, Token, Type, TypeParamBound,
+};
+
+use super::fields::{constr_index, parse_fields};
+use super::impl_constr_derive::{analyze_constructor, extract_tuple_fields};
+
+pub fn derive_plutus_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// A constructor of the generated definition
+struct Constructor {
+    title: String,
+    index: u64,
+    field_types: Vec<Type>,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let key = schema_key_attr(&input.attrs)?.unwrap_or_else(|| name.to_string());
+    let title = name.to_string();
+
+    let constructors = match &input.data {
+        Data::Struct(data_struct) => {
+            let custom_tag = constr_index(&input.attrs)?;
+            vec![struct_constructor(&title, &data_struct.fields, custom_tag)?]
+        }
+        Data::Enum(data_enum) => {
+            let mut constructors: Vec<Constructor> = vec![];
+            for (position, variant) in data_enum.variants.iter().enumerate() {
+                let index = constr_index(&variant.attrs)?.unwrap_or(position as u64);
+                if constructors.iter().any(|c| c.index == index) {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        format!("duplicate constructor index {}", index),
+                    ));
+                }
+                constructors.push(Constructor {
+                    title: variant.ident.to_string(),
+                    index,
+                    field_types: variant_field_types(&variant.fields)?,
+                });
+            }
+            constructors
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "PlutusSchema cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    let mut type_params: Vec<Ident> = vec![];
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            let bound: TypeParamBound = parse_quote!(::whisky::data::blueprint::PlutusSchema);
+            type_param.bounds.push(bound);
+            type_params.push(type_param.ident.clone());
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Generic types are keyed like Aiken's, e.g. `Interval$Int`
+    let key_expr = if type_params.is_empty() {
+        quote! { ::std::string::String::from(#key) }
+    } else {
+        quote! {
+            let params: ::std::vec::Vec<::std::string::String> = ::std::vec![
+                #(<#type_params as ::whisky::data::blueprint::PlutusSchema>::schema_key()),*
+            ];
+            format!("{}${}", #key, params.join("_"))
+        }
+    };
+
+    let mut all_field_types: Vec<&Type> = vec![];
+    let mut constructor_defs = vec![];
+    for constructor in &constructors {
+        let title = &constructor.title;
+        let index = u32::try_from(constructor.index)
+            .map_err(|_| syn::Error::new_spanned(name, "constructor index does not fit in u32"))?;
+        let field_types = &constructor.field_types;
+        all_field_types.extend(field_types.iter());
+        constructor_defs.push(quote! {
+            ::whisky::data::blueprint::ConstructorDefinition {
+                title: ::std::string::String::from(#title),
+                data_type: ::std::string::String::from("constructor"),
+                index: #index,
+                fields: ::std::vec![
+                    #(<#field_types as ::whisky::data::blueprint::PlutusSchema>::schema_reference()),*
+                ],
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::whisky::data::blueprint::PlutusSchema for #name #ty_generics #where_clause {
+            fn schema_key() -> ::std::string::String {
+                #key_expr
+            }
+
+            fn add_definitions(definitions: &mut ::whisky::data::blueprint::Definitions) {
+                let key = <Self as ::whisky::data::blueprint::PlutusSchema>::schema_key();
+                if definitions.contains_key(&key) {
+                    return;
+                }
+                // Reserve the key first so recursive types terminate
+                definitions.insert(key.clone(), ::std::default::Default::default());
+                #(<#all_field_types as ::whisky::data::blueprint::PlutusSchema>::add_definitions(definitions);)*
+                definitions.insert(
+                    key,
+                    ::whisky::data::blueprint::Definition {
+                        title: ::std::option::Option::Some(::std::string::String::from(#title)),
+                        any_of: ::std::option::Option::Some(
+                            ::whisky::data::blueprint::AnyOf::Constructors(::std::vec![#(#constructor_defs),*]),
+                        ),
+                        ..::std::default::Default::default()
+                    },
+                );
+            }
+        }
+    })
+}
+
+/// The constructor of a struct, following the layout of `ImplConstr`
+fn struct_constructor(
+    title: &str,
+    fields: &Fields,
+    custom_tag: Option<u64>,
+) -> syn::Result<Constructor> {
+    let parsed = parse_fields(fields)?;
+    if let Fields::Unnamed(unnamed) = fields {
+        if unnamed.unnamed.len() == 1 && !parsed[0].has_attributes() {
+            let field_ty = &parsed[0].ty;
+            if let Some((_, tag, constr_fields)) = analyze_constructor(field_ty) {
+                return Ok(Constructor {
+                    title: title.to_string(),
+                    index: tag.map(u64::from).or(custom_tag).unwrap_or(0),
+                    field_types: constr_fields,
+                });
+            }
+            return Ok(Constructor {
+                title: title.to_string(),
+                index: custom_tag.unwrap_or(0),
+                field_types: flattened_field(field_ty),
+            });
+        }
+    }
+    Ok(Constructor {
+        title: title.to_string(),
+        index: custom_tag.unwrap_or(0),
+        field_types: schema_field_types(fields)?,
+    })
+}
+
+/// The field types of an enum variant, following the layout of `ConstrEnum`
+fn variant_field_types(fields: &Fields) -> syn::Result<Vec<Type>> {
+    let parsed = parse_fields(fields)?;
+    match fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 && !parsed[0].has_attributes() => {
+            Ok(flattened_field(&parsed[0].ty))
+        }
+        _ => schema_field_types(fields),
+    }
+}
+
+/// A single field contributes its constructor fields, flattening `Box<(A, B)>`
+fn flattened_field(ty: &Type) -> Vec<Type> {
+    extract_tuple_fields(ty).unwrap_or_else(|| vec![ty.clone()])
+}
+
+fn schema_field_types(fields: &Fields) -> syn::Result<Vec<Type>> {
+    Ok(parse_fields(fields)?
+        .into_iter()
+        .filter(|field| !field.skip)
+        .map(|field| field.as_ty.unwrap_or(field.ty))
+        .collect())
+}
+
+/// Parse the definition key from `#[plutus(key = "types/Datum")]`
+fn schema_key_attr(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut key = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("plutus")) {
+        attr.parse_args_with(|input: ParseStream| {
+            let ident: Ident = input.parse()?;
+            if ident != "key" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `#[plutus(key = \"module/Type\")]`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let lit: LitStr = input.parse()?;
+            key = Some(lit.value());
+            Ok(())
+        })?;
+    }
+    Ok(key)
+}
@@ -1,8 +1,9 @@
 use proc_macro::TokenStream;
 
+mod blueprint;
 mod data;
 
-#[proc_macro_derive(ConstrEnum)]
+#[proc_macro_derive(ConstrEnum, attributes(constr, plutus))]
 pub fn derive_constr_enum(input: TokenStream) -> TokenStream {
     data::enum_constr::derive_plutus_data_to_json(input)
 }
@@ -17,7 +18,26 @@ pub fn impl_constr_type(input: TokenStream) -> TokenStream {
     data::impl_constr_type::impl_constr_type_macro(input)
 }
 
-#[proc_macro_derive(ImplConstr, attributes(constr))]
+#[proc_macro_derive(ImplConstr, attributes(constr, plutus))]
 pub fn derive_impl_constr(input: TokenStream) -> TokenStream {
     data::impl_constr_derive::derive_impl_constr(input)
 }
+
+/// Emits the CIP-57 blueprint definitions of a type, see `whisky::data::blueprint::PlutusSchema`.
+///
+/// The layout follows `ConstrEnum`/`ImplConstr`, including `#[constr(index = N)]`,
+/// `#[plutus(skip)]` and `#[plutus(as = "Type")]`. The definition key defaults to the
+/// type name and can be set with `#[plutus(key = "module/Type")]`.
+#[proc_macro_derive(PlutusSchema, attributes(constr, plutus))]
+pub fn derive_plutus_schema(input: TokenStream) -> TokenStream {
+    data::schema::derive_plutus_schema(input)
+}
+
+/// Generates typed datums, redeemers and validator blueprints from a CIP-57 `plutus.json`.
+///
+/// The path is relative to the manifest directory of the calling crate, e.g.
+/// `whisky::blueprint!("plutus.json");` inside a dedicated module.
+#[proc_macro]
+pub fn blueprint(input: TokenStream) -> TokenStream {
+    blueprint::blueprint_macro(input)
+}
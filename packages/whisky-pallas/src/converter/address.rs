@@ -1,4 +1,5 @@
 use bech32::{self};
+use pallas::ledger::addresses::{Address, ByronAddress};
 use whisky_common::WError;
 
 pub fn bytes_from_bech32(bech32_str: &str) -> Result<String, WError> {
@@ -24,3 +25,29 @@ pub fn bech32_from_bytes(bytes_hex: &str) -> Result<String, WError> {
         .map_err(|e| WError::new("Bech32 encode error", &format!("{}", e)))?;
     Ok(bech32_str)
 }
+
+/// Parses a bech32 Shelley address, or a base58 Byron address
+pub fn parse_address(address: &str) -> Result<Address, WError> {
+    Address::from_bech32(address)
+        .or_else(|_| ByronAddress::from_base58(address).map(Address::Byron))
+        .map_err(|e| {
+            WError::new(
+                "Address parse error",
+                &format!("Failed to parse address {}: {}", address, e),
+            )
+        })
+}
+
+/// Gets the address bytes in hex from either a bech32 Shelley address or a base58 Byron address
+pub fn bytes_from_address(address: &str) -> Result<String, WError> {
+    whisky_common::Address::parse(address)
+        .and_then(|address| address.to_hex())
+        .map_err(WError::add_err_trace("bytes_from_address"))
+}
+
+/// Encodes the address bytes in hex to bech32, or to base58 for Byron addresses
+pub fn address_from_bytes(bytes_hex: &str) -> Result<String, WError> {
+    whisky_common::Address::from_hex(bytes_hex)
+        .and_then(|address| address.to_bech32())
+        .map_err(WError::add_err_trace("address_from_bytes"))
+}
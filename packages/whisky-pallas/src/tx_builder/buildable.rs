@@ -5,9 +5,9 @@ use pallas::{
     ledger::{primitives::conway::VKeyWitness, traverse::ComputeHash},
 };
 use pallas_crypto::key::ed25519::SecretKey;
-use whisky_common::{TxBuildable, TxBuilderBody, WError};
+use whisky_common::{TxBuildable, TxBuilderBody, VkeyWitness, WError};
 
-use crate::{wrapper::transaction_body::Transaction, WhiskyPallas};
+use crate::{utils::validate_phase_one, wrapper::transaction_body::Transaction, WhiskyPallas};
 
 impl TxBuildable for WhiskyPallas {
     fn set_protocol_params(&mut self, protocol_params: whisky_common::Protocol) {
@@ -69,6 +69,56 @@ impl TxBuildable for WhiskyPallas {
         Ok(transaction.encode()?)
     }
 
+    fn tx_hash(&mut self) -> Result<String, whisky_common::WError> {
+        let transaction_bytes = hex::decode(self.tx_hex.clone()).map_err(|e| {
+            WError::new(
+                "WhiskyPallas TxHash - ",
+                &format!("Failed to decode transaction hex: {}", e),
+            )
+        })?;
+        let transaction = Transaction::decode_bytes(&transaction_bytes)?;
+        Ok(transaction
+            .inner
+            .transaction_body
+            .compute_hash()
+            .to_string())
+    }
+
+    fn add_vkey_witnesses(
+        &mut self,
+        vkey_witnesses: Vec<VkeyWitness>,
+    ) -> Result<String, whisky_common::WError> {
+        let transaction_bytes = hex::decode(self.tx_hex.clone()).map_err(|e| {
+            WError::new(
+                "WhiskyPallas AddVkeyWitnesses - ",
+                &format!("Failed to decode transaction hex: {}", e),
+            )
+        })?;
+        let mut transaction = Transaction::decode_bytes(&transaction_bytes)?;
+        let mut all_witnesses: Vec<VKeyWitness> =
+            match &transaction.inner.transaction_witness_set.vkeywitness {
+                Some(existing) => existing.iter().cloned().collect(),
+                None => vec![],
+            };
+        for vkey_witness in vkey_witnesses {
+            let vkey = Bytes::from_str(&vkey_witness.vkey)
+                .map_err(|_| WError::new("WhiskyPallas AddVkeyWitnesses - ", "Invalid vkey hex"))?;
+            if all_witnesses.iter().any(|w| w.vkey == vkey) {
+                continue;
+            }
+            let signature = Bytes::from_str(&vkey_witness.signature).map_err(|_| {
+                WError::new("WhiskyPallas AddVkeyWitnesses - ", "Invalid signature hex")
+            })?;
+            all_witnesses.push(VKeyWitness { vkey, signature });
+        }
+        if !all_witnesses.is_empty() {
+            transaction.inner.transaction_witness_set.vkeywitness =
+                NonEmptySet::from_vec(all_witnesses);
+        }
+        self.tx_hex = transaction.encode()?;
+        Ok(self.tx_hex.clone())
+    }
+
     fn set_tx_hex(&mut self, tx_hex: String) {
         self.tx_hex = tx_hex;
     }
@@ -87,4 +137,15 @@ impl TxBuildable for WhiskyPallas {
             .push(whisky_common::TxIn::PubKeyTxIn(input));
         Ok(())
     }
+
+    fn validate_phase_one(
+        &self,
+        tx_hex: &str,
+        utxos: &[whisky_common::UTxO],
+        protocol: &whisky_common::Protocol,
+        network: &whisky_common::Network,
+        current_slot: u64,
+    ) -> Result<Vec<whisky_common::Phase1Error>, WError> {
+        validate_phase_one(tx_hex, utxos, protocol, network, current_slot)
+    }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use pallas::codec::utils::PositiveCoin;
+use pallas::ledger::addresses::Address;
 use pallas::ledger::primitives::conway::{
     LanguageView, Redeemer as PallasRedeemer, RedeemerTag as PallasRedeemerTag, ScriptData,
     Value as PallasValue, WitnessSet as PallasWitnessSet,
@@ -8,38 +9,60 @@ use pallas::ledger::primitives::conway::{
 use pallas::ledger::primitives::Fragment;
 use whisky_common::{get_cost_models_from_network, MintItem, Output, PubKeyTxIn, RefTxIn};
 use whisky_common::{
-    Certificate as WhiskyCertificate,
+    BootstrapWitness as WhiskyBootstrapWitness, Certificate as WhiskyCertificate,
     Certificate::{BasicCertificate, ScriptCertificate, SimpleScriptCertificate},
-    CertificateType,
+    CertificateType, Credential as WhiskyCredential,
     DatumSource::{self, InlineDatumSource, ProvidedDatumSource},
-    LanguageVersion,
+    GovernanceId, LanguageVersion, Proposal as WhiskyProposal,
     ScriptSource::{self, InlineScriptSource, ProvidedScriptSource},
     SimpleScriptTxInParameter::{InlineSimpleScriptSource, ProvidedSimpleScriptSource},
-    TxBuilderBody, TxIn, Vote as WhiskyVote, WError, Withdrawal as WhiskyWithdrawal,
+    TxBuilderBody, TxIn, VkeyWitness as WhiskyVkeyWitness, Vote as WhiskyVote, WError,
+    Withdrawal as WhiskyWithdrawal,
     Withdrawal::{PlutusScriptWithdrawal, PubKeyWithdrawal, SimpleScriptWithdrawal},
 };
 
-use crate::utils::{calculate_fee, required_signatures_to_mock_witnesses};
+use crate::utils::{
+    byron_addresses_to_mock_bootstrap_witnesses, calculate_fee,
+    required_signatures_to_mock_witnesses,
+};
 use crate::{
-    converter::{bytes_from_bech32, convert_value},
+    converter::{bytes_from_address, bytes_from_bech32, convert_value, parse_address},
     wrapper::{
         transaction_body::{
-            Anchor, Certificate, CertificateKind, DRep, DRepKind, Datum, DatumKind, GovActionId,
-            MultiassetNonZeroInt, MultiassetPositiveCoin, NetworkId, NetworkIdKind, PoolMetadata,
-            Relay, RelayKind, RequiredSigners, RewardAccount, ScriptRef, ScriptRefKind,
-            StakeCredential, StakeCredentialKind, Transaction, TransactionBody, TransactionInput,
-            TransactionOutput, Value, Vote, VoteKind, Voter, VoterKind, VotingProdecedure,
+            Anchor, Certificate, CertificateKind, DRep, DRepKind, Datum, DatumKind, GovAction,
+            GovActionId, MultiassetNonZeroInt, MultiassetPositiveCoin, NetworkId, NetworkIdKind,
+            PoolMetadata, ProposalProcedure, Relay, RelayKind, RequiredSigners, RewardAccount,
+            ScriptRef, ScriptRefKind, StakeCredential, StakeCredentialKind, Transaction,
+            TransactionBody, TransactionInput, TransactionOutput, Value, Vote, VoteKind, Voter,
+            VoterKind, VotingProdecedure,
         },
         witness_set::{
+            bootstrap_witness::BootstrapWitness,
             native_script::NativeScript,
             plutus_data::PlutusData,
             plutus_script::PlutusScript,
             redeemer::{ExUnits, Redeemer, RedeemerTag},
+            vkey_witness::VKeyWitness,
             witness_set::WitnessSet,
         },
     },
 };
 
+// The committee credential is given either as a `cc_cold` / `cc_hot` identifier, or as a stake address
+fn committee_stake_cred(key_address: &str) -> Result<StakeCredential, WError> {
+    match GovernanceId::from_bech32(key_address) {
+        Ok(governance_id) => match governance_id.credential {
+            WhiskyCredential::KeyHash(key_hash_hex) => {
+                StakeCredential::new(StakeCredentialKind::KeyHash { key_hash_hex })
+            }
+            WhiskyCredential::ScriptHash(script_hash_hex) => {
+                StakeCredential::new(StakeCredentialKind::ScriptHash { script_hash_hex })
+            }
+        },
+        Err(_) => RewardAccount::from_bech32(key_address)?.to_stake_cred(),
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CorePallas {
     pub protocol_params: whisky_common::Protocol,
@@ -50,6 +73,7 @@ pub struct CorePallas {
     pub script_source_ref_inputs: Vec<RefTxIn>,
     pub total_script_size: usize,
     pub required_signatures_vec: Vec<String>,
+    pub byron_addresses_vec: Vec<String>,
 
     // Required info for generating witness set
     pub native_scripts_vec: Vec<NativeScript>,
@@ -79,6 +103,7 @@ impl CorePallas {
             script_source_ref_inputs: vec![],
             total_script_size: 0,
             required_signatures_vec: vec![],
+            byron_addresses_vec: vec![],
             native_scripts_vec: vec![],
             plutus_v1_scripts_vec: vec![],
             plutus_v2_scripts_vec: vec![],
@@ -113,8 +138,7 @@ impl CorePallas {
                     })?;
                     let value = convert_value(&asset_vec)?;
                     self.inputs_map.insert(input.clone(), value);
-                    self.required_signatures_vec
-                        .push(bytes_from_bech32(&pub_key_tx_in.tx_in.address.unwrap())?);
+                    self.add_key_witness_address(&pub_key_tx_in.tx_in.address.unwrap())?;
                     inputs.push(input);
                 }
                 TxIn::SimpleScriptTxIn(simple_script_tx_in) => {
@@ -213,6 +237,19 @@ impl CorePallas {
         Ok(inputs)
     }
 
+    // Byron addresses are witnessed by bootstrap witnesses instead of vkey witnesses
+    fn add_key_witness_address(&mut self, address: &str) -> Result<(), WError> {
+        match parse_address(address)? {
+            Address::Byron(byron_address) => {
+                self.byron_addresses_vec.push(byron_address.to_base58())
+            }
+            _ => self
+                .required_signatures_vec
+                .push(bytes_from_address(address)?),
+        }
+        Ok(())
+    }
+
     fn process_outputs(
         &mut self,
         whisky_outputs: Vec<Output>,
@@ -281,7 +318,7 @@ impl CorePallas {
                 None => None,
             };
             outputs.push(TransactionOutput::new(
-                &bytes_from_bech32(&output.address)?,
+                &bytes_from_address(&output.address)?,
                 convert_value(&output.amount.clone())?,
                 datum,
                 script_ref,
@@ -511,8 +548,7 @@ impl CorePallas {
             }
             CertificateType::CommitteeHotAuth(committee_hot_auth) => {
                 let committee_cold_cred =
-                    RewardAccount::from_bech32(&committee_hot_auth.committee_cold_key_address)?
-                        .to_stake_cred()?;
+                    committee_stake_cred(&committee_hot_auth.committee_cold_key_address)?;
                 match committee_cold_cred.inner {
                     pallas::ledger::primitives::StakeCredential::ScriptHash(_hash) => {}
                     pallas::ledger::primitives::StakeCredential::AddrKeyhash(hash) => {
@@ -521,10 +557,9 @@ impl CorePallas {
                 }
                 Ok(Certificate::new(CertificateKind::AuthCommitteeHot {
                     committee_cold_cred,
-                    committee_hot_cred: RewardAccount::from_bech32(
+                    committee_hot_cred: committee_stake_cred(
                         &committee_hot_auth.committee_hot_key_address,
-                    )?
-                    .to_stake_cred()?,
+                    )?,
                 }))?
             }
             CertificateType::CommitteeColdResign(committee_cold_resign) => {
@@ -536,8 +571,7 @@ impl CorePallas {
                     None => None,
                 };
                 let committee_cold_cred =
-                    RewardAccount::from_bech32(&committee_cold_resign.committee_cold_key_address)?
-                        .to_stake_cred()?;
+                    committee_stake_cred(&committee_cold_resign.committee_cold_key_address)?;
                 match committee_cold_cred.inner {
                     pallas::ledger::primitives::StakeCredential::ScriptHash(_hash) => {}
                     pallas::ledger::primitives::StakeCredential::AddrKeyhash(hash) => {
@@ -941,8 +975,7 @@ impl CorePallas {
     ) -> Result<Option<Vec<TransactionInput>>, WError> {
         let mut collaterals: Vec<TransactionInput> = vec![];
         for collateral in whisky_collaterals.clone() {
-            self.required_signatures_vec
-                .push(bytes_from_bech32(&collateral.tx_in.address.unwrap())?);
+            self.add_key_witness_address(&collateral.tx_in.address.unwrap())?;
             let transaction_input =
                 TransactionInput::new(&collateral.tx_in.tx_hash, collateral.tx_in.tx_index.into())?;
             collaterals.push(transaction_input.clone());
@@ -974,6 +1007,56 @@ impl CorePallas {
         })
     }
 
+    fn process_vkey_witnesses(
+        &mut self,
+        whisky_vkey_witnesses: Vec<WhiskyVkeyWitness>,
+    ) -> Result<Option<Vec<VKeyWitness>>, WError> {
+        let mut vkey_witnesses: Vec<VKeyWitness> = vec![];
+        for vkey_witness in whisky_vkey_witnesses {
+            vkey_witnesses.push(
+                VKeyWitness::new(vkey_witness.vkey, vkey_witness.signature).map_err(|e| {
+                    WError::new(
+                        "WhiskyPallas - Processing vkey witnesses:",
+                        &format!("Invalid vkey witness: {}", e),
+                    )
+                })?,
+            );
+        }
+        Ok(if vkey_witnesses.is_empty() {
+            None
+        } else {
+            Some(vkey_witnesses)
+        })
+    }
+
+    fn process_bootstrap_witnesses(
+        &mut self,
+        whisky_bootstrap_witnesses: Vec<WhiskyBootstrapWitness>,
+    ) -> Result<Option<Vec<BootstrapWitness>>, WError> {
+        let mut bootstrap_witnesses: Vec<BootstrapWitness> = vec![];
+        for bootstrap_witness in whisky_bootstrap_witnesses {
+            bootstrap_witnesses.push(
+                BootstrapWitness::new(
+                    bootstrap_witness.vkey,
+                    bootstrap_witness.signature,
+                    bootstrap_witness.chain_code,
+                    bootstrap_witness.attributes,
+                )
+                .map_err(|e| {
+                    WError::new(
+                        "WhiskyPallas - Processing bootstrap witnesses:",
+                        &format!("Invalid bootstrap witness: {}", e),
+                    )
+                })?,
+            );
+        }
+        Ok(if bootstrap_witnesses.is_empty() {
+            None
+        } else {
+            Some(bootstrap_witnesses)
+        })
+    }
+
     fn process_total_collateral(
         &mut self,
         whisky_total_collateral: Option<String>,
@@ -1218,6 +1301,49 @@ impl CorePallas {
         }
     }
 
+    fn process_proposal_procedures(
+        &mut self,
+        whisky_proposals: Vec<WhiskyProposal>,
+    ) -> Result<Option<Vec<ProposalProcedure>>, WError> {
+        let mut proposal_procedures: Vec<ProposalProcedure> = vec![];
+        for proposal in whisky_proposals {
+            match proposal {
+                WhiskyProposal::BasicProposal(proposal_type) => {
+                    let gov_action_bytes = hex::decode(&proposal_type.gov_action).map_err(|e| {
+                        WError::new(
+                            "WhiskyPallas - Processing proposals:",
+                            &format!("Invalid governance action hex: {}", e),
+                        )
+                    })?;
+                    let gov_action = GovAction::decode_bytes(&gov_action_bytes)
+                        .map_err(|e| WError::new("WhiskyPallas - Processing proposals:", &e))?;
+                    let proposal_procedure = ProposalProcedure::new(
+                        proposal_type.deposit,
+                        RewardAccount::from_bech32(&proposal_type.reward_account)?,
+                        gov_action,
+                        Anchor::new(
+                            proposal_type.anchor.anchor_url,
+                            proposal_type.anchor.anchor_data_hash,
+                        )?,
+                    )
+                    .map_err(|e| WError::new("WhiskyPallas - Processing proposals:", &e))?;
+                    proposal_procedures.push(proposal_procedure);
+                }
+                WhiskyProposal::ScriptProposal(_) => {
+                    return Err(WError::new(
+                        "WhiskyPallas - Processing proposals:",
+                        "Script proposals are currently not supported",
+                    ));
+                }
+            }
+        }
+        if proposal_procedures.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(proposal_procedures))
+        }
+    }
+
     fn process_reference_inputs(
         &mut self,
         whisky_ref_inputs: Vec<RefTxIn>,
@@ -1289,6 +1415,8 @@ impl CorePallas {
         withdrawals: Option<Vec<(RewardAccount, u64)>>,
         mints: Option<MultiassetNonZeroInt>,
         votes: Option<Vec<(Voter, Vec<(GovActionId, VotingProdecedure)>)>>,
+        vkey_witnesses: Option<Vec<VKeyWitness>>,
+        bootstrap_witnesses: Option<Vec<BootstrapWitness>>,
     ) -> Result<WitnessSet<'_>, WError> {
         let native_scripts = if self.native_scripts_vec.is_empty() {
             None
@@ -1420,9 +1548,9 @@ impl CorePallas {
         }
 
         WitnessSet::new(
-            None,
+            vkey_witnesses,
             native_scripts,
-            None,
+            bootstrap_witnesses,
             plutus_v1_scripts,
             plutus_data,
             if redeemers.is_empty() {
@@ -1457,6 +1585,9 @@ impl CorePallas {
         let collaterals = self.process_collaterals(tx_builder_body.collaterals)?;
         let required_signers =
             self.process_required_signers(tx_builder_body.required_signatures)?;
+        let vkey_witnesses = self.process_vkey_witnesses(tx_builder_body.vkey_witnesses)?;
+        let bootstrap_witnesses =
+            self.process_bootstrap_witnesses(tx_builder_body.bootstrap_witnesses)?;
         let network = tx_builder_body
             .network
             .clone()
@@ -1472,6 +1603,14 @@ impl CorePallas {
         let reference_inputs = self
             .process_reference_inputs(tx_builder_body.reference_inputs, tx_builder_body.inputs)?;
         let voting_procedures = self.process_voting_procedures(tx_builder_body.votes)?;
+        let proposal_deposits: u64 = tx_builder_body
+            .proposals
+            .iter()
+            .map(|proposal| proposal.proposal_type().deposit)
+            .sum();
+        let proposal_procedures = self.process_proposal_procedures(tx_builder_body.proposals)?;
+        let treasury_value = tx_builder_body.treasury_value;
+        let donation = tx_builder_body.donation;
         let cost_models = get_cost_models_from_network(&network);
         let plutus_version: Option<u8> = if self.plutus_v3_used {
             Some(2)
@@ -1486,12 +1625,15 @@ impl CorePallas {
         let protocol_params = self.protocol_params.clone();
         let inputs_map = self.inputs_map.clone();
         let required_signatures_vec = self.required_signatures_vec.clone();
+        let byron_addresses_vec = self.byron_addresses_vec.clone();
         let witness_set = self.process_witness_set(
             inputs.clone(),
             certificates.clone(),
             withdrawals.clone(),
             mints.clone(),
             voting_procedures.clone(),
+            vkey_witnesses,
+            bootstrap_witnesses,
         )?;
         let script_data_hash = match plutus_version {
             Some(version) => {
@@ -1537,16 +1679,18 @@ impl CorePallas {
                     total_collateral,
                     reference_inputs.clone(),
                     voting_procedures.clone(),
-                    None, // Proposals are currently not supported
-                    None, // Treasury donations are currently not supported
-                    None, // Treasury donations are currently not supported
+                    proposal_procedures.clone(),
+                    treasury_value,
+                    donation,
                 )?;
                 let mock_witness_set = PallasWitnessSet {
                     vkeywitness: required_signatures_to_mock_witnesses(
                         required_signatures_vec.clone(),
                     ),
                     native_script: witness_set.inner.native_script.clone(),
-                    bootstrap_witness: witness_set.inner.bootstrap_witness.clone(),
+                    bootstrap_witness: byron_addresses_to_mock_bootstrap_witnesses(
+                        byron_addresses_vec,
+                    )?,
                     plutus_v1_script: witness_set.inner.plutus_v1_script.clone(),
                     plutus_data: witness_set.inner.plutus_data.clone(),
                     redeemer: witness_set.inner.redeemer.clone(),
@@ -1754,6 +1898,20 @@ impl CorePallas {
                     }
                 }
             }
+            change_value = change_value
+                .sub(&Value::new(
+                    proposal_deposits + donation.unwrap_or_default(),
+                    None,
+                ))
+                .map_err(|e| {
+                    WError::new(
+                        "WhiskyPallas - Building transaction:",
+                        &format!(
+                            "Error while balancing change output, inputs less than proposal deposits and donation: {}",
+                            e
+                        ),
+                    )
+                })?;
             change_value = change_value.sub(&Value::new(fee, None)).map_err(|e| {
                 WError::new(
                     "WhiskyPallas - Building transaction:",
@@ -1764,7 +1922,7 @@ impl CorePallas {
                 )
             })?;
             outputs.push(TransactionOutput::new(
-                &bytes_from_bech32(&tx_builder_body.change_address)?,
+                &bytes_from_address(&tx_builder_body.change_address)?,
                 change_value,
                 None,
                 None,
@@ -1789,9 +1947,9 @@ impl CorePallas {
             total_collateral,
             reference_inputs,
             voting_procedures,
-            None, // Proposals are currently not supported
-            None, // Treasury donations are currently not supported
-            None, // Treasury donations are currently not supported
+            proposal_procedures,
+            treasury_value,
+            donation,
         )?;
         let transaction_bytes = Transaction::new(tx_body, witness_set, true, None)?
             .inner
@@ -244,19 +244,7 @@ pub fn extract_certificates(
                     let cert_type: CertificateType =
                         CertificateType::DelegateStake(DelegateStake {
                             stake_key_address: stake_address.to_bech32()?,
-                            pool_id: bech32::encode::<bech32::Bech32>(
-                                bech32::Hrp::parse("pool").unwrap(),
-                                &hex::decode(hash.to_string()).unwrap(),
-                            )
-                            .map_err(|_| {
-                                WError::new(
-                                    "WhiskyPallas Parser - Extracting certificates:",
-                                    &format!(
-                                        "Failed to encode pool id from hash: {}",
-                                        hash.to_string()
-                                    ),
-                                )
-                            })?,
+                            pool_id: hash.to_string(),
                         });
 
                     let cert =
@@ -292,19 +280,7 @@ pub fn extract_certificates(
                 pallas::ledger::primitives::conway::Certificate::PoolRetirement(hash, epoch) => {
                     let cert =
                         Certificate::BasicCertificate(CertificateType::RetirePool(RetirePool {
-                            pool_id: bech32::encode::<bech32::Bech32>(
-                                bech32::Hrp::parse("pool").unwrap(),
-                                &hex::decode(hash.to_string()).unwrap(),
-                            )
-                            .map_err(|_| {
-                                WError::new(
-                                    "WhiskyPallas Parser - Extracting certificates:",
-                                    &format!(
-                                        "Failed to encode pool id from hash: {}",
-                                        hash.to_string()
-                                    ),
-                                )
-                            })?,
+                            pool_id: hash.to_string(),
                             epoch: *epoch as u32,
                         }));
                     certs_vec.push(cert);
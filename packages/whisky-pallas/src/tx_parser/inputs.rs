@@ -5,6 +5,7 @@ use whisky_common::{
 };
 
 use crate::{
+    converter::parse_address,
     tx_parser::context::{ParserContext, RedeemerIndex, Script},
     wrapper::witness_set::redeemer::RedeemerTag,
 };
@@ -38,15 +39,12 @@ pub fn utxo_to_tx_in(
         address: Some(utxo.output.address.clone()),
     };
 
-    let address = Address::from_bech32(&utxo.output.address).map_err(|e| {
-        WError::new(
-            "utxo_to_tx_in",
-            &format!("Failed to parse address from bech32: {:?}", e),
-        )
-    })?;
+    let address =
+        parse_address(&utxo.output.address).map_err(WError::add_err_trace("utxo_to_tx_in"))?;
 
     match address {
-        Address::Byron(_byron_address) => todo!(),
+        // Byron addresses can only be spent with a bootstrap witness, i.e. a key witness
+        Address::Byron(_byron_address) => Ok(TxIn::PubKeyTxIn(PubKeyTxIn { tx_in: tx_in_param })),
         Address::Shelley(shelley_address) => {
             let payment_cred = shelley_address.payment();
             match payment_cred {
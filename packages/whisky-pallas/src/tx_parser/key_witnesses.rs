@@ -0,0 +1,30 @@
+use pallas::ledger::primitives::conway::Tx;
+use whisky_common::{BootstrapWitness, VkeyWitness, WError};
+
+pub fn extract_vkey_witnesses(pallas_tx: &Tx) -> Result<Vec<VkeyWitness>, WError> {
+    match &pallas_tx.transaction_witness_set.vkeywitness {
+        Some(vkey_witnesses) => Ok(vkey_witnesses
+            .iter()
+            .map(|vkey_witness| VkeyWitness {
+                vkey: vkey_witness.vkey.to_string(),
+                signature: vkey_witness.signature.to_string(),
+            })
+            .collect()),
+        None => Ok(vec![]),
+    }
+}
+
+pub fn extract_bootstrap_witnesses(pallas_tx: &Tx) -> Result<Vec<BootstrapWitness>, WError> {
+    match &pallas_tx.transaction_witness_set.bootstrap_witness {
+        Some(bootstrap_witnesses) => Ok(bootstrap_witnesses
+            .iter()
+            .map(|bootstrap_witness| BootstrapWitness {
+                vkey: bootstrap_witness.public_key.to_string(),
+                signature: bootstrap_witness.signature.to_string(),
+                chain_code: bootstrap_witness.chain_code.to_string(),
+                attributes: bootstrap_witness.attributes.to_string(),
+            })
+            .collect()),
+        None => Ok(vec![]),
+    }
+}
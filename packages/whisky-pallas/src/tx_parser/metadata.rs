@@ -1,7 +1,7 @@
 use std::collections::BTreeMap;
 
 use pallas::ledger::primitives::{conway::Tx, Metadatum};
-use whisky_common::{Metadata, WError};
+use whisky_common::{Metadata, TransactionMetadatum, WError};
 
 pub fn extract_metadata(pallas_tx: &Tx) -> Result<Vec<Metadata>, WError> {
     let mut metadata_list: Vec<Metadata> = vec![];
@@ -9,16 +9,7 @@ pub fn extract_metadata(pallas_tx: &Tx) -> Result<Vec<Metadata>, WError> {
         pallas::codec::utils::Nullable::Some(aux_data) => match aux_data.clone().unwrap() {
             pallas::ledger::primitives::conway::AuxiliaryData::Shelley(metadata_map) => {
                 for (key, metadatum) in metadata_map.iter() {
-                    metadata_list.push(Metadata {
-                        tag: key.to_string(),
-                        metadata: serde_json::to_string(&metadata_to_json_value(metadatum)?)
-                            .map_err(|e| {
-                                WError::new(
-                                    "WhiskyPallas Parser - ",
-                                    &format!("metadata to json string error: {:?}", e),
-                                )
-                            })?,
-                    })
+                    metadata_list.push(to_metadata(key, metadatum)?)
                 }
             }
             pallas::ledger::primitives::conway::AuxiliaryData::ShelleyMa(
@@ -27,16 +18,7 @@ pub fn extract_metadata(pallas_tx: &Tx) -> Result<Vec<Metadata>, WError> {
                 let metadata_map: &BTreeMap<u64, Metadatum> =
                     &shelley_ma_auxiliary_data.transaction_metadata;
                 for (key, metadatum) in metadata_map.iter() {
-                    metadata_list.push(Metadata {
-                        tag: key.to_string(),
-                        metadata: serde_json::to_string(&metadata_to_json_value(metadatum)?)
-                            .map_err(|e| {
-                                WError::new(
-                                    "WhiskyPallas Parser - ",
-                                    &format!("metadata to json string error: {:?}", e),
-                                )
-                            })?,
-                    })
+                    metadata_list.push(to_metadata(key, metadatum)?)
                 }
             }
             pallas::ledger::primitives::conway::AuxiliaryData::PostAlonzo(
@@ -44,16 +26,7 @@ pub fn extract_metadata(pallas_tx: &Tx) -> Result<Vec<Metadata>, WError> {
             ) => {
                 if let Some(metadata_map) = &post_alonzo_auxiliary_data.metadata {
                     for (key, metadatum) in metadata_map.iter() {
-                        metadata_list.push(Metadata {
-                            tag: key.to_string(),
-                            metadata: serde_json::to_string(&metadata_to_json_value(metadatum)?)
-                                .map_err(|e| {
-                                    WError::new(
-                                        "WhiskyPallas Parser - ",
-                                        &format!("metadata to json string error: {:?}", e),
-                                    )
-                                })?,
-                        })
+                        metadata_list.push(to_metadata(key, metadatum)?)
                     }
                 }
             }
@@ -64,6 +37,42 @@ pub fn extract_metadata(pallas_tx: &Tx) -> Result<Vec<Metadata>, WError> {
     Ok(metadata_list)
 }
 
+fn to_metadata(key: &u64, metadatum: &Metadatum) -> Result<Metadata, WError> {
+    Ok(Metadata {
+        tag: key.to_string(),
+        metadata: serde_json::to_string(&metadata_to_json_value(metadatum)?).map_err(|e| {
+            WError::new(
+                "WhiskyPallas Parser - ",
+                &format!("metadata to json string error: {:?}", e),
+            )
+        })?,
+        metadatum: Some(to_transaction_metadatum(metadatum)?),
+    })
+}
+
+fn to_transaction_metadatum(metadatum: &Metadatum) -> Result<TransactionMetadatum, WError> {
+    match metadatum {
+        Metadatum::Int(int) => Ok(TransactionMetadatum::Int(i128::from(*int))),
+        Metadatum::Bytes(bytes) => Ok(TransactionMetadatum::Bytes(bytes.to_string())),
+        Metadatum::Text(string) => Ok(TransactionMetadatum::Text(string.clone())),
+        Metadatum::Array(metadatums) => metadatums
+            .iter()
+            .map(to_transaction_metadatum)
+            .collect::<Result<Vec<_>, _>>()
+            .map(TransactionMetadatum::List),
+        Metadatum::Map(key_value_pairs) => key_value_pairs
+            .iter()
+            .map(|(key, value)| {
+                Ok((
+                    to_transaction_metadatum(key)?,
+                    to_transaction_metadatum(value)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, WError>>()
+            .map(TransactionMetadatum::Map),
+    }
+}
+
 fn metadata_to_json_value(metadatum: &Metadatum) -> Result<serde_json::Value, WError> {
     match metadatum {
         Metadatum::Int(int) => {
@@ -2,22 +2,36 @@ mod certificates;
 mod collaterals;
 mod context;
 mod inputs;
+mod key_witnesses;
 mod metadata;
 mod mints;
 pub mod outputs;
+mod proposals;
 mod reference_inputs;
 mod required_signers;
 mod validity_range;
 mod votes;
 mod withdrawals;
+mod witnesses;
+
+pub use witnesses::witness_report;
 
 use crate::{
     tx_parser::{
-        certificates::extract_certificates, collaterals::extract_collaterals,
-        context::ParserContext, inputs::extract_inputs, metadata::extract_metadata,
-        mints::extract_mints, outputs::extract_outputs, reference_inputs::extract_reference_inputs,
-        required_signers::extract_required_signers, validity_range::extract_validity_range,
-        votes::extract_votes, withdrawals::extract_withdrawals,
+        certificates::extract_certificates,
+        collaterals::extract_collaterals,
+        context::ParserContext,
+        inputs::extract_inputs,
+        key_witnesses::{extract_bootstrap_witnesses, extract_vkey_witnesses},
+        metadata::extract_metadata,
+        mints::extract_mints,
+        outputs::extract_outputs,
+        proposals::{extract_donation, extract_proposals, extract_treasury_value},
+        reference_inputs::extract_reference_inputs,
+        required_signers::extract_required_signers,
+        validity_range::extract_validity_range,
+        votes::extract_votes,
+        withdrawals::extract_withdrawals,
     },
     wrapper::transaction_body::{ScriptRef, ScriptRefKind, Transaction},
 };
@@ -51,6 +65,11 @@ pub fn parse(tx_hex: &str, resolved_utxos: &[UTxO]) -> Result<TxBuilderBody, WEr
     let validity_range = extract_validity_range(&pallas_tx.inner)?;
     let metadata = extract_metadata(&pallas_tx.inner)?;
     let votes = extract_votes(&pallas_tx.inner, &parser_context)?;
+    let proposals = extract_proposals(&pallas_tx.inner, &parser_context)?;
+    let treasury_value = extract_treasury_value(&pallas_tx.inner)?;
+    let donation = extract_donation(&pallas_tx.inner)?;
+    let vkey_witnesses = extract_vkey_witnesses(&pallas_tx.inner)?;
+    let bootstrap_witnesses = extract_bootstrap_witnesses(&pallas_tx.inner)?;
 
     let change_output = outputs.last().unwrap();
     Ok(TxBuilderBody {
@@ -67,7 +86,12 @@ pub fn parse(tx_hex: &str, resolved_utxos: &[UTxO]) -> Result<TxBuilderBody, WEr
         validity_range,
         certificates,
         votes,
+        proposals,
+        treasury_value,
+        donation,
         signing_key: vec![],
+        vkey_witnesses,
+        bootstrap_witnesses,
         fee: None, // These fields are expected to be recalculated by the TxBuilder
         network: None,
         total_collateral: None, // These fields are expected to be recalculated by the TxBuilder
@@ -7,7 +7,7 @@ use pallas::{
 };
 use whisky_common::{Output, ProvidedScriptSource, ProvidedSimpleScriptSource, WError};
 
-use crate::converter::{bech32_from_bytes, value_to_asset_vec};
+use crate::converter::{address_from_bytes, value_to_asset_vec};
 
 pub fn extract_outputs(pallas_tx: &Tx) -> Result<Vec<Output>, WError> {
     let mut outputs_vec: Vec<Output> = Vec::new();
@@ -39,7 +39,7 @@ pub fn extract_outputs(pallas_tx: &Tx) -> Result<Vec<Output>, WError> {
                     }
                 };
                 let tx_out = Output {
-                    address: bech32_from_bytes(&legacy_output.address.to_string())?,
+                    address: address_from_bytes(&legacy_output.address.to_string())?,
                     amount: value_to_asset_vec(&&crate::wrapper::transaction_body::Value {
                         inner: coerced_output,
                     })?,
@@ -55,7 +55,7 @@ pub fn extract_outputs(pallas_tx: &Tx) -> Result<Vec<Output>, WError> {
                 post_alonzo_output,
             ) => {
                 let tx_out: Output = Output {
-                    address: bech32_from_bytes(&post_alonzo_output.address.to_string())?,
+                    address: address_from_bytes(&post_alonzo_output.address.to_string())?,
                     amount: value_to_asset_vec(&&crate::wrapper::transaction_body::Value {
                         inner: post_alonzo_output.value.clone(),
                     })?,
@@ -0,0 +1,83 @@
+use pallas::ledger::primitives::conway::{GovAction as PallasGovAction, Tx};
+use whisky_common::{Proposal, ProposalType, ScriptProposal, ScriptSource, WError};
+
+use crate::{
+    tx_parser::context::{ParserContext, RedeemerIndex, Script},
+    wrapper::{
+        transaction_body::{Anchor, GovAction, RewardAccount},
+        witness_set::redeemer::RedeemerTag,
+    },
+};
+
+pub fn extract_proposals(pallas_tx: &Tx, context: &ParserContext) -> Result<Vec<Proposal>, WError> {
+    let mut proposals: Vec<Proposal> = vec![];
+    if let Some(pallas_proposals) = &pallas_tx.transaction_body.proposal_procedures {
+        for (index, proposal) in pallas_proposals.iter().enumerate() {
+            let reward_account = RewardAccount::from_bytes(&proposal.reward_account.to_vec())?
+                .to_bech32()
+                .map_err(|e| {
+                    WError::new(
+                        "WhiskyPallas - Extracting proposals:",
+                        &format!("Failed to convert reward account to bech32: {:?}", e),
+                    )
+                })?;
+            let proposal_type = ProposalType {
+                deposit: proposal.deposit,
+                reward_account,
+                gov_action: GovAction {
+                    inner: proposal.gov_action.clone(),
+                }
+                .encode(),
+                anchor: Anchor {
+                    inner: proposal.anchor.clone(),
+                }
+                .to_whisky_anchor(),
+            };
+
+            let policy_hash = match &proposal.gov_action {
+                PallasGovAction::ParameterChange(_, _, policy_hash) => *policy_hash,
+                PallasGovAction::TreasuryWithdrawals(_, policy_hash) => *policy_hash,
+                _ => None,
+            };
+            let script_source = policy_hash
+                .and_then(|hash| context.script_witnesses.scripts.get(&hash.to_string()))
+                .and_then(|script| match script {
+                    Script::ProvidedPlutus(provided_script_source) => Some(
+                        ScriptSource::ProvidedScriptSource(provided_script_source.clone()),
+                    ),
+                    Script::ReferencedPlutus(inline_script_source) => Some(
+                        ScriptSource::InlineScriptSource(inline_script_source.clone()),
+                    ),
+                    Script::ProvidedNative(_) | Script::ReferencedNative(_) => None,
+                });
+
+            match script_source {
+                Some(script_source) => {
+                    let redeemer = context
+                        .script_witnesses
+                        .redeemers
+                        .get(&RedeemerIndex {
+                            tag: RedeemerTag::Propose,
+                            index: index as u32,
+                        })
+                        .cloned();
+                    proposals.push(Proposal::ScriptProposal(ScriptProposal {
+                        proposal: proposal_type,
+                        redeemer,
+                        script_source: Some(script_source),
+                    }));
+                }
+                None => proposals.push(Proposal::BasicProposal(proposal_type)),
+            }
+        }
+    }
+    Ok(proposals)
+}
+
+pub fn extract_treasury_value(pallas_tx: &Tx) -> Result<Option<u64>, WError> {
+    Ok(pallas_tx.transaction_body.treasury_value)
+}
+
+pub fn extract_donation(pallas_tx: &Tx) -> Result<Option<u64>, WError> {
+    Ok(pallas_tx.transaction_body.donation.map(u64::from))
+}
@@ -371,5 +371,5 @@ pub fn extract_votes(pallas_tx: &Tx, context: &ParserContext) -> Result<Vec<Vote
             }
         }
     }
-    Ok(Vec::new())
+    Ok(votes)
 }
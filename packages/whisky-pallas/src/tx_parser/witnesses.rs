@@ -0,0 +1,486 @@
+use pallas::codec::minicbor;
+use pallas::ledger::{
+    addresses::{Address, ShelleyPaymentPart},
+    primitives::{
+        conway::{Certificate, GovAction, LanguageView, NativeScript, ScriptData, Tx, Voter},
+        StakeCredential,
+    },
+    traverse::ComputeHash,
+};
+use pallas_crypto::{
+    hash::Hasher,
+    key::ed25519::{PublicKey, Signature},
+};
+use whisky_common::{
+    get_cost_models_from_network, Datum, LanguageVersion, Network, ScriptDataHashCheck, UTxO,
+    UtxoInput, VkeySource, VkeyWitnessCheck, WError, WitnessInventory, WitnessReport,
+};
+
+use crate::{
+    converter::parse_address,
+    tx_parser::{
+        context::{ParserContext, Script},
+        outputs::extract_outputs,
+        required_signers::extract_required_signers,
+    },
+    wrapper::transaction_body::{RewardAccount, Transaction},
+};
+
+/// Audit the witness set of a transaction against everything its body requires, i.e. vkeys,
+/// scripts, datums and the script data hash under the network's cost models.
+/// Bootstrap witnesses of Byron inputs are not checked, and the script data hash is computed
+/// with the language view of the newest Plutus version used, as the pallas builder does.
+pub fn witness_report(
+    tx_hex: &str,
+    resolved_utxos: &[UTxO],
+    network: &Network,
+) -> Result<WitnessReport, WError> {
+    let bytes = hex::decode(tx_hex).map_err(|e| {
+        WError::new(
+            "WhiskyPallas - witness report:",
+            &format!("Hex decode error: {}", e),
+        )
+    })?;
+    let pallas_tx = Transaction::decode_bytes(&bytes)?;
+    let tx = &pallas_tx.inner;
+
+    let mut inventory = WitnessInventory::default();
+    let context = build_context(tx, resolved_utxos, &mut inventory)?;
+
+    collect_input_requirements(tx, &context, &mut inventory)?;
+    collect_certificate_requirements(tx, &mut inventory);
+    collect_withdrawal_requirements(tx, &mut inventory)?;
+    collect_vote_requirements(tx, &mut inventory);
+
+    if let Some(mints) = &tx.transaction_body.mint {
+        for (policy_id, _) in mints.iter() {
+            inventory.required_scripts.push(policy_id.to_string());
+        }
+    }
+
+    if let Some(proposals) = &tx.transaction_body.proposal_procedures {
+        for proposal in proposals.iter() {
+            let policy_hash = match &proposal.gov_action {
+                GovAction::ParameterChange(_, _, policy_hash) => *policy_hash,
+                GovAction::TreasuryWithdrawals(_, policy_hash) => *policy_hash,
+                _ => None,
+            };
+            if let Some(policy_hash) = policy_hash {
+                inventory.required_scripts.push(policy_hash.to_string());
+            }
+        }
+    }
+
+    for signer in extract_required_signers(tx)? {
+        inventory
+            .required_vkeys
+            .push((signer, VkeySource::RequiredSigner));
+    }
+
+    collect_witness_set(tx, &context, &mut inventory)?;
+    inventory.script_data_hash = check_script_data_hash(tx, &context, &inventory, network);
+
+    Ok(WitnessReport::new(inventory))
+}
+
+/// Resolves the spent and referenced UTxOs, recording the spent ones that are not provided.
+/// Reference scripts are only collected once every input is resolved.
+fn build_context(
+    tx: &Tx,
+    resolved_utxos: &[UTxO],
+    inventory: &mut WitnessInventory,
+) -> Result<ParserContext, WError> {
+    let mut context = ParserContext::new();
+    let body = &tx.transaction_body;
+    let spent = body.inputs.iter().chain(
+        body.collateral
+            .iter()
+            .flat_map(|collateral| collateral.iter()),
+    );
+    let referenced = body
+        .reference_inputs
+        .iter()
+        .flat_map(|reference_inputs| reference_inputs.iter());
+
+    let mut all_resolved = true;
+    for (input, is_spent) in spent
+        .map(|i| (i, true))
+        .chain(referenced.map(|i| (i, false)))
+    {
+        let utxo = resolved_utxos.iter().find(|utxo| {
+            input.transaction_id.to_string() == utxo.input.tx_hash
+                && input.index as u32 == utxo.input.output_index
+        });
+        match utxo {
+            Some(utxo) => {
+                context.resolved_utxos.insert(input.clone(), utxo.clone());
+            }
+            None => {
+                all_resolved = false;
+                if is_spent {
+                    inventory.unresolved_inputs.push(UtxoInput {
+                        tx_hash: input.transaction_id.to_string(),
+                        output_index: input.index as u32,
+                    });
+                }
+            }
+        }
+    }
+
+    context.collect_script_witnesses_from_tx_witnesses_set(&tx.transaction_witness_set)?;
+    if all_resolved {
+        context.collect_script_witnesses_from_tx_body(&tx.transaction_body)?;
+    }
+    Ok(context)
+}
+
+fn collect_input_requirements(
+    tx: &Tx,
+    context: &ParserContext,
+    inventory: &mut WitnessInventory,
+) -> Result<(), WError> {
+    let body = &tx.transaction_body;
+    let inputs = body.inputs.iter().map(|input| (input, false)).chain(
+        body.collateral
+            .iter()
+            .flat_map(|collateral| collateral.iter())
+            .map(|input| (input, true)),
+    );
+
+    for (input, is_collateral) in inputs {
+        let utxo = match context.resolved_utxos.get(input) {
+            Some(utxo) => utxo,
+            None => continue,
+        };
+        let address = parse_address(&utxo.output.address).map_err(WError::add_err_trace(
+            "WhiskyPallas - witness report - inputs",
+        ))?;
+        let shelley_address = match address {
+            Address::Shelley(shelley_address) => shelley_address,
+            _ => continue,
+        };
+        match shelley_address.payment() {
+            ShelleyPaymentPart::Key(key_hash) => {
+                let source = if is_collateral {
+                    VkeySource::Collateral(utxo.input.clone())
+                } else {
+                    VkeySource::Input(utxo.input.clone())
+                };
+                inventory
+                    .required_vkeys
+                    .push((key_hash.to_string(), source));
+            }
+            ShelleyPaymentPart::Script(script_hash) => {
+                if is_collateral {
+                    continue;
+                }
+                let script_hash = script_hash.to_string();
+                let is_native = matches!(
+                    context.script_witnesses.scripts.get(&script_hash),
+                    Some(Script::ProvidedNative(_)) | Some(Script::ReferencedNative(_))
+                );
+                inventory.required_scripts.push(script_hash);
+                if let (Some(data_hash), None, false) =
+                    (&utxo.output.data_hash, &utxo.output.plutus_data, is_native)
+                {
+                    inventory.required_datums.push(data_hash.clone());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn collect_certificate_requirements(tx: &Tx, inventory: &mut WitnessInventory) {
+    let certs = match &tx.transaction_body.certificates {
+        Some(certs) => certs,
+        None => return,
+    };
+    for (index, cert) in certs.iter().enumerate() {
+        let credential = match cert {
+            // The legacy registration certificate carries no deposit and needs no witness
+            Certificate::StakeRegistration(_) => None,
+            Certificate::StakeDeregistration(stake_credential)
+            | Certificate::StakeDelegation(stake_credential, _)
+            | Certificate::Reg(stake_credential, _)
+            | Certificate::UnReg(stake_credential, _)
+            | Certificate::VoteDeleg(stake_credential, _)
+            | Certificate::StakeVoteDeleg(stake_credential, _, _)
+            | Certificate::StakeRegDeleg(stake_credential, _, _)
+            | Certificate::VoteRegDeleg(stake_credential, _, _)
+            | Certificate::StakeVoteRegDeleg(stake_credential, _, _, _) => Some(stake_credential),
+            Certificate::AuthCommitteeHot(cold_credential, _)
+            | Certificate::ResignCommitteeCold(cold_credential, _) => Some(cold_credential),
+            Certificate::RegDRepCert(drep_credential, _, _)
+            | Certificate::UnRegDRepCert(drep_credential, _)
+            | Certificate::UpdateDRepCert(drep_credential, _) => Some(drep_credential),
+            Certificate::PoolRegistration {
+                operator,
+                pool_owners,
+                ..
+            } => {
+                inventory
+                    .required_vkeys
+                    .push((operator.to_string(), VkeySource::Certificate(index)));
+                for owner in pool_owners.iter() {
+                    inventory
+                        .required_vkeys
+                        .push((owner.to_string(), VkeySource::Certificate(index)));
+                }
+                None
+            }
+            Certificate::PoolRetirement(pool_key_hash, _) => {
+                inventory
+                    .required_vkeys
+                    .push((pool_key_hash.to_string(), VkeySource::Certificate(index)));
+                None
+            }
+        };
+        if let Some(credential) = credential {
+            push_credential(inventory, credential, VkeySource::Certificate(index));
+        }
+    }
+}
+
+fn collect_withdrawal_requirements(
+    tx: &Tx,
+    inventory: &mut WitnessInventory,
+) -> Result<(), WError> {
+    let withdrawals = match &tx.transaction_body.withdrawals {
+        Some(withdrawals) => withdrawals,
+        None => return Ok(()),
+    };
+    for (reward_address, _) in withdrawals.iter() {
+        let reward_account = RewardAccount::from_bytes(&reward_address.to_vec())?;
+        let address = reward_account.to_bech32().map_err(|e| {
+            WError::new(
+                "WhiskyPallas - witness report - withdrawals:",
+                &format!("Failed to convert reward address to bech32: {:?}", e),
+            )
+        })?;
+        let stake_credential = reward_account.to_stake_cred()?;
+        push_credential(
+            inventory,
+            &stake_credential.inner,
+            VkeySource::Withdrawal(address),
+        );
+    }
+    Ok(())
+}
+
+fn collect_vote_requirements(tx: &Tx, inventory: &mut WitnessInventory) {
+    let votes = match &tx.transaction_body.voting_procedures {
+        Some(votes) => votes,
+        None => return,
+    };
+    for (index, (voter, _)) in votes.iter().enumerate() {
+        match voter {
+            Voter::ConstitutionalCommitteeKey(key_hash)
+            | Voter::DRepKey(key_hash)
+            | Voter::StakePoolKey(key_hash) => inventory
+                .required_vkeys
+                .push((key_hash.to_string(), VkeySource::Vote(index))),
+            Voter::ConstitutionalCommitteeScript(script_hash) | Voter::DRepScript(script_hash) => {
+                inventory.required_scripts.push(script_hash.to_string())
+            }
+        }
+    }
+}
+
+fn collect_witness_set(
+    tx: &Tx,
+    context: &ParserContext,
+    inventory: &mut WitnessInventory,
+) -> Result<(), WError> {
+    let witness_set = &tx.transaction_witness_set;
+    let body_hash = tx.transaction_body.compute_hash();
+
+    if let Some(vkey_witnesses) = &witness_set.vkeywitness {
+        for vkey_witness in vkey_witnesses.iter() {
+            let vkey = vkey_witness.vkey.to_vec();
+            let valid = match (
+                <[u8; 32]>::try_from(vkey.clone()),
+                <[u8; 64]>::try_from(vkey_witness.signature.to_vec()),
+            ) {
+                (Ok(vkey), Ok(signature)) => {
+                    PublicKey::from(vkey).verify(body_hash, &Signature::from(signature))
+                }
+                _ => false,
+            };
+            inventory.vkey_witnesses.push(VkeyWitnessCheck {
+                vkey: hex::encode(&vkey),
+                key_hash: Hasher::<224>::hash(&vkey).to_string(),
+                valid,
+            });
+        }
+    }
+
+    if let Some(native_scripts) = &witness_set.native_script {
+        for native_script in native_scripts.iter() {
+            let script_hash = native_script.compute_hash().to_string();
+            let mut signers = vec![];
+            native_script_signers(native_script, &mut signers);
+            for signer in signers {
+                inventory
+                    .required_vkeys
+                    .push((signer, VkeySource::NativeScript(script_hash.clone())));
+            }
+            inventory.witness_scripts.push(script_hash);
+        }
+    }
+    if let Some(scripts) = &witness_set.plutus_v1_script {
+        for script in scripts.iter() {
+            inventory
+                .witness_scripts
+                .push(script.compute_hash().to_string());
+        }
+    }
+    if let Some(scripts) = &witness_set.plutus_v2_script {
+        for script in scripts.iter() {
+            inventory
+                .witness_scripts
+                .push(script.compute_hash().to_string());
+        }
+    }
+    if let Some(scripts) = &witness_set.plutus_v3_script {
+        for script in scripts.iter() {
+            inventory
+                .witness_scripts
+                .push(script.compute_hash().to_string());
+        }
+    }
+
+    for (script_hash, script) in &context.script_witnesses.scripts {
+        if let Script::ReferencedNative(_) | Script::ReferencedPlutus(_) = script {
+            inventory.reference_scripts.push(script_hash.clone());
+        }
+    }
+
+    if let Some(datums) = &witness_set.plutus_data {
+        for datum in datums.iter() {
+            inventory
+                .witness_datums
+                .push(datum.compute_hash().to_string());
+        }
+    }
+
+    for output in extract_outputs(tx)? {
+        if let Some(Datum::Hash(data_hash)) = output.datum {
+            inventory.allowed_datums.push(data_hash);
+        }
+    }
+    if let Some(reference_inputs) = &tx.transaction_body.reference_inputs {
+        for input in reference_inputs.iter() {
+            if let Some(data_hash) = context
+                .resolved_utxos
+                .get(input)
+                .and_then(|utxo| utxo.output.data_hash.clone())
+            {
+                inventory.allowed_datums.push(data_hash);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_script_data_hash(
+    tx: &Tx,
+    context: &ParserContext,
+    inventory: &WitnessInventory,
+    network: &Network,
+) -> ScriptDataHashCheck {
+    let body = tx
+        .transaction_body
+        .script_data_hash
+        .map(|hash| hash.to_string());
+
+    // Only the cost models of the languages actually executed are part of the hash
+    let mut plutus_versions: Vec<u8> = inventory
+        .required_scripts
+        .iter()
+        .filter_map(
+            |script_hash| match context.script_witnesses.scripts.get(script_hash) {
+                Some(Script::ProvidedPlutus(script)) => Some(&script.language_version),
+                Some(Script::ReferencedPlutus(script)) => Some(&script.language_version),
+                _ => None,
+            },
+        )
+        .map(|language_version| match language_version {
+            LanguageVersion::V1 => 0,
+            LanguageVersion::V2 => 1,
+            LanguageVersion::V3 => 2,
+        })
+        .collect();
+    plutus_versions.sort();
+    plutus_versions.dedup();
+    let cost_models = get_cost_models_from_network(network);
+    let language_views: Vec<LanguageView> = plutus_versions
+        .into_iter()
+        .filter_map(|version| {
+            cost_models
+                .get(version as usize)
+                .map(|cost_model| LanguageView(version, cost_model.clone()))
+        })
+        .collect();
+
+    let computed = ScriptData::build_for(&tx.transaction_witness_set, &None)
+        .map(|script_data| script_data_hash(&script_data, &language_views));
+    ScriptDataHashCheck { body, computed }
+}
+
+/// The hash of the redeemers, datums and language views, as `ScriptData::hash` only takes a
+/// single language view
+fn script_data_hash(script_data: &ScriptData, language_views: &[LanguageView]) -> String {
+    let mut buf = vec![];
+    match &script_data.redeemers {
+        Some(redeemers) => minicbor::encode(redeemers, &mut buf).unwrap(),
+        None => buf.push(0xa0),
+    }
+    if let Some(datums) = &script_data.datums {
+        minicbor::encode(datums, &mut buf).unwrap();
+    }
+    if script_data.redeemers.is_none() {
+        buf.push(0xa0);
+    } else {
+        // Canonical map order puts the one byte keys of V2 and V3 before the byte string key
+        // of V1
+        let mut language_views: Vec<&LanguageView> = language_views.iter().collect();
+        language_views.sort_by_key(|view| (view.0 == 0, view.0));
+        buf.push(0xa0 + language_views.len() as u8);
+        for language_view in language_views {
+            // Drop the header of the single entry map each language view encodes to
+            let entry = minicbor::to_vec(language_view).unwrap();
+            buf.extend_from_slice(&entry[1..]);
+        }
+    }
+    Hasher::<256>::hash(&buf).to_string()
+}
+
+fn native_script_signers(native_script: &NativeScript, signers: &mut Vec<String>) {
+    match native_script {
+        NativeScript::ScriptPubkey(key_hash) => signers.push(key_hash.to_string()),
+        NativeScript::ScriptAll(scripts)
+        | NativeScript::ScriptAny(scripts)
+        | NativeScript::ScriptNOfK(_, scripts) => {
+            for script in scripts {
+                native_script_signers(script, signers);
+            }
+        }
+        NativeScript::InvalidBefore(_) | NativeScript::InvalidHereafter(_) => {}
+    }
+}
+
+fn push_credential(
+    inventory: &mut WitnessInventory,
+    credential: &StakeCredential,
+    source: VkeySource,
+) {
+    match credential {
+        StakeCredential::AddrKeyhash(key_hash) => inventory
+            .required_vkeys
+            .push((key_hash.to_string(), source)),
+        StakeCredential::ScriptHash(script_hash) => {
+            inventory.required_scripts.push(script_hash.to_string())
+        }
+    }
+}
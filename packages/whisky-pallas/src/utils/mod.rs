@@ -2,6 +2,7 @@ pub mod address;
 pub mod constants;
 pub mod evaluator;
 pub mod fee;
+pub mod phase_one;
 pub mod phase_two;
 pub mod required_signatures;
 
@@ -9,5 +10,6 @@ pub use address::*;
 pub use constants::*;
 pub use evaluator::*;
 pub use fee::*;
+pub use phase_one::*;
 pub use phase_two::*;
 pub use required_signatures::*;
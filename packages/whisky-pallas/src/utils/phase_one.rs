@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+
+use pallas::ledger::primitives::{
+    alonzo,
+    babbage::GenTransactionOutput,
+    conway::{Certificate, TransactionInput, TransactionOutput, Tx, Value as PallasValue},
+    Fragment,
+};
+use whisky_common::{
+    data::Value, Network, Phase1Error, Phase1Facts, Protocol, UTxO, UtxoInput, WError,
+};
+
+use crate::{
+    tx_parser::{parse, witness_report},
+    utils::calculate_fee,
+    wrapper::transaction_body::Transaction,
+};
+
+/// Validate a transaction against the phase-1 ledger rules, i.e. value conservation, fee,
+/// minimum output lovelace, size, collateral, validity interval and witnesses. `utxos` must
+/// resolve every spent, collateral and reference input. Pool registrations are assumed to
+/// register new pools, so their deposit is always counted.
+pub fn validate_phase_one(
+    tx_hex: &str,
+    utxos: &[UTxO],
+    protocol: &Protocol,
+    network: &Network,
+    current_slot: u64,
+) -> Result<Vec<Phase1Error>, WError> {
+    let bytes = hex::decode(tx_hex).map_err(|e| {
+        WError::new(
+            "WhiskyPallas - validate phase one:",
+            &format!("Hex decode error: {}", e),
+        )
+    })?;
+    let pallas_tx = Transaction::decode_bytes(&bytes)?;
+    let tx = &pallas_tx.inner;
+    let body = &tx.transaction_body;
+
+    let unresolved = unresolved_inputs(tx, utxos);
+    if !unresolved.is_empty() {
+        return Ok(vec![Phase1Error::BadInputs(unresolved)]);
+    }
+
+    let tx_body = parse(tx_hex, utxos)?;
+    let mut consumed = Value::new();
+    let mut produced = Value::new();
+    for input in body.inputs.iter() {
+        if let Some(utxo) = find_utxo(utxos, &to_utxo_input(input)) {
+            consumed.add_assets(&utxo.output.amount);
+        }
+    }
+    for output in &tx_body.outputs {
+        produced.add_assets(&output.amount);
+    }
+    for mint in &tx_body.mints {
+        let mint = mint.get_mint_parameter();
+        let unit = format!("{}{}", mint.policy_id, mint.asset_name);
+        if mint.amount > 0 {
+            consumed.add_asset(&unit, mint.amount as u64);
+        } else {
+            produced.add_asset(&unit, mint.amount.unsigned_abs() as u64);
+        }
+    }
+    let (deposit, refund) = certificate_deposits(tx, protocol);
+    let withdrawals: u64 = body
+        .withdrawals
+        .iter()
+        .flat_map(|withdrawals| withdrawals.iter())
+        .map(|(_, amount)| *amount)
+        .sum();
+    let proposal_deposits: u64 = body
+        .proposal_procedures
+        .iter()
+        .flat_map(|proposals| proposals.iter())
+        .map(|proposal| proposal.deposit)
+        .sum();
+    consumed.add_asset("lovelace", withdrawals + refund);
+    produced.add_asset(
+        "lovelace",
+        body.fee + deposit + proposal_deposits + body.donation.map(u64::from).unwrap_or(0),
+    );
+
+    let mut outputs: Vec<(u64, u64)> = vec![];
+    for output in body.outputs.iter() {
+        let size = output.encode_fragment().map_err(|e| {
+            WError::new(
+                "WhiskyPallas - validate phase one:",
+                &format!("Failed to encode output: {:?}", e),
+            )
+        })?;
+        outputs.push((
+            output_lovelace(output),
+            (160 + size.len() as u64) * protocol.coins_per_utxo_size,
+        ));
+    }
+
+    let mut collateral: u64 = body
+        .collateral
+        .iter()
+        .flat_map(|collateral| collateral.iter())
+        .filter_map(|input| find_utxo(utxos, &to_utxo_input(input)))
+        .map(|utxo| Value::from_asset_vec(&utxo.output.amount).get("lovelace"))
+        .sum();
+    if let Some(collateral_return) = &body.collateral_return {
+        collateral = collateral.saturating_sub(output_lovelace(collateral_return));
+    }
+
+    let facts = Phase1Facts {
+        tx_size: bytes.len() as u64,
+        fee: body.fee,
+        min_fee: calculate_fee(
+            pallas_tx.clone(),
+            ref_script_size(tx, utxos),
+            protocol.clone(),
+        )?,
+        consumed,
+        produced,
+        outputs,
+        has_redeemers: tx.transaction_witness_set.redeemer.is_some(),
+        collateral_inputs: body
+            .collateral
+            .as_ref()
+            .map_or(0, |collateral| collateral.len() as u64),
+        collateral,
+        total_collateral: body.total_collateral,
+        invalid_before: tx_body.validity_range.invalid_before,
+        invalid_hereafter: tx_body.validity_range.invalid_hereafter,
+        witness_report: witness_report(tx_hex, utxos, network)?,
+    };
+    Ok(facts.validate(protocol, current_slot))
+}
+
+fn to_utxo_input(input: &TransactionInput) -> UtxoInput {
+    UtxoInput {
+        tx_hash: input.transaction_id.to_string(),
+        output_index: input.index as u32,
+    }
+}
+
+fn find_utxo<'a>(utxos: &'a [UTxO], input: &UtxoInput) -> Option<&'a UTxO> {
+    utxos.iter().find(|utxo| &utxo.input == input)
+}
+
+fn unresolved_inputs(tx: &Tx, utxos: &[UTxO]) -> Vec<UtxoInput> {
+    let body = &tx.transaction_body;
+    let mut unresolved: Vec<UtxoInput> = vec![];
+    let inputs = body
+        .inputs
+        .iter()
+        .chain(body.collateral.iter().flat_map(|inputs| inputs.iter()))
+        .chain(
+            body.reference_inputs
+                .iter()
+                .flat_map(|inputs| inputs.iter()),
+        );
+    for input in inputs.map(to_utxo_input) {
+        if find_utxo(utxos, &input).is_none() && !unresolved.contains(&input) {
+            unresolved.push(input);
+        }
+    }
+    unresolved
+}
+
+/// The size of the reference scripts of spent and referenced UTxOs. Inputs that are also
+/// referenced only count once.
+fn ref_script_size(tx: &Tx, utxos: &[UTxO]) -> usize {
+    let body = &tx.transaction_body;
+    let inputs: HashSet<UtxoInput> = body
+        .inputs
+        .iter()
+        .chain(
+            body.reference_inputs
+                .iter()
+                .flat_map(|inputs| inputs.iter()),
+        )
+        .map(to_utxo_input)
+        .collect();
+    inputs
+        .iter()
+        .filter_map(|input| find_utxo(utxos, input))
+        .filter_map(|utxo| utxo.output.script_ref.as_ref())
+        .map(|script_ref| script_ref.len() / 2)
+        .sum()
+}
+
+/// The deposits paid and refunds claimed by the certificates, in that order
+fn certificate_deposits(tx: &Tx, protocol: &Protocol) -> (u64, u64) {
+    let mut deposit: u64 = 0;
+    let mut refund: u64 = 0;
+    let certs = tx
+        .transaction_body
+        .certificates
+        .iter()
+        .flat_map(|certs| certs.iter());
+    for cert in certs {
+        match cert {
+            Certificate::StakeRegistration(_) => deposit += protocol.key_deposit,
+            Certificate::StakeDeregistration(_) => refund += protocol.key_deposit,
+            Certificate::Reg(_, coin)
+            | Certificate::StakeRegDeleg(_, _, coin)
+            | Certificate::VoteRegDeleg(_, _, coin)
+            | Certificate::StakeVoteRegDeleg(_, _, _, coin)
+            | Certificate::RegDRepCert(_, coin, _) => deposit += coin,
+            Certificate::UnReg(_, coin) | Certificate::UnRegDRepCert(_, coin) => refund += coin,
+            Certificate::PoolRegistration { .. } => deposit += protocol.pool_deposit,
+            _ => {}
+        }
+    }
+    (deposit, refund)
+}
+
+fn output_lovelace(output: &TransactionOutput<'_>) -> u64 {
+    match output {
+        GenTransactionOutput::Legacy(output) => match &output.amount {
+            alonzo::Value::Coin(coin) | alonzo::Value::Multiasset(coin, _) => *coin,
+        },
+        GenTransactionOutput::PostAlonzo(output) => match &output.value {
+            PallasValue::Coin(coin) | PallasValue::Multiasset(coin, _) => *coin,
+        },
+    }
+}
@@ -1,7 +1,14 @@
 use pallas::{
-    codec::utils::{Bytes, NonEmptySet},
-    ledger::primitives::conway::VKeyWitness,
+    codec::{
+        minicbor,
+        utils::{Bytes, NonEmptySet},
+    },
+    ledger::{
+        addresses::ByronAddress,
+        primitives::conway::{BootstrapWitness, VKeyWitness},
+    },
 };
+use whisky_common::WError;
 
 pub fn required_signatures_to_mock_witnesses(
     required_signatures: Vec<String>,
@@ -25,3 +32,42 @@ pub fn required_signatures_to_mock_witnesses(
 
     Some(NonEmptySet::from_vec(vkey_witnesses).unwrap())
 }
+
+/// Mock bootstrap witnesses for the Byron addresses being spent, one per distinct address.
+///
+/// The attributes of the real witness are copied from the address, since they are part of the
+/// witness size, e.g. the protocol magic of testnet addresses.
+pub fn byron_addresses_to_mock_bootstrap_witnesses(
+    byron_addresses: Vec<String>,
+) -> Result<Option<NonEmptySet<BootstrapWitness>>, WError> {
+    let mut distinct_addresses: Vec<String> = vec![];
+    for address in byron_addresses {
+        if !distinct_addresses.contains(&address) {
+            distinct_addresses.push(address);
+        }
+    }
+    let mut bootstrap_witnesses = Vec::new();
+    for (i, address) in distinct_addresses.iter().enumerate() {
+        let payload = ByronAddress::from_base58(address)
+            .and_then(|byron_address| byron_address.decode())
+            .map_err(|e| {
+                WError::new(
+                    "byron_addresses_to_mock_bootstrap_witnesses",
+                    &format!("Invalid Byron address {}: {}", address, e),
+                )
+            })?;
+        let attributes = minicbor::to_vec(&payload.attributes).map_err(|e| {
+            WError::new(
+                "byron_addresses_to_mock_bootstrap_witnesses",
+                &format!("Failed to encode address attributes: {}", e),
+            )
+        })?;
+        bootstrap_witnesses.push(BootstrapWitness {
+            public_key: Bytes::from(vec![i as u8; 32]), // Mock public key
+            signature: Bytes::from(vec![i as u8; 64]),  // Mock signature
+            chain_code: Bytes::from(vec![i as u8; 32]), // Mock chain code
+            attributes: Bytes::from(attributes),
+        });
+    }
+    Ok(NonEmptySet::from_vec(bootstrap_witnesses))
+}
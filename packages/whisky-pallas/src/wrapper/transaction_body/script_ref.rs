@@ -1,7 +1,5 @@
-use std::str::FromStr;
-
 use pallas::{
-    codec::utils::{Bytes, KeepRaw},
+    codec::utils::KeepRaw,
     ledger::primitives::{
         conway::{NativeScript, ScriptRef as PallasScriptRef},
         Fragment, PlutusScript,
@@ -43,34 +41,58 @@ impl<'a> ScriptRef<'a> {
             }
             ScriptRefKind::PlutusV1Script {
                 plutus_v1_script_hex,
-            } => PallasScriptRef::PlutusV1Script(PlutusScript::<1>(
-                Bytes::from_str(&plutus_v1_script_hex).map_err(|e| {
+            } => {
+                let bytes = hex::decode(plutus_v1_script_hex).map_err(|e| {
                     WError::new(
                         "WhiskyPallas - Creating script ref:",
-                        &format!("Invalid Plutus V1 script bytes: {}", e),
+                        &format!("Hex decode error: {}", e),
                     )
-                })?,
-            )),
+                })?;
+                PallasScriptRef::PlutusV1Script(
+                    PlutusScript::<1>::decode_fragment(&bytes).map_err(|e| {
+                        WError::new(
+                            "WhiskyPallas - Creating script ref:",
+                            &format!("Plutus V1 script fragment decode error: {}", e),
+                        )
+                    })?,
+                )
+            }
             ScriptRefKind::PlutusV2Script {
                 plutus_v2_script_hex,
-            } => PallasScriptRef::PlutusV2Script(PlutusScript::<2>(
-                Bytes::from_str(&plutus_v2_script_hex).map_err(|e| {
+            } => {
+                let bytes = hex::decode(plutus_v2_script_hex).map_err(|e| {
                     WError::new(
                         "WhiskyPallas - Creating script ref:",
-                        &format!("Invalid Plutus V2 script bytes: {}", e),
+                        &format!("Hex decode error: {}", e),
                     )
-                })?,
-            )),
+                })?;
+                PallasScriptRef::PlutusV2Script(
+                    PlutusScript::<2>::decode_fragment(&bytes).map_err(|e| {
+                        WError::new(
+                            "WhiskyPallas - Creating script ref:",
+                            &format!("Plutus V2 script fragment decode error: {}", e),
+                        )
+                    })?,
+                )
+            }
             ScriptRefKind::PlutusV3Script {
                 plutus_v3_script_hex,
-            } => PallasScriptRef::PlutusV3Script(PlutusScript::<3>(
-                Bytes::from_str(&plutus_v3_script_hex).map_err(|e| {
+            } => {
+                let bytes = hex::decode(plutus_v3_script_hex).map_err(|e| {
                     WError::new(
                         "WhiskyPallas - Creating script ref:",
-                        &format!("Invalid Plutus V3 script bytes: {}", e),
+                        &format!("Hex decode error: {}", e),
                     )
-                })?,
-            )),
+                })?;
+                PallasScriptRef::PlutusV3Script(
+                    PlutusScript::<3>::decode_fragment(&bytes).map_err(|e| {
+                        WError::new(
+                            "WhiskyPallas - Creating script ref:",
+                            &format!("Plutus V3 script fragment decode error: {}", e),
+                        )
+                    })?,
+                )
+            }
         };
 
         Ok(Self {
@@ -15,13 +15,10 @@ impl BootstrapWitness {
         attributes: String,
     ) -> Result<Self, String> {
         let inner = PallasBootstrapWitness {
-            public_key: Bytes::from_str(&public_key)
-                .expect("Invalid public key in bootstrap witness"),
-            signature: Bytes::from_str(&signature).expect("Invalid signature in bootstrap witness"),
-            chain_code: Bytes::from_str(&chain_code)
-                .expect("Invalid chain code in bootstrap witness"),
-            attributes: Bytes::from_str(&attributes)
-                .expect("Invalid attributes in bootstrap witness"),
+            public_key: Bytes::from_str(&public_key).map_err(|e| e.to_string())?,
+            signature: Bytes::from_str(&signature).map_err(|e| e.to_string())?,
+            chain_code: Bytes::from_str(&chain_code).map_err(|e| e.to_string())?,
+            attributes: Bytes::from_str(&attributes).map_err(|e| e.to_string())?,
         };
         Ok(Self { inner })
     }
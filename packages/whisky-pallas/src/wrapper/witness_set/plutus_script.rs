@@ -1,6 +1,3 @@
-use std::str::FromStr;
-
-use pallas::codec::utils::Bytes;
 use pallas::ledger::primitives::conway::PlutusScript as PallasPlutusScript;
 use pallas::ledger::primitives::Fragment;
 use whisky_common::WError;
@@ -12,14 +9,13 @@ pub struct PlutusScript<const VERSION: usize> {
 
 impl<const VERSION: usize> PlutusScript<VERSION> {
     pub fn new(script: String) -> Result<Self, WError> {
-        let inner = PallasPlutusScript::<VERSION>(Bytes::from_str(&script).map_err(|e| {
+        let bytes = hex::decode(script).map_err(|e| {
             WError::new(
                 "WhiskyPallas - Creating Plutus script:",
-                &format!("Invalid Plutus script: {}", e.to_string()),
+                &format!("Hex decode error: {}", e),
             )
-        })?);
-
-        Ok(Self { inner })
+        })?;
+        Self::decode_bytes(&bytes)
     }
 
     pub fn encode(&self) -> Result<String, WError> {
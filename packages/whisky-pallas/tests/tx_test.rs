@@ -12,7 +12,10 @@ use whisky_common::{
     ScriptMint, ScriptSource, ScriptTxIn, ScriptTxInParameter, TxBuilderBody, TxIn, TxInParameter,
     ValidityRange,
 };
-use whisky_pallas::{converter::bytes_from_bech32, tx_builder::core_pallas::CorePallas};
+use whisky_pallas::{
+    converter::{address_from_bytes, bytes_from_bech32},
+    tx_builder::core_pallas::CorePallas,
+};
 
 #[test]
 fn test_from_tx_builder_body() {
@@ -128,6 +131,9 @@ fn test_from_tx_builder_body() {
             })),
         ],
         votes: vec![],
+        proposals: vec![],
+        treasury_value: None,
+        donation: None,
         change_address: "addr_test1qzjhvr7xdqmyk6x7ax84rtgs3uasqyrvglz4k08kwhw4q4jp2fnzs02hl5fhjdtw07kkxeyfac0gf9aepnpp4vv3yy2s67j7tj".to_string(),
         fee: None,
         required_signatures: vec![],
@@ -138,6 +144,8 @@ fn test_from_tx_builder_body() {
             invalid_hereafter: None,
         },
         signing_key: vec![],
+        vkey_witnesses: vec![],
+        bootstrap_witnesses: vec![],
         network: Some(whisky_common::Network::Preprod),
         total_collateral: None,
         collateral_return_address: None,
@@ -220,3 +228,75 @@ fn test_from_tx_builder_body() {
         }
     }
 }
+
+fn byron_tx_builder_body(input_address: &str) -> TxBuilderBody {
+    TxBuilderBody {
+        inputs: vec![TxIn::PubKeyTxIn(PubKeyTxIn {
+            tx_in: TxInParameter {
+                tx_hash: "db0937db0e8a743e6e97e8cf29077af1e951b52e46f2e2c63ef12a3abaaf9052"
+                    .to_string(),
+                tx_index: 0,
+                amount: Some(vec![Asset::new_from_str("lovelace", "10000000")]),
+                address: Some(input_address.to_string()),
+            },
+        })],
+        outputs: vec![],
+        collaterals: vec![],
+        reference_inputs: vec![],
+        withdrawals: vec![],
+        mints: vec![],
+        certificates: vec![],
+        votes: vec![],
+        proposals: vec![],
+        treasury_value: None,
+        donation: None,
+        change_address: "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo".to_string(),
+        fee: None,
+        required_signatures: vec![],
+        change_datum: None,
+        metadata: vec![],
+        validity_range: ValidityRange {
+            invalid_before: None,
+            invalid_hereafter: None,
+        },
+        signing_key: vec![],
+        vkey_witnesses: vec![],
+        bootstrap_witnesses: vec![],
+        network: Some(whisky_common::Network::Mainnet),
+        total_collateral: None,
+        collateral_return_address: None,
+    }
+}
+
+#[test]
+fn test_byron_input_and_change() {
+    let byron_address = "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+    let shelley_address = "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8";
+
+    let byron_tx = CorePallas::new(Protocol::default())
+        .build_tx(byron_tx_builder_body(byron_address), true)
+        .unwrap();
+    let byron_tx_bytes = hex::decode(byron_tx).unwrap();
+    let byron_tx = Tx::decode_fragment(&byron_tx_bytes).unwrap();
+    let change_output = &byron_tx.transaction_body.outputs[0];
+    let change_address_bytes = match change_output {
+        pallas::ledger::primitives::babbage::GenTransactionOutput::Legacy(output) => {
+            output.address.to_string()
+        }
+        pallas::ledger::primitives::babbage::GenTransactionOutput::PostAlonzo(output) => {
+            output.address.to_string()
+        }
+    };
+    assert_eq!(
+        address_from_bytes(&change_address_bytes).unwrap(),
+        byron_address
+    );
+
+    let shelley_tx = CorePallas::new(Protocol::default())
+        .build_tx(byron_tx_builder_body(shelley_address), true)
+        .unwrap();
+    let shelley_tx_bytes = hex::decode(shelley_tx).unwrap();
+    let shelley_tx = Tx::decode_fragment(&shelley_tx_bytes).unwrap();
+    // The bootstrap witness carries the chain code and address attributes on top of a vkey witness
+    assert!(byron_tx.transaction_body.fee > shelley_tx.transaction_body.fee);
+}
@@ -2,4 +2,6 @@ mod wallet;
 pub use wallet::*;
 mod encryption;
 pub use encryption::*;
+mod signer;
+pub use signer::*;
 pub mod wallet_constants;
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use whisky_common::{Signer, VkeyWitness, WError};
+use whisky_csl::csl::{make_vkey_witness, PrivateKey, TransactionHash};
+
+use crate::{SigningKey, Wallet};
+
+/// A signer holding the private keys in process.
+///
+/// It signs in the same way as `Wallet::sign_tx`, and is mostly useful as the key holder
+/// behind a signing daemon, or as a drop-in for remote signers in tests.
+pub struct LocalSigner {
+    pub private_keys: Vec<PrivateKey>,
+}
+
+impl LocalSigner {
+    pub fn new(private_keys: Vec<PrivateKey>) -> Self {
+        Self { private_keys }
+    }
+
+    /// Creates a signer with the key of the wallet's current account.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet` - The wallet to take the signing key from
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signer or an error
+    pub fn from_wallet(wallet: &Wallet) -> Result<Self, WError> {
+        let account = Wallet::get_account(&wallet.wallet_type)
+            .map_err(WError::from_err("LocalSigner - from_wallet"))?;
+        Ok(Self::new(vec![account.private_key]))
+    }
+
+    /// Creates a signer with a single signing key.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_key` - The signing key
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signer or an error
+    pub fn from_signing_key(signing_key: &SigningKey) -> Result<Self, WError> {
+        let private_key = signing_key
+            .to_raw_key()
+            .map_err(WError::from_err("LocalSigner - from_signing_key"))?;
+        Ok(Self::new(vec![private_key]))
+    }
+
+    /// Signs the transaction hash with all keys of the signer, synchronously.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hash` - The transaction body hash in hex
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either one vkey witness per key or an error
+    pub fn sign(&self, tx_hash: &str) -> Result<Vec<VkeyWitness>, WError> {
+        let tx_hash = TransactionHash::from_hex(tx_hash)
+            .map_err(WError::from_err("LocalSigner - sign - invalid tx hash"))?;
+        Ok(self
+            .private_keys
+            .iter()
+            .map(|private_key| {
+                let vkey_witness = make_vkey_witness(&tx_hash, private_key);
+                VkeyWitness {
+                    vkey: vkey_witness.vkey().public_key().to_hex(),
+                    signature: vkey_witness.signature().to_hex(),
+                }
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Signer for LocalSigner {
+    async fn sign_tx_hash(&self, tx_hash: &str) -> Result<Vec<VkeyWitness>, WError> {
+        self.sign(tx_hash)
+    }
+}
@@ -0,0 +1,7 @@
+mod local;
+#[cfg(unix)]
+mod unix_socket;
+
+pub use local::LocalSigner;
+#[cfg(unix)]
+pub use unix_socket::{SignRequest, SignResponse, UnixSocketSigner};
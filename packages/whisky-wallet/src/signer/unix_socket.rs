@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use whisky_common::{Signer, VkeyWitness, WError};
+
+/// The request sent to a signing daemon, as one line of JSON
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignRequest {
+    pub tx_hash: String,
+}
+
+/// The response of a signing daemon, as one line of JSON
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SignResponse {
+    Witnesses(Vec<VkeyWitness>),
+    Error(String),
+}
+
+/// A signer delegating to a signing daemon listening on a Unix socket.
+///
+/// For every transaction, one connection is opened, a `SignRequest` line is written
+/// and a `SignResponse` line is read back. The keys never leave the daemon. The whole
+/// exchange fails once `timeout` elapses, 30 seconds by default.
+pub struct UnixSocketSigner {
+    pub socket_path: PathBuf,
+    pub timeout: Duration,
+}
+
+impl UnixSocketSigner {
+    pub fn new(socket_path: impl Into<PathBuf>) -> Self {
+        Self {
+            socket_path: socket_path.into(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn request(&self, tx_hash: &str) -> Result<Vec<VkeyWitness>, WError> {
+        let mut stream = UnixStream::connect(&self.socket_path)
+            .await
+            .map_err(WError::from_err(
+                "UnixSocketSigner - failed to connect to signer",
+            ))?;
+        let mut request = serde_json::to_string(&SignRequest {
+            tx_hash: tx_hash.to_string(),
+        })
+        .map_err(WError::from_err(
+            "UnixSocketSigner - failed to encode request",
+        ))?;
+        request.push('\n');
+        stream
+            .write_all(request.as_bytes())
+            .await
+            .map_err(WError::from_err(
+                "UnixSocketSigner - failed to send request",
+            ))?;
+
+        let mut line = String::new();
+        BufReader::new(stream)
+            .read_line(&mut line)
+            .await
+            .map_err(WError::from_err(
+                "UnixSocketSigner - failed to read response",
+            ))?;
+        let response: SignResponse = serde_json::from_str(line.trim())
+            .map_err(WError::from_err("UnixSocketSigner - invalid response"))?;
+        match response {
+            SignResponse::Witnesses(vkey_witnesses) => Ok(vkey_witnesses),
+            SignResponse::Error(message) => Err(WError::new(
+                "UnixSocketSigner - signer rejected request",
+                &message,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for UnixSocketSigner {
+    async fn sign_tx_hash(&self, tx_hash: &str) -> Result<Vec<VkeyWitness>, WError> {
+        tokio::time::timeout(self.timeout, self.request(tx_hash))
+            .await
+            .map_err(WError::from_err(
+                "UnixSocketSigner - timed out waiting for signer",
+            ))?
+    }
+}
@@ -0,0 +1,290 @@
+use serde::{Deserialize, Serialize};
+use whisky_common::WError;
+use whisky_csl::csl::{Bip32PrivateKey, PrivateKey, PublicKey};
+
+/// The role of a key in cardano-cli text envelopes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyRole {
+    Payment,
+    Stake,
+}
+
+impl KeyRole {
+    fn prefix(&self) -> &'static str {
+        match self {
+            KeyRole::Payment => "Payment",
+            KeyRole::Stake => "Stake",
+        }
+    }
+}
+
+/// The cardano-cli text envelope wrapping a key, i.e. the content of a `.skey` / `.vkey` file.
+///
+/// ```json
+/// {
+///     "type": "PaymentSigningKeyShelley_ed25519",
+///     "description": "Payment Signing Key",
+///     "cborHex": "5820..."
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextEnvelope {
+    #[serde(rename = "type")]
+    pub envelope_type: String,
+    pub description: String,
+    #[serde(rename = "cborHex")]
+    pub cbor_hex: String,
+}
+
+impl TextEnvelope {
+    pub fn from_json(json: &str) -> Result<Self, WError> {
+        serde_json::from_str(json).map_err(WError::from_err("TextEnvelope - from_json"))
+    }
+
+    pub fn to_json(&self) -> Result<String, WError> {
+        serde_json::to_string_pretty(self).map_err(WError::from_err("TextEnvelope - to_json"))
+    }
+
+    /// Reads a text envelope from a cardano-cli key file, e.g. `payment.skey`
+    pub fn from_file(path: &str) -> Result<Self, WError> {
+        let json = std::fs::read_to_string(path).map_err(WError::from_err(
+            "TextEnvelope - from_file - failed to read file",
+        ))?;
+        Self::from_json(&json)
+    }
+
+    /// Writes the text envelope to a file in the same layout as cardano-cli
+    pub fn to_file(&self, path: &str) -> Result<(), WError> {
+        std::fs::write(path, self.to_json()?).map_err(WError::from_err(
+            "TextEnvelope - to_file - failed to write file",
+        ))
+    }
+
+    /// Returns the key role indicated by the envelope type
+    pub fn key_role(&self) -> Result<KeyRole, WError> {
+        if self.envelope_type.starts_with("Payment") {
+            Ok(KeyRole::Payment)
+        } else if self.envelope_type.starts_with("Stake") {
+            Ok(KeyRole::Stake)
+        } else {
+            Err(WError::new(
+                "TextEnvelope - key_role",
+                &format!("unsupported envelope type: {}", self.envelope_type),
+            ))
+        }
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, WError> {
+        let cbor = hex::decode(&self.cbor_hex)
+            .map_err(WError::from_err("TextEnvelope - invalid cborHex"))?;
+        decode_cbor_bytes(&cbor)
+    }
+}
+
+/// A signing key in any of the formats accepted by cardano-cli and the bech32 key tools.
+///
+/// - `Normal` - 32 bytes ed25519 key (`ed25519_sk`, `PaymentSigningKeyShelley_ed25519`)
+/// - `Extended` - 64 bytes extended ed25519 key without chain code (`ed25519e_sk`), which cannot derive children
+/// - `Bip32` - 96 bytes extended key with chain code (`xprv`, `PaymentExtendedSigningKeyShelley_ed25519_bip32`)
+pub enum SigningKey {
+    Normal(PrivateKey),
+    Extended(PrivateKey),
+    Bip32(Bip32PrivateKey),
+}
+
+impl SigningKey {
+    /// Parses a signing key by detecting its format:
+    /// a text envelope JSON, a bech32 string (`ed25519_sk`, `ed25519e_sk`, `xprv`), or hex.
+    pub fn parse(key: &str) -> Result<Self, WError> {
+        let key = key.trim();
+        if key.starts_with('{') {
+            Self::from_text_envelope(&TextEnvelope::from_json(key)?)
+        } else if ["ed25519_sk1", "ed25519e_sk1", "xprv1"]
+            .iter()
+            .any(|prefix| key.starts_with(prefix))
+        {
+            Self::from_bech32(key)
+        } else {
+            Self::from_hex(key)
+        }
+    }
+
+    pub fn from_text_envelope(envelope: &TextEnvelope) -> Result<Self, WError> {
+        let bytes = envelope.payload()?;
+        match envelope.envelope_type.as_str() {
+            "PaymentSigningKeyShelley_ed25519" | "StakeSigningKeyShelley_ed25519" => {
+                Self::from_bytes(&bytes)
+            }
+            "PaymentExtendedSigningKeyShelley_ed25519_bip32"
+            | "StakeExtendedSigningKeyShelley_ed25519_bip32" => {
+                if bytes.len() != 128 {
+                    return Err(WError::new(
+                        "SigningKey - from_text_envelope",
+                        &format!("expected 128 bytes extended key, got {}", bytes.len()),
+                    ));
+                }
+                Self::from_bytes(&bytes)
+            }
+            other => Err(WError::new(
+                "SigningKey - from_text_envelope",
+                &format!("unsupported envelope type: {}", other),
+            )),
+        }
+    }
+
+    pub fn from_bech32(bech32: &str) -> Result<Self, WError> {
+        if bech32.starts_with("xprv") {
+            let key = Bip32PrivateKey::from_bech32(bech32)
+                .map_err(WError::from_err("SigningKey - from_bech32 - invalid xprv"))?;
+            return Ok(SigningKey::Bip32(key));
+        }
+        let key = PrivateKey::from_bech32(bech32).map_err(WError::from_err(
+            "SigningKey - from_bech32 - invalid private key",
+        ))?;
+        if bech32.starts_with("ed25519e_sk") {
+            Ok(SigningKey::Extended(key))
+        } else {
+            Ok(SigningKey::Normal(key))
+        }
+    }
+
+    /// Parses a hex encoded key, with or without the CBOR bytes header from a `cborHex` field.
+    ///
+    /// The key length decides the format: 32 bytes normal key, 64 bytes extended key,
+    /// 96 bytes extended key with chain code, or 128 bytes cardano-cli extended key (key, public key, chain code).
+    pub fn from_hex(key_hex: &str) -> Result<Self, WError> {
+        let bytes = hex::decode(key_hex)
+            .map_err(WError::from_err("SigningKey - from_hex - invalid hex"))?;
+        let bytes = match bytes.len() {
+            32 | 64 | 96 | 128 => bytes,
+            _ => decode_cbor_bytes(&bytes)?,
+        };
+        Self::from_bytes(&bytes)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, WError> {
+        match bytes.len() {
+            32 => Ok(SigningKey::Normal(
+                PrivateKey::from_normal_bytes(bytes)
+                    .map_err(WError::from_err("SigningKey - invalid normal key bytes"))?,
+            )),
+            64 => Ok(SigningKey::Extended(
+                PrivateKey::from_extended_bytes(bytes)
+                    .map_err(WError::from_err("SigningKey - invalid extended key bytes"))?,
+            )),
+            96 => Ok(SigningKey::Bip32(
+                Bip32PrivateKey::from_bytes(bytes)
+                    .map_err(WError::from_err("SigningKey - invalid bip32 key bytes"))?,
+            )),
+            128 => Ok(SigningKey::Bip32(
+                Bip32PrivateKey::from_128_xprv(bytes)
+                    .map_err(WError::from_err("SigningKey - invalid 128 bytes xprv"))?,
+            )),
+            len => Err(WError::new(
+                "SigningKey - from_bytes",
+                &format!("unsupported key length: {} bytes", len),
+            )),
+        }
+    }
+
+    /// Returns the raw key used for signing
+    pub fn to_raw_key(&self) -> Result<PrivateKey, WError> {
+        match self {
+            SigningKey::Normal(key) => PrivateKey::from_normal_bytes(&key.as_bytes())
+                .map_err(WError::from_err("SigningKey - to_raw_key")),
+            SigningKey::Extended(key) => PrivateKey::from_extended_bytes(&key.as_bytes())
+                .map_err(WError::from_err("SigningKey - to_raw_key")),
+            SigningKey::Bip32(key) => Ok(key.to_raw_key()),
+        }
+    }
+
+    pub fn to_public(&self) -> PublicKey {
+        match self {
+            SigningKey::Normal(key) | SigningKey::Extended(key) => key.to_public(),
+            SigningKey::Bip32(key) => key.to_public().to_raw_key(),
+        }
+    }
+
+    /// Exports the key in bech32, `ed25519_sk` for normal keys, `ed25519e_sk` for extended keys and `xprv` for bip32 keys
+    pub fn to_bech32(&self) -> String {
+        match self {
+            SigningKey::Normal(key) | SigningKey::Extended(key) => key.to_bech32(),
+            SigningKey::Bip32(key) => key.to_bech32(),
+        }
+    }
+
+    /// Exports the raw key bytes in hex, 32 bytes for normal keys, 64 bytes for extended keys and 96 bytes for bip32 keys
+    pub fn to_hex(&self) -> String {
+        match self {
+            SigningKey::Normal(key) | SigningKey::Extended(key) => hex::encode(key.as_bytes()),
+            SigningKey::Bip32(key) => hex::encode(key.as_bytes()),
+        }
+    }
+
+    /// Exports the signing key as a cardano-cli text envelope.
+    ///
+    /// Bip32 keys are exported as `*ExtendedSigningKeyShelley_ed25519_bip32`. Extended keys without
+    /// chain code have no cardano-cli envelope and are refused, export them with `to_bech32` or `to_hex`.
+    pub fn to_text_envelope(&self, role: KeyRole) -> Result<TextEnvelope, WError> {
+        let (envelope_type, payload) = match self {
+            SigningKey::Normal(key) => (
+                format!("{}SigningKeyShelley_ed25519", role.prefix()),
+                key.as_bytes(),
+            ),
+            SigningKey::Extended(_) => {
+                return Err(WError::new(
+                    "SigningKey - to_text_envelope",
+                    "extended key has no chain code for a bip32 envelope",
+                ))
+            }
+            SigningKey::Bip32(key) => (
+                format!("{}ExtendedSigningKeyShelley_ed25519_bip32", role.prefix()),
+                key.to_128_xprv(),
+            ),
+        };
+        Ok(TextEnvelope {
+            envelope_type,
+            description: format!("{} Signing Key", role.prefix()),
+            cbor_hex: encode_cbor_bytes(&payload),
+        })
+    }
+
+    /// Exports the verification key of this signing key as a cardano-cli text envelope
+    pub fn to_verification_key_envelope(&self, role: KeyRole) -> TextEnvelope {
+        TextEnvelope {
+            envelope_type: format!("{}VerificationKeyShelley_ed25519", role.prefix()),
+            description: format!("{} Verification Key", role.prefix()),
+            cbor_hex: encode_cbor_bytes(&self.to_public().as_bytes()),
+        }
+    }
+}
+
+fn encode_cbor_bytes(bytes: &[u8]) -> String {
+    let header = match bytes.len() {
+        len @ 0..=23 => vec![0x40 + len as u8],
+        len @ 24..=255 => vec![0x58, len as u8],
+        len => vec![0x59, (len >> 8) as u8, len as u8],
+    };
+    format!("{}{}", hex::encode(header), hex::encode(bytes))
+}
+
+fn decode_cbor_bytes(cbor: &[u8]) -> Result<Vec<u8>, WError> {
+    let (len, offset) = match cbor.first() {
+        Some(&b) if (0x40..=0x57).contains(&b) => ((b - 0x40) as usize, 1),
+        Some(0x58) if cbor.len() >= 2 => (cbor[1] as usize, 2),
+        Some(0x59) if cbor.len() >= 3 => (((cbor[1] as usize) << 8) | cbor[2] as usize, 3),
+        _ => {
+            return Err(WError::new(
+                "decode_cbor_bytes",
+                "expected a CBOR byte string",
+            ))
+        }
+    };
+    if cbor.len() != offset + len {
+        return Err(WError::new(
+            "decode_cbor_bytes",
+            "CBOR byte string length mismatch",
+        ));
+    }
+    Ok(cbor[offset..].to_vec())
+}
@@ -45,6 +45,37 @@ impl DerivationIndices {
         ])
     }
 
+    pub fn committee_cold(account_index: u32, key_index: u32) -> Self {
+        DerivationIndices(vec![
+            HARDENED_KEY_START + 1852,          // purpose
+            HARDENED_KEY_START + 1815,          // coin type
+            HARDENED_KEY_START + account_index, // account
+            4,                                  // committee cold
+            key_index,                          // key index
+        ])
+    }
+
+    pub fn committee_hot(account_index: u32, key_index: u32) -> Self {
+        DerivationIndices(vec![
+            HARDENED_KEY_START + 1852,          // purpose
+            HARDENED_KEY_START + 1815,          // coin type
+            HARDENED_KEY_START + account_index, // account
+            5,                                  // committee hot
+            key_index,                          // key index
+        ])
+    }
+
+    /// Icarus style derivation path of Byron addresses, e.g. the legacy Yoroi wallets
+    pub fn byron(account_index: u32, key_index: u32) -> Self {
+        DerivationIndices(vec![
+            HARDENED_KEY_START + 44,            // purpose
+            HARDENED_KEY_START + 1815,          // coin type
+            HARDENED_KEY_START + account_index, // account
+            0,                                  // payment
+            key_index,                          // key index
+        ])
+    }
+
     pub fn from_str(derivation_path_str: &str) -> Self {
         let derivation_path_vec: Vec<&str> = derivation_path_str.split('/').collect();
         let derivation_path_vec_u32: Vec<u32> = derivation_path_vec
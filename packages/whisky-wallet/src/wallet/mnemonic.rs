@@ -20,4 +20,14 @@ impl MnemonicWallet {
         self.derivation_indices = DerivationIndices::drep(account_index, key_index);
         self
     }
+
+    pub fn committee_cold_account(&mut self, account_index: u32, key_index: u32) -> &mut Self {
+        self.derivation_indices = DerivationIndices::committee_cold(account_index, key_index);
+        self
+    }
+
+    pub fn committee_hot_account(&mut self, account_index: u32, key_index: u32) -> &mut Self {
+        self.derivation_indices = DerivationIndices::committee_hot(account_index, key_index);
+        self
+    }
 }
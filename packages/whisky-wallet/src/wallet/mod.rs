@@ -1,19 +1,26 @@
+pub mod cli_key;
 pub mod derivation_indices;
 pub mod mnemonic;
+pub mod multisig;
 pub mod root_key;
 
 use bip39::{Language, Mnemonic};
+pub use cli_key::{KeyRole, SigningKey, TextEnvelope};
 use derivation_indices::DerivationIndices;
 pub use mnemonic::MnemonicWallet;
+pub use multisig::{MultisigSignatureStatus, MultisigWallet};
 pub use root_key::RootKeyWallet;
-use whisky_common::{Fetcher, Submitter, UTxO, WError};
-use whisky_csl::{
-    csl::{
-        BaseAddress, Bip32PrivateKey, Credential, EnterpriseAddress, FixedTransaction, PrivateKey,
-        PublicKey,
-    },
-    sign_transaction,
+use whisky_common::{
+    Credential as WCredential, Fetcher, GovernanceId, GovernanceIdKind, Signer, Submitter, UTxO,
+    WError,
 };
+use whisky_csl::csl::{
+    BaseAddress, Bip32PrivateKey, ByronAddress, Credential, EnterpriseAddress, FixedTransaction,
+    NetworkInfo, PrivateKey, PublicKey,
+};
+use whisky_csl::{add_vkey_witnesses, calculate_tx_hash};
+
+use crate::wallet_constants::HARDENED_KEY_START;
 
 #[derive(Copy, Clone)]
 pub enum NetworkId {
@@ -24,6 +31,7 @@ pub enum NetworkId {
 pub enum AddressType {
     Enterprise,
     Payment,
+    Byron,
 }
 
 pub enum WalletType {
@@ -43,11 +51,13 @@ pub struct Wallet {
     pub addresses: Addresses,
     pub fetcher: Option<Box<dyn Fetcher>>,
     pub submitter: Option<Box<dyn Submitter>>,
+    pub signer: Option<Box<dyn Signer>>,
     pub account: Option<Account>,
 }
 pub struct Addresses {
     pub base_address: Option<BaseAddress>,
     pub enterprise_address: Option<EnterpriseAddress>,
+    pub byron_address: Option<ByronAddress>,
 }
 
 pub struct Account {
@@ -83,9 +93,11 @@ impl Wallet {
             addresses: Addresses {
                 base_address: None,
                 enterprise_address: None,
+                byron_address: None,
             },
             fetcher: None,
             submitter: None,
+            signer: None,
             account: None,
         }
     }
@@ -114,9 +126,14 @@ impl Wallet {
 
     /// Creates a new CLI-based wallet using the provided signing key.
     ///
+    /// The signing key can be given in any of the formats below:
+    /// - hex of a 32 bytes normal key or a 64 bytes extended key, with or without the `5820` / `5840` CBOR prefix
+    /// - a cardano-cli text envelope JSON, e.g. the content of `payment.skey`
+    /// - a bech32 key with `ed25519_sk`, `ed25519e_sk` or `xprv` prefix
+    ///
     /// # Arguments
     ///
-    /// * `cli_skey` - The signing key string in hex format
+    /// * `cli_skey` - The signing key string
     ///
     /// # Returns
     ///
@@ -132,6 +149,21 @@ impl Wallet {
         Ok(wallet)
     }
 
+    /// Creates a new CLI-based wallet from a cardano-cli signing key file.
+    ///
+    /// # Arguments
+    ///
+    /// * `skey_path` - The path to the `.skey` text envelope file
+    ///
+    /// # Returns
+    ///
+    /// A new `Wallet` instance
+    pub fn new_cli_file(skey_path: &str) -> Result<Self, WError> {
+        let envelope = TextEnvelope::from_file(skey_path)
+            .map_err(WError::from_err("Wallet - new_cli_file"))?;
+        Self::new_cli(&envelope.to_json()?)
+    }
+
     /// Creates a new mnemonic-based wallet using the provided mnemonic phrase.
     ///
     /// # Arguments
@@ -228,6 +260,23 @@ impl Wallet {
         self
     }
 
+    /// Attaches a signer implementation to the wallet.
+    ///
+    /// A signer holds the keys outside of the wallet, e.g. in an HSM or a signing daemon,
+    /// and is used by `sign_tx_async` in place of the wallet's own account.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - The signer implementation to use
+    ///
+    /// # Returns
+    ///
+    /// The updated wallet with signer capability
+    pub fn with_signer<S: Signer + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(Box::new(signer));
+        self
+    }
+
     /// Sets the payment account indices for the wallet.
     ///
     /// This updates the derivation path for the payment address.
@@ -333,6 +382,76 @@ impl Wallet {
         Ok(self)
     }
 
+    /// Sets the constitutional committee cold key account indices for the wallet.
+    ///
+    /// This updates the derivation path for signing with the committee cold key.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_index` - The account index to use
+    /// * `key_index` - The key index to use
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn committee_cold_account(
+        &mut self,
+        account_index: u32,
+        key_index: u32,
+    ) -> Result<&mut Self, WError> {
+        match &mut self.wallet_type {
+            WalletType::MnemonicWallet(mnemonic_wallet) => {
+                mnemonic_wallet.committee_cold_account(account_index, key_index);
+            }
+            WalletType::RootKeyWallet(root_key_wallet) => {
+                root_key_wallet.committee_cold_account(account_index, key_index);
+            }
+            _ => {}
+        }
+        self.account = Some(
+            Self::get_account(&self.wallet_type).map_err(WError::from_err(
+                "Wallet - committee_cold_account - failed to get account",
+            ))?,
+        );
+        self.init_addresses();
+        Ok(self)
+    }
+
+    /// Sets the constitutional committee hot key account indices for the wallet.
+    ///
+    /// This updates the derivation path for signing votes with the committee hot key.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_index` - The account index to use
+    /// * `key_index` - The key index to use
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to self for method chaining
+    pub fn committee_hot_account(
+        &mut self,
+        account_index: u32,
+        key_index: u32,
+    ) -> Result<&mut Self, WError> {
+        match &mut self.wallet_type {
+            WalletType::MnemonicWallet(mnemonic_wallet) => {
+                mnemonic_wallet.committee_hot_account(account_index, key_index);
+            }
+            WalletType::RootKeyWallet(root_key_wallet) => {
+                root_key_wallet.committee_hot_account(account_index, key_index);
+            }
+            _ => {}
+        }
+        self.account = Some(
+            Self::get_account(&self.wallet_type).map_err(WError::from_err(
+                "Wallet - committee_hot_account - failed to get account",
+            ))?,
+        );
+        self.init_addresses();
+        Ok(self)
+    }
+
     /// Initializes or re-initializes wallet addresses based on the wallet type and current network ID.
     ///
     /// This method generates base and enterprise addresses for mnemonic and root key wallets.
@@ -405,11 +524,24 @@ impl Wallet {
             WalletType::Cli(_private_key) => Addresses {
                 base_address: None,
                 enterprise_address: None,
+                byron_address: None,
             },
         };
+        let protocol_magic = self.protocol_magic();
+        self.addresses.byron_address = Self::get_byron_signing_key(&self.wallet_type)
+            .ok()
+            .map(|byron_key| ByronAddress::icarus_from_key(&byron_key.to_public(), protocol_magic));
         self
     }
 
+    // The protocol magic embedded in Byron addresses of the network
+    fn protocol_magic(&self) -> u32 {
+        match self.network_id {
+            NetworkId::Mainnet => NetworkInfo::mainnet().protocol_magic(),
+            NetworkId::Preprod => NetworkInfo::testnet_preprod().protocol_magic(),
+        }
+    }
+
     /// Helper method to create addresses from payment and stake credentials.
     /// This reduces code duplication between wallet types.
     fn create_addresses(
@@ -427,74 +559,254 @@ impl Wallet {
                 self.network_id as u8,
                 &payment_credential,
             )),
+            byron_address: None,
         }
     }
 
     pub fn sign_tx(&self, tx_hex: &str) -> Result<String, WError> {
-        match &self.wallet_type {
-            WalletType::Cli(cli_skey) => {
-                let signed_tx = sign_transaction(tx_hex, &[cli_skey])
-                    .map_err(WError::from_err("Wallet - sign_tx"))?;
-                Ok(signed_tx)
-            }
-            _ => {
-                let account = self.account.as_ref().ok_or_else(WError::from_opt(
-                    "Wallet - sign_tx",
-                    "get account from wallet",
-                ))?;
-                let signed_tx = account
-                    .sign_transaction(tx_hex)
-                    .map_err(WError::from_err("Wallet - sign_tx"))?;
-                Ok(signed_tx.to_string())
-            }
-        }
+        let account = self.account.as_ref().ok_or_else(WError::from_opt(
+            "Wallet - sign_tx",
+            "get account from wallet",
+        ))?;
+        let signed_tx = account
+            .sign_transaction(tx_hex)
+            .map_err(WError::from_err("Wallet - sign_tx"))?;
+        Ok(signed_tx.to_string())
+    }
+
+    /// Signs a transaction with the attached signer, or with the wallet's account if no signer is attached.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The transaction to sign in hexadecimal format
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signed transaction in hexadecimal format or an error
+    pub async fn sign_tx_async(&self, tx_hex: &str) -> Result<String, WError> {
+        let signer = match &self.signer {
+            Some(signer) => signer,
+            None => return self.sign_tx(tx_hex),
+        };
+        let tx_hash =
+            calculate_tx_hash(tx_hex).map_err(WError::from_err("Wallet - sign_tx_async"))?;
+        let vkey_witnesses = signer
+            .sign_tx_hash(&tx_hash)
+            .await
+            .map_err(WError::add_err_trace("Wallet - sign_tx_async"))?;
+        add_vkey_witnesses(tx_hex, &vkey_witnesses)
+            .map_err(WError::from_err("Wallet - sign_tx_async"))
+    }
+
+    /// Signs a transaction spending from the wallet's Byron address, by adding an Icarus bootstrap witness.
+    ///
+    /// Transactions spending from both Byron and Shelley addresses of the wallet should be signed
+    /// with both `sign_tx` and `sign_tx_bootstrap`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The transaction to sign in hexadecimal format
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signed transaction in hexadecimal format or an error
+    pub fn sign_tx_bootstrap(&self, tx_hex: &str) -> Result<String, WError> {
+        let byron_address = self
+            .addresses
+            .byron_address
+            .as_ref()
+            .ok_or_else(WError::from_opt(
+                "Wallet - sign_tx_bootstrap",
+                "get Byron address from wallet",
+            ))?;
+        let byron_key = Self::get_byron_signing_key(&self.wallet_type)
+            .map_err(WError::from_err("Wallet - sign_tx_bootstrap"))?;
+        let mut tx = FixedTransaction::from_hex(tx_hex).map_err(WError::from_err(
+            "Wallet - sign_tx_bootstrap - failed to deserialize tx hex",
+        ))?;
+        tx.sign_and_add_icarus_bootstrap_signature(byron_address, &byron_key)
+            .map_err(WError::from_err(
+                "Wallet - sign_tx_bootstrap - failed to sign transaction",
+            ))?;
+        Ok(tx.to_hex())
     }
 
     pub fn get_account(wallet_type: &WalletType) -> Result<Account, WError> {
-        let private_key: PrivateKey = match wallet_type {
+        let private_key: PrivateKey = Self::get_signing_key(wallet_type)
+            .map_err(WError::from_err("Wallet - get_account"))?
+            .to_raw_key()?;
+        let public_key = private_key.to_public();
+        Ok(Account {
+            private_key,
+            public_key,
+        })
+    }
+
+    /// Gets the signing key of the wallet in a form that can be exported.
+    ///
+    /// Mnemonic and root key wallets return the derived `Bip32` key at the current derivation indices,
+    /// CLI wallets return the parsed key in its original format.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_type` - The wallet type to get the signing key from
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signing key or an error
+    pub fn get_signing_key(wallet_type: &WalletType) -> Result<SigningKey, WError> {
+        match wallet_type {
             WalletType::MnemonicWallet(mnemonic_wallet) => {
                 let mnemonic =
                     Mnemonic::from_phrase(&mnemonic_wallet.mnemonic_phrase, Language::English)
                         .map_err(WError::from_err(
-                            "Wallet - get_account - failed to create mnemonic",
+                            "Wallet - get_signing_key - failed to create mnemonic",
                         ))?;
                 let entropy = mnemonic.entropy();
                 let mut root_key = Bip32PrivateKey::from_bip39_entropy(entropy, &[]);
                 for index in &mnemonic_wallet.derivation_indices.0 {
                     root_key = root_key.derive(index.clone());
                 }
-                root_key.to_raw_key()
+                Ok(SigningKey::Bip32(root_key))
             }
             WalletType::RootKeyWallet(root_key_wallet) => {
                 let mut root_key = Bip32PrivateKey::from_bech32(&root_key_wallet.root_key)
                     .map_err(WError::from_err(
-                        "Wallet - get_account - invalid root key hex",
+                        "Wallet - get_signing_key - invalid root key hex",
                     ))?;
                 for index in &root_key_wallet.derivation_indices.0 {
                     root_key = root_key.derive(index.clone());
                 }
-                root_key.to_raw_key()
+                Ok(SigningKey::Bip32(root_key))
             }
-            WalletType::Cli(private_key) => PrivateKey::from_hex(&private_key).map_err(
-                WError::from_err("Wallet - get_account - invalid private key hex"),
-            )?,
+            WalletType::Cli(private_key) => SigningKey::parse(private_key).map_err(
+                WError::from_err("Wallet - get_signing_key - invalid private key"),
+            ),
+        }
+    }
+
+    /// Gets the Icarus style Byron signing key at `m/44'/1815'/account'/0/key`, using the account and key
+    /// index of the current derivation indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `wallet_type` - The wallet type to get the signing key from, either a mnemonic or root key wallet
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signing key or an error
+    pub fn get_byron_signing_key(wallet_type: &WalletType) -> Result<Bip32PrivateKey, WError> {
+        let (master_key, derivation_indices) = Self::get_master_key(wallet_type)
+            .map_err(WError::from_err("Wallet - get_byron_signing_key"))?;
+        let (Some(account_index), Some(key_index)) =
+            (derivation_indices.0.get(2), derivation_indices.0.get(4))
+        else {
+            return Err(WError::new(
+                "Wallet - get_byron_signing_key",
+                "derivation path too short for an account and key index",
+            ));
         };
-        let public_key = private_key.to_public();
-        Ok(Account {
-            private_key,
-            public_key,
-        })
+        let account_index = account_index % HARDENED_KEY_START;
+        Ok(DerivationIndices::byron(account_index, *key_index)
+            .0
+            .iter()
+            .fold(master_key, |key, index| key.derive(*index)))
+    }
+
+    /// Gets the DRep ID or constitutional committee credential of the wallet, derived at the
+    /// CIP-1852 role of the kind (3 for DRep, 4 for committee cold, 5 for committee hot) with the
+    /// account index of the current derivation indices and key index 0, as specified in CIP-105.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of governance identifier to derive
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the identifier, which encodes to CIP-129 / CIP-105 bech32, or an error
+    pub fn get_governance_id(&self, kind: GovernanceIdKind) -> Result<GovernanceId, WError> {
+        let (master_key, derivation_indices) = Self::get_master_key(&self.wallet_type)
+            .map_err(WError::from_err("Wallet - get_governance_id"))?;
+        let account_index = derivation_indices.0.get(2).ok_or_else(WError::from_opt(
+            "Wallet - get_governance_id",
+            "derivation path too short for an account index",
+        ))? % HARDENED_KEY_START;
+        let governance_indices = match kind {
+            GovernanceIdKind::DRep => DerivationIndices::drep(account_index, 0),
+            GovernanceIdKind::CommitteeCold => DerivationIndices::committee_cold(account_index, 0),
+            GovernanceIdKind::CommitteeHot => DerivationIndices::committee_hot(account_index, 0),
+        };
+        let key_hash = governance_indices
+            .0
+            .iter()
+            .fold(master_key, |key, index| key.derive(*index))
+            .to_public()
+            .to_raw_key()
+            .hash()
+            .to_hex();
+        Ok(GovernanceId::new(kind, WCredential::KeyHash(key_hash)))
+    }
+
+    // The master key and current derivation indices of mnemonic and root key wallets
+    fn get_master_key(
+        wallet_type: &WalletType,
+    ) -> Result<(Bip32PrivateKey, &DerivationIndices), WError> {
+        match wallet_type {
+            WalletType::MnemonicWallet(mnemonic_wallet) => {
+                let mnemonic =
+                    Mnemonic::from_phrase(&mnemonic_wallet.mnemonic_phrase, Language::English)
+                        .map_err(WError::from_err(
+                            "Wallet - get_master_key - failed to create mnemonic",
+                        ))?;
+                Ok((
+                    Bip32PrivateKey::from_bip39_entropy(mnemonic.entropy(), &[]),
+                    &mnemonic_wallet.derivation_indices,
+                ))
+            }
+            WalletType::RootKeyWallet(root_key_wallet) => Ok((
+                Bip32PrivateKey::from_bech32(&root_key_wallet.root_key).map_err(
+                    WError::from_err("Wallet - get_master_key - invalid root key hex"),
+                )?,
+                &root_key_wallet.derivation_indices,
+            )),
+            WalletType::Cli(_) => Err(WError::new(
+                "Wallet - get_master_key",
+                "keys can only be derived from mnemonic or root key wallets",
+            )),
+        }
+    }
+
+    /// Exports the wallet signing key as a cardano-cli text envelope.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Whether to label the envelope as a payment or stake key
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the text envelope or an error
+    pub fn export_text_envelope(&self, role: KeyRole) -> Result<TextEnvelope, WError> {
+        Self::get_signing_key(&self.wallet_type)?.to_text_envelope(role)
+    }
+
+    /// Exports the wallet signing key in bech32 (`ed25519_sk`, `ed25519e_sk` or `xprv`).
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the bech32 key or an error
+    pub fn export_bech32(&self) -> Result<String, WError> {
+        Ok(Self::get_signing_key(&self.wallet_type)?.to_bech32())
     }
 
     /// Gets a wallet address based on the specified address type.
     ///
     /// # Arguments
     ///
-    /// * `address_type` - The type of address to get (Payment or Enterprise)
+    /// * `address_type` - The type of address to get (Payment, Enterprise or Byron)
     ///
     /// # Returns
     ///
-    /// A Result containing either the bech32-encoded address or an error
+    /// A Result containing either the bech32-encoded address, or the base58-encoded address for Byron, or an error
     pub fn get_change_address(&self, address_type: AddressType) -> Result<String, WError> {
         match address_type {
             AddressType::Payment => {
@@ -521,6 +833,15 @@ impl Wallet {
                     )("Enterprise address not initialized"))
                 }
             }
+            AddressType::Byron => {
+                if let Some(byron_address) = &self.addresses.byron_address {
+                    Ok(byron_address.to_base58())
+                } else {
+                    Err(WError::from_err(
+                        "Byron address not available for this wallet type",
+                    )("Byron address not initialized"))
+                }
+            }
         }
     }
 
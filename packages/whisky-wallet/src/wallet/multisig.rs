@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use whisky_common::{
+    Fetcher, NativeScript, ProvidedSimpleScriptSource, SimpleScriptTxIn, SimpleScriptTxInParameter,
+    Submitter, TxIn, TxInParameter, UTxO, WError,
+};
+use whisky_csl::{
+    get_native_script_cbor, get_native_script_hash, get_tx_validity_range,
+    get_vkey_witness_key_hashes, merge_vkey_witnesses, script_to_address,
+};
+
+use super::NetworkId;
+
+/// The signing progress of a transaction against the multisig policy
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultisigSignatureStatus {
+    /// Key hashes in the policy which have a vkey witness in the transaction
+    pub signed_key_hashes: Vec<String>,
+    /// Key hashes in the policy which have not signed yet
+    pub missing_key_hashes: Vec<String>,
+    /// Whether the policy is satisfied with the current witnesses and the transaction validity range
+    pub is_satisfied: bool,
+}
+
+/// Represents a wallet locked behind a native script policy, e.g. a treasury
+/// guarded by `atLeast` 2 of 3 keys.
+///
+/// The wallet holds no keys itself. Each party signs the transaction with its own
+/// `Wallet::sign_tx`, and the partially signed transactions are merged back together
+/// with `merge_signatures`.
+pub struct MultisigWallet {
+    pub script: NativeScript,
+    pub network_id: NetworkId,
+    pub stake_credential: Option<(String, bool)>,
+    pub fetcher: Option<Box<dyn Fetcher>>,
+    pub submitter: Option<Box<dyn Submitter>>,
+    script_cbor: String,
+}
+
+impl MultisigWallet {
+    /// Creates a new multisig wallet with the native script policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `script` - The native script policy
+    ///
+    /// # Returns
+    ///
+    /// A new `MultisigWallet` instance
+    pub fn new(script: NativeScript) -> Result<Self, WError> {
+        let script_cbor = get_native_script_cbor(&script).map_err(WError::from_err(
+            "MultisigWallet - new - invalid native script",
+        ))?;
+        Ok(Self {
+            script,
+            network_id: NetworkId::Preprod,
+            stake_credential: None,
+            fetcher: None,
+            submitter: None,
+            script_cbor,
+        })
+    }
+
+    /// Creates a new multisig wallet from a native script in cardano-cli JSON format.
+    ///
+    /// # Arguments
+    ///
+    /// * `script_json` - The native script JSON, e.g. the content of `policy.script`
+    ///
+    /// # Returns
+    ///
+    /// A new `MultisigWallet` instance
+    pub fn from_json(script_json: &str) -> Result<Self, WError> {
+        let script = NativeScript::from_json(script_json)
+            .map_err(WError::from_err("MultisigWallet - from_json"))?;
+        Self::new(script)
+    }
+
+    /// Sets the network ID used to derive the script address.
+    pub fn with_network_id(mut self, network_id: NetworkId) -> Self {
+        self.network_id = network_id;
+        self
+    }
+
+    /// Sets the stake credential of the script address.
+    ///
+    /// # Arguments
+    ///
+    /// * `stake_hash` - The stake key hash or stake script hash
+    /// * `is_script` - Whether the stake credential is a script hash
+    pub fn with_stake_credential(mut self, stake_hash: &str, is_script: bool) -> Self {
+        self.stake_credential = Some((stake_hash.to_string(), is_script));
+        self
+    }
+
+    /// Attaches a fetcher implementation to the wallet.
+    pub fn with_fetcher<F: Fetcher + 'static>(mut self, fetcher: F) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Attaches a submitter implementation to the wallet.
+    pub fn with_submitter<S: Submitter + 'static>(mut self, submitter: S) -> Self {
+        self.submitter = Some(Box::new(submitter));
+        self
+    }
+
+    /// Gets the CBOR hex of the native script.
+    pub fn script_cbor(&self) -> String {
+        self.script_cbor.clone()
+    }
+
+    /// Gets the hash of the native script, i.e. the payment credential of the script address.
+    pub fn script_hash(&self) -> Result<String, WError> {
+        get_native_script_hash(&self.script_cbor)
+    }
+
+    /// Gets the bech32 script address of the multisig policy.
+    pub fn get_address(&self) -> Result<String, WError> {
+        let script_hash = self.script_hash()?;
+        let stake_credential = self
+            .stake_credential
+            .as_ref()
+            .map(|(stake_hash, is_script)| (stake_hash.as_str(), *is_script));
+        Ok(script_to_address(
+            self.network_id as u8,
+            &script_hash,
+            stake_credential,
+        ))
+    }
+
+    /// Gets all key hashes which can sign for the multisig policy.
+    pub fn key_hashes(&self) -> Vec<String> {
+        self.script.key_hashes()
+    }
+
+    /// Creates a transaction input spending a UTxO at the script address, with the native script provided as witness.
+    ///
+    /// Remember to set `invalid_before` / `invalid_hereafter` on the transaction if the policy contains `after` / `before` scripts.
+    ///
+    /// # Arguments
+    ///
+    /// * `utxo` - The UTxO to spend
+    ///
+    /// # Returns
+    ///
+    /// A `TxIn::SimpleScriptTxIn` to add to the transaction inputs
+    pub fn tx_in(&self, utxo: &UTxO) -> TxIn {
+        TxIn::SimpleScriptTxIn(SimpleScriptTxIn {
+            tx_in: TxInParameter {
+                tx_hash: utxo.input.tx_hash.clone(),
+                tx_index: utxo.input.output_index,
+                amount: Some(utxo.output.amount.clone()),
+                address: Some(utxo.output.address.clone()),
+            },
+            simple_script_tx_in: SimpleScriptTxInParameter::ProvidedSimpleScriptSource(
+                ProvidedSimpleScriptSource {
+                    script_cbor: self.script_cbor.clone(),
+                },
+            ),
+        })
+    }
+
+    /// Checks which keys in the policy have signed the transaction, and whether the policy is satisfied.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The (partially) signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the signature status or an error
+    pub fn signature_status(&self, tx_hex: &str) -> Result<MultisigSignatureStatus, WError> {
+        let witness_key_hashes: HashSet<String> = get_vkey_witness_key_hashes(tx_hex)
+            .map_err(WError::from_err("MultisigWallet - signature_status"))?
+            .into_iter()
+            .collect();
+        let validity_range = get_tx_validity_range(tx_hex)
+            .map_err(WError::from_err("MultisigWallet - signature_status"))?;
+        let (signed_key_hashes, missing_key_hashes) = self
+            .key_hashes()
+            .into_iter()
+            .partition(|key_hash| witness_key_hashes.contains(key_hash));
+        Ok(MultisigSignatureStatus {
+            signed_key_hashes,
+            missing_key_hashes,
+            is_satisfied: self
+                .script
+                .is_satisfied(&witness_key_hashes, &validity_range),
+        })
+    }
+
+    /// Merges the vkey witnesses from several parties' partially signed transactions into one transaction.
+    ///
+    /// The transaction body is kept byte for byte, so the collected signatures remain valid.
+    /// An error is returned if any of the partially signed transactions has a different body.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The transaction to merge the witnesses into
+    /// * `partially_signed_tx_hexes` - The partially signed transactions from the other parties
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the merged transaction hex or an error
+    pub fn merge_signatures(
+        &self,
+        tx_hex: &str,
+        partially_signed_tx_hexes: &[&str],
+    ) -> Result<String, WError> {
+        merge_vkey_witnesses(tx_hex, partially_signed_tx_hexes)
+            .map_err(WError::from_err("MultisigWallet - merge_signatures"))
+    }
+
+    /// Fetches the UTxOs at the script address.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Optional asset ID to filter UTxOs
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either a vector of UTxOs or an error
+    pub async fn get_utxos(&self, asset: Option<&str>) -> Result<Vec<UTxO>, WError> {
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| {
+            WError::from_err("Fetcher is required to fetch UTxOs. Please provide a fetcher.")(
+                "No fetcher provided",
+            )
+        })?;
+        let address = self.get_address()?;
+        fetcher
+            .fetch_address_utxos(&address, asset)
+            .await
+            .map_err(WError::from_err("Failed to fetch UTxOs"))
+    }
+
+    /// Submits the transaction once the policy is satisfied.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_hex` - The fully signed transaction
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either the transaction hash or an error
+    pub async fn submit_tx(&self, tx_hex: &str) -> Result<String, WError> {
+        let submitter = self.submitter.as_ref().ok_or_else(|| {
+            WError::from_err(
+                "Submitter is required to submit transactions. Please provide a submitter.",
+            )("No submitter provided")
+        })?;
+        let status = self.signature_status(tx_hex)?;
+        if !status.is_satisfied {
+            return Err(WError::new(
+                "MultisigWallet - submit_tx",
+                &format!(
+                    "multisig policy is not satisfied, missing signatures from: {:?}",
+                    status.missing_key_hashes
+                ),
+            ));
+        }
+        submitter.submit_tx(tx_hex).await
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod test {
+    use whisky_csl::csl::{ByronAddress, FixedTransaction};
+    use whisky_wallet::{
+        derivation_indices::DerivationIndices, AddressType, MnemonicWallet, NetworkId, Wallet,
+        WalletType,
+    };
+
+    const MNEMONIC: &str = "summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer";
+    const TX_HEX: &str = "84a4008182582004509185eb98edd8e2420c1ceea914d6a7a3142041039b2f12b4d4f03162d56f04018282581d605867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f1a000f42408258390004845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66e0464447c1f51adaefe1ebfb0dd485a349a70479ced1d198cbdf7fe71a15d35396021a0002917d075820bdaa99eb158414dea0a91d6c727e2268574b23efe6e08ab3b841abe8059a030ca0f5d90103a0";
+
+    #[test]
+    fn test_byron_address() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC)
+            .unwrap()
+            .with_network_id(NetworkId::Mainnet);
+        let address = wallet.get_change_address(AddressType::Byron).unwrap();
+        // Icarus addresses on mainnet carry no attributes and always start with Ae2
+        assert!(address.starts_with("Ae2"));
+        assert!(ByronAddress::is_valid(&address));
+
+        let testnet_address = Wallet::new_mnemonic(MNEMONIC)
+            .unwrap()
+            .get_change_address(AddressType::Byron)
+            .unwrap();
+        assert_ne!(address, testnet_address);
+        assert_eq!(
+            ByronAddress::from_base58(&testnet_address)
+                .unwrap()
+                .byron_protocol_magic(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_byron_address_follows_account_index() {
+        let mut wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let address = wallet.get_change_address(AddressType::Byron).unwrap();
+        wallet.payment_account(0, 1).unwrap();
+        let next_address = wallet.get_change_address(AddressType::Byron).unwrap();
+        assert_ne!(address, next_address);
+    }
+
+    #[test]
+    fn test_cli_wallet_has_no_byron_address() {
+        let wallet =
+            Wallet::new_cli("5820f1e4a3a1e4dc8a3bf4f5ad2c6c1e8fe1a1fef0ef5c37bb0d0d7c9d0b12bba4d1")
+                .unwrap();
+        assert!(wallet.get_change_address(AddressType::Byron).is_err());
+        assert!(wallet.sign_tx_bootstrap(TX_HEX).is_err());
+    }
+
+    #[test]
+    fn test_short_derivation_path_has_no_byron_key() {
+        let wallet_type = WalletType::MnemonicWallet(MnemonicWallet {
+            mnemonic_phrase: MNEMONIC.to_string(),
+            derivation_indices: DerivationIndices::from_str("m/1852'/1815'/0'"),
+        });
+        assert!(Wallet::get_byron_signing_key(&wallet_type).is_err());
+    }
+
+    #[test]
+    fn test_sign_tx_bootstrap() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let signed_tx = wallet.sign_tx_bootstrap(TX_HEX).unwrap();
+        let tx = FixedTransaction::from_hex(&signed_tx).unwrap();
+        let bootstrap_witnesses = tx.witness_set().bootstraps().unwrap();
+        assert_eq!(bootstrap_witnesses.len(), 1);
+        assert!(tx.witness_set().vkeys().is_none());
+
+        let byron_key = Wallet::get_byron_signing_key(&wallet.wallet_type).unwrap();
+        assert_eq!(
+            bootstrap_witnesses.get(0).vkey().public_key().as_bytes(),
+            byron_key.to_public().to_raw_key().as_bytes()
+        );
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod test {
+    use whisky_wallet::{KeyRole, SigningKey, TextEnvelope, Wallet};
+
+    const MNEMONIC: &str = "summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer";
+    const TX_HEX: &str = "84a4008182582004509185eb98edd8e2420c1ceea914d6a7a3142041039b2f12b4d4f03162d56f04018282581d605867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f1a000f42408258390004845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66e0464447c1f51adaefe1ebfb0dd485a349a70479ced1d198cbdf7fe71a15d35396021a0002917d075820bdaa99eb158414dea0a91d6c727e2268574b23efe6e08ab3b841abe8059a030ca0f5d90103a0";
+    const SIGNED_TX_HEX: &str = "84a4008182582004509185eb98edd8e2420c1ceea914d6a7a3142041039b2f12b4d4f03162d56f04018282581d605867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f1a000f42408258390004845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66e0464447c1f51adaefe1ebfb0dd485a349a70479ced1d198cbdf7fe71a15d35396021a0002917d075820bdaa99eb158414dea0a91d6c727e2268574b23efe6e08ab3b841abe8059a030ca1008182582089f4b576f05f5aad99bce0bdd51afe48529772f7561bb2ac9d84a4afbda1ecd658404cd1466fcc4579fa9c89656dbbd25ca659cccf2d2783417ef13a1b060bf836fbe8383c10e25c6fa323c1c81a0799e87e6cf3eaa25990113b27953a9836635a01f5d90103a0";
+    const NORMAL_SKEY_HEX: &str =
+        "d4ffb1b5a4b9e8c6f1b2f3a1c4e7d8b9a0c1d2e3f405162738495a6b7c8d9eaf";
+
+    #[test]
+    fn test_extended_text_envelope_roundtrip_sign_tx() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let envelope = wallet.export_text_envelope(KeyRole::Payment).unwrap();
+        assert_eq!(
+            envelope.envelope_type,
+            "PaymentExtendedSigningKeyShelley_ed25519_bip32"
+        );
+        assert!(envelope.cbor_hex.starts_with("5880"));
+
+        let cli_wallet = Wallet::new_cli(&envelope.to_json().unwrap()).unwrap();
+        assert_eq!(cli_wallet.sign_tx(TX_HEX).unwrap(), SIGNED_TX_HEX);
+        assert_eq!(
+            cli_wallet.export_text_envelope(KeyRole::Payment).unwrap(),
+            envelope
+        );
+    }
+
+    #[test]
+    fn test_xprv_bech32_sign_tx() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let xprv = wallet.export_bech32().unwrap();
+        assert!(xprv.starts_with("xprv"));
+
+        let cli_wallet = Wallet::new_cli(&xprv).unwrap();
+        assert_eq!(cli_wallet.sign_tx(TX_HEX).unwrap(), SIGNED_TX_HEX);
+    }
+
+    #[test]
+    fn test_non_derivable_extended_key_sign_tx() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let raw_key = Wallet::get_signing_key(&wallet.wallet_type)
+            .unwrap()
+            .to_raw_key()
+            .unwrap();
+        let extended_key = SigningKey::Extended(raw_key);
+
+        let bech32 = extended_key.to_bech32();
+        assert!(bech32.starts_with("ed25519e_sk"));
+        let bech32_wallet = Wallet::new_cli(&bech32).unwrap();
+        assert_eq!(bech32_wallet.sign_tx(TX_HEX).unwrap(), SIGNED_TX_HEX);
+
+        let hex_wallet = Wallet::new_cli(&extended_key.to_hex()).unwrap();
+        assert_eq!(hex_wallet.sign_tx(TX_HEX).unwrap(), SIGNED_TX_HEX);
+
+        // Without a chain code, there is no bip32 envelope to export to
+        assert!(extended_key.to_text_envelope(KeyRole::Payment).is_err());
+        assert!(hex_wallet.export_text_envelope(KeyRole::Payment).is_err());
+    }
+
+    #[test]
+    fn test_normal_key_formats() {
+        let envelope_json = format!(
+            r#"{{
+    "type": "StakeSigningKeyShelley_ed25519",
+    "description": "Stake Signing Key",
+    "cborHex": "5820{}"
+}}"#,
+            NORMAL_SKEY_HEX
+        );
+        let envelope = TextEnvelope::from_json(&envelope_json).unwrap();
+        assert_eq!(envelope.key_role().unwrap(), KeyRole::Stake);
+
+        let from_envelope = SigningKey::from_text_envelope(&envelope).unwrap();
+        assert!(matches!(from_envelope, SigningKey::Normal(_)));
+        assert_eq!(from_envelope.to_hex(), NORMAL_SKEY_HEX);
+        assert_eq!(
+            from_envelope.to_text_envelope(KeyRole::Stake).unwrap(),
+            envelope
+        );
+
+        let bech32 = from_envelope.to_bech32();
+        assert!(bech32.starts_with("ed25519_sk"));
+        let from_bech32 = SigningKey::parse(&bech32).unwrap();
+        let from_cbor_hex = SigningKey::parse(&envelope.cbor_hex).unwrap();
+        assert_eq!(from_bech32.to_hex(), NORMAL_SKEY_HEX);
+        assert_eq!(from_cbor_hex.to_hex(), NORMAL_SKEY_HEX);
+
+        let vkey = from_envelope.to_verification_key_envelope(KeyRole::Stake);
+        assert_eq!(vkey.envelope_type, "StakeVerificationKeyShelley_ed25519");
+        assert_eq!(
+            vkey.cbor_hex,
+            format!("5820{}", from_envelope.to_public().to_hex())
+        );
+    }
+
+    #[test]
+    fn test_invalid_bech32_reports_bech32_error() {
+        let bech32 = SigningKey::from_hex(NORMAL_SKEY_HEX).unwrap().to_bech32();
+        let last = if bech32.ends_with('q') { 'p' } else { 'q' };
+        let typo = format!("{}{}", &bech32[..bech32.len() - 1], last);
+        let err = SigningKey::parse(&typo).err().unwrap();
+        assert!(format!("{:?}", err).contains("from_bech32"));
+    }
+
+    #[test]
+    fn test_unsupported_envelope_type() {
+        let envelope = TextEnvelope {
+            envelope_type: "GenesisSigningKey_ed25519".to_string(),
+            description: "".to_string(),
+            cbor_hex: format!("5820{}", NORMAL_SKEY_HEX),
+        };
+        assert!(SigningKey::from_text_envelope(&envelope).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod test {
+    use whisky_common::{Credential, GovernanceId, GovernanceIdKind};
+    use whisky_csl::csl::DRep;
+    use whisky_wallet::{
+        derivation_indices::DerivationIndices, MnemonicWallet, Wallet, WalletType,
+    };
+
+    const MNEMONIC: &str = "summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer";
+
+    #[test]
+    fn test_drep_id_matches_drep_account() {
+        let mut wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let drep_id = wallet.get_governance_id(GovernanceIdKind::DRep).unwrap();
+
+        wallet.drep_account(0, 0).unwrap();
+        let drep_key_hash = wallet.account.as_ref().unwrap().public_key.hash().to_hex();
+        assert_eq!(
+            drep_id.credential,
+            Credential::KeyHash(drep_key_hash.clone())
+        );
+
+        // Both CIP-129 and CIP-105 forms decode to the same key hash in CSL
+        for bech32 in [
+            drep_id.to_bech32().unwrap(),
+            drep_id.to_bech32_cip105().unwrap(),
+        ] {
+            let csl_drep = DRep::from_bech32(&bech32).unwrap();
+            assert_eq!(csl_drep.to_key_hash().unwrap().to_hex(), drep_key_hash);
+        }
+    }
+
+    #[test]
+    fn test_committee_credentials() {
+        let mut wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let cold = wallet
+            .get_governance_id(GovernanceIdKind::CommitteeCold)
+            .unwrap();
+        let hot = wallet
+            .get_governance_id(GovernanceIdKind::CommitteeHot)
+            .unwrap();
+        assert_ne!(cold.credential, hot.credential);
+        assert!(cold.to_bech32().unwrap().starts_with("cc_cold1"));
+        assert!(hot.to_bech32().unwrap().starts_with("cc_hot1"));
+        assert_eq!(
+            GovernanceId::from_bech32(&hot.to_bech32().unwrap()).unwrap(),
+            hot
+        );
+
+        wallet.committee_hot_account(0, 0).unwrap();
+        let hot_key_hash = wallet.account.as_ref().unwrap().public_key.hash().to_hex();
+        assert_eq!(hot.credential, Credential::KeyHash(hot_key_hash));
+    }
+
+    #[test]
+    fn test_cli_wallet_has_no_governance_id() {
+        let wallet =
+            Wallet::new_cli("5820f1e4a3a1e4dc8a3bf4f5ad2c6c1e8fe1a1fef0ef5c37bb0d0d7c9d0b12bba4d1")
+                .unwrap();
+        assert!(wallet.get_governance_id(GovernanceIdKind::DRep).is_err());
+    }
+
+    #[test]
+    fn test_short_derivation_path_has_no_governance_id() {
+        let mut wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        wallet.wallet_type = WalletType::MnemonicWallet(MnemonicWallet {
+            mnemonic_phrase: MNEMONIC.to_string(),
+            derivation_indices: DerivationIndices::from_str("m/1852'/1815'"),
+        });
+        assert!(wallet.get_governance_id(GovernanceIdKind::DRep).is_err());
+    }
+}
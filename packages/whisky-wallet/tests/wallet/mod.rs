@@ -1,2 +1,8 @@
 mod address;
 mod bip39;
+mod byron;
+mod cli_key;
+mod governance_id;
+mod multisig;
+#[cfg(unix)]
+mod signer;
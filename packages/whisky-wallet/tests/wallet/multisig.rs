@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod test {
+    use whisky_common::NativeScript;
+    use whisky_wallet::{MultisigWallet, Wallet};
+
+    const MNEMONIC: &str = "summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer";
+    const ROOT_KEY: &str = "xprv1cqa46gk29plgkg98upclnjv5t425fcpl4rgf9mq2txdxuga7jfq5shk7np6l55nj00sl3m4syzna3uwgrwppdm0azgy9d8zahyf32s62klfyhe0ayyxkc7x92nv4s77fa0v25tufk9tnv7x6dgexe9kdz5gpeqgu";
+    const TX_HEX: &str = "84a4008182582004509185eb98edd8e2420c1ceea914d6a7a3142041039b2f12b4d4f03162d56f04018282581d605867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f1a000f42408258390004845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66e0464447c1f51adaefe1ebfb0dd485a349a70479ced1d198cbdf7fe71a15d35396021a0002917d075820bdaa99eb158414dea0a91d6c727e2268574b23efe6e08ab3b841abe8059a030ca0f5d90103a0";
+    const OTHER_KEY_HASH: &str = "fa5136e9e9ecbc9071da73eeb6c9a4ff73cbf436105cf8380d1c525c";
+
+    fn key_hash(wallet: &Wallet) -> String {
+        wallet.account.as_ref().unwrap().public_key.hash().to_hex()
+    }
+
+    fn two_of_three(key_hashes: Vec<String>) -> MultisigWallet {
+        MultisigWallet::new(NativeScript::AtLeast {
+            required: 2,
+            scripts: key_hashes
+                .into_iter()
+                .map(|key_hash| NativeScript::Sig { key_hash })
+                .collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_multisig_address() {
+        let multisig = two_of_three(vec![
+            OTHER_KEY_HASH.to_string(),
+            OTHER_KEY_HASH.to_string(),
+            OTHER_KEY_HASH.to_string(),
+        ]);
+        let address = multisig.get_address().unwrap();
+        assert!(address.starts_with("addr_test1w"));
+        assert_eq!(multisig.key_hashes(), vec![OTHER_KEY_HASH.to_string()]);
+    }
+
+    #[test]
+    fn test_multisig_merge_signatures() {
+        let party_a = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let party_b = Wallet::new_root_key(ROOT_KEY).unwrap();
+        let multisig = two_of_three(vec![
+            key_hash(&party_a),
+            key_hash(&party_b),
+            OTHER_KEY_HASH.to_string(),
+        ]);
+
+        let signed_by_a = party_a.sign_tx(TX_HEX).unwrap();
+        let signed_by_b = party_b.sign_tx(TX_HEX).unwrap();
+
+        let status_a = multisig.signature_status(&signed_by_a).unwrap();
+        assert_eq!(status_a.signed_key_hashes, vec![key_hash(&party_a)]);
+        assert!(!status_a.is_satisfied);
+
+        let merged = multisig
+            .merge_signatures(TX_HEX, &[&signed_by_a, &signed_by_b])
+            .unwrap();
+        let status = multisig.signature_status(&merged).unwrap();
+        assert_eq!(
+            status.signed_key_hashes,
+            vec![key_hash(&party_a), key_hash(&party_b)]
+        );
+        assert_eq!(status.missing_key_hashes, vec![OTHER_KEY_HASH.to_string()]);
+        assert!(status.is_satisfied);
+
+        // merging the same witness twice does not duplicate it
+        let merged_again = multisig.merge_signatures(&merged, &[&signed_by_a]).unwrap();
+        assert_eq!(merged_again, merged);
+    }
+
+    #[test]
+    fn test_multisig_merge_body_mismatch() {
+        let party_a = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let multisig = two_of_three(vec![
+            key_hash(&party_a),
+            OTHER_KEY_HASH.to_string(),
+            OTHER_KEY_HASH.to_string(),
+        ]);
+        let other_tx = "84a400d90102818258202cb57168ee66b68bd04a0d595060b546edf30c04ae1031b883c9ac797967dd8503018283581d60f95cab9352c14782a366802b7967746a89356e8915c17006149ff68c1a001e84805820923918e403bf43c34b4ef6b48eb2ee04babed17320d8d1b9ff9ad086e86f44ec82581d60f95cab9352c14782a366802b7967746a89356e8915c17006149ff68c1b000000024d74dae2021a000295f50b5820623e03fb3d662e91ccec09c098e017b0a65b9213844d227acb5745709744ecbba104d901029fd87980fff5f6";
+        let signed_other = party_a.sign_tx(other_tx).unwrap();
+        assert!(multisig.merge_signatures(TX_HEX, &[&signed_other]).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use whisky_common::Signer;
+    use whisky_wallet::{LocalSigner, SignRequest, SignResponse, UnixSocketSigner, Wallet};
+
+    const MNEMONIC: &str = "summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer summer";
+    const TX_HEX: &str = "84a4008182582004509185eb98edd8e2420c1ceea914d6a7a3142041039b2f12b4d4f03162d56f04018282581d605867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f1a000f42408258390004845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66e0464447c1f51adaefe1ebfb0dd485a349a70479ced1d198cbdf7fe71a15d35396021a0002917d075820bdaa99eb158414dea0a91d6c727e2268574b23efe6e08ab3b841abe8059a030ca0f5d90103a0";
+
+    // A signing daemon answering a single request with the keys of the local signer
+    fn spawn_mock_daemon(name: &str, signer: LocalSigner) -> std::path::PathBuf {
+        let socket_path = std::env::temp_dir().join(format!(
+            "whisky-signer-{}-{}.sock",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut line = String::new();
+            BufReader::new(&stream).read_line(&mut line).unwrap();
+            let request: SignRequest = serde_json::from_str(line.trim()).unwrap();
+            let response = match signer.sign(&request.tx_hash) {
+                Ok(vkey_witnesses) => SignResponse::Witnesses(vkey_witnesses),
+                Err(err) => SignResponse::Error(format!("{:?}", err)),
+            };
+            let mut response = serde_json::to_string(&response).unwrap();
+            response.push('\n');
+            (&stream).write_all(response.as_bytes()).unwrap();
+        });
+        socket_path
+    }
+
+    #[tokio::test]
+    async fn test_local_signer_matches_sign_tx() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let local_signer = LocalSigner::from_wallet(&wallet).unwrap();
+        let tx_hash = whisky_csl::calculate_tx_hash(TX_HEX).unwrap();
+        let vkey_witnesses = local_signer.sign_tx_hash(&tx_hash).await.unwrap();
+        assert_eq!(vkey_witnesses.len(), 1);
+        assert_eq!(
+            vkey_witnesses[0].vkey,
+            wallet.account.as_ref().unwrap().public_key.to_hex()
+        );
+
+        let signed_with_signer = Wallet::new_mnemonic(MNEMONIC)
+            .unwrap()
+            .with_signer(local_signer)
+            .sign_tx_async(TX_HEX)
+            .await
+            .unwrap();
+        assert_eq!(signed_with_signer, wallet.sign_tx(TX_HEX).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unix_socket_signer() {
+        let wallet = Wallet::new_mnemonic(MNEMONIC).unwrap();
+        let socket_path = spawn_mock_daemon("wallet", LocalSigner::from_wallet(&wallet).unwrap());
+
+        let remote_wallet = Wallet::new_mnemonic(MNEMONIC)
+            .unwrap()
+            .with_signer(UnixSocketSigner::new(&socket_path));
+        let signed_tx = remote_wallet.sign_tx_async(TX_HEX).await.unwrap();
+        assert_eq!(signed_tx, wallet.sign_tx(TX_HEX).unwrap());
+
+        // Witnesses of keys which already signed are not added twice
+        let resigned_tx = whisky_csl::add_vkey_witnesses(
+            &signed_tx,
+            &LocalSigner::from_wallet(&wallet)
+                .unwrap()
+                .sign(&whisky_csl::calculate_tx_hash(TX_HEX).unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(resigned_tx, signed_tx);
+        let _ = std::fs::remove_file(&socket_path);
+    }
+
+    #[tokio::test]
+    async fn test_unix_socket_signer_rejection() {
+        let socket_path = spawn_mock_daemon("rejection", LocalSigner::new(vec![]));
+        let signer = UnixSocketSigner::new(&socket_path);
+        assert!(signer.sign_tx_hash("not a tx hash").await.is_err());
+        let _ = std::fs::remove_file(&socket_path);
+    }
+
+    #[tokio::test]
+    async fn test_unix_socket_signer_timeout() {
+        let socket_path =
+            std::env::temp_dir().join(format!("whisky-signer-timeout-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        // A daemon accepting the connection but never answering
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let signer = UnixSocketSigner::new(&socket_path).with_timeout(Duration::from_millis(100));
+        let err = signer.sign_tx_hash(&"00".repeat(32)).await.unwrap_err();
+        assert!(format!("{:?}", err).contains("timed out"));
+        drop(listener);
+        let _ = std::fs::remove_file(&socket_path);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::data::{Cip68Datum, Cip68Label, Cip68UserLabel, PlutusDataJson};
+use crate::*;
+
+use super::{TxBuilder, WData};
+
+impl TxBuilder {
+    /// ## Transaction building method
+    ///
+    /// Mint a CIP-68 token pair and lock the `(100)` reference token with its metadata datum
+    ///
+    /// Mints 1 reference token and `quantity` user tokens under `policy`, then adds an output
+    /// at `reference_address` holding the reference token with `datum` inline. Attach the
+    /// minting witness right after this call (e.g. `minting_script`, `mint_redeemer_value`),
+    /// it is shared by both tokens.
+    ///
+    /// ### Arguments
+    ///
+    /// * `policy` - The policy
+    /// * `name` - The asset name in hex, without label
+    /// * `user_label` - The label of the user token
+    /// * `quantity` - The quantity of user tokens to mint
+    /// * `reference_address` - The address locking the reference token, usually a script address
+    /// * `datum` - The CIP-68 metadata datum
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn mint_cip68_pair(
+        &mut self,
+        policy: &str,
+        name: &str,
+        user_label: Cip68UserLabel,
+        quantity: i128,
+        reference_address: &str,
+        datum: &Cip68Datum,
+    ) -> &mut Self {
+        let reference_name = Cip68Label::ReferenceNft.asset_name(name);
+        self.mint(1, policy, &reference_name);
+        self.cip68_user_mint = Some(MintParameter {
+            policy_id: policy.to_string(),
+            asset_name: user_label.asset_name(name),
+            amount: quantity,
+        });
+        self.tx_out(
+            reference_address,
+            &[Asset::new(
+                format!("{}{}", policy, reference_name),
+                "1".to_string(),
+            )],
+        )
+        .tx_out_inline_datum_value(&WData::JSON(datum.to_json_string()))
+    }
+}
@@ -53,7 +53,81 @@ impl TxBuilder {
             }
             None => self,
         };
-        self.complete_sync(None)
+        self.complete_sync(None)?;
+        if let Some(current_slot) = self.phase_one_slot {
+            self.check_phase_one(current_slot)?;
+        }
+        Ok(self)
+    }
+
+    /// ## Internal method
+    ///
+    /// Validate the built transaction against the phase-1 ledger rules, ignoring the vkey
+    /// witnesses that signing will add
+    fn check_phase_one(&mut self, current_slot: u64) -> Result<(), WError> {
+        let network = self
+            .tx_builder_body
+            .network
+            .clone()
+            .unwrap_or(Network::Mainnet);
+        let mut utxos: Vec<UTxO> = self.inputs_for_evaluation.values().cloned().collect();
+        let collaterals = self
+            .tx_builder_body
+            .collaterals
+            .iter()
+            .cloned()
+            .map(TxIn::PubKeyTxIn);
+        for tx_in in self.tx_builder_body.inputs.clone().into_iter().chain(collaterals) {
+            let parameter = match &tx_in {
+                TxIn::PubKeyTxIn(tx_in) => &tx_in.tx_in,
+                TxIn::SimpleScriptTxIn(tx_in) => &tx_in.tx_in,
+                TxIn::ScriptTxIn(tx_in) => &tx_in.tx_in,
+            };
+            if parameter.address.is_none() || parameter.amount.is_none() {
+                continue;
+            }
+            let utxo = tx_in.to_utxo();
+            if !utxos.iter().any(|u| u.input == utxo.input) {
+                utxos.push(utxo);
+            }
+        }
+        let tx_hex = self.serializer.tx_hex();
+        let errors: Vec<Phase1Error> = self
+            .serializer
+            .validate_phase_one(
+                &tx_hex,
+                &utxos,
+                &self.protocol_params.clone().unwrap_or_default(),
+                &network,
+                current_slot,
+            )
+            .map_err(WError::add_err_trace("TxBuilder - check_phase_one"))?
+            .into_iter()
+            .filter(|error| !matches!(error, Phase1Error::MissingVkeyWitnesses(_)))
+            .collect();
+        if let Some(Phase1Error::BadInputs(unresolved)) = errors.first() {
+            let unresolved: Vec<String> = unresolved
+                .iter()
+                .map(|input| format!("{}#{}", input.tx_hash, input.output_index))
+                .collect();
+            return Err(WError::new(
+                "TxBuilder - complete",
+                &format!(
+                    "Cannot resolve the UTxOs of inputs [ {} ] for phase-1 validation, provide them with input_for_evaluation",
+                    unresolved.join(", ")
+                ),
+            ));
+        }
+        if !errors.is_empty() {
+            return Err(WError::new(
+                "TxBuilder - complete",
+                &format!(
+                    "Transaction fails phase-1 validation - tx_hex: [ {} ] , Errors: [ {:?} ]",
+                    tx_hex, errors
+                ),
+            ));
+        }
+        Ok(())
     }
 
     /// ## Transaction building method
@@ -131,6 +205,31 @@ impl TxBuilder {
         self.serializer.complete_signing()
     }
 
+    /// ## Transaction building method
+    ///
+    /// Complete the signing process with both the signing keys and the signers added to the builder
+    ///
+    /// ### Returns
+    ///
+    /// * `String` - The signed transaction in hex
+    pub async fn complete_signing_async(&mut self) -> Result<String, WError> {
+        let tx_hex = self.serializer.complete_signing()?;
+        self.serializer.set_tx_hex(tx_hex.clone());
+        if self.signers.is_empty() {
+            return Ok(tx_hex);
+        }
+        let tx_hash = self.serializer.tx_hash()?;
+        let mut vkey_witnesses = vec![];
+        for signer in &self.signers {
+            let witnesses = signer
+                .sign_tx_hash(&tx_hash)
+                .await
+                .map_err(WError::add_err_trace("TxBuilder - complete_signing_async"))?;
+            vkey_witnesses.extend(witnesses);
+        }
+        self.serializer.add_vkey_witnesses(vkey_witnesses)
+    }
+
     /// ## Transaction building method
     ///
     /// Obtain the transaction hex
@@ -11,11 +11,23 @@ impl WData {
         match self {
             WData::CBOR(data) => Ok(data.clone()),
             WData::JSON(data) => {
-                let data_cbor =
-                    &csl::PlutusData::from_json(data, csl::PlutusDatumSchema::DetailedSchema)
-                        .map_err(WError::from_err("WData - to_cbor"))?
-                        .to_hex();
-                Ok(data_cbor.clone())
+                let value: serde_json::Value =
+                    serde_json::from_str(data).map_err(WError::from_err("WData - to_cbor"))?;
+                let data_cbor = data::encode_plutus_data(&value)
+                    .map_err(WError::add_err_trace("WData - to_cbor"))?;
+                Ok(hex::encode(data_cbor))
+            }
+        }
+    }
+
+    /// Human-readable rendering, see [`data::pretty_plutus_data`]
+    pub fn to_pretty(&self) -> Result<String, WError> {
+        match self {
+            WData::CBOR(cbor) => data::pretty_plutus_data_cbor(cbor),
+            WData::JSON(json) => {
+                let value: serde_json::Value =
+                    serde_json::from_str(json).map_err(WError::from_err("WData - to_pretty"))?;
+                Ok(data::pretty_plutus_data(&value))
             }
         }
     }
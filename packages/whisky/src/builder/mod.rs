@@ -1,8 +1,12 @@
 mod certificate;
+mod cip68;
 mod collateral_return;
 mod complete;
 mod data;
 mod mint;
+#[cfg(feature = "wallet")]
+mod multisig;
+mod proposal;
 mod service;
 mod tx_eval;
 mod tx_in;
@@ -24,6 +28,7 @@ pub struct TxBuilder {
     pub withdrawal_item: Option<Withdrawal>,
     pub vote_item: Option<Vote>,
     pub mint_item: Option<MintItem>,
+    pub cip68_user_mint: Option<MintParameter>,
     pub collateral_item: Option<PubKeyTxIn>,
     pub tx_output: Option<Output>,
     pub adding_script_input: Option<LanguageVersion>,
@@ -33,10 +38,12 @@ pub struct TxBuilder {
     pub fetcher: Option<Box<dyn Fetcher>>,
     pub evaluator: Option<Box<dyn Evaluator>>,
     pub submitter: Option<Box<dyn Submitter>>,
+    pub signers: Vec<Box<dyn Signer>>,
     pub extra_inputs: Vec<UTxO>,
     pub selection_threshold: u64,
     pub chained_txs: Vec<String>,
     pub inputs_for_evaluation: HashMap<String, UTxO>,
+    pub phase_one_slot: Option<u64>,
 }
 
 pub struct TxBuilderParam {
@@ -68,6 +75,7 @@ impl TxBuilder {
             withdrawal_item: None,
             vote_item: None,
             mint_item: None,
+            cip68_user_mint: None,
             collateral_item: None,
             tx_output: None,
             adding_script_input: None,
@@ -80,10 +88,12 @@ impl TxBuilder {
                 None => Some(Box::new(OfflineTxEvaluator::new())),
             },
             submitter: param.submitter,
+            signers: vec![],
             extra_inputs: vec![],
             selection_threshold: 5_000_000,
             chained_txs: vec![],
             inputs_for_evaluation: HashMap::new(),
+            phase_one_slot: None,
         }
     }
 
@@ -209,10 +219,31 @@ impl TxBuilder {
         self.tx_builder_body.metadata.push(Metadata {
             tag: tag.to_string(),
             metadata: metadata.to_string(),
+            metadatum: None,
         });
         self
     }
 
+    /// ## Transaction building method
+    ///
+    /// Add a typed metadata value to the TxBuilder instance, text and bytes longer than
+    /// 64 bytes being split into lists of chunks
+    ///
+    /// ### Arguments
+    ///
+    /// * `tag` - The tag for the metadata, e.g. `CIP25_LABEL`
+    /// * `metadatum` - The metadata value
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn metadata(&mut self, tag: u64, metadatum: TransactionMetadatum) -> &mut Self {
+        self.tx_builder_body
+            .metadata
+            .push(Metadata::new(tag, metadatum));
+        self
+    }
+
     /// ## Transaction building method
     ///
     /// Add a cli signing key to the TxBuilder instance
@@ -229,6 +260,23 @@ impl TxBuilder {
         self
     }
 
+    /// ## Transaction building method
+    ///
+    /// Add a signer holding the key outside of the process, e.g. in an HSM or a KMS.
+    /// The signers are called in `complete_signing_async`.
+    ///
+    /// ### Arguments
+    ///
+    /// * `signer` - The signer producing vkey witnesses for the transaction hash
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn signer(&mut self, signer: impl Signer + 'static) -> &mut Self {
+        self.signers.push(Box::new(signer));
+        self
+    }
+
     /// ## Transaction building method
     ///
     /// Add a transaction that used as input, but not yet reflected on global blockchain
@@ -245,6 +293,26 @@ impl TxBuilder {
         self
     }
 
+    /// ## Transaction building method
+    ///
+    /// Validate the transaction against the phase-1 ledger rules at the end of `complete`,
+    /// failing with the broken rules instead of returning a transaction the node would reject.
+    /// Missing vkey witnesses are not reported since signing happens afterwards. Spent and
+    /// collateral UTxOs are taken from the inputs; reference UTxOs must be provided with
+    /// `input_for_evaluation`, otherwise `complete` fails naming the unresolved inputs
+    ///
+    /// ### Arguments
+    ///
+    /// * `current_slot` - The slot the validity interval is checked against
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn validate_phase_one(&mut self, current_slot: u64) -> &mut Self {
+        self.phase_one_slot = Some(current_slot);
+        self
+    }
+
     /// ## Transaction building method
     ///
     /// Add a transaction input to provide information for offline evaluation
@@ -460,6 +528,17 @@ impl TxBuilder {
                     .push(MintItem::SimpleScriptMint(simple_script_mint));
             }
         }
+        // The CIP-68 user token shares the witness of its reference token
+        if let Some(user_mint) = self.cip68_user_mint.take() {
+            let mut user_item = self.tx_builder_body.mints.last().cloned().unwrap();
+            match &mut user_item {
+                MintItem::ScriptMint(script_mint) => script_mint.mint = user_mint,
+                MintItem::SimpleScriptMint(simple_script_mint) => {
+                    simple_script_mint.mint = user_mint
+                }
+            }
+            self.tx_builder_body.mints.push(user_item);
+        }
         self.mint_item = None;
     }
 
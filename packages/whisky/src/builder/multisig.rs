@@ -0,0 +1,27 @@
+use super::TxBuilder;
+
+use crate::*;
+
+impl TxBuilder {
+    /// ## Transaction building method
+    ///
+    /// Add a transaction input spending from a multisig wallet script address, with the native script provided as witness
+    ///
+    /// ### Arguments
+    ///
+    /// * `multisig_wallet` - The multisig wallet locking the UTxO
+    /// * `utxo` - The UTxO to spend
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn multisig_tx_in(&mut self, multisig_wallet: &MultisigWallet, utxo: &UTxO) -> &mut Self {
+        self.tx_in(
+            &utxo.input.tx_hash,
+            utxo.input.output_index,
+            &utxo.output.amount,
+            &utxo.output.address,
+        )
+        .tx_in_script(&multisig_wallet.script_cbor())
+    }
+}
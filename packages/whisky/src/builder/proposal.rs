@@ -0,0 +1,69 @@
+use crate::*;
+
+use super::TxBuilder;
+
+impl TxBuilder {
+    /// ## Transaction building method
+    ///
+    /// Add a governance proposal to the TxBuilder instance
+    ///
+    /// ### Arguments
+    ///
+    /// * `deposit` - The proposal deposit in lovelace
+    /// * `reward_account` - The stake address receiving the deposit refund, in bech32
+    /// * `gov_action_cbor` - The governance action in CBOR hex
+    /// * `anchor` - The anchor pointing to the proposal rationale
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn proposal(
+        &mut self,
+        deposit: u64,
+        reward_account: &str,
+        gov_action_cbor: &str,
+        anchor: &Anchor,
+    ) -> &mut Self {
+        self.tx_builder_body
+            .proposals
+            .push(Proposal::BasicProposal(ProposalType {
+                deposit,
+                reward_account: reward_account.to_string(),
+                gov_action: gov_action_cbor.to_string(),
+                anchor: anchor.clone(),
+            }));
+        self
+    }
+
+    /// ## Transaction building method
+    ///
+    /// Set the current treasury value asserted by the transaction
+    ///
+    /// ### Arguments
+    ///
+    /// * `treasury_value` - The treasury value in lovelace
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn treasury_value(&mut self, treasury_value: u64) -> &mut Self {
+        self.tx_builder_body.treasury_value = Some(treasury_value);
+        self
+    }
+
+    /// ## Transaction building method
+    ///
+    /// Donate lovelace to the treasury
+    ///
+    /// ### Arguments
+    ///
+    /// * `donation` - The donation in lovelace
+    ///
+    /// ### Returns
+    ///
+    /// * `Self` - The TxBuilder instance
+    pub fn donation(&mut self, donation: u64) -> &mut Self {
+        self.tx_builder_body.donation = Some(donation);
+        self
+    }
+}
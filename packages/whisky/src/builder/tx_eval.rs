@@ -54,7 +54,16 @@ impl TxEvaluation for TxBuilder {
                             redeemer_evaluation.budget.steps * multiplier / 100;
                     }
                 }
-                RedeemerTag::Propose => todo!(),
+                RedeemerTag::Propose => {
+                    let proposal_item =
+                        &mut self.tx_builder_body.proposals[redeemer_evaluation.index as usize];
+                    if let Proposal::ScriptProposal(proposal) = proposal_item {
+                        let redeemer: &mut Redeemer = proposal.redeemer.as_mut().unwrap();
+                        redeemer.ex_units.mem = redeemer_evaluation.budget.mem * multiplier / 100;
+                        redeemer.ex_units.steps =
+                            redeemer_evaluation.budget.steps * multiplier / 100;
+                    }
+                }
                 RedeemerTag::Vote => todo!(),
             }
         }
@@ -0,0 +1,228 @@
+use whisky_common::data::{Bool, ByteString, Int, List, Map, PlutusData};
+use whisky_common::models::{Cip25Asset, TransactionMetadatum};
+use whisky_macros::ImplConstr;
+
+/// The CIP-68 asset classes, identified by their CIP-67 label
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cip68Label {
+    /// `(100)` reference NFT, holding the metadata datum
+    ReferenceNft,
+    /// `(222)` user NFT
+    Nft,
+    /// `(333)` user fungible token
+    Ft,
+    /// `(444)` user rich fungible token
+    Rft,
+}
+
+impl Cip68Label {
+    pub fn label(&self) -> u16 {
+        match self {
+            Cip68Label::ReferenceNft => 100,
+            Cip68Label::Nft => 222,
+            Cip68Label::Ft => 333,
+            Cip68Label::Rft => 444,
+        }
+    }
+
+    pub fn from_label(label: u16) -> Option<Self> {
+        match label {
+            100 => Some(Cip68Label::ReferenceNft),
+            222 => Some(Cip68Label::Nft),
+            333 => Some(Cip68Label::Ft),
+            444 => Some(Cip68Label::Rft),
+            _ => None,
+        }
+    }
+
+    /// The asset name in hex, e.g. `000de140` + `name_hex` for `(222)`
+    pub fn asset_name(&self, name_hex: &str) -> String {
+        cip67_asset_name(self.label(), name_hex)
+    }
+}
+
+/// The CIP-68 user token classes, i.e. every label but the `(100)` reference NFT
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cip68UserLabel {
+    /// `(222)` user NFT
+    Nft,
+    /// `(333)` user fungible token
+    Ft,
+    /// `(444)` user rich fungible token
+    Rft,
+}
+
+impl Cip68UserLabel {
+    /// The asset name in hex, e.g. `000de140` + `name_hex` for `(222)`
+    pub fn asset_name(&self, name_hex: &str) -> String {
+        Cip68Label::from(*self).asset_name(name_hex)
+    }
+}
+
+impl From<Cip68UserLabel> for Cip68Label {
+    fn from(label: Cip68UserLabel) -> Self {
+        match label {
+            Cip68UserLabel::Nft => Cip68Label::Nft,
+            Cip68UserLabel::Ft => Cip68Label::Ft,
+            Cip68UserLabel::Rft => Cip68Label::Rft,
+        }
+    }
+}
+
+/// CRC-8 (polynomial 0x07) of the label as 2 big-endian bytes, as specified by CIP-67
+fn cip67_checksum(label: u16) -> u8 {
+    let mut crc: u8 = 0;
+    for byte in label.to_be_bytes() {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// The 4-byte CIP-67 prefix in hex, e.g. `000643b0` for label 100
+pub fn cip67_label(label: u16) -> String {
+    format!("0{:04x}{:02x}0", label, cip67_checksum(label))
+}
+
+/// Prefix `name_hex` with the CIP-67 label
+pub fn cip67_asset_name(label: u16, name_hex: &str) -> String {
+    format!("{}{}", cip67_label(label), name_hex)
+}
+
+/// Split an asset name in hex into its CIP-67 label and the remaining name.
+///
+/// Returns `None` when the name does not start with a well-formed label (outer zero
+/// nibbles and a matching checksum).
+pub fn parse_cip67_asset_name(asset_name_hex: &str) -> Option<(u16, String)> {
+    let prefix = asset_name_hex.get(0..8)?;
+    if !prefix.starts_with('0') || !prefix.ends_with('0') {
+        return None;
+    }
+    let label = u16::from_str_radix(&prefix[1..5], 16).ok()?;
+    let checksum = u8::from_str_radix(&prefix[5..7], 16).ok()?;
+    if checksum != cip67_checksum(label) {
+        return None;
+    }
+    Some((label, asset_name_hex[8..].to_string()))
+}
+
+/// The CIP-68 metadata datum locked with the `(100)` reference token:
+/// `Constr 0 [metadata, version, extra]`.
+///
+/// Metadata keys are UTF-8 strings as bytes, values any PlutusData (UTF-8 bytes for text).
+#[derive(Clone, Debug, ImplConstr)]
+pub struct Cip68Datum {
+    pub metadata: Map<ByteString, PlutusData>,
+    pub version: Int,
+    pub extra: PlutusData,
+}
+
+impl Cip68Datum {
+    /// An empty datum of the given version (1 for NFTs, 2 for FTs and 3 for RFTs),
+    /// `extra` defaulting to `Constr 0 []`
+    pub fn new(version: i128) -> Self {
+        Cip68Datum {
+            metadata: Map::new(&[]),
+            version: Int::new(version),
+            extra: PlutusData::Bool(Bool::new(false)),
+        }
+    }
+
+    /// A datum carrying the same properties as CIP-25 asset metadata, text being
+    /// stored as whole UTF-8 bytes rather than 64-byte chunks
+    pub fn from_cip25_asset(asset: &Cip25Asset, version: i128) -> Self {
+        let mut datum = Cip68Datum::new(version);
+        datum.text("name", &asset.name).text("image", &asset.image);
+        if let Some(media_type) = &asset.media_type {
+            datum.text("mediaType", media_type);
+        }
+        if let Some(description) = &asset.description {
+            datum.text("description", description);
+        }
+        if !asset.files.is_empty() {
+            let files: Vec<PlutusData> = asset
+                .files
+                .iter()
+                .map(|file| {
+                    PlutusData::Map(Map::new(&[
+                        (utf8_bytes("name"), utf8_bytes(&file.name)),
+                        (utf8_bytes("mediaType"), utf8_bytes(&file.media_type)),
+                        (utf8_bytes("src"), utf8_bytes(&file.src)),
+                    ]))
+                })
+                .collect();
+            datum.field("files", PlutusData::List(List::new(&files)));
+        }
+        for (key, value) in &asset.extra {
+            datum.field(key, metadatum_to_plutus_data(value));
+        }
+        datum
+    }
+
+    /// Add a text entry, e.g. `("name", "My NFT")`
+    pub fn text(&mut self, key: &str, value: &str) -> &mut Self {
+        self.field(key, utf8_bytes(value))
+    }
+
+    /// Add an entry with any PlutusData value
+    pub fn field(&mut self, key: &str, value: PlutusData) -> &mut Self {
+        self.metadata
+            .insert(ByteString::new(&hex::encode(key)), value);
+        self
+    }
+
+    pub fn extra(&mut self, extra: PlutusData) -> &mut Self {
+        self.extra = extra;
+        self
+    }
+
+    /// The value of `key` in the metadata
+    pub fn get(&self, key: &str) -> Option<&PlutusData> {
+        let key_hex = hex::encode(key);
+        self.metadata
+            .map
+            .iter()
+            .find(|(k, _)| k.bytes == key_hex)
+            .map(|(_, v)| v)
+    }
+
+    /// The value of `key` decoded as UTF-8 text
+    pub fn get_text(&self, key: &str) -> Option<String> {
+        match self.get(key)? {
+            PlutusData::ByteString(bytes) => {
+                String::from_utf8(hex::decode(&bytes.bytes).ok()?).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+fn utf8_bytes(text: &str) -> PlutusData {
+    PlutusData::ByteString(ByteString::new(&hex::encode(text)))
+}
+
+fn metadatum_to_plutus_data(metadatum: &TransactionMetadatum) -> PlutusData {
+    match metadatum {
+        TransactionMetadatum::Int(int) => PlutusData::Integer(Int::new(*int)),
+        TransactionMetadatum::Bytes(bytes) => PlutusData::ByteString(ByteString::new(bytes)),
+        TransactionMetadatum::Text(text) => utf8_bytes(text),
+        TransactionMetadatum::List(items) => PlutusData::List(List::new(
+            &items
+                .iter()
+                .map(metadatum_to_plutus_data)
+                .collect::<Vec<_>>(),
+        )),
+        TransactionMetadatum::Map(entries) => PlutusData::Map(Map::new(
+            &entries
+                .iter()
+                .map(|(k, v)| (metadatum_to_plutus_data(k), metadatum_to_plutus_data(v)))
+                .collect::<Vec<_>>(),
+        )),
+    }
+}
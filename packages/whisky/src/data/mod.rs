@@ -1,7 +1,9 @@
 pub mod aliases;
+pub mod cip68;
 
 // Re-export all whisky_common::data types for convenience
 pub use whisky_common::data::*;
 
 // Re-export whisky-specific data types
 pub use aliases::*;
+pub use cip68::*;
@@ -1,7 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
-use whisky_common::{Fetcher, TxBuilderBody, TxParsable, TxTester, UTxO, WError};
-use whisky_csl::WhiskyCSL;
+use whisky_common::{
+    Fetcher, Network, Protocol, SlotConfig, TxBuilderBody, TxDiff, TxExplanation, TxParsable,
+    TxTester, UTxO, WError, WitnessReport,
+};
+use whisky_csl::{get_vkey_witness_key_hashes, WhiskyCSL};
+
+use crate::utils::tx_builder_code;
 
 pub struct TxParser {
     pub fetcher: Option<Box<dyn Fetcher>>,
@@ -90,4 +95,59 @@ impl TxParser {
     pub fn get_builder_body_without_change(&self) -> TxBuilderBody {
         self.serializer.get_builder_body_without_change()
     }
+
+    /// Fee of the parsed transaction, the builder bodies leave it for the TxBuilder to recalculate
+    pub fn get_fee(&self) -> u64 {
+        self.serializer.get_fee()
+    }
+
+    /// Audit the witness set of the parsed transaction, see [`WitnessReport`]
+    pub fn witness_report(&self, network: &Network) -> Result<WitnessReport, WError> {
+        self.serializer
+            .witness_report(network)
+            .map_err(WError::add_err_trace("TxParser - witness_report"))
+    }
+
+    /// Rust source rebuilding the parsed transaction with `TxBuilder`, see [`tx_builder_code`].
+    ///
+    /// The last output is taken as change, so the builder adds it back on completion.
+    pub fn to_builder_code(&self) -> String {
+        tx_builder_code(&self.get_builder_body_without_change())
+    }
+
+    /// Summarize the parsed transaction in plain terms, see [`TxExplanation`]
+    pub fn explain(
+        &self,
+        protocol: &Protocol,
+        slot_config: &SlotConfig,
+    ) -> Result<TxExplanation, WError> {
+        let mut explanation =
+            TxExplanation::new(&self.get_builder_body(), &[], protocol, slot_config)
+                .map_err(WError::add_err_trace("TxParser - explain"))?;
+        explanation.with_fee(self.get_fee());
+        Ok(explanation)
+    }
+
+    /// Diff two tx hexes, see [`TxDiff`], including their fees and vkey witnesses.
+    ///
+    /// Both txs are parsed with [`TxParser::parse`], so UTxOs not provided are resolved with
+    /// the fetcher, and the parser holds `after_hex` afterwards.
+    pub async fn diff(
+        &mut self,
+        before_hex: &str,
+        after_hex: &str,
+        provided_utxos: &[UTxO],
+    ) -> Result<TxDiff, WError> {
+        self.parse(before_hex, provided_utxos).await?;
+        let (before, before_fee) = (self.get_builder_body(), self.get_fee());
+        self.parse(after_hex, provided_utxos).await?;
+        let (after, after_fee) = (self.get_builder_body(), self.get_fee());
+        let mut diff =
+            TxDiff::new(&before, &after).map_err(WError::add_err_trace("TxParser - diff"))?;
+        diff.with_fees(before_fee, after_fee).with_vkey_witnesses(
+            &get_vkey_witness_key_hashes(before_hex)?,
+            &get_vkey_witness_key_hashes(after_hex)?,
+        );
+        Ok(diff)
+    }
 }
@@ -0,0 +1,698 @@
+use whisky_common::*;
+
+/// Generate Rust source that rebuilds `body` with [`crate::TxBuilder`], e.g. to turn a
+/// parsed mainnet transaction into a regression test.
+///
+/// The output is a `build_tx` function chaining `tx_in`, `tx_in_script`, `tx_out`, `mint`,
+/// `withdrawal`, `vote`, certificate and similar calls, followed by `complete_sync`. Data
+/// is emitted as CBOR and metadata as `TransactionMetadatum`. Within inputs, mints,
+/// withdrawals and votes, items without a Plutus script come first, since the ledger does
+/// not order them and the builder expects a Plutus item to be followed by a Plutus item once
+/// its script is given by reference.
+///
+/// Items the builder cannot express, such as native script withdrawals and votes or script
+/// proposals, are left as comments in the chain. Pass a body without the change output, see
+/// `TxParser::get_builder_body_without_change`, so the builder adds the change back.
+pub fn tx_builder_code(body: &TxBuilderBody) -> String {
+    let mut calls: Vec<String> = vec![];
+
+    let (plutus_inputs, other_inputs): (Vec<&TxIn>, Vec<&TxIn>) = body
+        .inputs
+        .iter()
+        .partition(|input| matches!(input, TxIn::ScriptTxIn(_)));
+    for input in other_inputs.into_iter().chain(plutus_inputs) {
+        tx_in_calls(&mut calls, input);
+    }
+    for input in &body.reference_inputs {
+        calls.push(format!(
+            ".read_only_tx_in_reference({:?}, {}, {:?})",
+            input.tx_hash, input.tx_index, input.script_size
+        ));
+    }
+
+    for output in &body.outputs {
+        tx_out_calls(&mut calls, output);
+    }
+
+    let (plutus_mints, native_mints): (Vec<&MintItem>, Vec<&MintItem>) = body
+        .mints
+        .iter()
+        .partition(|mint| matches!(mint, MintItem::ScriptMint(_)));
+    for mint in native_mints.into_iter().chain(plutus_mints) {
+        mint_calls(&mut calls, mint);
+    }
+
+    let (plutus_withdrawals, other_withdrawals): (Vec<&Withdrawal>, Vec<&Withdrawal>) = body
+        .withdrawals
+        .iter()
+        .partition(|withdrawal| matches!(withdrawal, Withdrawal::PlutusScriptWithdrawal(_)));
+    for withdrawal in other_withdrawals.into_iter().chain(plutus_withdrawals) {
+        withdrawal_calls(&mut calls, withdrawal);
+    }
+
+    for certificate in &body.certificates {
+        certificate_calls(&mut calls, certificate);
+    }
+
+    let (plutus_votes, other_votes): (Vec<&Vote>, Vec<&Vote>) = body
+        .votes
+        .iter()
+        .partition(|vote| matches!(vote, Vote::ScriptVote(_)));
+    for vote in other_votes.into_iter().chain(plutus_votes) {
+        vote_calls(&mut calls, vote);
+    }
+
+    for proposal in &body.proposals {
+        match proposal {
+            Proposal::BasicProposal(proposal) => calls.push(format!(
+                ".proposal({}, {:?}, {:?}, &{})",
+                proposal.deposit,
+                proposal.reward_account,
+                proposal.gov_action,
+                anchor_code(&proposal.anchor)
+            )),
+            Proposal::ScriptProposal(proposal) => calls.push(format!(
+                "// script proposal {} by {}",
+                proposal.proposal.gov_action, proposal.proposal.reward_account
+            )),
+        }
+    }
+    if let Some(treasury_value) = body.treasury_value {
+        calls.push(format!(".treasury_value({})", treasury_value));
+    }
+    if let Some(donation) = body.donation {
+        calls.push(format!(".donation({})", donation));
+    }
+
+    for collateral in &body.collaterals {
+        let tx_in = &collateral.tx_in;
+        calls.push(format!(
+            ".tx_in_collateral({:?}, {}, {}, {:?})",
+            tx_in.tx_hash,
+            tx_in.tx_index,
+            assets(tx_in.amount.as_deref().unwrap_or_default()),
+            tx_in.address.as_deref().unwrap_or_default()
+        ));
+    }
+    if let Some(total_collateral) = &body.total_collateral {
+        calls.push(format!(".set_total_collateral({:?})", total_collateral));
+    }
+    if let Some(address) = &body.collateral_return_address {
+        calls.push(format!(".set_collateral_return_address({:?})", address));
+    }
+
+    for signer in &body.required_signatures {
+        calls.push(format!(".required_signer_hash({:?})", signer));
+    }
+    if let Some(slot) = body.validity_range.invalid_before {
+        calls.push(format!(".invalid_before({})", slot));
+    }
+    if let Some(slot) = body.validity_range.invalid_hereafter {
+        calls.push(format!(".invalid_hereafter({})", slot));
+    }
+    for metadata in &body.metadata {
+        match (&metadata.metadatum, metadata.tag.parse::<u64>()) {
+            (Some(metadatum), Ok(tag)) => {
+                calls.push(format!(".metadata({}, {})", tag, metadatum_code(metadatum)))
+            }
+            _ => calls.push(format!(
+                ".metadata_value({:?}, {:?})",
+                metadata.tag, metadata.metadata
+            )),
+        }
+    }
+    if let Some(network) = &body.network {
+        calls.push(format!(".network({})", network_code(network)));
+    }
+    if let Some(fee) = &body.fee {
+        calls.push(format!(".set_fee({:?})", fee));
+    }
+    if !body.change_address.is_empty() {
+        calls.push(format!(".change_address({:?})", body.change_address));
+    }
+    match &body.change_datum {
+        Some(Datum::Inline(data)) => {
+            calls.push(format!(".change_output_datum({})", data_code(data)))
+        }
+        Some(_) => calls.push("// change datum which is not inline".to_string()),
+        None => {}
+    }
+    calls.push(".complete_sync(None)?;".to_string());
+
+    let mut code = String::from("use whisky::*;\n\n");
+    code.push_str("pub fn build_tx() -> Result<String, WError> {\n");
+    code.push_str("    let mut tx_builder = TxBuilder::new_core();\n");
+    code.push_str("    tx_builder\n");
+    for call in calls {
+        code.push_str(&format!("        {}\n", call));
+    }
+    code.push_str("    Ok(tx_builder.tx_hex())\n");
+    code.push_str("}\n");
+    code
+}
+
+fn tx_in_calls(calls: &mut Vec<String>, input: &TxIn) {
+    let tx_in = match input {
+        TxIn::PubKeyTxIn(input) => &input.tx_in,
+        TxIn::SimpleScriptTxIn(input) => &input.tx_in,
+        TxIn::ScriptTxIn(input) => &input.tx_in,
+    };
+    if let TxIn::ScriptTxIn(input) = input {
+        let language_version = match &input.script_tx_in.script_source {
+            Some(ScriptSource::ProvidedScriptSource(source)) => &source.language_version,
+            Some(ScriptSource::InlineScriptSource(source)) => &source.language_version,
+            None => &LanguageVersion::V3,
+        };
+        calls.push(format!(
+            ".spending_plutus_script(&{})",
+            language_version_code(language_version)
+        ));
+    }
+    calls.push(format!(
+        ".tx_in({:?}, {}, {}, {:?})",
+        tx_in.tx_hash,
+        tx_in.tx_index,
+        assets(tx_in.amount.as_deref().unwrap_or_default()),
+        tx_in.address.as_deref().unwrap_or_default()
+    ));
+
+    match input {
+        TxIn::PubKeyTxIn(_) => {}
+        TxIn::SimpleScriptTxIn(input) => match &input.simple_script_tx_in {
+            SimpleScriptTxInParameter::ProvidedSimpleScriptSource(source) => {
+                calls.push(format!(".tx_in_script({:?})", source.script_cbor))
+            }
+            SimpleScriptTxInParameter::InlineSimpleScriptSource(source) => calls.push(format!(
+                "// native script {} from reference input {}#{}",
+                source.simple_script_hash, source.ref_tx_in.tx_hash, source.ref_tx_in.tx_index
+            )),
+        },
+        TxIn::ScriptTxIn(input) => {
+            match &input.script_tx_in.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => {
+                    calls.push(format!(".tx_in_script({:?})", source.script_cbor))
+                }
+                Some(ScriptSource::InlineScriptSource(source)) => calls.push(format!(
+                    ".spending_tx_in_reference({:?}, {}, {:?}, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// script source not found".to_string()),
+            }
+            match &input.script_tx_in.datum_source {
+                Some(DatumSource::ProvidedDatumSource(source)) => {
+                    calls.push(format!(".tx_in_datum_value(&{})", data_code(&source.data)))
+                }
+                Some(DatumSource::InlineDatumSource(_)) => {
+                    calls.push(".tx_in_inline_datum_present()".to_string())
+                }
+                None => calls.push("// datum not found".to_string()),
+            }
+            if let Some(redeemer) = &input.script_tx_in.redeemer {
+                calls.push(format!(
+                    ".tx_in_redeemer_value(&{})",
+                    redeemer_code(redeemer)
+                ));
+            }
+        }
+    }
+}
+
+fn tx_out_calls(calls: &mut Vec<String>, output: &Output) {
+    calls.push(format!(
+        ".tx_out({:?}, {})",
+        output.address,
+        assets(&output.amount)
+    ));
+    match &output.datum {
+        Some(Datum::Inline(data)) => {
+            calls.push(format!(".tx_out_inline_datum_value(&{})", data_code(data)))
+        }
+        Some(Datum::Hash(data)) => {
+            calls.push(format!(".tx_out_datum_hash_value(&{})", data_code(data)))
+        }
+        Some(Datum::Embedded(data)) => {
+            calls.push(format!(".tx_out_datum_embed_value(&{})", data_code(data)))
+        }
+        None => {}
+    }
+    match &output.reference_script {
+        Some(OutputScriptSource::ProvidedScriptSource(source)) => calls.push(format!(
+            ".tx_out_reference_script({:?}, Some({}))",
+            source.script_cbor,
+            language_version_code(&source.language_version)
+        )),
+        Some(OutputScriptSource::ProvidedSimpleScriptSource(source)) => calls.push(format!(
+            ".tx_out_reference_script({:?}, None)",
+            source.script_cbor
+        )),
+        None => {}
+    }
+}
+
+fn mint_calls(calls: &mut Vec<String>, mint: &MintItem) {
+    match mint {
+        MintItem::SimpleScriptMint(mint) => {
+            calls.push(mint_call(&mint.mint));
+            match &mint.script_source {
+                Some(SimpleScriptSource::ProvidedSimpleScriptSource(source)) => {
+                    calls.push(format!(".minting_script({:?})", source.script_cbor))
+                }
+                Some(SimpleScriptSource::InlineSimpleScriptSource(source)) => calls.push(format!(
+                    ".mint_tx_in_reference({:?}, {}, {:?}, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.simple_script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// minting script not found".to_string()),
+            }
+        }
+        MintItem::ScriptMint(mint) => {
+            let language_version = match &mint.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => &source.language_version,
+                Some(ScriptSource::InlineScriptSource(source)) => &source.language_version,
+                None => &LanguageVersion::V3,
+            };
+            calls.push(format!(
+                ".mint_plutus_script(&{})",
+                language_version_code(language_version)
+            ));
+            calls.push(mint_call(&mint.mint));
+            match &mint.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => {
+                    calls.push(format!(".minting_script({:?})", source.script_cbor))
+                }
+                Some(ScriptSource::InlineScriptSource(source)) => calls.push(format!(
+                    ".mint_tx_in_reference({:?}, {}, {:?}, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// minting script not found".to_string()),
+            }
+            if let Some(redeemer) = &mint.redeemer {
+                calls.push(format!(
+                    ".mint_redeemer_value(&{})",
+                    redeemer_code(redeemer)
+                ));
+            }
+        }
+    }
+}
+
+fn mint_call(mint: &MintParameter) -> String {
+    format!(
+        ".mint({}, {:?}, {:?})",
+        mint.amount, mint.policy_id, mint.asset_name
+    )
+}
+
+fn withdrawal_calls(calls: &mut Vec<String>, withdrawal: &Withdrawal) {
+    match withdrawal {
+        Withdrawal::PubKeyWithdrawal(withdrawal) => calls.push(format!(
+            ".withdrawal({:?}, {})",
+            withdrawal.address, withdrawal.coin
+        )),
+        Withdrawal::SimpleScriptWithdrawal(withdrawal) => calls.push(format!(
+            "// native script withdrawal of {} lovelace from {}",
+            withdrawal.coin, withdrawal.address
+        )),
+        Withdrawal::PlutusScriptWithdrawal(withdrawal) => {
+            let language_version = match &withdrawal.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => &source.language_version,
+                Some(ScriptSource::InlineScriptSource(source)) => &source.language_version,
+                None => &LanguageVersion::V3,
+            };
+            calls.push(format!(
+                ".withdrawal_plutus_script(&{})",
+                language_version_code(language_version)
+            ));
+            calls.push(format!(
+                ".withdrawal({:?}, {})",
+                withdrawal.address, withdrawal.coin
+            ));
+            match &withdrawal.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => {
+                    calls.push(format!(".withdrawal_script({:?})", source.script_cbor))
+                }
+                Some(ScriptSource::InlineScriptSource(source)) => calls.push(format!(
+                    ".withdrawal_tx_in_reference({:?}, {}, {:?}, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// withdrawal script not found".to_string()),
+            }
+            if let Some(redeemer) = &withdrawal.redeemer {
+                calls.push(format!(
+                    ".withdrawal_redeemer_value(&{})",
+                    redeemer_code(redeemer)
+                ));
+            }
+        }
+    }
+}
+
+fn certificate_calls(calls: &mut Vec<String>, certificate: &Certificate) {
+    match certificate {
+        Certificate::BasicCertificate(cert) => certificate_type_calls(calls, cert),
+        Certificate::ScriptCertificate(cert) => {
+            certificate_type_calls(calls, &cert.cert);
+            match &cert.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => calls.push(format!(
+                    ".certificate_script({:?}, Some({}))",
+                    source.script_cbor,
+                    language_version_code(&source.language_version)
+                )),
+                Some(ScriptSource::InlineScriptSource(source)) => calls.push(format!(
+                    ".certificate_tx_in_reference({:?}, {}, {:?}, Some({}), {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.script_hash,
+                    language_version_code(&source.language_version),
+                    source.script_size
+                )),
+                None => calls.push("// certificate script not found".to_string()),
+            }
+            if let Some(redeemer) = &cert.redeemer {
+                calls.push(format!(
+                    ".certificate_redeemer_value(&{})",
+                    redeemer_code(redeemer)
+                ));
+            }
+        }
+        Certificate::SimpleScriptCertificate(cert) => {
+            certificate_type_calls(calls, &cert.cert);
+            match &cert.simple_script_source {
+                Some(SimpleScriptSource::ProvidedSimpleScriptSource(source)) => calls.push(
+                    format!(".certificate_script({:?}, None)", source.script_cbor),
+                ),
+                Some(SimpleScriptSource::InlineSimpleScriptSource(source)) => calls.push(format!(
+                    ".certificate_tx_in_reference({:?}, {}, {:?}, None, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.simple_script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// certificate script not found".to_string()),
+            }
+        }
+    }
+}
+
+fn certificate_type_calls(calls: &mut Vec<String>, cert: &CertificateType) {
+    let call = match cert {
+        CertificateType::RegisterPool(cert) => format!(
+            ".register_pool_certificate(&{})",
+            pool_params_code(&cert.pool_params)
+        ),
+        CertificateType::RegisterStake(cert) => {
+            if cert.coin != Protocol::default().key_deposit {
+                calls.push(format!(
+                    "// the deposit of {} lovelace comes from the protocol parameters",
+                    cert.coin
+                ));
+            }
+            format!(".register_stake_certificate({:?})", cert.stake_key_address)
+        }
+        CertificateType::DelegateStake(cert) => format!(
+            ".delegate_stake_certificate({:?}, {:?})",
+            cert.stake_key_address, cert.pool_id
+        ),
+        CertificateType::DeregisterStake(cert) => format!(
+            ".deregister_stake_certificate({:?})",
+            cert.stake_key_address
+        ),
+        CertificateType::RetirePool(cert) => format!(
+            ".retire_pool_certificate({:?}, {})",
+            cert.pool_id, cert.epoch
+        ),
+        CertificateType::VoteDelegation(cert) => format!(
+            ".vote_delegation_certificate({:?}, &{})",
+            cert.stake_key_address,
+            drep_code(&cert.drep)
+        ),
+        CertificateType::StakeAndVoteDelegation(cert) => format!(
+            ".stake_and_vote_delegation_certificate({:?}, {:?}, &{})",
+            cert.stake_key_address,
+            cert.pool_key_hash,
+            drep_code(&cert.drep)
+        ),
+        CertificateType::StakeRegistrationAndDelegation(cert) => format!(
+            ".stake_registration_and_delegation({:?}, {:?}, {})",
+            cert.stake_key_address, cert.pool_key_hash, cert.coin
+        ),
+        CertificateType::VoteRegistrationAndDelegation(cert) => format!(
+            ".vote_registration_and_delegation({:?}, &{}, {})",
+            cert.stake_key_address,
+            drep_code(&cert.drep),
+            cert.coin
+        ),
+        CertificateType::StakeVoteRegistrationAndDelegation(cert) => format!(
+            ".stake_vote_registration_and_delegation({:?}, {:?}, &{}, {})",
+            cert.stake_key_address,
+            cert.pool_key_hash,
+            drep_code(&cert.drep),
+            cert.coin
+        ),
+        CertificateType::CommitteeHotAuth(cert) => format!(
+            ".committee_hot_auth({:?}, {:?})",
+            cert.committee_cold_key_address, cert.committee_hot_key_address
+        ),
+        CertificateType::CommitteeColdResign(cert) => format!(
+            ".commitee_cold_resign({:?}, {})",
+            cert.committee_cold_key_address,
+            option_code(cert.anchor.as_ref(), anchor_code)
+        ),
+        CertificateType::DRepRegistration(cert) => format!(
+            ".drep_registration({:?}, {}, {})",
+            cert.drep_id,
+            cert.coin,
+            option_code(cert.anchor.as_ref(), anchor_code)
+        ),
+        CertificateType::DRepDeregistration(cert) => {
+            format!(".drep_deregistration({:?}, {})", cert.drep_id, cert.coin)
+        }
+        CertificateType::DRepUpdate(cert) => format!(
+            ".drep_update({:?}, {})",
+            cert.drep_id,
+            option_code(cert.anchor.as_ref(), anchor_code)
+        ),
+    };
+    calls.push(call);
+}
+
+fn vote_calls(calls: &mut Vec<String>, vote: &Vote) {
+    match vote {
+        Vote::BasicVote(vote) => calls.push(vote_call(vote)),
+        Vote::SimpleScriptVote(vote) => calls.push(format!(
+            "// native script vote by {:?} on {}#{}",
+            vote.vote.voter, vote.vote.gov_action_id.tx_hash, vote.vote.gov_action_id.tx_index
+        )),
+        Vote::ScriptVote(vote) => {
+            let language_version = match &vote.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => &source.language_version,
+                Some(ScriptSource::InlineScriptSource(source)) => &source.language_version,
+                None => &LanguageVersion::V3,
+            };
+            calls.push(format!(
+                ".voting_plutus_script(&{})",
+                language_version_code(language_version)
+            ));
+            calls.push(vote_call(&vote.vote));
+            match &vote.script_source {
+                Some(ScriptSource::ProvidedScriptSource(source)) => {
+                    calls.push(format!(".vote_script({:?})", source.script_cbor))
+                }
+                Some(ScriptSource::InlineScriptSource(source)) => calls.push(format!(
+                    ".vote_tx_in_reference({:?}, {}, {:?}, {})",
+                    source.ref_tx_in.tx_hash,
+                    source.ref_tx_in.tx_index,
+                    source.script_hash,
+                    source.script_size
+                )),
+                None => calls.push("// voting script not found".to_string()),
+            }
+            if let Some(redeemer) = &vote.redeemer {
+                calls.push(format!(
+                    ".vote_redeemer_value(&{})",
+                    redeemer_code(redeemer)
+                ));
+            }
+        }
+    }
+}
+
+fn vote_call(vote: &VoteType) -> String {
+    let voter = match &vote.voter {
+        Voter::ConstitutionalCommitteeHotCred(credential) => format!(
+            "Voter::ConstitutionalCommitteeHotCred({})",
+            credential_code(credential)
+        ),
+        Voter::DRepId(drep_id) => format!("Voter::DRepId({:?}.to_string())", drep_id),
+        Voter::StakingPoolKeyHash(pool) => {
+            format!("Voter::StakingPoolKeyHash({:?}.to_string())", pool)
+        }
+    };
+    format!(
+        ".vote(&{}, &RefTxIn {{ tx_hash: {:?}.to_string(), tx_index: {}, script_size: None }}, &VotingProcedure {{ vote_kind: VoteKind::{:?}, anchor: {} }})",
+        voter,
+        vote.gov_action_id.tx_hash,
+        vote.gov_action_id.tx_index,
+        vote.voting_procedure.vote_kind,
+        option_code(vote.voting_procedure.anchor.as_ref(), anchor_code)
+    )
+}
+
+fn assets(amount: &[Asset]) -> String {
+    let assets: Vec<String> = amount
+        .iter()
+        .map(|asset| {
+            format!(
+                "Asset::new_from_str({:?}, {:?})",
+                asset.unit(),
+                asset.quantity()
+            )
+        })
+        .collect();
+    format!("&[{}]", assets.join(", "))
+}
+
+fn data_code(cbor: &str) -> String {
+    format!("WData::CBOR({:?}.to_string())", cbor)
+}
+
+fn redeemer_code(redeemer: &Redeemer) -> String {
+    format!(
+        "WRedeemer {{ data: {}, ex_units: Budget {{ mem: {}, steps: {} }} }}",
+        data_code(&redeemer.data),
+        redeemer.ex_units.mem,
+        redeemer.ex_units.steps
+    )
+}
+
+fn metadatum_code(metadatum: &TransactionMetadatum) -> String {
+    match metadatum {
+        TransactionMetadatum::Int(int) => format!("TransactionMetadatum::Int({})", int),
+        TransactionMetadatum::Bytes(bytes) => {
+            format!("TransactionMetadatum::Bytes({:?}.to_string())", bytes)
+        }
+        TransactionMetadatum::Text(text) => {
+            format!("TransactionMetadatum::Text({:?}.to_string())", text)
+        }
+        TransactionMetadatum::List(items) => {
+            let items: Vec<String> = items.iter().map(metadatum_code).collect();
+            format!("TransactionMetadatum::List(vec![{}])", items.join(", "))
+        }
+        TransactionMetadatum::Map(entries) => {
+            let entries: Vec<String> = entries
+                .iter()
+                .map(|(key, value)| format!("({}, {})", metadatum_code(key), metadatum_code(value)))
+                .collect();
+            format!("TransactionMetadatum::Map(vec![{}])", entries.join(", "))
+        }
+    }
+}
+
+fn language_version_code(language_version: &LanguageVersion) -> String {
+    format!("LanguageVersion::{:?}", language_version)
+}
+
+fn network_code(network: &Network) -> String {
+    match network {
+        Network::Mainnet => "Network::Mainnet".to_string(),
+        Network::Preprod => "Network::Preprod".to_string(),
+        Network::Preview => "Network::Preview".to_string(),
+        Network::Custom(cost_models) => {
+            let cost_models: Vec<String> = cost_models
+                .iter()
+                .map(|cost_model| format!("vec!{:?}", cost_model))
+                .collect();
+            format!("Network::Custom(vec![{}])", cost_models.join(", "))
+        }
+    }
+}
+
+fn credential_code(credential: &Credential) -> String {
+    match credential {
+        Credential::KeyHash(hash) => format!("Credential::KeyHash({:?}.to_string())", hash),
+        Credential::ScriptHash(hash) => {
+            format!("Credential::ScriptHash({:?}.to_string())", hash)
+        }
+    }
+}
+
+fn drep_code(drep: &DRep) -> String {
+    match drep {
+        DRep::DRepId(drep_id) => format!("DRep::DRepId({:?}.to_string())", drep_id),
+        DRep::AlwaysAbstain => "DRep::AlwaysAbstain".to_string(),
+        DRep::AlwaysNoConfidence => "DRep::AlwaysNoConfidence".to_string(),
+    }
+}
+
+fn anchor_code(anchor: &Anchor) -> String {
+    format!(
+        "Anchor {{ anchor_url: {:?}.to_string(), anchor_data_hash: {:?}.to_string() }}",
+        anchor.anchor_url, anchor.anchor_data_hash
+    )
+}
+
+fn option_code<T>(value: Option<&T>, code: impl Fn(&T) -> String) -> String {
+    match value {
+        Some(value) => format!("Some({})", code(value)),
+        None => "None".to_string(),
+    }
+}
+
+fn option_string_code(value: Option<&String>) -> String {
+    option_code(value, |value| format!("{:?}.to_string()", value))
+}
+
+fn pool_params_code(pool_params: &PoolParams) -> String {
+    let relays: Vec<String> = pool_params
+        .relays
+        .iter()
+        .map(|relay| match relay {
+            Relay::SingleHostAddr(relay) => format!(
+                "Relay::SingleHostAddr(SingleHostAddr {{ ipv4: {}, ipv6: {}, port: {:?} }})",
+                option_string_code(relay.ipv4.as_ref()),
+                option_string_code(relay.ipv6.as_ref()),
+                relay.port
+            ),
+            Relay::SingleHostName(relay) => format!(
+                "Relay::SingleHostName(SingleHostName {{ domain_name: {:?}.to_string(), port: {:?} }})",
+                relay.domain_name, relay.port
+            ),
+            Relay::MultiHostName(relay) => format!(
+                "Relay::MultiHostName(MultiHostName {{ domain_name: {:?}.to_string() }})",
+                relay.domain_name
+            ),
+        })
+        .collect();
+    let owners: Vec<String> = pool_params
+        .owners
+        .iter()
+        .map(|owner| format!("{:?}.to_string()", owner))
+        .collect();
+    format!(
+        "PoolParams {{ vrf_key_hash: {:?}.to_string(), operator: {:?}.to_string(), pledge: {:?}.to_string(), cost: {:?}.to_string(), margin: ({}, {}), relays: vec![{}], owners: vec![{}], reward_address: {:?}.to_string(), metadata: {} }}",
+        pool_params.vrf_key_hash,
+        pool_params.operator,
+        pool_params.pledge,
+        pool_params.cost,
+        pool_params.margin.0,
+        pool_params.margin.1,
+        relays.join(", "),
+        owners.join(", "),
+        pool_params.reward_address,
+        option_code(pool_params.metadata.as_ref(), |metadata| format!(
+            "PoolMetadata {{ url: {:?}.to_string(), hash: {:?}.to_string() }}",
+            metadata.url, metadata.hash
+        ))
+    )
+}
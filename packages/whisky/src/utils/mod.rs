@@ -1,3 +1,5 @@
 pub mod blueprint;
+pub mod codegen;
 pub mod utxos_to_assets;
 pub use blueprint::*;
+pub use codegen::*;
@@ -0,0 +1,145 @@
+mod common;
+
+#[cfg(test)]
+mod cip68_tests {
+    use crate::common::{utxo, ADDRESS};
+    use whisky::data::PlutusData;
+    use whisky::{data::*, *};
+
+    const NATIVE_SCRIPT: &str = "8200581c5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f";
+    const SCRIPT_ADDRESS: &str = "addr_test1wpgxy3dc6yzzs4y5n8k0e5zrt4dqhx364sk9hnxgy3zp5usfh3tau";
+
+    #[test]
+    fn test_cip67_labels() {
+        assert_eq!(cip67_label(100), "000643b0");
+        assert_eq!(cip67_label(222), "000de140");
+        assert_eq!(cip67_label(333), "0014df10");
+        assert_eq!(cip67_label(444), "001bc280");
+        assert_eq!(Cip68Label::Nft.asset_name("74657374"), "000de14074657374");
+
+        assert_eq!(
+            parse_cip67_asset_name("000643b074657374"),
+            Some((100, "74657374".to_string()))
+        );
+        assert_eq!(
+            Cip68Label::from_label(parse_cip67_asset_name("001bc280").unwrap().0),
+            Some(Cip68Label::Rft)
+        );
+        // Wrong checksum, missing zero nibbles, too short
+        assert_eq!(parse_cip67_asset_name("000643b174657374"), None);
+        assert_eq!(parse_cip67_asset_name("100643b074657374"), None);
+        assert_eq!(parse_cip67_asset_name("000643"), None);
+    }
+
+    #[test]
+    fn test_cip68_datum() {
+        let mut datum = Cip68Datum::new(1);
+        datum
+            .text("name", "My NFT")
+            .field("rarity", PlutusData::Integer(Int::new(3)));
+        assert_eq!(datum.get_text("name"), Some("My NFT".to_string()));
+        assert_eq!(datum.get_text("rarity"), None);
+        assert_eq!(datum.get_text("image"), None);
+
+        let json = datum.to_json();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "constructor": 0,
+                "fields": [
+                    {"map": [
+                        {"k": {"bytes": "6e616d65"}, "v": {"bytes": "4d79204e4654"}},
+                        {"k": {"bytes": "726172697479"}, "v": {"int": 3}}
+                    ]},
+                    {"int": 1},
+                    {"constructor": 0, "fields": []}
+                ]
+            })
+        );
+
+        let parsed = Cip68Datum::from_json(&json).unwrap();
+        assert_eq!(parsed.get_text("name"), Some("My NFT".to_string()));
+        assert_eq!(parsed.version.int, 1);
+    }
+
+    #[tokio::test]
+    async fn test_mint_cip68_pair() {
+        let mut datum = Cip68Datum::new(1);
+        datum.text("name", "My NFT");
+        let policy = get_native_script_hash(NATIVE_SCRIPT).unwrap();
+        let utxo = utxo(0, "9496607660");
+
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            )
+            .mint_cip68_pair(
+                &policy,
+                "74657374",
+                Cip68UserLabel::Nft,
+                1,
+                SCRIPT_ADDRESS,
+                &datum,
+            )
+            .minting_script(NATIVE_SCRIPT)
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+
+        let mut tx_parser = TxParser::new(None);
+        tx_parser
+            .parse(&tx_builder.tx_hex(), &[utxo])
+            .await
+            .unwrap();
+        let body = tx_parser.get_builder_body();
+        let mut minted: Vec<(String, String, i128)> = body
+            .mints
+            .iter()
+            .map(|mint| {
+                let mint = mint.get_mint_parameter();
+                (mint.policy_id, mint.asset_name, mint.amount)
+            })
+            .collect();
+        minted.sort();
+        assert_eq!(
+            minted,
+            vec![
+                (policy.clone(), "000643b074657374".to_string(), 1),
+                (policy.clone(), "000de14074657374".to_string(), 1)
+            ]
+        );
+
+        let output = &body.outputs[0];
+        assert_eq!(output.address, SCRIPT_ADDRESS);
+        assert_eq!(
+            output.amount[1..],
+            [Asset::new(
+                format!("{}000643b074657374", policy),
+                "1".to_string()
+            )]
+        );
+        assert_eq!(output.datum, Some(Datum::Inline(datum.to_cbor().unwrap())));
+    }
+
+    #[test]
+    fn test_cip68_datum_from_cip25_asset() {
+        let image = format!("ipfs://{}", "Q".repeat(70));
+        let mut asset = Cip25Asset::new("My NFT", &image);
+        asset
+            .media_type("image/png")
+            .field("rarity", TransactionMetadatum::Int(3));
+        let datum = Cip68Datum::from_cip25_asset(&asset, 1);
+        assert_eq!(datum.get_text("name"), Some("My NFT".to_string()));
+        // Whole text, CIP-68 datums are not bound by the 64-byte metadata limit
+        assert_eq!(datum.get_text("image"), Some(image));
+        assert_eq!(datum.get_text("mediaType"), Some("image/png".to_string()));
+        assert_eq!(
+            datum.get("rarity").map(PlutusDataJson::to_json),
+            Some(PlutusData::Integer(Int::new(3)).to_json())
+        );
+    }
+}
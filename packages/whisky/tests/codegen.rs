@@ -0,0 +1,205 @@
+// Generated by `tx_builder_code` from the parsed `metadata_tx`, see `test_generated_fixture`
+#[cfg(test)]
+#[rustfmt::skip]
+#[path = "codegen/metadata_tx.rs"]
+mod metadata_tx;
+
+#[cfg(test)]
+mod codegen_tests {
+    use whisky::*;
+
+    const TX_HASH: &str = "8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7";
+    const ADDRESS: &str = "addr_test1qra9zdhfa8kteyr3mfe7adkf5nlh8jl5xcg9e7pcp5w9yhyf5tek6vpnha97yd5yw9pezm3wyd77fyrfs3ynftyg7njs5cfz2x";
+    const SCRIPT_HASH: &str = "237948b06719bdca9c9ae03c7d9f70a070514758a4fb4514ba2c2ecb";
+
+    fn redeemer() -> WRedeemer {
+        WRedeemer {
+            data: WData::CBOR("d87980".to_string()),
+            ex_units: Budget {
+                mem: 35588,
+                steps: 13042895,
+            },
+        }
+    }
+
+    fn metadata_utxo() -> UTxO {
+        UTxO {
+            input: UtxoInput {
+                output_index: 2,
+                tx_hash: TX_HASH.to_string(),
+            },
+            output: UtxoOutput {
+                address: ADDRESS.to_string(),
+                amount: vec![Asset::new_from_str("lovelace", "5000000")],
+                data_hash: None,
+                plutus_data: None,
+                script_ref: None,
+                script_hash: None,
+            },
+        }
+    }
+
+    fn metadata_tx() -> String {
+        let utxo = metadata_utxo();
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            )
+            .tx_out(ADDRESS, &[Asset::new_from_str("lovelace", "2000000")])
+            .metadata(
+                674,
+                TransactionMetadatum::text_map(vec![(
+                    "msg",
+                    TransactionMetadatum::list(vec![TransactionMetadatum::text("whisky")]),
+                )]),
+            )
+            .metadata(
+                1,
+                TransactionMetadatum::list(vec![
+                    TransactionMetadatum::Int(-1),
+                    TransactionMetadatum::Int(u64::MAX as i128),
+                ]),
+            )
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        tx_builder.tx_hex()
+    }
+
+    async fn metadata_tx_code() -> String {
+        let mut tx_parser = TxParser::new(None);
+        tx_parser
+            .parse(&metadata_tx(), &[metadata_utxo()])
+            .await
+            .unwrap();
+        tx_builder_code(&tx_parser.get_builder_body_without_change())
+    }
+
+    fn tx_body() -> TxBuilderBody {
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                TX_HASH,
+                2,
+                &[Asset::new_from_str("lovelace", "5000000")],
+                ADDRESS,
+            )
+            .spending_plutus_script_v2()
+            .tx_in(
+                TX_HASH,
+                0,
+                &[Asset::new_from_str("lovelace", "6904620")],
+                ADDRESS,
+            )
+            .spending_tx_in_reference(TX_HASH, 1, SCRIPT_HASH, 950)
+            .tx_in_inline_datum_present()
+            .tx_in_redeemer_value(&redeemer())
+            .tx_out(ADDRESS, &[Asset::new_from_str("lovelace", "2000000")])
+            .tx_out_inline_datum_value(&WData::CBOR("d87980".to_string()))
+            .mint(1, SCRIPT_HASH, "4d794e4654")
+            .minting_script("8200581c9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e")
+            .required_signer_hash(SCRIPT_HASH)
+            .invalid_hereafter(100)
+            .change_address(ADDRESS);
+        tx_builder.queue_all_last_item();
+        let mut body = tx_builder.tx_builder_body.clone();
+        // As parsed from a tx, where inputs are sorted
+        body.inputs.reverse();
+        body
+    }
+
+    #[test]
+    fn test_tx_builder_code() {
+        let code = tx_builder_code(&tx_body());
+        assert!(
+            code.starts_with("use whisky::*;\n\npub fn build_tx() -> Result<String, WError> {\n")
+        );
+        assert!(code.ends_with("        .complete_sync(None)?;\n    Ok(tx_builder.tx_hex())\n}\n"));
+
+        let lines: Vec<&str> = code.lines().map(str::trim).collect();
+        let position = |line: &str| {
+            lines
+                .iter()
+                .position(|l| *l == line)
+                .unwrap_or_else(|| panic!("missing {} in\n{}", line, code))
+        };
+
+        let pub_key_input = position(&format!(
+            ".tx_in({:?}, 2, &[Asset::new_from_str(\"lovelace\", \"5000000\")], {:?})",
+            TX_HASH, ADDRESS
+        ));
+        let plutus = position(".spending_plutus_script(&LanguageVersion::V2)");
+        assert!(pub_key_input < plutus, "pub key inputs come first");
+        assert_eq!(
+            &lines[plutus + 1..plutus + 5],
+            &[
+                format!(
+                    ".tx_in({:?}, 0, &[Asset::new_from_str(\"lovelace\", \"6904620\")], {:?})",
+                    TX_HASH, ADDRESS
+                )
+                .as_str(),
+                format!(
+                    ".spending_tx_in_reference({:?}, 1, {:?}, 950)",
+                    TX_HASH, SCRIPT_HASH
+                )
+                .as_str(),
+                ".tx_in_inline_datum_present()",
+                ".tx_in_redeemer_value(&WRedeemer { data: WData::CBOR(\"d87980\".to_string()), ex_units: Budget { mem: 35588, steps: 13042895 } })",
+            ]
+        );
+
+        position(".tx_out_inline_datum_value(&WData::CBOR(\"d87980\".to_string()))");
+        position(&format!(".mint(1, {:?}, \"4d794e4654\")", SCRIPT_HASH));
+        position(
+            ".minting_script(\"8200581c9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e\")",
+        );
+        position(&format!(".required_signer_hash({:?})", SCRIPT_HASH));
+        position(".invalid_hereafter(100)");
+        position(&format!(".change_address({:?})", ADDRESS));
+    }
+
+    #[test]
+    fn test_tx_builder_code_governance() {
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .vote_delegation_certificate(
+                "stake_test1uzx0ksy9f4qnj2mzfdncqyjy84sszh64w43853nug5pedjgytgke9",
+                &DRep::AlwaysAbstain,
+            )
+            .vote(
+                &Voter::DRepId("drep1abc".to_string()),
+                &RefTxIn {
+                    tx_hash: TX_HASH.to_string(),
+                    tx_index: 0,
+                    script_size: None,
+                },
+                &VotingProcedure {
+                    vote_kind: VoteKind::Yes,
+                    anchor: None,
+                },
+            );
+        tx_builder.queue_all_last_item();
+        let code = tx_builder_code(&tx_builder.tx_builder_body);
+
+        assert!(code.contains(
+            ".vote_delegation_certificate(\"stake_test1uzx0ksy9f4qnj2mzfdncqyjy84sszh64w43853nug5pedjgytgke9\", &DRep::AlwaysAbstain)"
+        ));
+        assert!(code.contains(&format!(
+            ".vote(&Voter::DRepId(\"drep1abc\".to_string()), &RefTxIn {{ tx_hash: {:?}.to_string(), tx_index: 0, script_size: None }}, &VotingProcedure {{ vote_kind: VoteKind::Yes, anchor: None }})",
+            TX_HASH
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_generated_fixture() {
+        assert_eq!(
+            metadata_tx_code().await,
+            include_str!("codegen/metadata_tx.rs")
+        );
+        assert_eq!(super::metadata_tx::build_tx().unwrap(), metadata_tx());
+    }
+}
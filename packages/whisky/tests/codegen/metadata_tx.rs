@@ -0,0 +1,13 @@
+use whisky::*;
+
+pub fn build_tx() -> Result<String, WError> {
+    let mut tx_builder = TxBuilder::new_core();
+    tx_builder
+        .tx_in("8b7ea04a142933b3d8005bf98be906bdba10978891593b383deac933497e2ea7", 2, &[Asset::new_from_str("lovelace", "5000000")], "addr_test1qra9zdhfa8kteyr3mfe7adkf5nlh8jl5xcg9e7pcp5w9yhyf5tek6vpnha97yd5yw9pezm3wyd77fyrfs3ynftyg7njs5cfz2x")
+        .tx_out("addr_test1qra9zdhfa8kteyr3mfe7adkf5nlh8jl5xcg9e7pcp5w9yhyf5tek6vpnha97yd5yw9pezm3wyd77fyrfs3ynftyg7njs5cfz2x", &[Asset::new_from_str("lovelace", "2000000")])
+        .metadata(674, TransactionMetadatum::Map(vec![(TransactionMetadatum::Text("msg".to_string()), TransactionMetadatum::List(vec![TransactionMetadatum::Text("whisky".to_string())]))]))
+        .metadata(1, TransactionMetadatum::List(vec![TransactionMetadatum::Int(-1), TransactionMetadatum::Int(18446744073709551615)]))
+        .change_address("addr_test1qra9zdhfa8kteyr3mfe7adkf5nlh8jl5xcg9e7pcp5w9yhyf5tek6vpnha97yd5yw9pezm3wyd77fyrfs3ynftyg7njs5cfz2x")
+        .complete_sync(None)?;
+    Ok(tx_builder.tx_hex())
+}
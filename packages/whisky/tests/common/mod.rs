@@ -0,0 +1,22 @@
+use whisky::{Asset, UTxO, UtxoInput, UtxoOutput};
+
+const TX_HASH: &str = "fbd3e8091c9f0c5fb446be9e58d9235f548546a5a7d5f60ee56e389344db9c5e";
+pub const ADDRESS: &str = "addr_test1qpjfsrjdr8kk5ffj4jnw02ht3y3td0y0zkcm52rc6w7z7flmy7vplnvz6a7dncss4q5quqwt48tv9dewuvdxqssur9jqc4x459";
+
+/// A UTxO of `ADDRESS` holding only lovelace, as the inputs of the test transactions
+pub fn utxo(output_index: u32, lovelace: &str) -> UTxO {
+    UTxO {
+        input: UtxoInput {
+            output_index,
+            tx_hash: TX_HASH.to_string(),
+        },
+        output: UtxoOutput {
+            address: ADDRESS.to_string(),
+            amount: vec![Asset::new_from_str("lovelace", lovelace)],
+            data_hash: None,
+            plutus_data: None,
+            script_ref: None,
+            script_hash: None,
+        },
+    }
+}
@@ -202,6 +202,42 @@ mod int_tests {
         assert!(tx_builder.serializer.tx_hex() != *"");
     }
 
+    #[tokio::test]
+    async fn test_simple_spend_with_signer() {
+        let signing_key = "51022b7e38be01d1cc581230e18030e6e1a3e949a1fdd2aeae5f5412154fe82b";
+        let local_signer =
+            LocalSigner::from_signing_key(&SigningKey::from_hex(signing_key).unwrap()).unwrap();
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                "2cb57168ee66b68bd04a0d595060b546edf30c04ae1031b883c9ac797967dd85",
+                3,
+                &[Asset::new_from_str("lovelace", "9891607895")],
+                "addr_test1vru4e2un2tq50q4rv6qzk7t8w34gjdtw3y2uzuqxzj0ldrqqactxh",
+            )
+            .change_address("addr_test1vru4e2un2tq50q4rv6qzk7t8w34gjdtw3y2uzuqxzj0ldrqqactxh")
+            .signer(local_signer)
+            .complete_sync(None)
+            .unwrap();
+        let signed_with_signer = tx_builder.complete_signing_async().await.unwrap();
+
+        let mut local_tx_builder = TxBuilder::new_core();
+        let signed_locally = local_tx_builder
+            .tx_in(
+                "2cb57168ee66b68bd04a0d595060b546edf30c04ae1031b883c9ac797967dd85",
+                3,
+                &[Asset::new_from_str("lovelace", "9891607895")],
+                "addr_test1vru4e2un2tq50q4rv6qzk7t8w34gjdtw3y2uzuqxzj0ldrqqactxh",
+            )
+            .change_address("addr_test1vru4e2un2tq50q4rv6qzk7t8w34gjdtw3y2uzuqxzj0ldrqqactxh")
+            .signing_key(signing_key)
+            .complete_sync(None)
+            .unwrap()
+            .complete_signing()
+            .unwrap();
+        assert_eq!(signed_with_signer, signed_locally);
+    }
+
     #[test]
     fn test_simple_withdraw() {
         let mut tx_builder = TxBuilder::new(TxBuilderParam {
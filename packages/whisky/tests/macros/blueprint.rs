@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky::data::{Bool, ByteString, Int, List, Map, PlutusDataJson, Tuple};
+
+    mod contract {
+        whisky::blueprint!("tests/macros/plutus.json");
+    }
+    use contract::*;
+
+    const TX_HASH: &str = "2cb57168ee66b68bd04a0d595060b546edf30c04ae1031b883c9ac797967dd85";
+    const KEY_HASH: &str = "5867c3b8e27840f556ac268b781578b14c5661fc63ee720dbeab663f";
+
+    fn output_reference() -> OutputReference {
+        OutputReference {
+            transaction_id: ByteString::new(TX_HASH),
+            output_index: Int::new(1),
+        }
+    }
+
+    #[test]
+    fn test_blueprint_struct() {
+        let datum = VestingDatum {
+            lock_until: Int::new(1000),
+            owner: VerificationKeyHash::new(KEY_HASH),
+            beneficiary: OptionOutputReference::Some(output_reference()),
+            schedule: Map::new(&[(ByteString::new("aa"), Int::new(5))]),
+            type_: Bool::new(true),
+        };
+        let expected = json!({
+            "constructor": 0,
+            "fields": [
+                { "int": 1000 },
+                { "bytes": KEY_HASH },
+                {
+                    "constructor": 0,
+                    "fields": [{
+                        "constructor": 0,
+                        "fields": [{ "bytes": TX_HASH }, { "int": 1 }]
+                    }]
+                },
+                { "map": [{ "k": { "bytes": "aa" }, "v": { "int": 5 } }] },
+                { "constructor": 1, "fields": [] }
+            ]
+        });
+        assert_eq!(datum.to_json(), expected);
+
+        let parsed = VestingDatum::from_json(&expected).unwrap();
+        assert_eq!(parsed.to_json(), expected);
+        assert!(matches!(
+            parsed.beneficiary,
+            OptionOutputReference::Some(ref reference) if reference.output_index.int == 1
+        ));
+    }
+
+    #[test]
+    fn test_blueprint_enum() {
+        assert_eq!(
+            VestingAction::Claim.to_json(),
+            json!({ "constructor": 0, "fields": [] })
+        );
+        let extend = VestingAction::Extend(
+            Int::new(7),
+            Tuple::new((ByteString::new("bb"), Int::new(8))),
+        );
+        let extend_json = json!({
+            "constructor": 1,
+            "fields": [{ "int": 7 }, { "list": [{ "bytes": "bb" }, { "int": 8 }] }]
+        });
+        assert_eq!(extend.to_json(), extend_json);
+        assert!(matches!(
+            VestingAction::from_json(&extend_json).unwrap(),
+            VestingAction::Extend(..)
+        ));
+
+        let split = VestingAction::Split {
+            amounts: List::new(&[Int::new(1), Int::new(2)]),
+        };
+        let split_json = json!({
+            "constructor": 2,
+            "fields": [{ "list": [{ "int": 1 }, { "int": 2 }] }]
+        });
+        assert_eq!(split.to_json(), split_json);
+        assert_eq!(
+            VestingAction::from_json(&split_json).unwrap().to_json(),
+            split_json
+        );
+        assert!(VestingAction::from_json(&json!({ "constructor": 3, "fields": [] })).is_err());
+        assert!(
+            VestingAction::from_json(&json!({ "constructor": 1, "fields": [{ "int": 7 }] }))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_blueprint_recursive_type() {
+        let tree = Tree::Node {
+            left: Box::new(Tree::Leaf { value: Int::new(1) }),
+            right: Box::new(Tree::Leaf { value: Int::new(2) }),
+        };
+        let tree_json = tree.to_json();
+        assert_eq!(
+            tree_json,
+            json!({
+                "constructor": 1,
+                "fields": [
+                    { "constructor": 0, "fields": [{ "int": 1 }] },
+                    { "constructor": 0, "fields": [{ "int": 2 }] }
+                ]
+            })
+        );
+        assert_eq!(Tree::from_json(&tree_json).unwrap().to_json(), tree_json);
+    }
+
+    #[test]
+    fn test_blueprint_validators() {
+        assert_eq!(VestingVestingSpend::TITLE, "vesting.vesting.spend");
+
+        let withdrawal = StakeStakeWithdraw::blueprint(0).unwrap();
+        assert_eq!(withdrawal.hash, StakeStakeWithdraw::HASH);
+        assert!(withdrawal.address.starts_with("stake_test1"));
+
+        let spending =
+            VestingVestingSpend::blueprint(0, None, VerificationKeyHash::new(KEY_HASH)).unwrap();
+        assert!(spending.address.starts_with("addr_test1w"));
+        assert_ne!(spending.hash, VestingVestingSpend::HASH);
+        assert_eq!(
+            spending.cbor,
+            VestingVestingSpend::cbor(VerificationKeyHash::new(KEY_HASH)).unwrap()
+        );
+
+        let minting = TokenTokenMint::blueprint(output_reference(), Int::new(100)).unwrap();
+        assert_eq!(minting.hash.len(), 56);
+        assert_ne!(minting.hash, spending.hash);
+    }
+}
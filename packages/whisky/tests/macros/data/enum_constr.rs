@@ -209,4 +209,41 @@ mod tests {
                 .unwrap()
         );
     }
+
+    #[test]
+    fn test_constr_enum_cbor() {
+        let account = |id: &str| {
+            Account::from(
+                id,
+                (
+                    "04845038ee499ee8bc0afe56f688f27b2dd76f230d3698a9afcc1b66",
+                    false,
+                ),
+                (
+                    "b21f857716821354725bc2bd255dc2e5d5fdfa202556039b76c080a5",
+                    true,
+                ),
+            )
+        };
+        let variant = HydraUserIntentRedeemer::MintTransferIntent(
+            UserAccount::UserSpotAccount(account("508373c93a99495e949ed5101eecb3c4")),
+            UserAccount::UserSpotAccount(account("ab")),
+            Value::from_asset(&Asset::new_from_str("lovelace", "1000")),
+        );
+
+        let cbor = variant.to_cbor().unwrap();
+        assert_eq!(
+            cbor,
+            WData::JSON(variant.to_json_string()).to_cbor().unwrap()
+        );
+        let parsed = HydraUserIntentRedeemer::from_cbor(&cbor).unwrap();
+        assert_eq!(parsed.to_json(), variant.to_json());
+
+        let unit_cbor = HydraUserIntentRedeemer::BurnIntent.to_cbor().unwrap();
+        assert_eq!(unit_cbor, "d9050180");
+        assert!(matches!(
+            HydraUserIntentRedeemer::from_cbor(&unit_cbor).unwrap(),
+            HydraUserIntentRedeemer::BurnIntent
+        ));
+    }
 }
@@ -2,3 +2,4 @@ pub mod aliases;
 pub mod complex_generics;
 pub mod constr_wrapper;
 pub mod enum_constr;
+pub mod named_fields;
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use whisky::data::{ByteString, Int, List, PlutusDataJson};
+    use whisky_macros::{ConstrEnum, ConstrWrapper, ImplConstr};
+
+    #[derive(Debug, Clone, PartialEq, ImplConstr)]
+    #[constr(index = 1)]
+    pub struct Order {
+        pub owner: ByteString,
+        #[plutus(as = "Int")]
+        pub amount: u64,
+        pub referrer: Option<ByteString>,
+        #[plutus(skip)]
+        pub note: String,
+    }
+
+    #[derive(Debug, Clone, ImplConstr)]
+    pub struct Pair(pub Int, pub ByteString);
+
+    #[derive(Debug, Clone, ConstrWrapper)]
+    pub struct Amounts {
+        pub items: List<Int>,
+    }
+
+    #[derive(Debug, Clone, ConstrEnum)]
+    pub enum Action {
+        Cancel,
+        Fill {
+            order: Order,
+            #[plutus(as = "Int")]
+            filled: u32,
+        },
+        #[constr(index = 5)]
+        Close(ByteString, Option<Int>),
+    }
+
+    fn order() -> Order {
+        Order {
+            owner: ByteString::new("aa"),
+            amount: 1_000,
+            referrer: Some(ByteString::new("bb")),
+            note: "local only".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_named_struct() {
+        let expected = json!({
+            "constructor": 1,
+            "fields": [
+                { "bytes": "aa" },
+                { "int": 1000 },
+                { "constructor": 0, "fields": [{ "bytes": "bb" }] }
+            ]
+        });
+        assert_eq!(order().to_json(), expected);
+
+        let parsed = Order::from_json(&expected).unwrap();
+        assert_eq!(parsed.note, "");
+        assert_eq!(
+            parsed,
+            Order {
+                note: String::new(),
+                ..order()
+            }
+        );
+
+        let none = Order {
+            referrer: None,
+            ..order()
+        };
+        assert_eq!(
+            none.to_json()["fields"][2],
+            json!({ "constructor": 1, "fields": [] })
+        );
+        assert_eq!(Order::from_json(&none.to_json()).unwrap().referrer, None);
+    }
+
+    #[test]
+    fn test_named_struct_errors() {
+        let wrong_tag = json!({ "constructor": 0, "fields": [] });
+        assert!(Order::from_json(&wrong_tag).is_err());
+
+        let out_of_range = json!({
+            "constructor": 1,
+            "fields": [
+                { "bytes": "aa" },
+                { "int": -1 },
+                { "constructor": 1, "fields": [] }
+            ]
+        });
+        assert!(Order::from_json(&out_of_range).is_err());
+
+        let missing_field = json!({ "constructor": 1, "fields": [{ "bytes": "aa" }] });
+        assert!(Order::from_json(&missing_field).is_err());
+    }
+
+    #[test]
+    fn test_tuple_struct_and_wrapper() {
+        let pair = Pair(Int::new(1), ByteString::new("cc"));
+        let pair_json = json!({ "constructor": 0, "fields": [{ "int": 1 }, { "bytes": "cc" }] });
+        assert_eq!(pair.to_json(), pair_json);
+        assert_eq!(Pair::from_json(&pair_json).unwrap().1.bytes, "cc");
+
+        let amounts = Amounts {
+            items: List::new(&[Int::new(2)]),
+        };
+        let amounts_json = json!({ "list": [{ "int": 2 }] });
+        assert_eq!(amounts.to_json(), amounts_json);
+        assert_eq!(
+            Amounts::from_json(&amounts_json).unwrap().items.items.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_enum_named_variant_and_index_override() {
+        assert_eq!(
+            Action::Cancel.to_json(),
+            json!({ "constructor": 0, "fields": [] })
+        );
+
+        let fill = Action::Fill {
+            order: order(),
+            filled: 7,
+        };
+        let fill_json = json!({
+            "constructor": 1,
+            "fields": [order().to_json(), { "int": 7 }]
+        });
+        assert_eq!(fill.to_json(), fill_json);
+        assert!(matches!(
+            Action::from_json(&fill_json).unwrap(),
+            Action::Fill { filled: 7, .. }
+        ));
+
+        let close = Action::Close(ByteString::new("dd"), None);
+        let close_json = json!({
+            "constructor": 5,
+            "fields": [{ "bytes": "dd" }, { "constructor": 1, "fields": [] }]
+        });
+        assert_eq!(close.to_json(), close_json);
+        assert!(matches!(
+            Action::from_json(&close_json).unwrap(),
+            Action::Close(_, None)
+        ));
+        assert!(Action::from_json(&json!({ "constructor": 2, "fields": [] })).is_err());
+    }
+}
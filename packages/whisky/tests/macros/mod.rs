@@ -1 +1,3 @@
+mod blueprint;
 mod data;
+mod schema;
@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use whisky::data::blueprint::{Blueprint, PlutusSchema};
+    use whisky::data::{ByteString, Int, List, PlutusDataJson, Tuple};
+    use whisky_macros::{ConstrEnum, ImplConstr, PlutusSchema};
+
+    #[derive(Debug, Clone, ImplConstr, PlutusSchema)]
+    #[plutus(key = "cardano/transaction/OutputReference")]
+    pub struct OutputReference {
+        pub transaction_id: ByteString,
+        pub output_index: Int,
+    }
+
+    #[derive(Debug, Clone, ConstrEnum, PlutusSchema)]
+    #[plutus(key = "vesting/VestingAction")]
+    pub enum VestingAction {
+        Claim,
+        Extend(Int, Tuple<(ByteString, Int)>),
+        Split { amounts: List<Int> },
+    }
+
+    #[derive(Debug, Clone, ConstrEnum, PlutusSchema)]
+    #[plutus(key = "tree/Tree")]
+    pub enum Tree {
+        Leaf { value: Int },
+        Node { left: Box<Tree>, right: Box<Tree> },
+    }
+
+    #[derive(Debug, Clone, ConstrEnum, PlutusSchema)]
+    #[plutus(key = "vesting/VestingAction")]
+    pub enum DriftedVestingAction {
+        Claim,
+        Extend(Int, Tuple<(ByteString, Int)>),
+        #[constr(index = 3)]
+        Split {
+            amounts: List<Int>,
+        },
+    }
+
+    fn blueprint() -> Blueprint {
+        serde_json::from_str(include_str!("plutus.json")).unwrap()
+    }
+
+    #[test]
+    fn test_schema_matches_blueprint() {
+        let blueprint = blueprint();
+        assert_eq!(blueprint.schema_drift::<OutputReference>(), vec![]);
+        assert_eq!(blueprint.schema_drift::<Option<OutputReference>>(), vec![]);
+        assert_eq!(blueprint.schema_drift::<VestingAction>(), vec![]);
+        assert_eq!(blueprint.schema_drift::<Tree>(), vec![]);
+    }
+
+    #[test]
+    fn test_schema_definitions() {
+        assert_eq!(Tree::schema_key(), "tree/Tree");
+        assert_eq!(
+            Tree::schema_reference().reference,
+            "#/definitions/tree~1Tree"
+        );
+
+        let definitions = VestingAction::schema_definitions();
+        let mut keys: Vec<&String> = definitions.keys().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            vec![
+                "ByteArray",
+                "Int",
+                "List$Int",
+                "Tuple$ByteArray_Int",
+                "vesting/VestingAction"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schema_drift() {
+        let drifts = blueprint().schema_drift::<DriftedVestingAction>();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].key, "vesting/VestingAction");
+
+        #[derive(Debug, Clone, ImplConstr, PlutusSchema)]
+        #[plutus(key = "unknown/Type")]
+        pub struct Unknown {
+            pub value: Int,
+        }
+        let drifts = blueprint().schema_drift::<Unknown>();
+        assert_eq!(drifts.len(), 1);
+        assert_eq!(drifts[0].message, "missing from the blueprint");
+
+        // Keeps the value usable as data as well
+        let tree = Tree::Leaf { value: Int::new(1) };
+        assert_eq!(
+            Tree::from_json(&tree.to_json()).unwrap().to_json(),
+            tree.to_json()
+        );
+    }
+}
@@ -0,0 +1,137 @@
+mod common;
+
+#[cfg(test)]
+mod phase_one_tests {
+    use crate::common::{utxo, ADDRESS};
+    use whisky::*;
+
+    const PAYMENT_KEY_HASH: &str = "64980e4d19ed6a2532aca6e7aaeb8922b6bc8f15b1ba2878d3bc2f27";
+    const SIGNING_KEY: &str =
+        "58208d4cfa90e8bd0c48c52d2fb62c77ba3f6f5eb46f640d5f997390012928d670f7";
+
+    fn utxos() -> Vec<UTxO> {
+        vec![utxo(0, "9496607660")]
+    }
+
+    fn tx_builder() -> TxBuilder {
+        let utxo = &utxos()[0];
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            )
+            .tx_out(ADDRESS, &[Asset::new_from_str("lovelace", "2000000")])
+            .invalid_hereafter(1000)
+            .change_address(ADDRESS)
+            .network(Network::Preprod);
+        tx_builder
+    }
+
+    fn transfer_tx(signed: bool) -> String {
+        let mut builder = tx_builder();
+        if signed {
+            builder.signing_key(SIGNING_KEY);
+        }
+        builder.complete_sync(None).unwrap();
+        builder.complete_signing().unwrap()
+    }
+
+    fn validate(tx_hex: &str, protocol: &Protocol, current_slot: u64) -> Vec<Phase1Error> {
+        let errors =
+            validate_phase_one(tx_hex, &utxos(), protocol, &Network::Preprod, current_slot)
+                .unwrap();
+        let pallas_errors = whisky_pallas::utils::validate_phase_one(
+            tx_hex,
+            &utxos(),
+            protocol,
+            &Network::Preprod,
+            current_slot,
+        )
+        .unwrap();
+        assert_eq!(pallas_errors, errors);
+        errors
+    }
+
+    #[test]
+    fn test_phase_one_valid() {
+        let tx_hex = transfer_tx(true);
+        assert_eq!(validate(&tx_hex, &Protocol::default(), 500), vec![]);
+    }
+
+    #[test]
+    fn test_phase_one_missing_witness() {
+        let tx_hex = transfer_tx(false);
+        assert_eq!(
+            validate(&tx_hex, &Protocol::default(), 500),
+            vec![Phase1Error::MissingVkeyWitnesses(vec![
+                PAYMENT_KEY_HASH.to_string()
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_phase_one_protocol_and_slot() {
+        let tx_hex = transfer_tx(true);
+        let protocol = Protocol {
+            min_fee_b: 10_000_000,
+            max_tx_size: 100,
+            ..Protocol::default()
+        };
+        let errors = validate(&tx_hex, &protocol, 1000);
+        assert!(matches!(errors[0], Phase1Error::FeeTooSmall { .. }));
+        assert!(matches!(
+            errors[1],
+            Phase1Error::TxTooLarge { max_size: 100, .. }
+        ));
+        assert_eq!(
+            errors[2],
+            Phase1Error::OutsideValidityInterval {
+                current_slot: 1000,
+                invalid_before: None,
+                invalid_hereafter: Some(1000),
+            }
+        );
+        assert_eq!(errors.len(), 3);
+    }
+
+    #[test]
+    fn test_phase_one_unresolved_input() {
+        let tx_hex = transfer_tx(true);
+        let errors =
+            validate_phase_one(&tx_hex, &[], &Protocol::default(), &Network::Preprod, 500).unwrap();
+        assert_eq!(
+            errors,
+            vec![Phase1Error::BadInputs(vec![utxos()[0].input.clone()])]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_complete_with_phase_one() {
+        let mut builder = tx_builder();
+        builder.validate_phase_one(500);
+        assert!(builder.complete(None).await.is_ok());
+
+        let mut builder = tx_builder();
+        builder.validate_phase_one(1000);
+        let Err(err) = builder.complete(None).await else {
+            panic!("expected phase-1 validation to fail");
+        };
+        assert!(format!("{:?}", err).contains("OutsideValidityInterval"));
+    }
+
+    #[tokio::test]
+    async fn test_complete_with_unresolved_reference_input() {
+        let mut builder = tx_builder();
+        let reference = utxo(1, "5000000").input;
+        builder
+            .read_only_tx_in_reference(&reference.tx_hash, reference.output_index, None)
+            .validate_phase_one(500);
+        let Err(err) = builder.complete(None).await else {
+            panic!("expected phase-1 validation to fail");
+        };
+        assert!(format!("{:?}", err).contains(&format!("{}#1", reference.tx_hash)));
+    }
+}
@@ -0,0 +1,258 @@
+mod common;
+
+#[cfg(test)]
+mod round_trip_tests {
+    use crate::common::{utxo, ADDRESS};
+    use serde_json::json;
+    use whisky::*;
+    use whisky_common::data::{byte_string, constr0};
+    use whisky_pallas::WhiskyPallas;
+
+    const OTHER_ADDRESS: &str = "addr_test1vru4e2un2tq50q4rv6qzk7t8w34gjdtw3y2uzuqxzj0ldrqqactxh";
+    const BYRON_ADDRESS: &str = "Ae2tdPwUPEZ4YjgvykNpoFeYUxoyhNj2kg8KfKWN2FizsSpLUPv68MpTVDo";
+    const STAKE_ADDRESS: &str = "stake_test1uraj0xqlekpdwlxeugg2s2qwq896n4kzkuhwxxnqggwpjeqe9s9k2";
+    const PAYMENT_KEY_HASH: &str = "64980e4d19ed6a2532aca6e7aaeb8922b6bc8f15b1ba2878d3bc2f27";
+    // Byron address of the "summer" x 24 mnemonic, whose key signed the Byron fixtures
+    const WALLET_BYRON_ADDRESS: &str =
+        "FHnt4NL7yPY3TM4hLqfCUBYdCuaH25PdMVFwyvBJ1sVzK7A8jG4XbeEwR5B4LH7";
+    const ALWAYS_SUCCEED: &str = "5251010000322253330034a229309b2b2b9a01";
+    // An info action, `[6]`
+    const INFO_ACTION: &str = "8106";
+
+    fn tx_in(tx_builder: &mut TxBuilder, utxo: &UTxO) {
+        tx_builder.tx_in(
+            &utxo.input.tx_hash,
+            utxo.input.output_index,
+            &utxo.output.amount,
+            &utxo.output.address,
+        );
+    }
+
+    fn anchor() -> Anchor {
+        Anchor {
+            anchor_url: "https://example.com/proposal.jsonld".to_string(),
+            anchor_data_hash: "2a3f8c5b0d7e4a1c9f6b3e8d2a5c7f1b4e9d6a3c8f2b5e7d1a4c9f6b3e8d2a5c"
+                .to_string(),
+        }
+    }
+
+    fn spend_tx() -> (String, Vec<UTxO>) {
+        let utxos = vec![utxo(0, "9496607660")];
+        let mut tx_builder = TxBuilder::new_core();
+        tx_in(&mut tx_builder, &utxos[0]);
+        tx_builder
+            .tx_out(OTHER_ADDRESS, &[Asset::new_from_str("lovelace", "2000000")])
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        (tx_builder.tx_hex(), utxos)
+    }
+
+    fn byron_spend_tx() -> (String, Vec<UTxO>) {
+        let mut utxos = vec![utxo(3, "5000000")];
+        utxos[0].output.address = BYRON_ADDRESS.to_string();
+        let mut tx_builder = TxBuilder::new_core();
+        tx_in(&mut tx_builder, &utxos[0]);
+        tx_builder
+            .tx_out(OTHER_ADDRESS, &[Asset::new_from_str("lovelace", "2000000")])
+            .change_address(BYRON_ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        (tx_builder.tx_hex(), utxos)
+    }
+
+    fn withdrawal_tx() -> (String, Vec<UTxO>) {
+        let utxos = vec![utxo(0, "9496607660"), utxo(1, "5000000")];
+        let mut tx_builder = TxBuilder::new_core();
+        for utxo in &utxos {
+            tx_in(&mut tx_builder, utxo);
+        }
+        tx_builder
+            .withdrawal(STAKE_ADDRESS, 0)
+            .required_signer_hash("fb27981fcd82d77cd9e210a8280e01cba9d6c2b72ee31a60421c1964")
+            .invalid_hereafter(99999999)
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        (tx_builder.tx_hex(), utxos)
+    }
+
+    fn proposal_tx() -> (String, Vec<UTxO>) {
+        let utxos = vec![utxo(2, "150000000000")];
+        let mut tx_builder = TxBuilder::new_core();
+        tx_in(&mut tx_builder, &utxos[0]);
+        tx_builder
+            .proposal(100000000000, STAKE_ADDRESS, INFO_ACTION, &anchor())
+            .treasury_value(1500000000000000)
+            .donation(1000000)
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        (tx_builder.tx_hex(), utxos)
+    }
+
+    fn script_utxo() -> UTxO {
+        let script_hash = get_script_hash(ALWAYS_SUCCEED, LanguageVersion::V3).unwrap();
+        let mut utxo = utxo(4, "20000000");
+        utxo.output.address = Address::enterprise(0, Credential::ScriptHash(script_hash))
+            .to_bech32()
+            .unwrap();
+        utxo.output.plutus_data = Some(
+            WData::JSON(constr0(json!([byte_string(PAYMENT_KEY_HASH)])).to_string())
+                .to_cbor()
+                .unwrap(),
+        );
+        utxo
+    }
+
+    fn wallet_byron_utxo() -> UTxO {
+        let mut utxo = utxo(3, "5000000");
+        utxo.output.address = WALLET_BYRON_ADDRESS.to_string();
+        utxo
+    }
+
+    // Signed transactions built by each backend: a Plutus V3 spend, stake registration and
+    // delegation certificates, a DRep vote and a Byron input spent with a bootstrap witness.
+    // The backends encode the same transaction differently, so each has its own fixtures.
+    fn signed_fixtures(tx_hexes: [&str; 4]) -> Vec<(String, Vec<UTxO>)> {
+        let [plutus_spend, certificates, votes, byron] = tx_hexes;
+        vec![
+            (
+                plutus_spend.trim().to_string(),
+                vec![utxo(0, "9496607660"), script_utxo()],
+            ),
+            (certificates.trim().to_string(), vec![utxo(1, "5000000000")]),
+            (votes.trim().to_string(), vec![utxo(2, "5000000000")]),
+            (byron.trim().to_string(), vec![wallet_byron_utxo()]),
+        ]
+    }
+
+    fn csl_signed_fixtures() -> Vec<(String, Vec<UTxO>)> {
+        signed_fixtures([
+            include_str!("round_trip/csl_plutus_spend.hex"),
+            include_str!("round_trip/csl_certificates.hex"),
+            include_str!("round_trip/csl_votes.hex"),
+            include_str!("round_trip/csl_byron.hex"),
+        ])
+    }
+
+    fn pallas_signed_fixtures() -> Vec<(String, Vec<UTxO>)> {
+        signed_fixtures([
+            include_str!("round_trip/pallas_plutus_spend.hex"),
+            include_str!("round_trip/pallas_certificates.hex"),
+            include_str!("round_trip/pallas_votes.hex"),
+            include_str!("round_trip/pallas_byron.hex"),
+        ])
+    }
+
+    // Witnesses are carried in `TxBuilderBody`, so a signed transaction rebuilds to itself.
+    async fn round_trip(tx_hex: &str, utxos: &[UTxO]) -> TxBuilderBody {
+        let mut tx_parser = TxParser::new(None);
+        tx_parser.parse(tx_hex, utxos).await.unwrap();
+        let body = tx_parser.get_builder_body_without_change();
+
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder.tx_builder_body = body.clone();
+        tx_builder.complete_sync(None).unwrap();
+        assert_eq!(tx_builder.tx_hex(), tx_hex);
+        body
+    }
+
+    fn pallas_round_trip(tx_hex: &str, utxos: &[UTxO]) -> TxBuilderBody {
+        let mut body = whisky_pallas::tx_parser::parse(tx_hex, utxos).unwrap();
+        body.outputs.pop();
+
+        let mut tx_builder = TxBuilder::new(TxBuilderParam {
+            serializer: Box::new(WhiskyPallas::new(None)),
+            evaluator: None,
+            fetcher: None,
+            submitter: None,
+            params: None,
+        });
+        tx_builder.tx_builder_body = body.clone();
+        tx_builder.complete_sync(None).unwrap();
+        assert_eq!(tx_builder.tx_hex(), tx_hex);
+        body
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_corpus() {
+        for (tx_hex, utxos) in [spend_tx(), byron_spend_tx(), withdrawal_tx(), proposal_tx()] {
+            round_trip(&tx_hex, &utxos).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_signed_csl() {
+        for (tx_hex, utxos) in csl_signed_fixtures() {
+            round_trip(&tx_hex, &utxos).await;
+        }
+    }
+
+    #[test]
+    fn test_round_trip_signed_pallas() {
+        for (tx_hex, utxos) in pallas_signed_fixtures() {
+            pallas_round_trip(&tx_hex, &utxos);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_carries_key_witnesses() {
+        let csl_fixtures = csl_signed_fixtures();
+        let pallas_fixtures = pallas_signed_fixtures();
+        for (index, vkey_count, bootstrap_count) in [(0, 1, 0), (1, 2, 0), (2, 2, 0), (3, 0, 1)] {
+            let (tx_hex, utxos) = &csl_fixtures[index];
+            let body = round_trip(tx_hex, utxos).await;
+            assert_eq!(body.vkey_witnesses.len(), vkey_count);
+            assert_eq!(body.bootstrap_witnesses.len(), bootstrap_count);
+
+            let (tx_hex, utxos) = &pallas_fixtures[index];
+            let body = pallas_round_trip(tx_hex, utxos);
+            assert_eq!(body.vkey_witnesses.len(), vkey_count);
+            assert_eq!(body.bootstrap_witnesses.len(), bootstrap_count);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_proposal() {
+        let (tx_hex, utxos) = proposal_tx();
+        let body = round_trip(&tx_hex, &utxos).await;
+        assert_eq!(
+            body.proposals,
+            vec![Proposal::BasicProposal(ProposalType {
+                deposit: 100000000000,
+                reward_account: STAKE_ADDRESS.to_string(),
+                gov_action: INFO_ACTION.to_string(),
+                anchor: anchor(),
+            })]
+        );
+        assert_eq!(body.treasury_value, Some(1500000000000000));
+        assert_eq!(body.donation, Some(1000000));
+    }
+
+    #[test]
+    fn test_pallas_parse_byron_input() {
+        let (tx_hex, utxos) = byron_spend_tx();
+        let body = whisky_pallas::tx_parser::parse(&tx_hex, &utxos).unwrap();
+        assert_eq!(body.inputs.len(), 1);
+        assert_eq!(body.inputs[0].to_utxo(), utxos[0]);
+        assert_eq!(body.change_address, BYRON_ADDRESS);
+    }
+
+    #[test]
+    fn test_pallas_parse_proposal() {
+        let (tx_hex, utxos) = proposal_tx();
+        let body = whisky_pallas::tx_parser::parse(&tx_hex, &utxos).unwrap();
+        assert_eq!(
+            body.proposals,
+            vec![Proposal::BasicProposal(ProposalType {
+                deposit: 100000000000,
+                reward_account: STAKE_ADDRESS.to_string(),
+                gov_action: INFO_ACTION.to_string(),
+                anchor: anchor(),
+            })]
+        );
+        assert_eq!(body.treasury_value, Some(1500000000000000));
+        assert_eq!(body.donation, Some(1000000));
+    }
+}
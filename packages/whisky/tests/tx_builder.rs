@@ -265,6 +265,24 @@ mod tx_builder_core_tests {
             });
     }
 
+    #[test]
+    fn test_metadata_u64_max_int() {
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                "fc1c806abc9981f4bee2ce259f61578c3341012f3d04f22e82e7e40c7e7e3c3c",
+                3,
+                &[Asset::new_from_str("lovelace", "9692479606")],
+                "addr_test1vpw22xesfv0hnkfw4k5vtrz386tfgkxu6f7wfadug7prl7s6gt89x",
+            )
+            .metadata(674, TransactionMetadatum::Int(u64::MAX as i128))
+            .change_address("addr_test1vpw22xesfv0hnkfw4k5vtrz386tfgkxu6f7wfadug7prl7s6gt89x")
+            .complete_sync(None)
+            .unwrap();
+        // The auxiliary data maps label 674 to the unsigned 64-bit int 2^64 - 1
+        assert!(tx_builder.tx_hex().contains("a11902a21bffffffffffffffff"));
+    }
+
     #[tokio::test]
     async fn test_wasm_request_with_offline_evaluation() {
         // This test builds a transaction from a WASM request JSON and triggers offline evaluation
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tx_parser_tests {
+    use whisky::*;
+
+    fn utxos() -> Vec<UTxO> {
+        let utxo_1: UTxO = serde_json::from_str("{\"input\":{\"outputIndex\":0,\"txHash\":\"1a6157c0c9e170d716aee64b25384cad275770e2ef86df31eeebda4892980723\"},\"output\":{\"address\":\"addr_test1qrs3jlcsapdufgagzt35ug3nncwl26mlkcux49gs673sflmrjfm6y2eu7del3pprckzt4jaal9s7w9gq5kguqs5pf6fq542mmq\",\"amount\":[{\"quantity\":\"10000000000\",\"unit\":\"lovelace\"}],\"dataHash\":null,\"plutusData\":null,\"scriptHash\":null,\"scriptRef\":null}}").unwrap();
+        let utxo_2: UTxO = serde_json::from_str("{\"input\":{\"outputIndex\":5,\"txHash\":\"158a0bff150e9c6f68a14fdb1623c363f54e36cb22efc800911bffafa4e53442\"},\"output\":{\"address\":\"addr_test1qra9zdhfa8kteyr3mfe7adkf5nlh8jl5xcg9e7pcp5w9yhyf5tek6vpnha97yd5yw9pezm3wyd77fyrfs3ynftyg7njs5cfz2x\",\"amount\":[{\"quantity\":\"5000000\",\"unit\":\"lovelace\"}],\"dataHash\":null,\"plutusData\":null,\"scriptHash\":null,\"scriptRef\":null}}").unwrap();
+        vec![utxo_1, utxo_2]
+    }
+
+    const TX_HEX: &str = "84a700d90102818258201a6157c0c9e170d716aee64b25384cad275770e2ef86df31eeebda4892980723000183a300581d70506245b8d10428549499ecfcd0435d5a0b9a3aac2c5bccc824441a7201821a001e8480a1581ceab3a1d125a3bf4cd941a6a0b5d7752af96fae7f5bcc641e8a0b6762a14001028201d818586ad8799fd8799fd8799f5041bfc7325343428683bbd0b94a4da41cd8799f581ce1197f10e85bc4a3a812e34e22339e1df56b7fb6386a9510d7a304ffffd8799f581c7c87b6b5a0963af3eadb107da2ac4e1d34747a4df363858b649aa845ffffffa140a1401a00989680ff82581d70ba3efbd72650cbc7d5d7e6bede007cd3cb6730ba1972debf1c2c098f1a007a120082583900e1197f10e85bc4a3a812e34e22339e1df56b7fb6386a9510d7a304ff639277a22b3cf373f88423c584bacbbdf961e71500a591c042814e921b0000000253704b3f021a0003024109a1581ceab3a1d125a3bf4cd941a6a0b5d7752af96fae7f5bcc641e8a0b6762a140010b5820d88d41dd788fcf7c3b1f15808e11b01d71e0413d57265ddb7fc5b5776ff16e720dd9010281825820158a0bff150e9c6f68a14fdb1623c363f54e36cb22efc800911bffafa4e53442050ed9010281581cfa5136e9e9ecbc9071da73eeb6c9a4ff73cbf436105cf8380d1c525ca207d901028158b558b30101009800aba2a6011e581cfa5136e9e9ecbc9071da73eeb6c9a4ff73cbf436105cf8380d1c525c00a6010746332d6d696e740048c8c8c8c88c88966002646464646464660020026eb0c038c03cc03cc03cc03cc03cc03cc03cc03cc030dd5180718061baa0072259800800c52844c96600266e3cdd71808001005c528c4cc00c00c00500d1808000a01c300c300d002300b001300b002300900130063754003149a26cac8028dd7000ab9a5573caae7d5d0905a182010082d87980821956861a0066ad1cf5f6";
+
+    const USER: &str = "addr_test1qrs3jlcsapdufgagzt35ug3nncwl26mlkcux49gs673sflmrjfm6y2eu7del3pprckzt4jaal9s7w9gq5kguqs5pf6fq542mmq";
+    const POLICY_ID: &str = "eab3a1d125a3bf4cd941a6a0b5d7752af96fae7f5bcc641e8a0b6762";
+
+    #[tokio::test]
+    async fn test_explain() {
+        let mut tx_parser = TxParser::new(None);
+        tx_parser.parse(TX_HEX, &utxos()).await.unwrap();
+        let explanation = tx_parser
+            .explain(&Protocol::default(), &SlotConfig::preprod())
+            .unwrap();
+        assert_eq!(explanation.fee, Some(197185));
+        assert_eq!(explanation.transfers[0].address, USER);
+        assert_eq!(
+            explanation.transfers[0].sent,
+            vec![Asset::new_from_str("lovelace", "10197185")]
+        );
+        assert_eq!(explanation.mints[0].policy_id, POLICY_ID);
+        assert_eq!(explanation.mints[0].quantity, 1);
+        assert!(explanation
+            .to_markdown()
+            .contains("## Fee\n\n197185 lovelace"));
+    }
+
+    #[tokio::test]
+    async fn test_diff_fee() {
+        let mut tx_parser = TxParser::new(None);
+        tx_parser.parse(TX_HEX, &utxos()).await.unwrap();
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder.tx_builder_body = tx_parser.get_builder_body_without_change();
+        let after_hex = tx_builder
+            .set_fee("250000")
+            .complete_sync(None)
+            .unwrap()
+            .tx_hex();
+
+        let diff = tx_parser.diff(TX_HEX, &after_hex, &utxos()).await.unwrap();
+        assert_eq!(
+            diff.fee,
+            Some(AmountChange {
+                before: 197185,
+                after: 250000,
+                delta: 52815,
+            })
+        );
+        // The change output pays the fee increase
+        assert_eq!(diff.output_changes.len(), 1);
+        assert_eq!(diff.output_changes[0].address, USER);
+        assert!(diff.outputs_added.is_empty() && diff.outputs_removed.is_empty());
+    }
+}
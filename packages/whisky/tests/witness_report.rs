@@ -0,0 +1,155 @@
+mod common;
+
+#[cfg(test)]
+mod witness_report_tests {
+    use crate::common::{utxo, ADDRESS};
+    use serde_json::json;
+    use whisky::*;
+    use whisky_common::data::constr0;
+
+    const STAKE_ADDRESS: &str = "stake_test1uraj0xqlekpdwlxeugg2s2qwq896n4kzkuhwxxnqggwpjeqe9s9k2";
+    const PAYMENT_KEY_HASH: &str = "64980e4d19ed6a2532aca6e7aaeb8922b6bc8f15b1ba2878d3bc2f27";
+    const STAKE_KEY_HASH: &str = "fb27981fcd82d77cd9e210a8280e01cba9d6c2b72ee31a60421c1964";
+    const ALWAYS_SUCCEED: &str = "5251010000322253330034a229309b2b2b9a01";
+
+    fn utxos() -> Vec<UTxO> {
+        vec![utxo(0, "9496607660")]
+    }
+
+    fn withdrawal_tx(signing_keys: &[&str]) -> String {
+        let utxo = &utxos()[0];
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            )
+            .withdrawal(STAKE_ADDRESS, 0)
+            .required_signer_hash(STAKE_KEY_HASH)
+            .change_address(ADDRESS);
+        for signing_key in signing_keys {
+            tx_builder.signing_key(signing_key);
+        }
+        tx_builder.complete_sync(None).unwrap();
+        tx_builder.complete_signing().unwrap()
+    }
+
+    fn mint_v2_and_v3_tx() -> String {
+        let utxo = &utxos()[0];
+        let redeemer = WRedeemer {
+            data: WData::JSON(constr0(json!([])).to_string()),
+            ex_units: Budget {
+                mem: 7000000,
+                steps: 14000000,
+            },
+        };
+        let mut tx_builder = TxBuilder::new_core();
+        tx_builder
+            .tx_in(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            )
+            .tx_in_collateral(
+                &utxo.input.tx_hash,
+                utxo.input.output_index,
+                &utxo.output.amount,
+                &utxo.output.address,
+            );
+        for version in [LanguageVersion::V2, LanguageVersion::V3] {
+            let policy_id = get_script_hash(ALWAYS_SUCCEED, version.clone()).unwrap();
+            match version {
+                LanguageVersion::V2 => tx_builder.mint_plutus_script_v2(),
+                _ => tx_builder.mint_plutus_script_v3(),
+            };
+            tx_builder
+                .mint(1, &policy_id, "7465737431")
+                .mint_redeemer_value(&redeemer)
+                .minting_script(ALWAYS_SUCCEED);
+        }
+        tx_builder
+            .change_address(ADDRESS)
+            .complete_sync(None)
+            .unwrap();
+        tx_builder.tx_hex()
+    }
+
+    async fn csl_report(tx_hex: &str) -> WitnessReport {
+        let mut tx_parser = TxParser::new(None);
+        tx_parser.parse(tx_hex, &utxos()).await.unwrap();
+        tx_parser.witness_report(&Network::Preprod).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_witness_report_signed() {
+        let tx_hex = withdrawal_tx(&[
+            "58208d4cfa90e8bd0c48c52d2fb62c77ba3f6f5eb46f640d5f997390012928d670f7",
+            "5820ba73019f1239fa47f8d9c0c42c5d05bf34f2b2f6ebd1c556f8f86e5bee1aac66",
+        ]);
+        let report = csl_report(&tx_hex).await;
+        assert_eq!(
+            report.required_vkeys,
+            vec![
+                RequiredVkey {
+                    key_hash: PAYMENT_KEY_HASH.to_string(),
+                    sources: vec![VkeySource::Input(utxos()[0].input.clone())],
+                    signed: true,
+                },
+                RequiredVkey {
+                    key_hash: STAKE_KEY_HASH.to_string(),
+                    sources: vec![
+                        VkeySource::Withdrawal(STAKE_ADDRESS.to_string()),
+                        VkeySource::RequiredSigner
+                    ],
+                    signed: true,
+                },
+            ]
+        );
+        assert!(report.extraneous_vkeys.is_empty());
+        assert_eq!(report.script_data_hash, ScriptDataHashCheck::default());
+        assert!(report.is_valid());
+
+        let pallas_report =
+            whisky_pallas::tx_parser::witness_report(&tx_hex, &utxos(), &Network::Preprod).unwrap();
+        assert_eq!(pallas_report, report);
+    }
+
+    #[tokio::test]
+    async fn test_witness_report_unsigned() {
+        let tx_hex = withdrawal_tx(&[]);
+        let report = csl_report(&tx_hex).await;
+        assert_eq!(
+            report.missing_vkeys(),
+            vec![PAYMENT_KEY_HASH.to_string(), STAKE_KEY_HASH.to_string()]
+        );
+        assert!(!report.is_valid());
+
+        let pallas_report =
+            whisky_pallas::tx_parser::witness_report(&tx_hex, &utxos(), &Network::Preprod).unwrap();
+        assert_eq!(pallas_report.missing_vkeys(), report.missing_vkeys());
+    }
+
+    #[test]
+    fn test_witness_report_unresolved_input() {
+        let tx_hex = withdrawal_tx(&[]);
+        let report =
+            whisky_pallas::tx_parser::witness_report(&tx_hex, &[], &Network::Preprod).unwrap();
+        assert_eq!(report.unresolved_inputs, vec![utxos()[0].input.clone()]);
+        assert!(!report.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_witness_report_script_data_hash_mixed_languages() {
+        let tx_hex = mint_v2_and_v3_tx();
+        let report = csl_report(&tx_hex).await;
+        assert!(report.script_data_hash.computed.is_some());
+        assert!(report.script_data_hash.matches());
+
+        let pallas_report =
+            whisky_pallas::tx_parser::witness_report(&tx_hex, &utxos(), &Network::Preprod).unwrap();
+        assert_eq!(pallas_report.script_data_hash, report.script_data_hash);
+    }
+}